@@ -1,36 +1,129 @@
 use anyhow::Result;
 use tokio::net::TcpListener;
+use tonic::codec::CompressionEncoding;
 use tonic::transport::{Channel, Server};
 use tonic::Code;
 use zkauth::discrete_logarithm::{
     configuration::DiscreteLogarithmConfiguration, verifier::DiscreteLogarithmVerifier,
 };
-use zkauth_client::{client::Client, AuthClient};
-use zkauth_protobuf::v1::auth_server::AuthServer;
+use zkauth::elliptic_curve::{
+    configuration::EllipticCurveConfiguration, verifier::EllipticCurveVerifier,
+};
+use zkauth::Scalar;
+use zkauth_client::{
+    client::{connect, Client},
+    pool::ClientPool,
+    AuthClient,
+};
+use zkauth_protobuf::bundle::{ParameterBundle, SigningKey};
+use zkauth_protobuf::v1::{
+    auth_server::AuthServer, configuration::Flavor, watch_sessions_response, Configuration,
+    RegisterRequest, WatchSessionsRequest,
+};
+use zkauth_server::audit::MetadataInterceptor;
+use zkauth_server::cli::config::ConfigFlavor;
+use zkauth_server::router::FlavorRouter;
 use zkauth_server::service::Service;
 
-fn test_service() -> Service {
-    let config = DiscreteLogarithmConfiguration::generate(16);
+fn test_service(config: DiscreteLogarithmConfiguration) -> Service {
     let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
     Service::new(config.into(), verifier)
 }
 
-async fn start_server_in_background() -> Result<AuthClient<Channel>> {
+async fn start_auth_server_in_background(server: AuthServer<Service>) -> Result<String> {
     let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
-    let address = format!("http://{}", listener.local_addr().unwrap().to_string());
+    let address = format!("http://{}", listener.local_addr().unwrap());
 
     tokio::spawn(async move {
         Server::builder()
-            .add_service(AuthServer::new(test_service()))
+            .add_service(server)
             .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
             .await
     });
 
+    Ok(address)
+}
+
+async fn start_server_with_config_in_background(
+    config: DiscreteLogarithmConfiguration,
+) -> Result<String> {
+    start_auth_server_in_background(AuthServer::new(test_service(config))).await
+}
+
+async fn start_server_in_background() -> Result<AuthClient<Channel>> {
+    let config = DiscreteLogarithmConfiguration::generate(16);
+    let address = start_server_with_config_in_background(config).await?;
+
     let client = AuthClient::connect(address).await.unwrap();
 
     Ok(client)
 }
 
+#[tokio::test]
+async fn fetch_configuration_returns_server_flavor() -> Result<()> {
+    let config = DiscreteLogarithmConfiguration::generate(16);
+    let address = start_server_with_config_in_background(config.clone()).await?;
+
+    let fetched = Client::fetch_configuration(address).await.unwrap();
+    assert!(matches!(fetched.flavor, Some(Flavor::DiscreteLogarithm(_))));
+    assert_eq!(fetched.flavor, Configuration::from(config).flavor);
+    assert_eq!(fetched.protocol_version, zkauth::PROTOCOL_VERSION);
+
+    Ok(())
+}
+
+/// Tests that a server serving both flavors authenticates a client of each flavor, selected by the
+/// path of the address it connects to.
+#[tokio::test]
+async fn register_login_succeeds_for_each_flavor_when_serving_both() -> Result<()> {
+    let elliptic_curve_config = EllipticCurveConfiguration::generate();
+    let elliptic_curve_service = Service::new(
+        elliptic_curve_config.clone().into(),
+        Box::new(EllipticCurveVerifier::new(elliptic_curve_config)),
+    );
+    let router = FlavorRouter::new(
+        AuthServer::new(test_service(DiscreteLogarithmConfiguration::generate(16))),
+        MetadataInterceptor::default(),
+    )
+    .with_flavor(
+        ConfigFlavor::EllipticCurve,
+        AuthServer::new(elliptic_curve_service),
+        MetadataInterceptor::default(),
+    );
+
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let address = format!("http://{}", listener.local_addr()?);
+    tokio::spawn(async move {
+        Server::builder()
+            .add_routes(router.into_routes())
+            .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
+            .await
+    });
+
+    for (path, elliptic_curve) in [("", false), ("/elliptic-curve", true)] {
+        let address = format!("{}{}", address, path);
+        let config = Client::fetch_configuration(address.clone()).await.unwrap();
+        assert_eq!(
+            matches!(config.flavor, Some(Flavor::EllipticCurve(_))),
+            elliptic_curve,
+            "{}",
+            address
+        );
+
+        let prover = Client::new(
+            connect(address).await.unwrap(),
+            "user".to_string(),
+            "password".to_string(),
+        )
+        .await
+        .unwrap();
+        prover.register().await.unwrap();
+        prover.login().await.unwrap();
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn register_login_succeeds() -> Result<()> {
     let client = start_server_in_background().await.unwrap();
@@ -138,3 +231,298 @@ async fn register_login_twice_with_different_users_succeeds() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn from_parameter_bundle_register_login_succeeds() -> Result<()> {
+    let config = DiscreteLogarithmConfiguration::generate(16);
+    let address = start_server_with_config_in_background(config.clone()).await?;
+    let bundle = ParameterBundle::new(config.into());
+
+    let prover =
+        Client::from_parameter_bundle(address, bundle, "user".to_string(), "password".to_string())
+            .await
+            .unwrap();
+
+    prover.register().await.unwrap();
+    prover.login().await.unwrap();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn from_signed_parameter_bundle_register_login_succeeds() -> Result<()> {
+    let config = DiscreteLogarithmConfiguration::generate(16);
+    let address = start_server_with_config_in_background(config.clone()).await?;
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let bundle = ParameterBundle::new(config.into()).sign(&key);
+
+    let prover = Client::from_signed_parameter_bundle(
+        address,
+        bundle,
+        &key.verifying_key(),
+        "user".to_string(),
+        "password".to_string(),
+    )
+    .await
+    .unwrap();
+
+    prover.register().await.unwrap();
+    prover.login().await.unwrap();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn from_parameter_bundle_fails_when_tampered() -> Result<()> {
+    let config = DiscreteLogarithmConfiguration::generate(16);
+    let address = start_server_with_config_in_background(config.clone()).await?;
+    let mut bundle = ParameterBundle::new(config.into());
+    if let Some(Flavor::DiscreteLogarithm(ref mut params)) = bundle.configuration.flavor {
+        params.h = "0".to_string();
+    }
+
+    let err =
+        Client::from_parameter_bundle(address, bundle, "user".to_string(), "password".to_string())
+            .await
+            .unwrap_err();
+    assert_eq!(err.code(), Code::InvalidArgument);
+    assert_eq!(
+        err.message(),
+        "Invalid parameter bundle: config version does not match"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn from_signed_parameter_bundle_fails_with_other_key() -> Result<()> {
+    let config = DiscreteLogarithmConfiguration::generate(16);
+    let address = start_server_with_config_in_background(config.clone()).await?;
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let other = SigningKey::from_bytes(&[8; 32]);
+    let bundle = ParameterBundle::new(config.into()).sign(&key);
+
+    let err = Client::from_signed_parameter_bundle(
+        address,
+        bundle,
+        &other.verifying_key(),
+        "user".to_string(),
+        "password".to_string(),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.code(), Code::InvalidArgument);
+    assert_eq!(
+        err.message(),
+        "Invalid parameter bundle: bundle signature is invalid"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn concurrent_logins_through_client_pool_succeed() -> Result<()> {
+    let config = DiscreteLogarithmConfiguration::generate(16);
+    let address = start_server_with_config_in_background(config).await?;
+    let pool = std::sync::Arc::new(ClientPool::new(address, 2)?);
+
+    let mut tasks = tokio::task::JoinSet::new();
+    for i in 0..32 {
+        let pool = pool.clone();
+        tasks.spawn(async move {
+            let client = pool
+                .client(format!("user-{}", i), "password".to_string())
+                .await?;
+            client.register().await?;
+            client.login().await
+        });
+    }
+    while let Some(result) = tasks.join_next().await {
+        result?.unwrap();
+    }
+
+    assert_eq!(pool.size().await, 2);
+
+    Ok(())
+}
+
+async fn start_server_with_signing_key_in_background(
+    key: Option<SigningKey>,
+) -> Result<AuthClient<Channel>> {
+    let mut service = test_service(DiscreteLogarithmConfiguration::generate(16));
+    if let Some(key) = key {
+        service = service.with_signing_key(key);
+    }
+    let address = start_auth_server_in_background(AuthServer::new(service)).await?;
+
+    Ok(AuthClient::connect(address).await?)
+}
+
+#[tokio::test]
+async fn login_with_server_verifying_key_succeeds() -> Result<()> {
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let client = start_server_with_signing_key_in_background(Some(key.clone())).await?;
+
+    let prover = Client::new(client, "user".to_string(), "password".to_string())
+        .await
+        .unwrap()
+        .with_server_verifying_key(key.verifying_key());
+
+    prover.register().await.unwrap();
+    prover.login().await.unwrap();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn login_with_server_verifying_key_fails_with_other_key() -> Result<()> {
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let other = SigningKey::from_bytes(&[8; 32]);
+    let client = start_server_with_signing_key_in_background(Some(other)).await?;
+
+    let prover = Client::new(client, "user".to_string(), "password".to_string())
+        .await
+        .unwrap()
+        .with_server_verifying_key(key.verifying_key());
+
+    prover.register().await.unwrap();
+    let err = prover.login().await.unwrap_err();
+    assert_eq!(err.code(), Code::Unauthenticated);
+    assert_eq!(
+        err.message(),
+        "Invalid server signature: challenge signature is invalid"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn login_with_server_verifying_key_fails_when_unsigned() -> Result<()> {
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let client = start_server_with_signing_key_in_background(None).await?;
+
+    let prover = Client::new(client, "user".to_string(), "password".to_string())
+        .await
+        .unwrap()
+        .with_server_verifying_key(key.verifying_key());
+
+    prover.register().await.unwrap();
+    let err = prover.login().await.unwrap_err();
+    assert_eq!(err.code(), Code::Unauthenticated);
+    assert_eq!(
+        err.message(),
+        "Invalid server signature: challenge is not signed"
+    );
+
+    Ok(())
+}
+
+async fn start_server_with_max_decoding_message_size_in_background(
+    max_decoding_message_size: usize,
+) -> Result<AuthClient<Channel>> {
+    let service = test_service(DiscreteLogarithmConfiguration::generate(16));
+    let address =
+        start_auth_server_in_background(service.into_server(max_decoding_message_size)).await?;
+
+    let client = AuthClient::connect(address).await.unwrap();
+
+    Ok(client)
+}
+
+#[tokio::test]
+async fn register_fails_with_oversized_message() -> Result<()> {
+    let mut client = start_server_with_max_decoding_message_size_in_background(1024).await?;
+
+    let err = client
+        .register(RegisterRequest {
+            user: "user".to_string(),
+            y1: "1".repeat(2048),
+            y2: "1".to_string(),
+            metadata: Default::default(),
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::OutOfRange);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn register_fails_with_oversized_compressed_message() -> Result<()> {
+    let client = start_server_with_max_decoding_message_size_in_background(1024).await?;
+    let mut client = client.send_compressed(CompressionEncoding::Gzip);
+
+    // Highly compressible payload that expands well beyond the limit once decompressed.
+    let err = client
+        .register(RegisterRequest {
+            user: "user".to_string(),
+            y1: "1".repeat(1024 * 1024),
+            y2: "1".to_string(),
+            metadata: Default::default(),
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::Unimplemented);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn register_login_with_secret_succeeds() -> Result<()> {
+    let config = DiscreteLogarithmConfiguration::generate(16);
+    let secret: Scalar = (&config.q - 1u32).into();
+    let address = start_server_with_config_in_background(config).await?;
+    let client = AuthClient::connect(address).await?;
+
+    let prover = Client::new_with_secret(client, "user".to_string(), secret)
+        .await
+        .unwrap();
+
+    prover.register().await.unwrap();
+    prover.login().await.unwrap();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn new_with_secret_fails_when_out_of_range() -> Result<()> {
+    let config = DiscreteLogarithmConfiguration::generate(16);
+    let secret: Scalar = config.q.clone().into();
+    let address = start_server_with_config_in_background(config).await?;
+    let client = AuthClient::connect(address).await?;
+
+    let err = Client::new_with_secret(client, "user".to_string(), secret)
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::InvalidArgument);
+    assert_eq!(err.message(), "Invalid secret argument");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn watch_sessions_observes_created_after_login() -> Result<()> {
+    let mut client = start_server_in_background().await.unwrap();
+
+    let mut events = client
+        .watch_sessions(WatchSessionsRequest {})
+        .await?
+        .into_inner();
+
+    let prover = Client::new(client, "user".to_string(), "password".to_string())
+        .await
+        .unwrap();
+    prover.register().await.unwrap();
+    prover.login().await.unwrap();
+
+    let event = events.message().await?.unwrap();
+    match event.event {
+        Some(watch_sessions_response::Event::Created(created)) => {
+            assert_eq!(created.user, "user");
+            assert!(!created.session_id.is_empty());
+        }
+        other => panic!("unexpected event: {:?}", other),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+//! A blocklist of breached passwords, checked locally before a password is used.
+//!
+//! The blocklist is loaded from a file of full hex-encoded SHA-1 hashes, one per line, such as the
+//! downloadable "Have I Been Pwned" password list. A line may have a `:count` suffix as in that
+//! list's format, and blank lines and lines starting with `#` are ignored. A password is blocked
+//! if the SHA-1 hash of its UTF-8 bytes is in the list, so only hashes, never passwords, need to
+//! be distributed. Hash prefixes are not accepted, since a short prefix such as a k-anonymity
+//! range prefix matches many unrelated passwords.
+
+use anyhow::{Error, Result};
+use std::collections::HashSet;
+use zkauth_protobuf::error::AuthError;
+
+/// The number of hex digits in a full SHA-1 hash.
+const SHA1_HEX_LENGTH: usize = 40;
+
+/// A set of SHA-1 hashes of breached passwords.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PasswordBlocklist {
+    hashes: HashSet<String>,
+}
+
+/// Implementation of the password blocklist.
+impl PasswordBlocklist {
+    /// Loads a blocklist from a file of SHA-1 hashes.
+    /// # Errors
+    /// * Returns an error if the file cannot be read.
+    /// * Returns an error if a line is not a valid SHA-1 hash.
+    pub fn load_from_file(path: &str) -> Result<Self> {
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            Error::msg(format!(
+                "failed to read password blocklist '{}': {}",
+                path, e
+            ))
+        })?;
+        Self::parse(&contents)
+    }
+
+    /// Parses a blocklist from the contents of a file of SHA-1 hashes.
+    /// # Errors
+    /// * Returns an error if a line is not a valid SHA-1 hash.
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut blocklist = Self::default();
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let hash = line.split(':').next().unwrap_or_default().trim();
+            if hash.len() != SHA1_HEX_LENGTH || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(Error::msg(format!(
+                    "invalid SHA-1 hash on line {} of password blocklist: '{}'",
+                    index + 1,
+                    hash
+                )));
+            }
+            blocklist.hashes.insert(hash.to_ascii_uppercase());
+        }
+        Ok(blocklist)
+    }
+
+    /// Returns whether the SHA-1 hash of the password is in the blocklist.
+    pub fn is_blocked(&self, password: &str) -> bool {
+        let hash = sha1_smol::Sha1::from(password.as_bytes())
+            .digest()
+            .to_string()
+            .to_ascii_uppercase();
+        self.hashes.contains(&hash)
+    }
+
+    /// Checks that the password is not in the blocklist.
+    /// # Errors
+    /// * Returns an invalid argument error if the password is blocked.
+    pub fn check(&self, password: &str) -> Result<(), AuthError> {
+        if self.is_blocked(password) {
+            return Err(AuthError::InvalidArgument(
+                "Invalid password argument: found in a list of breached passwords".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> PasswordBlocklist {
+        PasswordBlocklist::parse(include_str!("testdata/password_blocklist.txt")).unwrap()
+    }
+
+    #[test]
+    fn blocks_passwords_in_the_list() {
+        let blocklist = fixture();
+        assert!(blocklist.is_blocked("password"));
+        assert!(blocklist.is_blocked("123456"));
+        assert!(matches!(
+            blocklist.check("password"),
+            Err(AuthError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn allows_passwords_not_in_the_list() {
+        let blocklist = fixture();
+        assert!(!blocklist.is_blocked("correct horse battery staple"));
+        assert!(blocklist.check("correct horse battery staple").is_ok());
+    }
+
+    #[test]
+    fn allows_passwords_sharing_a_prefix_with_a_listed_hash() {
+        // The SHA-1 hash of "pass611807" starts with 5BAA6, like the listed hash of "password".
+        let blocklist = fixture();
+        assert!(!blocklist.is_blocked("pass611807"));
+    }
+
+    #[test]
+    fn empty_blocklist_allows_everything() {
+        let blocklist = PasswordBlocklist::parse("# no hashes\n").unwrap();
+        assert!(!blocklist.is_blocked("password"));
+    }
+
+    #[test]
+    fn rejects_invalid_hashes() {
+        for contents in ["5BAA6", &"G".repeat(40), &"A".repeat(39), &"A".repeat(41)] {
+            let err = PasswordBlocklist::parse(contents).unwrap_err();
+            assert!(
+                err.to_string().starts_with("invalid SHA-1 hash on line 1"),
+                "{}",
+                err
+            );
+        }
+    }
+}
@@ -11,23 +11,185 @@
 //!
 //! This command will register and login the user `user` with the password `password` using the
 //! zkauth protocol at the address `http://localhost:5001`.
+//!
+//! ```sh
+//! zkauth-client verify --config-path config.json --proof-path proof.json
+//! ```
+//!
+//! This command will check a single proof against the public parameters in `config.json`, without
+//! any network or store, and print whether the verification equations hold.
+//!
+//! ```sh
+//! zkauth-client config --address http://localhost:5001
+//! ```
+//!
+//! This command will print the configuration of the server at `http://localhost:5001` as JSON,
+//! without a user or password.
+
+use crate::{
+    blocklist::PasswordBlocklist,
+    client::{connect, connect_with_tls, Client},
+};
+use anyhow::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use zkauth::{
+    discrete_logarithm::verifier::DiscreteLogarithmVerifier,
+    elliptic_curve::{
+        curve::{Curve, Ristretto, Secp256k1},
+        verifier::EllipticCurveVerifier,
+    },
+    Element, Scalar, Verifier,
+};
+use zkauth_protobuf::{
+    config_file::ConfigFile,
+    v1::{configuration::Flavor, Configuration},
+};
+
+/// A full proof, with each value encoded as a decimal string as on the wire.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Proof {
+    /// The registration y1 value.
+    pub y1: String,
+    /// The registration y2 value.
+    pub y2: String,
+    /// The challenge commitment r1 value.
+    pub r1: String,
+    /// The challenge commitment r2 value.
+    pub r2: String,
+    /// The challenge c value.
+    pub c: String,
+    /// The challenge response s value.
+    pub s: String,
+}
+
+/// A server configuration along with its version, as printed by the config command.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServerConfiguration {
+    /// The version of the configuration.
+    pub version: String,
+    /// The configuration, including its flavor and public parameters.
+    pub configuration: Configuration,
+}
+
+/// The TLS settings for connecting to a server with a pinned CA.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsOptions {
+    /// The PEM of the CA certificate the server's certificate must be signed by.
+    pub ca_pem: Vec<u8>,
+    /// The domain the server's certificate must be valid for, instead of the host of the address.
+    pub domain: Option<String>,
+}
+
+/// Implementation of the TLS options.
+impl TlsOptions {
+    /// Loads the CA certificate from a PEM file.
+    pub fn load_from_file(ca_path: &str, domain: Option<String>) -> Result<Self> {
+        let ca_pem = std::fs::read(ca_path)
+            .map_err(|e| Error::msg(format!("Failed to read TLS CA {}: {}", ca_path, e)))?;
+        Ok(TlsOptions { ca_pem, domain })
+    }
+}
+
+/// Loads a configuration from a JSON file, as written by the server.
+pub fn load_config_from_file(path: &str) -> Result<Configuration> {
+    let value = serde_json::from_reader(File::open(path)?)?;
+    Ok(ConfigFile::parse_value(value)?)
+}
+
+/// Loads a proof from a JSON file.
+pub fn load_proof_from_file(path: &str) -> Result<Proof> {
+    Ok(serde_json::from_reader(File::open(path)?)?)
+}
+
+/// Verifies a single proof against the given configuration, returning whether the verification
+/// equations hold.
+/// # Errors
+/// * Returns an error if the configuration is unknown or cannot be converted.
+/// * Returns an error if any of the proof values cannot be parsed.
+pub fn verify(config: Configuration, proof: Proof) -> Result<bool> {
+    let verifier: Box<dyn Verifier> = match config.flavor {
+        Some(Flavor::DiscreteLogarithm(config)) => {
+            Box::new(DiscreteLogarithmVerifier::new(config.try_into().map_err(
+                |_| Error::msg("Failed to convert discrete logarithm configuration"),
+            )?))
+        }
+        Some(Flavor::EllipticCurve(config)) if config.curve_name() == Secp256k1::NAME => {
+            Box::new(EllipticCurveVerifier::<Secp256k1>::new(
+                config
+                    .try_into()
+                    .map_err(|_| Error::msg("Failed to convert elliptic curve configuration"))?,
+            ))
+        }
+        Some(Flavor::EllipticCurve(config)) => Box::new(EllipticCurveVerifier::<Ristretto>::new(
+            config
+                .try_into()
+                .map_err(|_| Error::msg("Failed to convert elliptic curve configuration"))?,
+        )),
+        None => return Err(Error::msg("unknown configuration")),
+    };
 
-use crate::{client::Client, AuthClient};
-use anyhow::Result;
+    let element = |name: &str, value: &str| {
+        value
+            .parse::<Element>()
+            .map_err(|_| Error::msg(format!("Invalid {} argument", name)))
+    };
+    let scalar = |name: &str, value: &str| {
+        value
+            .parse::<Scalar>()
+            .map_err(|_| Error::msg(format!("Invalid {} argument", name)))
+    };
+
+    let y1 = element("y1", &proof.y1)?;
+    let y2 = element("y2", &proof.y2)?;
+    let r1 = element("r1", &proof.r1)?;
+    let r2 = element("r2", &proof.r2)?;
+    let c = scalar("c", &proof.c)?;
+    let s = scalar("s", &proof.s)?;
+
+    // Invalid points or values are reported as a failed verification rather than an error.
+    match verifier.compute_verification_r1r2(y1, y2, c, s) {
+        Ok((r1_prime, r2_prime)) => Ok(r1_prime == r1 && r2_prime == r2),
+        Err(_) => Ok(false),
+    }
+}
+
+/// Fetches the configuration of the server at the given address, without a user or password.
+pub async fn config(address: String) -> Result<ServerConfiguration> {
+    let configuration = Client::fetch_configuration(address).await?;
+    Ok(ServerConfiguration {
+        version: configuration.config_version(),
+        configuration,
+    })
+}
 
-/// Runs the zkauth client.
+/// Runs the zkauth client. When registering with a password blocklist, a password found in it is
+/// rejected before anything is derived from it. With TLS options, the connection is made over TLS
+/// to a server whose certificate is signed by the given CA.
 pub async fn run(
     address: String,
     user: String,
     password: String,
     register: bool,
     login: bool,
+    password_blocklist: Option<&PasswordBlocklist>,
+    tls: Option<&TlsOptions>,
 ) -> Result<()> {
-    let client = AuthClient::connect(address).await?;
-    let prover = Client::new(client, user, password).await?;
+    let client = match tls {
+        Some(tls) => connect_with_tls(address, &tls.ca_pem, tls.domain.as_deref()).await?,
+        None => connect(address).await?,
+    };
+    let prover = match password_blocklist {
+        Some(blocklist) if register => {
+            Client::new_with_password_blocklist(client, user, password, blocklist).await?
+        }
+        _ => Client::new(client, user, password).await?,
+    };
 
+    // Registering again with the same credentials is harmless, so an already registered user
+    // is not an error.
     if register {
-        prover.register().await?;
+        prover.ensure_registered().await?;
     }
 
     if login {
@@ -56,7 +218,7 @@ mod run {
 
     async fn start_server_in_background() -> Result<String> {
         let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
-        let address = format!("http://{}", listener.local_addr().unwrap().to_string());
+        let address = format!("http://{}", listener.local_addr().unwrap());
 
         tokio::spawn(async move {
             Server::builder()
@@ -68,6 +230,18 @@ mod run {
         Ok(address)
     }
 
+    #[tokio::test]
+    async fn config_returns_server_configuration() -> Result<()> {
+        let address = start_server_in_background().await?;
+        let config = config(address).await?;
+        assert!(matches!(
+            config.configuration.flavor,
+            Some(Flavor::DiscreteLogarithm(_))
+        ));
+        assert_eq!(config.version, config.configuration.config_version());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn succeeds() -> Result<()> {
         let address = start_server_in_background().await?;
@@ -77,8 +251,83 @@ mod run {
             "password".to_string(),
             true,
             true,
+            None,
+            None,
         )
         .await?;
         Ok(())
     }
+
+    #[tokio::test]
+    async fn succeeds_when_already_registered() -> Result<()> {
+        let address = start_server_in_background().await?;
+        for _ in 0..2 {
+            run(
+                address.clone(),
+                "user".to_string(),
+                "password".to_string(),
+                true,
+                true,
+                None,
+                None,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod verify {
+    use super::*;
+    use zkauth::discrete_logarithm::{
+        configuration::DiscreteLogarithmConfiguration, prover::DiscreteLogarithmProver,
+    };
+    use zkauth::Prover;
+
+    fn test_proof(config: &DiscreteLogarithmConfiguration) -> Result<Proof> {
+        let prover = DiscreteLogarithmProver::new(config.clone());
+        let verifier = DiscreteLogarithmVerifier::new(config.clone());
+        let x = prover.compute_registration_x("password".to_string());
+        let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+        let k = prover.generate_challenge_k();
+        let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+        let c = verifier.generate_challenge_c();
+        let s = prover.compute_challenge_response_s(&x, &k, c.clone())?;
+        Ok(Proof {
+            y1: y1.to_string(),
+            y2: y2.to_string(),
+            r1: r1.to_string(),
+            r2: r2.to_string(),
+            c: c.to_string(),
+            s: s.to_string(),
+        })
+    }
+
+    #[test]
+    fn succeeds_with_valid_proof() -> Result<()> {
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let proof = test_proof(&config)?;
+        assert!(verify(config.into(), proof)?);
+        Ok(())
+    }
+
+    #[test]
+    fn fails_with_invalid_proof() -> Result<()> {
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let mut proof = test_proof(&config)?;
+        proof.r1 = "0".to_string();
+        assert!(!verify(config.into(), proof)?);
+        Ok(())
+    }
+
+    #[test]
+    fn returns_error_with_unparseable_value() -> Result<()> {
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let mut proof = test_proof(&config)?;
+        proof.s = "not-a-number".to_string();
+        let err = verify(config.into(), proof).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid s argument");
+        Ok(())
+    }
 }
@@ -1,20 +1,106 @@
-use tonic::{transport::Channel, Status};
+use crate::blocklist::PasswordBlocklist;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::SystemTime;
+use tokio::sync::OnceCell;
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 use zkauth::{
     discrete_logarithm::prover::DiscreteLogarithmProver,
-    elliptic_curve::prover::EllipticCurveProver, Prover, Scalar,
+    elliptic_curve::{
+        curve::{Curve, Ristretto, Secp256k1},
+        prover::EllipticCurveProver,
+    },
+    Element, ProofBinding, Prover, Scalar,
 };
-use zkauth_protobuf::v1::{
-    auth_client::AuthClient, configuration::Flavor, AuthenticationAnswerRequest,
-    AuthenticationChallengeRequest, GetConfigurationRequest, RegisterRequest,
+use zkauth_protobuf::{
+    bundle::{ParameterBundle, VerifyingKey},
+    error::AuthError,
+    v1::{
+        auth_client::AuthClient, configuration::Flavor, AuthenticateRequest,
+        AuthenticationAnswerRequest, AuthenticationChallengeRequest, Configuration,
+        GetConfigurationRequest, RegisterRequest,
+    },
 };
 
+/// Stands in for secrets in `Debug` output.
+const REDACTED: &str = "<redacted>";
+
 /// Client for the authentication protocol.
-#[derive(Debug)]
+///
+/// The secret x, computed from the password or given directly, is the only secret the client
+/// holds, and is wiped when the client is dropped. It is only lent to the prover, which wipes any
+/// copy it converts it into, such as a curve scalar, once it is done with it. The random k of each
+/// login is wiped once the commitment is computed, except for the copy in the returned
+/// `LoginState`, which is wiped when the state is dropped. Neither is included in `Debug` output.
 pub struct Client {
     client: AuthClient<Channel>,
     prover: Box<dyn Prover>,
     user: String,
     x: Scalar,
+    server_key: Option<VerifyingKey>,
+}
+
+/// Wipes the secret x when the client is dropped.
+impl Drop for Client {
+    fn drop(&mut self) {
+        self.zeroize_secrets();
+    }
+}
+
+/// Marks the client as wiping its secrets when dropped.
+impl ZeroizeOnDrop for Client {}
+
+/// Formats the client without its secret x.
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("client", &self.client)
+            .field("prover", &self.prover)
+            .field("user", &self.user)
+            .field("x", &REDACTED)
+            .field("server_key", &self.server_key)
+            .finish()
+    }
+}
+
+/// The state of a login between sending the commitment and answering the challenge, holding the
+/// random k the commitment was computed from. It can be serialized to answer the challenge later,
+/// such as in another process, and must be kept secret, since k and s together reveal x.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoginState {
+    /// The user logging in.
+    pub user: String,
+    /// The random k, as a decimal string.
+    pub k: String,
+}
+
+/// Wipes the random k when the login state is dropped.
+impl Drop for LoginState {
+    fn drop(&mut self) {
+        self.zeroize_secrets();
+    }
+}
+
+/// Marks the login state as wiping its k when dropped.
+impl ZeroizeOnDrop for LoginState {}
+
+/// Formats the login state without its random k.
+impl fmt::Debug for LoginState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoginState")
+            .field("user", &self.user)
+            .field("k", &REDACTED)
+            .finish()
+    }
+}
+
+/// Implementation of the login state.
+impl LoginState {
+    /// Wipes the random k.
+    fn zeroize_secrets(&mut self) {
+        self.k.zeroize();
+    }
 }
 
 /// Implementation of the client.
@@ -24,36 +110,173 @@ impl Client {
     /// * Returns an error if the user or password is invalid.
     /// * Returns an error if the configuration is unknown or cannot be converted.
     pub async fn new(
-        mut client: AuthClient<Channel>,
+        client: AuthClient<Channel>,
         user: String,
         password: String,
-    ) -> Result<Self, Status> {
-        if user.is_empty() {
-            return Err(Status::invalid_argument("Invalid user argument"));
+    ) -> Result<Self, AuthError> {
+        validate_credentials(&user, &password)?;
+
+        // Get the configuration from the server.
+        let config = Self::get_configuration(client.clone()).await?;
+
+        Self::from_configuration(client, config, user, password)
+    }
+
+    /// Creates a new client given a user and password like `new`, but first rejects a password
+    /// found in the given blocklist of breached passwords, before anything is derived from it.
+    /// # Errors
+    /// * Returns an error if the password is in the blocklist.
+    /// * Returns the same errors as `new`.
+    pub async fn new_with_password_blocklist(
+        client: AuthClient<Channel>,
+        user: String,
+        password: String,
+        blocklist: &PasswordBlocklist,
+    ) -> Result<Self, AuthError> {
+        validate_credentials(&user, &password)?;
+        blocklist.check(&password)?;
+
+        Self::new(client, user, password).await
+    }
+
+    /// Creates a new client given a user and password, checking that the server's configuration
+    /// has the pinned config version before computing anything from the password. This keeps a
+    /// man in the middle from substituting weak parameters.
+    /// # Errors
+    /// * Returns an error if the user or password is invalid.
+    /// * Returns an error if the server's config version does not match the pinned version.
+    /// * Returns an error if the configuration is unknown or cannot be converted.
+    pub async fn new_with_pinned_config(
+        client: AuthClient<Channel>,
+        user: String,
+        password: String,
+        expected_version: String,
+    ) -> Result<Self, AuthError> {
+        validate_credentials(&user, &password)?;
+
+        // Get the configuration from the server, and check it against the pin.
+        let config = Self::get_configuration(client.clone()).await?;
+        let version = config.config_version();
+        if !version.eq_ignore_ascii_case(expected_version.trim()) {
+            return Err(AuthError::FailedPrecondition(format!(
+                "Server configuration version {} does not match the pinned version {}",
+                version, expected_version
+            )));
         }
 
-        if password.is_empty() {
-            return Err(Status::invalid_argument("Invalid password argument"));
+        Self::from_configuration(client, config, user, password)
+    }
+
+    /// Creates a new client given a user and a secret x, such as a high-entropy or key-backed
+    /// credential, instead of computing x from a password.
+    /// # Errors
+    /// * Returns an error if the user is invalid.
+    /// * Returns an error if the secret is not in range for the configuration flavor.
+    /// * Returns an error if the configuration is unknown or cannot be converted.
+    pub async fn new_with_secret(
+        client: AuthClient<Channel>,
+        user: String,
+        secret: Scalar,
+    ) -> Result<Self, AuthError> {
+        if user.is_empty() {
+            return Err(AuthError::InvalidArgument(
+                "Invalid user argument".to_string(),
+            ));
         }
 
         // Get the configuration from the server.
-        let config = client
+        let config = Self::get_configuration(client.clone()).await?;
+
+        let prover = prover_from_configuration(config)?;
+        if !prover.is_valid_registration_x(&secret) {
+            return Err(AuthError::InvalidArgument(
+                "Invalid secret argument".to_string(),
+            ));
+        }
+
+        Ok(Client {
+            client,
+            prover,
+            user,
+            x: secret,
+            server_key: None,
+        })
+    }
+
+    /// Fetches the configuration from the server at the given address, without a user or password.
+    /// This is useful for diagnostics, such as checking which flavor and parameters a server uses.
+    /// # Errors
+    /// * Returns an error if the connection to the server fails.
+    /// * Returns an error if the configuration request fails.
+    pub async fn fetch_configuration(address: String) -> Result<Configuration, AuthError> {
+        let client = connect(address).await?;
+
+        Self::get_configuration(client).await
+    }
+
+    /// Gets the configuration from the server over an existing connection, without a user or
+    /// password.
+    /// # Errors
+    /// * Returns an error if the configuration request fails.
+    pub async fn get_configuration(
+        mut client: AuthClient<Channel>,
+    ) -> Result<Configuration, AuthError> {
+        Ok(client
             .get_configuration(GetConfigurationRequest {})
             .await?
-            .into_inner();
-        let prover: Box<dyn Prover> = match config.flavor {
-            Some(Flavor::DiscreteLogarithm(config)) => {
-                Box::new(DiscreteLogarithmProver::new(config.try_into().map_err(
-                    |_| Status::internal("failed to convert discrete logarithm configuration"),
-                )?))
-            }
-            Some(Flavor::EllipticCurve(config)) => {
-                Box::new(EllipticCurveProver::new(config.try_into().map_err(
-                    |_| Status::internal("failed to convert elliptic curve configuration"),
-                )?))
-            }
-            None => return Err(Status::internal("unknown configuration")),
-        };
+            .into_inner())
+    }
+
+    /// Creates a new client from a parameter bundle instead of fetching the configuration from the
+    /// server, connecting to the server at the given address.
+    /// # Errors
+    /// * Returns an error if the user or password is invalid.
+    /// * Returns an error if the bundle's config version does not match its configuration.
+    /// * Returns an error if the configuration is unknown or cannot be converted.
+    /// * Returns an error if the connection to the server fails.
+    pub async fn from_parameter_bundle(
+        address: String,
+        bundle: ParameterBundle,
+        user: String,
+        password: String,
+    ) -> Result<Self, AuthError> {
+        validate_credentials(&user, &password)?;
+        bundle
+            .verify()
+            .map_err(|e| AuthError::InvalidArgument(format!("Invalid parameter bundle: {}", e)))?;
+
+        let client = connect(address).await?;
+
+        Self::from_configuration(client, bundle.configuration, user, password)
+    }
+
+    /// Creates a new client from a signed parameter bundle, verifying its signature against the
+    /// given public key before trusting it.
+    /// # Errors
+    /// * Returns an error if the bundle is not signed by the given key.
+    /// * Returns the same errors as `from_parameter_bundle`.
+    pub async fn from_signed_parameter_bundle(
+        address: String,
+        bundle: ParameterBundle,
+        key: &VerifyingKey,
+        user: String,
+        password: String,
+    ) -> Result<Self, AuthError> {
+        bundle
+            .verify_signature(key)
+            .map_err(|e| AuthError::InvalidArgument(format!("Invalid parameter bundle: {}", e)))?;
+
+        Self::from_parameter_bundle(address, bundle, user, password).await
+    }
+
+    /// Creates a new client with the given configuration.
+    pub(crate) fn from_configuration(
+        client: AuthClient<Channel>,
+        config: Configuration,
+        user: String,
+        password: String,
+    ) -> Result<Self, AuthError> {
+        let prover = prover_from_configuration(config)?;
 
         // Convert password string to x number.
         let x = prover.compute_registration_x(password);
@@ -63,21 +286,31 @@ impl Client {
             prover,
             user,
             x,
+            server_key: None,
         })
     }
 
+    /// Sets the server's public key, so that each authentication challenge must be signed by the
+    /// server before it is answered.
+    pub fn with_server_verifying_key(mut self, key: VerifyingKey) -> Self {
+        self.server_key = Some(key);
+        self
+    }
+
     /// Registers the user by computing y1 and y2 and sending a registration request to the server.
     /// # Errors
     /// * Returns an error if the registration fails.
     /// * Returns an error if the y1 and y2 cannot be computed.
     /// * Returns an error if the registration request fails to the server.
     /// * Returns an error if the challenge response from the server is invalid.
-    pub async fn register(&self) -> Result<(), Status> {
+    pub async fn register(&self) -> Result<(), AuthError> {
         // Compute y1 and y2 for registration.
         let (y1, y2) = self
             .prover
-            .compute_registration_y1y2(self.x.clone())
-            .map_err(|_| Status::internal("failed to compute registration y1 and y2"))?;
+            .compute_registration_y1y2(&self.x)
+            .map_err(|_| {
+                AuthError::Internal("failed to compute registration y1 and y2".to_string())
+            })?;
         log::info!("y1 = {:?}", y1);
         log::info!("y2 = {:?}", y2);
 
@@ -89,6 +322,7 @@ impl Client {
                 user: self.user.clone(),
                 y1: y1.to_string(),
                 y2: y2.to_string(),
+                metadata: Default::default(),
             })
             .await?
             .into_inner();
@@ -98,21 +332,84 @@ impl Client {
         Ok(())
     }
 
-    /// Logs in the user by sending a challenge request to the server and verifying the response.
+    /// Registers the user like `register`, but treats the user already being registered as success,
+    /// so callers do not need to special-case it. Returns whether the user was newly registered.
     /// # Errors
-    /// * Returns an error if the challenge response fails.
-    /// * Returns an error if the verification fails.
-    pub async fn login(&self) -> Result<(), Status> {
+    /// * Returns an error if the registration fails for any other reason.
+    pub async fn ensure_registered(&self) -> Result<bool, AuthError> {
+        match self.register().await {
+            Ok(()) => Ok(true),
+            Err(AuthError::AlreadyExists(_)) => {
+                log::info!("User '{}' is already registered.", self.user);
+                Ok(false)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Wipes the secret x.
+    fn zeroize_secrets(&mut self) {
+        self.x.zeroize();
+    }
+
+    /// Starts a login by generating a random k and computing the commitment (r1, r2) from it,
+    /// returning the commitment to send in a challenge request along with the state needed to
+    /// answer the challenge with `finish_login`.
+    /// # Errors
+    /// * Returns an error if the commitment cannot be computed.
+    pub fn begin_login(&self) -> Result<((Element, Element), LoginState), AuthError> {
         // Generate random number k.
-        let k = self.prover.generate_challenge_k();
+        let mut k = self.prover.generate_challenge_k();
 
         // Compute commitment (r1, r2) for authentication challenge.
-        let (r1, r2) = self
-            .prover
-            .compute_challenge_commitment_r1r2(k.clone())
-            .map_err(|_| Status::internal("failed to compute challenge commitment"))?;
-        log::info!("r1 = {:?}", r1);
-        log::info!("r2 = {:?}", r2);
+        let commitment = self.prover.compute_challenge_commitment_r1r2(&k);
+        let state = LoginState {
+            user: self.user.clone(),
+            k: k.to_string(),
+        };
+        k.zeroize();
+        let (r1, r2) = commitment.map_err(|_| {
+            AuthError::Internal("failed to compute challenge commitment".to_string())
+        })?;
+        log::debug!("Computed the challenge commitment.");
+
+        Ok(((r1, r2), state))
+    }
+
+    /// Finishes a login started with `begin_login` by computing the response s to the
+    /// challenge c.
+    /// # Errors
+    /// * Returns an error if the state is for another user or its k is invalid.
+    /// * Returns an error if the challenge response cannot be computed.
+    pub fn finish_login(&self, state: LoginState, c: Scalar) -> Result<Scalar, AuthError> {
+        if state.user != self.user {
+            return Err(AuthError::InvalidArgument(
+                "Invalid login state argument: different user".to_string(),
+            ));
+        }
+        let mut k: Scalar = state
+            .k
+            .parse()
+            .map_err(|_| AuthError::InvalidArgument("Invalid login state argument".to_string()))?;
+
+        // Compute challenge response s.
+        let s = self.prover.compute_challenge_response_s(&self.x, &k, c);
+        k.zeroize();
+        let s = s.map_err(|_| {
+            AuthError::Internal("failed to compute challenge response s".to_string())
+        })?;
+        log::debug!("Computed the challenge response.");
+
+        Ok(s)
+    }
+
+    /// Logs in the user by sending a challenge request to the server and verifying the response.
+    /// # Errors
+    /// * Returns an error if the challenge response fails.
+    /// * Returns an error if a server key is set and the challenge is not signed by it.
+    /// * Returns an error if the verification fails.
+    pub async fn login(&self) -> Result<(), AuthError> {
+        let ((r1, r2), state) = self.begin_login()?;
 
         // Send create_authentication_challenge request.
         let resp = self
@@ -128,17 +425,19 @@ impl Client {
 
         log::info!("{:?}", resp);
 
+        // Authenticate the server before answering its challenge.
+        if let Some(key) = &self.server_key {
+            resp.verify_signature(key).map_err(|e| {
+                AuthError::Unauthenticated(format!("Invalid server signature: {}", e))
+            })?;
+        }
+
         let c: Scalar = resp
             .c
             .parse()
-            .map_err(|_| Status::internal("failed to parse c"))?;
+            .map_err(|_| AuthError::Internal("failed to parse c".to_string()))?;
 
-        // Compute challenge response s.
-        let s = self
-            .prover
-            .compute_challenge_response_s(self.x.clone(), k, c)
-            .map_err(|_| Status::internal("failed to compute challenge response s"))?;
-        log::info!("s = {:?}", s);
+        let s = self.finish_login(state, c)?;
 
         // Send verify_authentication request.
         let resp = self
@@ -147,6 +446,51 @@ impl Client {
             .verify_authentication(AuthenticationAnswerRequest {
                 auth_id: resp.auth_id,
                 s: s.to_string(),
+                c: resp.c,
+                mac: resp.mac,
+            })
+            .await?
+            .into_inner();
+
+        log::info!("{:?}", resp);
+
+        Ok(())
+    }
+
+    /// Logs in the user in a single round trip with a non-interactive proof, deriving the
+    /// challenge from the commitment, the user, and the current time instead of requesting one
+    /// from the server. The proof is bound to the user as given, so it must already be in the
+    /// form the server normalizes usernames to.
+    /// # Errors
+    /// * Returns an error if the proof cannot be computed.
+    /// * Returns an error if the verification fails, or the clocks of the client and the server
+    ///   differ by more than the server's freshness window.
+    pub async fn login_noninteractive(&self) -> Result<(), AuthError> {
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(|_| AuthError::Internal("system time is before the Unix epoch".to_string()))?
+            .as_secs();
+        let binding = ProofBinding {
+            user: self.user.clone(),
+            timestamp,
+        };
+        let proof = self
+            .prover
+            .compute_noninteractive_proof(&self.x, binding)
+            .map_err(|_| {
+                AuthError::Internal("failed to compute non-interactive proof".to_string())
+            })?;
+
+        // Send authenticate request.
+        let resp = self
+            .client
+            .clone()
+            .authenticate(AuthenticateRequest {
+                user: self.user.clone(),
+                r1: proof.context.r1.to_string(),
+                r2: proof.context.r2.to_string(),
+                s: proof.s.to_string(),
+                timestamp,
             })
             .await?
             .into_inner();
@@ -157,10 +501,218 @@ impl Client {
     }
 }
 
+/// Connects to the server at the given address. The path of the address, if any, prefixes every
+/// request path, such as /elliptic-curve to select that flavor on a server serving both.
+/// # Errors
+/// * Returns an error if the address is invalid.
+/// * Returns an error if the connection to the server fails.
+pub async fn connect(address: String) -> Result<AuthClient<Channel>, tonic::transport::Error> {
+    let endpoint = Endpoint::from_shared(address)?;
+    let origin = endpoint.uri().clone();
+    let channel = endpoint.connect().await?;
+    Ok(AuthClient::with_origin(channel, origin))
+}
+
+/// Connects to the server at the given https address over TLS, trusting only the CA certificate
+/// in the given PEM rather than the system roots. The server's certificate must be valid for the
+/// given domain, or for the host of the address if none is given, such as when connecting by IP
+/// address to a server whose certificate names its host.
+/// # Errors
+/// * Returns an error if the address is invalid.
+/// * Returns an error if the connection to the server fails, including when its certificate is
+///   not signed by the CA or is not valid for the domain.
+pub async fn connect_with_tls(
+    address: String,
+    ca_pem: &[u8],
+    domain: Option<&str>,
+) -> Result<AuthClient<Channel>, AuthError> {
+    let mut tls = ClientTlsConfig::new().ca_certificate(Certificate::from_pem(ca_pem));
+    if let Some(domain) = domain {
+        tls = tls.domain_name(domain);
+    }
+    let endpoint = Endpoint::from_shared(address)?.tls_config(tls)?;
+    let origin = endpoint.uri().clone();
+    let channel = endpoint.connect().await?;
+    Ok(AuthClient::with_origin(channel, origin))
+}
+
+/// Creates a connection to the server at the given address without connecting, so the connection
+/// is only made when the first request is sent. The path of the address, if any, prefixes every
+/// request path like `connect`.
+/// # Errors
+/// * Returns an error if the address is invalid.
+pub fn connect_lazy(address: String) -> Result<AuthClient<Channel>, tonic::transport::Error> {
+    let endpoint = Endpoint::from_shared(address)?;
+    let origin = endpoint.uri().clone();
+    let channel = endpoint.connect_lazy();
+    Ok(AuthClient::with_origin(channel, origin))
+}
+
+/// Builder for a client that validates the user and password up front, but defers fetching the
+/// configuration from the server until the first operation. The password is wiped when the
+/// builder is dropped, and is not included in `Debug` output.
+pub struct ClientBuilder {
+    client: AuthClient<Channel>,
+    user: String,
+    password: Zeroizing<String>,
+    server_key: Option<VerifyingKey>,
+}
+
+/// Formats the client builder without its password.
+impl fmt::Debug for ClientBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientBuilder")
+            .field("client", &self.client)
+            .field("user", &self.user)
+            .field("password", &REDACTED)
+            .field("server_key", &self.server_key)
+            .finish()
+    }
+}
+
+/// Implementation of the client builder.
+impl ClientBuilder {
+    /// Creates a new client builder given a connection, a user and a password.
+    pub fn new(client: AuthClient<Channel>, user: String, password: String) -> Self {
+        ClientBuilder {
+            client,
+            user,
+            password: Zeroizing::new(password),
+            server_key: None,
+        }
+    }
+
+    /// Sets the server's public key, like `Client::with_server_verifying_key`.
+    pub fn with_server_verifying_key(mut self, key: VerifyingKey) -> Self {
+        self.server_key = Some(key);
+        self
+    }
+
+    /// Builds the lazy client, without sending any request to the server.
+    /// # Errors
+    /// * Returns an error if the user or password is invalid.
+    pub fn build(self) -> Result<LazyClient, AuthError> {
+        validate_credentials(&self.user, &self.password)?;
+
+        Ok(LazyClient {
+            builder: self,
+            client: OnceCell::new(),
+        })
+    }
+}
+
+/// A client that fetches the configuration from the server on its first operation, and reuses the
+/// resulting prover for every operation after that. The password is kept until the lazy client is
+/// dropped, so that a failed first operation can be retried.
+#[derive(Debug)]
+pub struct LazyClient {
+    builder: ClientBuilder,
+    client: OnceCell<Client>,
+}
+
+/// Implementation of the lazy client.
+impl LazyClient {
+    /// Returns the user of the client.
+    pub fn user(&self) -> &str {
+        &self.builder.user
+    }
+
+    /// Returns the client, fetching the configuration from the server if this is the first call.
+    /// # Errors
+    /// * Returns an error if the configuration request fails.
+    /// * Returns an error if the configuration is unknown or cannot be converted.
+    pub async fn client(&self) -> Result<&Client, AuthError> {
+        self.client
+            .get_or_try_init(|| async {
+                let builder = &self.builder;
+                let config = Client::get_configuration(builder.client.clone()).await?;
+                let client = Client::from_configuration(
+                    builder.client.clone(),
+                    config,
+                    builder.user.clone(),
+                    builder.password.to_string(),
+                )?;
+                Ok(match builder.server_key {
+                    Some(key) => client.with_server_verifying_key(key),
+                    None => client,
+                })
+            })
+            .await
+    }
+
+    /// Registers the user like `Client::register`.
+    /// # Errors
+    /// * Returns the same errors as `client` and `Client::register`.
+    pub async fn register(&self) -> Result<(), AuthError> {
+        self.client().await?.register().await
+    }
+
+    /// Registers the user like `Client::ensure_registered`.
+    /// # Errors
+    /// * Returns the same errors as `client` and `Client::ensure_registered`.
+    pub async fn ensure_registered(&self) -> Result<bool, AuthError> {
+        self.client().await?.ensure_registered().await
+    }
+
+    /// Logs in the user like `Client::login`.
+    /// # Errors
+    /// * Returns the same errors as `client` and `Client::login`.
+    pub async fn login(&self) -> Result<(), AuthError> {
+        self.client().await?.login().await
+    }
+}
+
+/// Creates the prover for the flavor of the given configuration.
+fn prover_from_configuration(config: Configuration) -> Result<Box<dyn Prover>, AuthError> {
+    let prover: Box<dyn Prover> = match config.flavor.clone() {
+        Some(Flavor::DiscreteLogarithm(config)) => Box::new(DiscreteLogarithmProver::new(
+            config.try_into().map_err(|_| {
+                AuthError::Internal(
+                    "failed to convert discrete logarithm configuration".to_string(),
+                )
+            })?,
+        )),
+        Some(Flavor::EllipticCurve(config)) if config.curve_name() == Secp256k1::NAME => Box::new(
+            EllipticCurveProver::<Secp256k1>::new(config.try_into().map_err(|_| {
+                AuthError::Internal("failed to convert elliptic curve configuration".to_string())
+            })?),
+        ),
+        Some(Flavor::EllipticCurve(config)) => Box::new(EllipticCurveProver::<Ristretto>::new(
+            config.try_into().map_err(|_| {
+                AuthError::Internal("failed to convert elliptic curve configuration".to_string())
+            })?,
+        )),
+        None => {
+            return Err(AuthError::FailedPrecondition(
+                "server returned no protocol configuration; it may be misconfigured".to_string(),
+            ))
+        }
+    };
+    config.check_protocol_version(prover.protocol_version())?;
+    Ok(prover)
+}
+
+/// Validates the user and password arguments.
+pub(crate) fn validate_credentials(user: &str, password: &str) -> Result<(), AuthError> {
+    if user.is_empty() {
+        return Err(AuthError::InvalidArgument(
+            "Invalid user argument".to_string(),
+        ));
+    }
+
+    if password.is_empty() {
+        return Err(AuthError::InvalidArgument(
+            "Invalid password argument".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod new {
     use super::*;
-    use crate::test::mock_client;
+    use crate::test::{mock_client, mock_client_with_verifier, MockVerifier};
     use anyhow::Result;
 
     /// Tests the new client creation.
@@ -175,14 +727,351 @@ mod new {
 
         Ok(())
     }
+
+    /// Tests that a server advertising a different protocol version is rejected.
+    #[tokio::test]
+    async fn returns_failed_precondition_with_unsupported_protocol_version() -> Result<()> {
+        let client = mock_client_with_verifier(MockVerifier {
+            protocol_version: zkauth::PROTOCOL_VERSION + 1,
+            ..Default::default()
+        })
+        .await?;
+        let err = Client::new(client, "user".to_string(), "password".to_string())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            AuthError::FailedPrecondition(format!(
+                "Unsupported protocol version {}, expected {}",
+                zkauth::PROTOCOL_VERSION + 1,
+                zkauth::PROTOCOL_VERSION
+            ))
+        );
+
+        Ok(())
+    }
+
+    /// Tests that a configuration without a flavor is reported as a failed precondition.
+    #[tokio::test]
+    async fn returns_failed_precondition_without_flavor() -> Result<()> {
+        let client = mock_client_with_verifier(MockVerifier {
+            without_flavor: true,
+            ..Default::default()
+        })
+        .await?;
+        let err = Client::new(client, "user".to_string(), "password".to_string())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            AuthError::FailedPrecondition(
+                "server returned no protocol configuration; it may be misconfigured".to_string()
+            )
+        );
+        assert_eq!(
+            tonic::Status::from(err).code(),
+            tonic::Code::FailedPrecondition
+        );
+
+        Ok(())
+    }
+
+    /// Tests that invalid credentials are reported as invalid argument errors.
+    #[tokio::test]
+    async fn returns_invalid_argument_with_empty_password() -> Result<()> {
+        let client = mock_client().await?;
+        let err = Client::new(client, "user".to_string(), "".to_string())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            AuthError::InvalidArgument("Invalid password argument".to_string())
+        );
+        assert_eq!(
+            tonic::Status::from(err).code(),
+            tonic::Code::InvalidArgument
+        );
+
+        Ok(())
+    }
+
+    /// Tests that a password in the blocklist is rejected, and one that is not is accepted.
+    #[tokio::test]
+    async fn with_password_blocklist_rejects_blocked_password() -> Result<()> {
+        let blocklist = PasswordBlocklist::parse(include_str!("testdata/password_blocklist.txt"))?;
+
+        let err = Client::new_with_password_blocklist(
+            mock_client().await?,
+            "user".to_string(),
+            "password".to_string(),
+            &blocklist,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            err,
+            AuthError::InvalidArgument(
+                "Invalid password argument: found in a list of breached passwords".to_string()
+            )
+        );
+
+        Client::new_with_password_blocklist(
+            mock_client().await?,
+            "user".to_string(),
+            "correct horse battery staple".to_string(),
+            &blocklist,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Tests that the client wipes its secret x, as it does when dropped.
+    #[tokio::test]
+    async fn zeroizes_secret_x() -> Result<()> {
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<Client>();
+
+        let client = mock_client().await?;
+        let mut client = Client::new(client, "user".to_string(), "password".to_string()).await?;
+        assert!(!num_traits::Zero::is_zero(&client.x));
+
+        client.zeroize_secrets();
+        assert_eq!(
+            client.x.0.to_bytes_le(),
+            (num_bigint::Sign::NoSign, vec![0])
+        );
+
+        Ok(())
+    }
+
+    /// Tests that a login state wipes the buffer holding its random k, as it does when dropped.
+    #[tokio::test]
+    async fn zeroizes_login_state_k() -> Result<()> {
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<LoginState>();
+
+        let client = mock_client().await?;
+        let client = Client::new(client, "user".to_string(), "password".to_string()).await?;
+        let (_, mut state) = client.begin_login()?;
+        let (ptr, len) = (state.k.as_ptr(), state.k.len());
+        assert!(len > 0);
+
+        state.zeroize_secrets();
+        assert!(state.k.is_empty());
+        // The buffer is still owned by k, which clears its length without freeing it.
+        assert_eq!(state.k.as_ptr(), ptr);
+        let buffer = unsafe { std::slice::from_raw_parts(ptr, len) };
+        assert!(buffer.iter().all(|byte| *byte == 0));
+
+        Ok(())
+    }
+
+    /// Tests that the client and login state do not include x or k in their debug output.
+    #[tokio::test]
+    async fn debug_redacts_secrets() -> Result<()> {
+        let client = mock_client().await?;
+        let client = Client::new(client, "user".to_string(), "password".to_string()).await?;
+        let (_, state) = client.begin_login()?;
+
+        let debug = format!("{:?}", client);
+        assert!(debug.contains(r#"x: "<redacted>""#), "{}", debug);
+        assert!(
+            !debug.contains(&format!("Scalar({})", client.x)),
+            "{}",
+            debug
+        );
+        let debug = format!("{:?}", state);
+        assert!(debug.contains(r#"k: "<redacted>""#), "{}", debug);
+        assert!(!debug.contains(&format!("{:?}", state.k)), "{}", debug);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod new_with_pinned_config {
+    use super::*;
+    use crate::test::mock_client;
+    use anyhow::Result;
+
+    /// Tests that a client is created when the server's config version matches the pin.
+    #[tokio::test]
+    async fn succeeds_with_matching_pin() -> Result<()> {
+        let version = Client::get_configuration(mock_client().await?)
+            .await?
+            .config_version();
+
+        let client = mock_client().await?;
+        let prover = Client::new_with_pinned_config(
+            client,
+            "user".to_string(),
+            "password".to_string(),
+            version.to_uppercase(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(prover.user, "user");
+
+        Ok(())
+    }
+
+    /// Tests that a server whose config version differs from the pin is rejected.
+    #[tokio::test]
+    async fn returns_failed_precondition_with_mismatching_pin() -> Result<()> {
+        let version = Client::get_configuration(mock_client().await?)
+            .await?
+            .config_version();
+        let pinned = "0".repeat(64);
+
+        let client = mock_client().await?;
+        let err = Client::new_with_pinned_config(
+            client,
+            "user".to_string(),
+            "password".to_string(),
+            pinned.clone(),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            AuthError::FailedPrecondition(format!(
+                "Server configuration version {} does not match the pinned version {}",
+                version, pinned
+            ))
+        );
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
-mod register {
+mod get_configuration {
     use super::*;
     use crate::test::mock_client;
     use anyhow::Result;
 
+    /// Tests that the configuration is returned without a user or password.
+    #[tokio::test]
+    async fn succeeds() -> Result<()> {
+        let client = mock_client().await?;
+        let config = Client::get_configuration(client).await.unwrap();
+
+        assert!(matches!(config.flavor, Some(Flavor::DiscreteLogarithm(_))));
+
+        Ok(())
+    }
+
+    /// Tests that a failed connection is reported as an unavailable error.
+    #[tokio::test]
+    async fn returns_unavailable_when_connection_fails() -> Result<()> {
+        let err = Client::fetch_configuration("http://127.0.0.1:1".to_string())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, AuthError::Unavailable(_)));
+        assert!(err.message().starts_with("failed to connect"));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod connect_with_tls {
+    use super::*;
+    use anyhow::Result;
+    use tokio::net::TcpListener;
+    use tonic::transport::{Identity, Server, ServerTlsConfig};
+    use zkauth::discrete_logarithm::{
+        configuration::DiscreteLogarithmConfiguration, verifier::DiscreteLogarithmVerifier,
+    };
+    use zkauth_protobuf::v1::auth_server::AuthServer;
+    use zkauth_server::service::Service;
+
+    /// The self-signed test CA, and a certificate it issued for localhost with its key.
+    const CA_PEM: &[u8] = include_bytes!("testdata/tls/ca.pem");
+    const SERVER_PEM: &[u8] = include_bytes!("testdata/tls/server.pem");
+    const SERVER_KEY: &[u8] = include_bytes!("testdata/tls/server.key");
+
+    /// Starts a server with the test certificate for localhost, returning its https address.
+    async fn start_tls_server_in_background() -> Result<String> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = format!("https://{}", listener.local_addr()?);
+
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+        let service = Service::new(config.into(), verifier);
+        let tls = ServerTlsConfig::new().identity(Identity::from_pem(SERVER_PEM, SERVER_KEY));
+        let mut server = Server::builder().tls_config(tls)?;
+        tokio::spawn(async move {
+            server
+                .add_service(AuthServer::new(service))
+                .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
+                .await
+        });
+
+        Ok(address)
+    }
+
+    /// Tests that a client trusting the CA can register and log in over TLS.
+    #[tokio::test]
+    async fn succeeds_with_pinned_ca() -> Result<()> {
+        let address = start_tls_server_in_background().await?;
+        let auth = super::connect_with_tls(address, CA_PEM, Some("localhost")).await?;
+
+        let client = Client::new(auth, "user".to_string(), "password".to_string()).await?;
+        client.register().await?;
+        client.login().await?;
+
+        Ok(())
+    }
+
+    /// Tests that a server whose certificate is not valid for the domain is rejected with an
+    /// error naming the cause.
+    #[tokio::test]
+    async fn fails_with_mismatched_domain() -> Result<()> {
+        let address = start_tls_server_in_background().await?;
+        let err = super::connect_with_tls(address, CA_PEM, Some("zkauth.example"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, AuthError::Unavailable(_)), "{:?}", err);
+        assert!(
+            err.message().contains("certificate") && err.message().contains("NotValidForName"),
+            "{}",
+            err.message()
+        );
+
+        Ok(())
+    }
+
+    /// Tests that a server whose certificate is not signed by the pinned CA is rejected.
+    #[tokio::test]
+    async fn fails_with_another_ca() -> Result<()> {
+        let address = start_tls_server_in_background().await?;
+        // The server certificate is not a CA, so nothing it signs, including itself, is trusted.
+        let err = super::connect_with_tls(address, SERVER_PEM, Some("localhost"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, AuthError::Unavailable(_)), "{:?}", err);
+        assert!(err.message().contains("certificate"), "{}", err.message());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod register {
+    use super::*;
+    use crate::test::{mock_client, mock_client_with_verifier, MockVerifier};
+    use anyhow::Result;
+
     /// Tests that the registration process succeeds.
     #[tokio::test]
     async fn succeeds() -> Result<()> {
@@ -195,13 +1084,63 @@ mod register {
 
         Ok(())
     }
+
+    /// Tests that registering an already registered user returns already_exists.
+    #[tokio::test]
+    async fn returns_already_exists_when_already_registered() -> Result<()> {
+        let client = mock_client_with_verifier(MockVerifier {
+            already_registered: true,
+            ..Default::default()
+        })
+        .await?;
+        let prover = Client::new(client, "user".to_string(), "password".to_string()).await?;
+
+        let err = prover.register().await.unwrap_err();
+        assert_eq!(
+            err,
+            AuthError::AlreadyExists("User already registered".to_string())
+        );
+
+        Ok(())
+    }
+
+    /// Tests that ensure_registered registers a new user.
+    #[tokio::test]
+    async fn ensure_registered_registers_new_user() -> Result<()> {
+        let client = mock_client().await?;
+        let prover = Client::new(client, "user".to_string(), "password".to_string()).await?;
+
+        assert!(prover.ensure_registered().await?);
+
+        Ok(())
+    }
+
+    /// Tests that ensure_registered succeeds when the user is already registered.
+    #[tokio::test]
+    async fn ensure_registered_succeeds_when_already_registered() -> Result<()> {
+        let client = mock_client_with_verifier(MockVerifier {
+            already_registered: true,
+            ..Default::default()
+        })
+        .await?;
+        let prover = Client::new(client, "user".to_string(), "password".to_string()).await?;
+
+        assert!(!prover.ensure_registered().await?);
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod login {
     use super::*;
-    use crate::test::mock_client;
+    use crate::test::{mock_client, mock_client_with_verifier};
     use anyhow::Result;
+    use num_traits::One;
+    use zkauth::discrete_logarithm::{
+        configuration::DiscreteLogarithmConfiguration, verifier::DiscreteLogarithmVerifier,
+    };
+    use zkauth_server::service::Service;
 
     /// Tests that the login process succeeds.
     #[tokio::test]
@@ -215,4 +1154,172 @@ mod login {
 
         Ok(())
     }
+
+    /// Tests that a login can be resumed from serialized state, answering the challenge with a
+    /// client that did not compute the commitment.
+    #[tokio::test]
+    async fn resumes_from_serialized_state() -> Result<()> {
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+        let mut auth = mock_client_with_verifier(Service::new(config.into(), verifier)).await?;
+
+        let client = Client::new(auth.clone(), "user".to_string(), "password".to_string()).await?;
+        client.register().await?;
+
+        let ((r1, r2), state) = client.begin_login()?;
+        let state = serde_json::to_string(&state)?;
+        drop(client);
+
+        let challenge = auth
+            .create_authentication_challenge(AuthenticationChallengeRequest {
+                user: "user".to_string(),
+                r1: r1.to_string(),
+                r2: r2.to_string(),
+            })
+            .await?
+            .into_inner();
+
+        let client = Client::new(auth.clone(), "user".to_string(), "password".to_string()).await?;
+        let state: LoginState = serde_json::from_str(&state)?;
+        let s = client
+            .finish_login(state, challenge.c.parse().unwrap())
+            .unwrap();
+
+        let resp = auth
+            .verify_authentication(AuthenticationAnswerRequest {
+                auth_id: challenge.auth_id,
+                s: s.to_string(),
+                c: challenge.c,
+                mac: challenge.mac,
+            })
+            .await?
+            .into_inner();
+        assert!(!resp.session_id.is_empty());
+
+        Ok(())
+    }
+
+    /// Tests that a non-interactive login succeeds against a real service, and fails with the
+    /// wrong password.
+    #[tokio::test]
+    async fn noninteractive_succeeds() -> Result<()> {
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+        let auth = mock_client_with_verifier(Service::new(config.into(), verifier)).await?;
+
+        let client = Client::new(auth.clone(), "user".to_string(), "password".to_string()).await?;
+        client.register().await?;
+        client.login_noninteractive().await?;
+
+        let client = Client::new(auth, "user".to_string(), "wrong".to_string()).await?;
+        let err = client.login_noninteractive().await.unwrap_err();
+        assert!(matches!(err, AuthError::FailedPrecondition(_)), "{:?}", err);
+
+        Ok(())
+    }
+
+    /// Tests that finish_login rejects state from another user's login.
+    #[tokio::test]
+    async fn finish_login_rejects_state_for_another_user() -> Result<()> {
+        let client = Client::new(
+            mock_client().await?,
+            "user".to_string(),
+            "password".to_string(),
+        )
+        .await?;
+        let (_, state) = client.begin_login()?;
+
+        let other = Client::new(
+            mock_client().await?,
+            "other".to_string(),
+            "password".to_string(),
+        )
+        .await?;
+        let err = other.finish_login(state, Scalar::one()).unwrap_err();
+        assert!(matches!(err, AuthError::InvalidArgument(_)));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod client_builder {
+    use super::*;
+    use crate::test::{mock_client_with_verifier, MockVerifier};
+    use anyhow::Result;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    /// Tests that the configuration is fetched on the first operation, and only once.
+    #[tokio::test]
+    async fn fetches_configuration_on_first_operation() -> Result<()> {
+        let configuration_requests = Arc::new(AtomicUsize::new(0));
+        let client = mock_client_with_verifier(MockVerifier {
+            configuration_requests: configuration_requests.clone(),
+            ..Default::default()
+        })
+        .await?;
+
+        let lazy =
+            ClientBuilder::new(client, "user".to_string(), "password".to_string()).build()?;
+        assert_eq!(lazy.user(), "user");
+        assert_eq!(configuration_requests.load(Ordering::SeqCst), 0);
+
+        lazy.register().await?;
+        assert_eq!(configuration_requests.load(Ordering::SeqCst), 1);
+
+        lazy.login().await?;
+        assert_eq!(configuration_requests.load(Ordering::SeqCst), 1);
+
+        Ok(())
+    }
+
+    /// Tests that building a client over a lazy connection does not connect to the server.
+    #[tokio::test]
+    async fn does_not_connect_until_first_operation() -> Result<()> {
+        let client = connect_lazy("http://127.0.0.1:1".to_string())?;
+        let lazy =
+            ClientBuilder::new(client, "user".to_string(), "password".to_string()).build()?;
+
+        let err = lazy.register().await.unwrap_err();
+        assert!(matches!(err, AuthError::Unavailable(_)), "{:?}", err);
+
+        Ok(())
+    }
+
+    /// Tests that the builder and lazy client do not include the password in their debug output.
+    #[tokio::test]
+    async fn debug_redacts_password() -> Result<()> {
+        let client = connect_lazy("http://127.0.0.1:1".to_string())?;
+        let builder = ClientBuilder::new(client, "user".to_string(), "hunter2".to_string());
+        assert!(!format!("{:?}", builder).contains("hunter2"));
+        let lazy = builder.build()?;
+        assert!(!format!("{:?}", lazy).contains("hunter2"));
+
+        Ok(())
+    }
+
+    /// Tests that invalid credentials are rejected when building, before any request.
+    #[tokio::test]
+    async fn build_fails_with_empty_password() -> Result<()> {
+        let configuration_requests = Arc::new(AtomicUsize::new(0));
+        let client = mock_client_with_verifier(MockVerifier {
+            configuration_requests: configuration_requests.clone(),
+            ..Default::default()
+        })
+        .await?;
+
+        let err = ClientBuilder::new(client, "user".to_string(), "".to_string())
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            AuthError::InvalidArgument("Invalid password argument".to_string())
+        );
+        assert_eq!(configuration_requests.load(Ordering::SeqCst), 0);
+
+        Ok(())
+    }
 }
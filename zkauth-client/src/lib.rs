@@ -6,9 +6,15 @@
 //! This library provides the client for the zkauth service, acting as the prover for the zero-
 //! knowledge authentication protocol.
 
+/// The password blocklist module.
+pub mod blocklist;
+
 /// The client module.
 pub mod client;
 
+/// The connection pool module.
+pub mod pool;
+
 /// The command-line interface module.
 pub mod cli;
 
@@ -14,39 +14,150 @@
 //!
 //! This command will register and login the user `user` with the password `password` using the
 //! zkauth protocol at the address `http://localhost:5001`.
+//!
+//! ```sh
+//! zkauth-client verify --config-path config.json --proof-path proof.json
+//! ```
+//!
+//! This command will check a single proof against the public parameters in `config.json`, without
+//! any network or store, and print whether the verification equations hold.
+//!
+//! ```sh
+//! zkauth-client config --address http://localhost:5001
+//! ```
+//!
+//! This command will print the configuration of the server at `http://localhost:5001` as JSON,
+//! without a user or password.
 
 use anyhow::Result;
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
 use clap_verbosity_flag::{InfoLevel, Verbosity};
 use env_logger::Env;
-use zkauth_client::cli::run;
+use zkauth_client::blocklist::PasswordBlocklist;
+use zkauth_client::cli::{
+    config, load_config_from_file, load_proof_from_file, run, verify, Proof, TlsOptions,
+};
 
 /// The command-line options for the zkauth client.
 #[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
+#[command(author, version, about, long_about = None, subcommand_negates_reqs = true)]
 pub struct Options {
     #[command(flatten)]
     verbose: Verbosity<InfoLevel>,
 
-    /// Specifies the address of the gRPC server to connect to. Example: http://127.0.0.1:50001
-    #[arg(short, long, env("ZKAUTH_ADDRESS"))]
-    address: String,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Specifies the address of the gRPC server to connect to. Example: http://127.0.0.1:50001. For
+    /// a server serving both flavors, append the flavor to select it, such as /elliptic-curve.
+    #[arg(short, long, env("ZKAUTH_ADDRESS"), required = true)]
+    address: Option<String>,
 
     /// Specifies the username to authenticate with.
-    #[arg(short, long, env("ZKAUTH_USER"))]
-    user: String,
+    #[arg(short, long, env("ZKAUTH_USER"), required = true)]
+    user: Option<String>,
 
     /// Specifies the password to authenticate with.
-    #[arg(short, long, env("ZKAUTH_PASSWORD"))]
-    password: String,
+    #[arg(short, long, env("ZKAUTH_PASSWORD"), required = true)]
+    password: Option<String>,
 
-    /// Specifies whether to execute the registration step.
+    /// Specifies whether to execute the registration step. A user that is already registered is not
+    /// an error.
     #[arg(long, default_value_t = false)]
     register: bool,
 
     /// Specifies whether to execute the login step.
     #[arg(long, default_value_t = false)]
     login: bool,
+
+    /// Specifies a file of SHA-1 hashes of breached passwords, one full hex hash per line, such as
+    /// from the "Have I Been Pwned" list. Registering with a password whose hash is in the file is
+    /// refused before anything is derived from the password.
+    #[arg(long)]
+    deny_weak_passwords: Option<String>,
+
+    /// Specifies a PEM file of the CA certificate to trust when connecting to an https address,
+    /// instead of connecting over plaintext. Only servers with a certificate signed by it are
+    /// accepted.
+    #[arg(long)]
+    tls_ca: Option<String>,
+
+    /// Specifies the domain the server's certificate must be valid for, if it differs from the host
+    /// of the address, such as when connecting by IP address.
+    #[arg(long, requires = "tls_ca")]
+    tls_domain: Option<String>,
+}
+
+/// The subcommands of the zkauth client.
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Verifies a single proof against the given parameters, without any network or store.
+    Verify(VerifyOptions),
+
+    /// Prints the configuration of a server as JSON, without a user or password.
+    Config(ConfigOptions),
+}
+
+/// The command-line options for the config subcommand.
+#[derive(Args, Debug)]
+struct ConfigOptions {
+    /// Specifies the address of the gRPC server to connect to. Example: http://127.0.0.1:50001
+    #[arg(short, long, env("ZKAUTH_ADDRESS"))]
+    address: String,
+}
+
+/// The command-line options for the verify subcommand.
+#[derive(Args, Debug)]
+struct VerifyOptions {
+    /// Specifies the configuration file path containing the public parameters.
+    #[arg(long)]
+    config_path: String,
+
+    /// Specifies a JSON file containing the proof values y1, y2, r1, r2, c, and s.
+    #[arg(long, conflicts_with_all = ["y1", "y2", "r1", "r2", "c", "s"])]
+    proof_path: Option<String>,
+
+    /// Specifies the registration y1 value.
+    #[arg(long, required_unless_present = "proof_path")]
+    y1: Option<String>,
+
+    /// Specifies the registration y2 value.
+    #[arg(long, required_unless_present = "proof_path")]
+    y2: Option<String>,
+
+    /// Specifies the challenge commitment r1 value.
+    #[arg(long, required_unless_present = "proof_path")]
+    r1: Option<String>,
+
+    /// Specifies the challenge commitment r2 value.
+    #[arg(long, required_unless_present = "proof_path")]
+    r2: Option<String>,
+
+    /// Specifies the challenge c value.
+    #[arg(long, required_unless_present = "proof_path")]
+    c: Option<String>,
+
+    /// Specifies the challenge response s value.
+    #[arg(long, required_unless_present = "proof_path")]
+    s: Option<String>,
+}
+
+/// Implementation of the verify options.
+impl VerifyOptions {
+    /// Returns the proof from the proof file, or from the individual values.
+    fn proof(self) -> Result<Proof> {
+        if let Some(path) = self.proof_path {
+            return load_proof_from_file(&path);
+        }
+        Ok(Proof {
+            y1: self.y1.unwrap_or_default(),
+            y2: self.y2.unwrap_or_default(),
+            r1: self.r1.unwrap_or_default(),
+            r2: self.r2.unwrap_or_default(),
+            c: self.c.unwrap_or_default(),
+            s: self.s.unwrap_or_default(),
+        })
+    }
 }
 
 /// Implementation of the options.
@@ -70,19 +181,52 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let opts = Options::parse();
     opts.init_logger();
 
+    match opts.command {
+        // Verify a single proof offline, exiting with a failure if it does not hold.
+        Some(Command::Verify(verify_opts)) => {
+            let config = load_config_from_file(&verify_opts.config_path)?;
+            if verify(config, verify_opts.proof()?)? {
+                println!("Proof is valid");
+            } else {
+                println!("Proof is invalid");
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        // Print the server configuration.
+        Some(Command::Config(config_opts)) => {
+            let config = config(config_opts.address).await?;
+            println!("{}", serde_json::to_string_pretty(&config)?);
+            return Ok(());
+        }
+        None => {}
+    }
+
     // Ensure that either --register or --login is true.
     if !opts.register && !opts.login {
         eprintln!("Error: Either --register or --login should be true");
         std::process::exit(1);
     }
 
+    let password_blocklist = match opts.deny_weak_passwords.as_deref() {
+        Some(path) => Some(PasswordBlocklist::load_from_file(path)?),
+        None => None,
+    };
+
+    let tls = match opts.tls_ca.as_deref() {
+        Some(path) => Some(TlsOptions::load_from_file(path, opts.tls_domain)?),
+        None => None,
+    };
+
     // Run the client.
     run(
-        opts.address,
-        opts.user,
-        opts.password,
+        opts.address.unwrap_or_default(),
+        opts.user.unwrap_or_default(),
+        opts.password.unwrap_or_default(),
         opts.register,
         opts.login,
+        password_blocklist.as_ref(),
+        tls.as_ref(),
     )
     .await?;
 
@@ -101,7 +245,118 @@ mod options {
             "--user=user",
             "--password=password",
         ]);
-        assert_eq!(opts.address, "https://test.net:5000");
+        assert_eq!(opts.address, Some("https://test.net:5000".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn deny_weak_passwords() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--address=a", "--user=u", "--password=p"]);
+        assert_eq!(opts.deny_weak_passwords, None);
+
+        let opts = Options::parse_from(vec![
+            "bin",
+            "--address=a",
+            "--user=u",
+            "--password=p",
+            "--deny-weak-passwords=blocklist.txt",
+        ]);
+        assert_eq!(opts.deny_weak_passwords, Some("blocklist.txt".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn tls() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--address=a", "--user=u", "--password=p"]);
+        assert_eq!(opts.tls_ca, None);
+        assert_eq!(opts.tls_domain, None);
+
+        let opts = Options::parse_from(vec![
+            "bin",
+            "--address=https://10.0.0.1:5000",
+            "--user=u",
+            "--password=p",
+            "--tls-ca=ca.pem",
+            "--tls-domain=zkauth.example",
+        ]);
+        assert_eq!(opts.tls_ca, Some("ca.pem".to_string()));
+        assert_eq!(opts.tls_domain, Some("zkauth.example".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn tls_domain_requires_tls_ca() -> Result<()> {
+        let result = Options::try_parse_from(vec![
+            "bin",
+            "--address=a",
+            "--user=u",
+            "--password=p",
+            "--tls-domain=zkauth.example",
+        ]);
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn verify_with_values() -> Result<()> {
+        let opts = Options::parse_from(vec![
+            "bin",
+            "verify",
+            "--config-path=config.json",
+            "--y1=1",
+            "--y2=2",
+            "--r1=3",
+            "--r2=4",
+            "--c=5",
+            "--s=6",
+        ]);
+        let Some(Command::Verify(verify_opts)) = opts.command else {
+            panic!("expected verify subcommand");
+        };
+        assert_eq!(verify_opts.config_path, "config.json");
+        assert_eq!(
+            verify_opts.proof()?,
+            Proof {
+                y1: "1".to_string(),
+                y2: "2".to_string(),
+                r1: "3".to_string(),
+                r2: "4".to_string(),
+                c: "5".to_string(),
+                s: "6".to_string(),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn verify_with_proof_path() -> Result<()> {
+        let opts = Options::parse_from(vec![
+            "bin",
+            "verify",
+            "--config-path=config.json",
+            "--proof-path=proof.json",
+        ]);
+        let Some(Command::Verify(verify_opts)) = opts.command else {
+            panic!("expected verify subcommand");
+        };
+        assert_eq!(verify_opts.proof_path, Some("proof.json".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn verify_requires_proof() -> Result<()> {
+        let result = Options::try_parse_from(vec!["bin", "verify", "--config-path=config.json"]);
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn config_with_address() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "config", "--address=https://test.net:5000"]);
+        let Some(Command::Config(config_opts)) = opts.command else {
+            panic!("expected config subcommand");
+        };
+        assert_eq!(config_opts.address, "https://test.net:5000");
         Ok(())
     }
 
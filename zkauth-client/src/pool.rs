@@ -0,0 +1,120 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::sync::{Mutex, OnceCell};
+use tonic::transport::{Channel, Endpoint};
+use zkauth_protobuf::{error::AuthError, v1::Configuration};
+
+use crate::client::{validate_credentials, Client};
+use crate::AuthClient;
+
+/// A pool of connections to a server, handing out clients that share a bounded set of HTTP/2
+/// channels instead of each opening its own connection.
+///
+/// Channels are opened lazily, up to the maximum size, and then reused round-robin. Each channel
+/// multiplexes concurrent requests, so a small pool serves many concurrent clients. The server's
+/// configuration is fetched once and shared by every client.
+#[derive(Debug)]
+pub struct ClientPool {
+    endpoint: Endpoint,
+    max_size: usize,
+    channels: Mutex<Vec<Channel>>,
+    next: AtomicUsize,
+    configuration: OnceCell<Configuration>,
+}
+
+/// Implementation of the client pool.
+impl ClientPool {
+    /// Creates a new pool of at most `max_size` connections to the server at the given address.
+    /// No connection is opened until the first client is requested.
+    /// # Errors
+    /// * Returns an error if the address is invalid.
+    /// * Returns an error if the maximum size is 0.
+    pub fn new(address: String, max_size: usize) -> Result<Self, AuthError> {
+        if max_size == 0 {
+            return Err(AuthError::InvalidArgument(
+                "Invalid max_size argument".to_string(),
+            ));
+        }
+        let endpoint = Endpoint::from_shared(address)
+            .map_err(|_| AuthError::InvalidArgument("Invalid address argument".to_string()))?;
+
+        Ok(ClientPool {
+            endpoint,
+            max_size,
+            channels: Mutex::new(Vec::with_capacity(max_size)),
+            next: AtomicUsize::new(0),
+            configuration: OnceCell::new(),
+        })
+    }
+
+    /// Returns the maximum number of connections in the pool.
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Returns the number of connections currently open in the pool.
+    pub async fn size(&self) -> usize {
+        self.channels.lock().await.len()
+    }
+
+    /// Returns a client for the given user and password over a pooled connection.
+    /// # Errors
+    /// * Returns an error if the user or password is invalid.
+    /// * Returns an error if the connection to the server fails.
+    /// * Returns an error if the configuration request fails, or the configuration is unknown or
+    ///   cannot be converted.
+    pub async fn client(&self, user: String, password: String) -> Result<Client, AuthError> {
+        validate_credentials(&user, &password)?;
+
+        let client = AuthClient::with_origin(self.channel().await?, self.endpoint.uri().clone());
+        let config = self
+            .configuration
+            .get_or_try_init(|| Client::get_configuration(client.clone()))
+            .await?
+            .clone();
+
+        Client::from_configuration(client, config, user, password)
+    }
+
+    /// Returns a pooled channel, opening a new one if the pool is not yet full.
+    async fn channel(&self) -> Result<Channel, AuthError> {
+        let mut channels = self.channels.lock().await;
+        if channels.len() < self.max_size {
+            let channel = self.endpoint.connect().await?;
+            channels.push(channel.clone());
+            return Ok(channel);
+        }
+
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % channels.len();
+        Ok(channels[index].clone())
+    }
+}
+
+#[cfg(test)]
+mod new {
+    use super::*;
+
+    #[test]
+    fn fails_with_zero_max_size() {
+        let err = ClientPool::new("http://127.0.0.1:50001".to_string(), 0).unwrap_err();
+        assert_eq!(
+            err,
+            AuthError::InvalidArgument("Invalid max_size argument".to_string())
+        );
+    }
+
+    #[test]
+    fn fails_with_invalid_address() {
+        let err = ClientPool::new("not a uri".to_string(), 1).unwrap_err();
+        assert_eq!(
+            err,
+            AuthError::InvalidArgument("Invalid address argument".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_connect_until_used() {
+        let pool = ClientPool::new("http://127.0.0.1:50001".to_string(), 4).unwrap();
+        assert_eq!(pool.max_size(), 4);
+        assert_eq!(pool.size().await, 0);
+    }
+}
@@ -1,4 +1,8 @@
 use anyhow::Result;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 use tonic::{
     transport::{Channel, Endpoint, Server, Uri},
     Request, Response, Status,
@@ -7,16 +11,23 @@ use tower::service_fn;
 use zkauth_protobuf::v1::{
     auth_client::AuthClient,
     auth_server::{Auth, AuthServer},
-    AuthenticationAnswerRequest, AuthenticationAnswerResponse, AuthenticationChallengeRequest,
-    AuthenticationChallengeResponse, Configuration, GetConfigurationRequest, RegisterRequest,
-    RegisterResponse,
+    AuthenticateRequest, AuthenticationAnswerRequest, AuthenticationAnswerResponse,
+    AuthenticationChallengeRequest, AuthenticationChallengeResponse, Configuration,
+    GetConfigurationRequest, GetUserRequest, GetUserResponse, RegisterAndChallengeRequest,
+    RegisterRequest, RegisterResponse, SelfTestRequest, SelfTestResponse, UnregisterRequest,
+    UnregisterResponse, UpdateCredentialsRequest, UpdateCredentialsResponse,
+    WatchConfigurationRequest, WatchSessionsRequest, WatchSessionsResponse,
 };
 
 /// Creates a mock client for testing.
 pub async fn mock_client() -> Result<AuthClient<Channel>> {
-    let (client, server) = tokio::io::duplex(1024);
+    mock_client_with_verifier(MockVerifier::default()).await
+}
 
-    let verifier = MockVerifier::default();
+/// Creates a mock client for testing, backed by the given verifier, such as a mock verifier or a
+/// real service.
+pub async fn mock_client_with_verifier<A: Auth>(verifier: A) -> Result<AuthClient<Channel>> {
+    let (client, server) = tokio::io::duplex(1024);
 
     tokio::spawn(async move {
         Server::builder()
@@ -32,11 +43,7 @@ pub async fn mock_client() -> Result<AuthClient<Channel>> {
         .connect_with_connector(service_fn(move |_: Uri| {
             let client = client.take();
 
-            async move {
-                client.ok_or_else(|| {
-                    std::io::Error::new(std::io::ErrorKind::Other, "Client already taken")
-                })
-            }
+            async move { client.ok_or_else(|| std::io::Error::other("Client already taken")) }
         }))
         .await?;
 
@@ -45,8 +52,31 @@ pub async fn mock_client() -> Result<AuthClient<Channel>> {
     Ok(client)
 }
 
-#[derive(Default)]
-pub struct MockVerifier {}
+pub struct MockVerifier {
+    /// The protocol version advertised in the configuration, where 0 means unset. Defaults to the
+    /// version the client speaks.
+    pub protocol_version: u32,
+
+    /// Whether register returns already_exists, as if the user were already registered.
+    pub already_registered: bool,
+
+    /// Whether the configuration has no flavor, as if the server were misconfigured.
+    pub without_flavor: bool,
+
+    /// The number of configuration requests received.
+    pub configuration_requests: Arc<AtomicUsize>,
+}
+
+impl Default for MockVerifier {
+    fn default() -> Self {
+        MockVerifier {
+            protocol_version: zkauth::PROTOCOL_VERSION,
+            already_registered: false,
+            without_flavor: false,
+            configuration_requests: Arc::default(),
+        }
+    }
+}
 
 /// Mock implementation of the verifier.
 #[tonic::async_trait]
@@ -55,24 +85,37 @@ impl Auth for MockVerifier {
         &self,
         _: Request<GetConfigurationRequest>,
     ) -> Result<Response<Configuration>, Status> {
+        self.configuration_requests.fetch_add(1, Ordering::SeqCst);
+        let flavor = zkauth_protobuf::v1::configuration::Flavor::DiscreteLogarithm(
+            zkauth_protobuf::v1::configuration::DiscreteLogarithm {
+                p: "23".to_string(),
+                q: "11".to_string(),
+                g: "4".to_string(),
+                h: "9".to_string(),
+            },
+        );
         Ok(Response::new(Configuration {
-            flavor: Some(
-                zkauth_protobuf::v1::configuration::Flavor::DiscreteLogarithm(
-                    zkauth_protobuf::v1::configuration::DiscreteLogarithm {
-                        p: "1".to_string(),
-                        q: "1".to_string(),
-                        g: "1".to_string(),
-                        h: "1".to_string(),
-                    },
-                ),
-            ),
+            flavor: (!self.without_flavor).then_some(flavor),
+            protocol_version: self.protocol_version,
         }))
     }
 
+    type WatchConfigurationStream = tokio_stream::Empty<Result<Configuration, Status>>;
+
+    async fn watch_configuration(
+        &self,
+        _: Request<WatchConfigurationRequest>,
+    ) -> Result<Response<Self::WatchConfigurationStream>, Status> {
+        Ok(Response::new(tokio_stream::empty()))
+    }
+
     async fn register(
         &self,
         _: Request<RegisterRequest>,
     ) -> Result<Response<RegisterResponse>, Status> {
+        if self.already_registered {
+            return Err(Status::already_exists("User already registered"));
+        }
         Ok(Response::new(RegisterResponse {}))
     }
 
@@ -83,6 +126,8 @@ impl Auth for MockVerifier {
         Ok(Response::new(AuthenticationChallengeResponse {
             auth_id: "auth-id".to_string(),
             c: "1".to_string(),
+            signature: "".to_string(),
+            mac: "".to_string(),
         }))
     }
 
@@ -92,6 +137,75 @@ impl Auth for MockVerifier {
     ) -> Result<Response<AuthenticationAnswerResponse>, Status> {
         Ok(Response::new(AuthenticationAnswerResponse {
             session_id: "session-id".to_string(),
+            token: "".to_string(),
+        }))
+    }
+
+    type WatchSessionsStream = tokio_stream::Empty<Result<WatchSessionsResponse, Status>>;
+
+    async fn watch_sessions(
+        &self,
+        _: Request<WatchSessionsRequest>,
+    ) -> Result<Response<Self::WatchSessionsStream>, Status> {
+        Ok(Response::new(tokio_stream::empty()))
+    }
+
+    async fn self_test(
+        &self,
+        _: Request<SelfTestRequest>,
+    ) -> Result<Response<SelfTestResponse>, Status> {
+        Ok(Response::new(SelfTestResponse {
+            passed: true,
+            error: "".to_string(),
+        }))
+    }
+
+    async fn get_user(
+        &self,
+        request: Request<GetUserRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        Ok(Response::new(GetUserResponse {
+            user: request.into_inner().user,
+            metadata: Default::default(),
+        }))
+    }
+
+    async fn register_and_challenge(
+        &self,
+        _: Request<RegisterAndChallengeRequest>,
+    ) -> Result<Response<AuthenticationChallengeResponse>, Status> {
+        if self.already_registered {
+            return Err(Status::already_exists("User already registered"));
+        }
+        Ok(Response::new(AuthenticationChallengeResponse {
+            auth_id: "auth-id".to_string(),
+            c: "1".to_string(),
+            signature: "".to_string(),
+            mac: "".to_string(),
+        }))
+    }
+
+    async fn update_credentials(
+        &self,
+        _: Request<UpdateCredentialsRequest>,
+    ) -> Result<Response<UpdateCredentialsResponse>, Status> {
+        Ok(Response::new(UpdateCredentialsResponse {}))
+    }
+
+    async fn authenticate(
+        &self,
+        _: Request<AuthenticateRequest>,
+    ) -> Result<Response<AuthenticationAnswerResponse>, Status> {
+        Ok(Response::new(AuthenticationAnswerResponse {
+            session_id: "session-id".to_string(),
+            token: "".to_string(),
         }))
     }
+
+    async fn unregister(
+        &self,
+        _: Request<UnregisterRequest>,
+    ) -> Result<Response<UnregisterResponse>, Status> {
+        Ok(Response::new(UnregisterResponse {}))
+    }
 }
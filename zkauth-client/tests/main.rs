@@ -3,7 +3,92 @@ mod main {
     use anyhow::Result;
     use assert_cmd::prelude::*;
     use predicates::prelude::*;
+    use std::fs::File;
     use std::process::Command;
+    use tempdir::TempDir;
+    use zkauth::discrete_logarithm::{
+        configuration::DiscreteLogarithmConfiguration, prover::DiscreteLogarithmProver,
+        verifier::DiscreteLogarithmVerifier,
+    };
+    use zkauth::{Prover, Verifier};
+    use zkauth_client::cli::Proof;
+    use zkauth_protobuf::v1::Configuration;
+
+    /// Writes a configuration to the given directory, returning its path and a valid proof for it.
+    fn write_config_and_proof(dir: &TempDir) -> Result<(String, Proof)> {
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let prover = DiscreteLogarithmProver::new(config.clone());
+        let verifier = DiscreteLogarithmVerifier::new(config.clone());
+
+        let x = prover.compute_registration_x("password".to_string());
+        let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+        let k = prover.generate_challenge_k();
+        let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+        let c = verifier.generate_challenge_c();
+        let s = prover.compute_challenge_response_s(&x, &k, c.clone())?;
+
+        let config_path = dir.path().join("config.json");
+        let config: Configuration = config.into();
+        serde_json::to_writer(File::create(&config_path)?, &config)?;
+
+        let proof = Proof {
+            y1: y1.to_string(),
+            y2: y2.to_string(),
+            r1: r1.to_string(),
+            r2: r2.to_string(),
+            c: c.to_string(),
+            s: s.to_string(),
+        };
+        Ok((config_path.to_str().unwrap().to_string(), proof))
+    }
+
+    #[test]
+    fn verify_valid_proof_succeeds() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = TempDir::new("zkauth-client")?;
+        let (config_path, proof) = write_config_and_proof(&dir)?;
+        let proof_path = dir.path().join("proof.json");
+        serde_json::to_writer(File::create(&proof_path)?, &proof)?;
+
+        let mut cmd = Command::cargo_bin("zkauth-client")?;
+        cmd.arg("verify")
+            .arg("--config-path")
+            .arg(&config_path)
+            .arg("--proof-path")
+            .arg(&proof_path);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("Proof is valid"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn verify_invalid_proof_fails() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = TempDir::new("zkauth-client")?;
+        let (config_path, proof) = write_config_and_proof(&dir)?;
+
+        let mut cmd = Command::cargo_bin("zkauth-client")?;
+        cmd.arg("verify")
+            .arg("--config-path")
+            .arg(&config_path)
+            .arg("--y1")
+            .arg(&proof.y1)
+            .arg("--y2")
+            .arg(&proof.y2)
+            .arg("--r1")
+            .arg("0")
+            .arg("--r2")
+            .arg(&proof.r2)
+            .arg("--c")
+            .arg(&proof.c)
+            .arg("--s")
+            .arg(&proof.s);
+        cmd.assert()
+            .failure()
+            .stdout(predicate::str::contains("Proof is invalid"));
+
+        Ok(())
+    }
 
     #[test]
     fn no_server_fails() -> Result<(), Box<dyn std::error::Error>> {
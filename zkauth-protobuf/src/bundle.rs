@@ -0,0 +1,204 @@
+//! A parameter bundle is a self-contained export of a server's configuration along with its
+//! config version, optionally signed with an Ed25519 key. It allows clients to be provisioned
+//! out-of-band (e.g. in air-gapped environments) instead of fetching the configuration from the
+//! server with `get_configuration`.
+//!
+//! The config version is the SHA-256 digest of the encoded configuration, so any modification of
+//! the parameters is detected by [`ParameterBundle::verify`]. When the bundle is signed, the
+//! signature covers the config version, and can be checked with
+//! [`ParameterBundle::verify_signature`] against the server's public key.
+
+use ed25519_dalek::{Signer, Verifier};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::v1::Configuration;
+
+pub use ed25519_dalek::{Signature, SigningKey, VerifyingKey};
+
+/// A configuration export with its version and an optional signature.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParameterBundle {
+    /// The exported configuration.
+    pub configuration: Configuration,
+
+    /// The version of the exported configuration.
+    pub config_version: String,
+
+    /// The hex-encoded Ed25519 signature over the config version, if the bundle is signed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+/// An error for when a parameter bundle fails verification.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BundleError {
+    /// The config version does not match the configuration.
+    VersionMismatch,
+
+    /// The bundle is not signed.
+    MissingSignature,
+
+    /// The signature is malformed or does not match the config version.
+    InvalidSignature,
+}
+
+impl fmt::Display for BundleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BundleError::VersionMismatch => write!(f, "config version does not match"),
+            BundleError::MissingSignature => write!(f, "bundle is not signed"),
+            BundleError::InvalidSignature => write!(f, "bundle signature is invalid"),
+        }
+    }
+}
+
+impl std::error::Error for BundleError {}
+
+impl ParameterBundle {
+    /// Creates an unsigned bundle for the given configuration, stamped with the protocol version
+    /// of this crate unless it already has one. The config version excludes the protocol version,
+    /// so stamping it does not change what is signed.
+    pub fn new(mut configuration: Configuration) -> Self {
+        if configuration.protocol_version == 0 {
+            configuration.protocol_version = zkauth::PROTOCOL_VERSION;
+        }
+        let config_version = configuration.config_version();
+        ParameterBundle {
+            configuration,
+            config_version,
+            signature: None,
+        }
+    }
+
+    /// Signs the bundle's config version with the given key.
+    pub fn sign(mut self, key: &SigningKey) -> Self {
+        let signature = key.sign(self.config_version.as_bytes());
+        self.signature = Some(hex::encode(signature.to_bytes()));
+        self
+    }
+
+    /// Verifies that the config version matches the configuration.
+    pub fn verify(&self) -> Result<(), BundleError> {
+        if self.configuration.config_version() != self.config_version {
+            return Err(BundleError::VersionMismatch);
+        }
+        Ok(())
+    }
+
+    /// Verifies the config version and the signature against the given public key.
+    pub fn verify_signature(&self, key: &VerifyingKey) -> Result<(), BundleError> {
+        self.verify()?;
+        let signature = self
+            .signature
+            .as_ref()
+            .ok_or(BundleError::MissingSignature)?;
+        let bytes: [u8; 64] = hex::decode(signature)
+            .map_err(|_| BundleError::InvalidSignature)?
+            .try_into()
+            .map_err(|_| BundleError::InvalidSignature)?;
+        key.verify(
+            self.config_version.as_bytes(),
+            &Signature::from_bytes(&bytes),
+        )
+        .map_err(|_| BundleError::InvalidSignature)
+    }
+}
+
+#[cfg(all(test, feature = "discrete_logarithm"))]
+mod tests {
+    use super::*;
+    use crate::v1::configuration::Flavor;
+    use zkauth::discrete_logarithm::configuration::DiscreteLogarithmConfiguration;
+
+    fn test_bundle() -> ParameterBundle {
+        ParameterBundle::new(DiscreteLogarithmConfiguration::generate(16).into())
+    }
+
+    fn tamper(bundle: &mut ParameterBundle) {
+        if let Some(Flavor::DiscreteLogarithm(ref mut params)) = bundle.configuration.flavor {
+            params.h = "0".to_string();
+        }
+    }
+
+    #[test]
+    fn verify_succeeds() {
+        let bundle = test_bundle();
+        assert_eq!(bundle.verify(), Ok(()));
+    }
+
+    #[test]
+    fn new_stamps_protocol_version() {
+        let bundle = test_bundle();
+        assert_eq!(
+            bundle.configuration.protocol_version,
+            zkauth::PROTOCOL_VERSION
+        );
+        assert_eq!(
+            bundle.config_version,
+            Configuration {
+                protocol_version: 0,
+                ..bundle.configuration.clone()
+            }
+            .config_version()
+        );
+    }
+
+    #[test]
+    fn verify_fails_when_tampered() {
+        let mut bundle = test_bundle();
+        tamper(&mut bundle);
+        assert_eq!(bundle.verify(), Err(BundleError::VersionMismatch));
+    }
+
+    #[test]
+    fn verify_signature_succeeds() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let bundle = test_bundle().sign(&key);
+        assert_eq!(bundle.verify_signature(&key.verifying_key()), Ok(()));
+    }
+
+    #[test]
+    fn verify_signature_fails_when_unsigned() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let bundle = test_bundle();
+        assert_eq!(
+            bundle.verify_signature(&key.verifying_key()),
+            Err(BundleError::MissingSignature)
+        );
+    }
+
+    #[test]
+    fn verify_signature_fails_with_other_key() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let other = SigningKey::from_bytes(&[8; 32]);
+        let bundle = test_bundle().sign(&key);
+        assert_eq!(
+            bundle.verify_signature(&other.verifying_key()),
+            Err(BundleError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn verify_signature_fails_when_resigned_version_is_tampered() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let mut bundle = test_bundle().sign(&key);
+        tamper(&mut bundle);
+        bundle.config_version = bundle.configuration.config_version();
+        assert_eq!(
+            bundle.verify_signature(&key.verifying_key()),
+            Err(BundleError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() -> Result<(), serde_json::Error> {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let bundle = test_bundle().sign(&key);
+        let json = serde_json::to_string(&bundle)?;
+        let loaded: ParameterBundle = serde_json::from_str(&json)?;
+        assert_eq!(bundle, loaded);
+        Ok(())
+    }
+}
@@ -0,0 +1,263 @@
+//! Server signatures and MACs over authentication challenges.
+//!
+//! A server configured with a long-term Ed25519 key signs each `(auth_id, c)` pair it issues, so a
+//! client holding the server's public key can check that it is talking to the legitimate server
+//! before answering the challenge.
+//!
+//! As a lighter protection, a server configured with a secret can instead MAC each `(auth_id, c)`
+//! pair. The client echoes c and the MAC back with its answer, so the same server can detect c
+//! being altered in transit, without the client holding any key.
+
+use ed25519_dalek::{Signature, Signer, Verifier};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::fmt;
+
+use crate::bundle::{SigningKey, VerifyingKey};
+use crate::v1::{AuthenticationAnswerRequest, AuthenticationChallengeResponse};
+
+/// The domain separation prefix of the signed challenge message.
+const CHALLENGE_SIGNATURE_CONTEXT: &[u8] = b"zkauth-challenge-v1";
+
+/// The domain separation prefix of the MACed challenge message.
+const CHALLENGE_MAC_CONTEXT: &[u8] = b"zkauth-challenge-mac-v1";
+
+/// Returns the message for a challenge under the given context, binding the auth_id to c.
+fn challenge_message(context: &[u8], auth_id: &str, c: &str) -> Vec<u8> {
+    let mut message = context.to_vec();
+    for field in [auth_id, c] {
+        message.extend_from_slice(&(field.len() as u64).to_be_bytes());
+        message.extend_from_slice(field.as_bytes());
+    }
+    message
+}
+
+/// Returns the HMAC-SHA256 of a challenge's auth_id and c with the given secret.
+fn challenge_mac(secret: &[u8], auth_id: &str, c: &str) -> Hmac<Sha256> {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts secrets of any length");
+    mac.update(&challenge_message(CHALLENGE_MAC_CONTEXT, auth_id, c));
+    mac
+}
+
+/// An error for when a challenge MAC fails verification.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChallengeMacError {
+    /// The answer does not carry a MAC.
+    MissingMac,
+
+    /// The MAC is malformed or does not match the echoed challenge.
+    InvalidMac,
+}
+
+impl fmt::Display for ChallengeMacError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChallengeMacError::MissingMac => write!(f, "challenge MAC is missing"),
+            ChallengeMacError::InvalidMac => write!(f, "challenge MAC is invalid"),
+        }
+    }
+}
+
+impl std::error::Error for ChallengeMacError {}
+
+/// An error for when a challenge signature fails verification.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChallengeSignatureError {
+    /// The challenge is not signed.
+    MissingSignature,
+
+    /// The signature is malformed or does not match the challenge.
+    InvalidSignature,
+}
+
+impl fmt::Display for ChallengeSignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChallengeSignatureError::MissingSignature => write!(f, "challenge is not signed"),
+            ChallengeSignatureError::InvalidSignature => {
+                write!(f, "challenge signature is invalid")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ChallengeSignatureError {}
+
+impl AuthenticationChallengeResponse {
+    /// Returns the message that is signed for the challenge, binding the auth_id to c.
+    pub fn signed_message(&self) -> Vec<u8> {
+        challenge_message(CHALLENGE_SIGNATURE_CONTEXT, &self.auth_id, &self.c)
+    }
+
+    /// Signs the challenge's auth_id and c with the given key.
+    pub fn sign(self, key: &SigningKey) -> Self {
+        let signature = key.sign(&self.signed_message());
+        self.with_signature(&signature)
+    }
+
+    /// Sets the challenge's signature over its signed message, for signatures produced elsewhere,
+    /// such as by a key held in a KMS.
+    pub fn with_signature(mut self, signature: &Signature) -> Self {
+        self.signature = hex::encode(signature.to_bytes());
+        self
+    }
+
+    /// Verifies the challenge's signature against the given public key.
+    pub fn verify_signature(&self, key: &VerifyingKey) -> Result<(), ChallengeSignatureError> {
+        if self.signature.is_empty() {
+            return Err(ChallengeSignatureError::MissingSignature);
+        }
+        let bytes: [u8; 64] = hex::decode(&self.signature)
+            .map_err(|_| ChallengeSignatureError::InvalidSignature)?
+            .try_into()
+            .map_err(|_| ChallengeSignatureError::InvalidSignature)?;
+        key.verify(&self.signed_message(), &Signature::from_bytes(&bytes))
+            .map_err(|_| ChallengeSignatureError::InvalidSignature)
+    }
+
+    /// Sets the challenge's MAC over its auth_id and c with the given secret.
+    pub fn with_mac(mut self, secret: &[u8]) -> Self {
+        let mac = challenge_mac(secret, &self.auth_id, &self.c);
+        self.mac = hex::encode(mac.finalize().into_bytes());
+        self
+    }
+}
+
+impl AuthenticationAnswerRequest {
+    /// Verifies the MAC echoed back with the answer against its auth_id and echoed c, in constant
+    /// time.
+    pub fn verify_mac(&self, secret: &[u8]) -> Result<(), ChallengeMacError> {
+        if self.mac.is_empty() {
+            return Err(ChallengeMacError::MissingMac);
+        }
+        let bytes = hex::decode(&self.mac).map_err(|_| ChallengeMacError::InvalidMac)?;
+        challenge_mac(secret, &self.auth_id, &self.c)
+            .verify_slice(&bytes)
+            .map_err(|_| ChallengeMacError::InvalidMac)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_challenge() -> AuthenticationChallengeResponse {
+        AuthenticationChallengeResponse {
+            auth_id: "0b7a8f4e-5f0e-4b8e-9a59-1f3e6c2d7a10".to_string(),
+            c: "12345".to_string(),
+            signature: "".to_string(),
+            mac: "".to_string(),
+        }
+    }
+
+    /// Returns the answer a client sends for the challenge, echoing its c and MAC.
+    fn echoed_answer(challenge: &AuthenticationChallengeResponse) -> AuthenticationAnswerRequest {
+        AuthenticationAnswerRequest {
+            auth_id: challenge.auth_id.clone(),
+            s: "1".to_string(),
+            c: challenge.c.clone(),
+            mac: challenge.mac.clone(),
+        }
+    }
+
+    #[test]
+    fn mac_verifies_untampered_challenge() {
+        let challenge = test_challenge().with_mac(b"secret");
+        assert_eq!(challenge.mac.len(), 64);
+        assert!(echoed_answer(&challenge).verify_mac(b"secret").is_ok());
+    }
+
+    #[test]
+    fn mac_fails_when_tampered() {
+        let challenge = test_challenge().with_mac(b"secret");
+
+        let mut answer = echoed_answer(&challenge);
+        answer.c = "12346".to_string();
+        assert_eq!(
+            answer.verify_mac(b"secret"),
+            Err(ChallengeMacError::InvalidMac)
+        );
+
+        let mut answer = echoed_answer(&challenge);
+        answer.auth_id = "1b7a8f4e-5f0e-4b8e-9a59-1f3e6c2d7a10".to_string();
+        assert_eq!(
+            answer.verify_mac(b"secret"),
+            Err(ChallengeMacError::InvalidMac)
+        );
+
+        let mut answer = echoed_answer(&challenge);
+        answer.mac = "zz".to_string();
+        assert_eq!(
+            answer.verify_mac(b"secret"),
+            Err(ChallengeMacError::InvalidMac)
+        );
+
+        assert_eq!(
+            echoed_answer(&challenge).verify_mac(b"other"),
+            Err(ChallengeMacError::InvalidMac)
+        );
+    }
+
+    #[test]
+    fn mac_fails_when_missing() {
+        assert_eq!(
+            echoed_answer(&test_challenge()).verify_mac(b"secret"),
+            Err(ChallengeMacError::MissingMac)
+        );
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let challenge = test_challenge().sign(&key);
+        assert_eq!(challenge.signature.len(), 128);
+        assert!(challenge.verify_signature(&key.verifying_key()).is_ok());
+    }
+
+    #[test]
+    fn verify_fails_when_unsigned() {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        assert_eq!(
+            test_challenge().verify_signature(&key.verifying_key()),
+            Err(ChallengeSignatureError::MissingSignature)
+        );
+    }
+
+    #[test]
+    fn verify_fails_when_tampered() {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+
+        let mut challenge = test_challenge().sign(&key);
+        challenge.c = "12346".to_string();
+        assert_eq!(
+            challenge.verify_signature(&key.verifying_key()),
+            Err(ChallengeSignatureError::InvalidSignature)
+        );
+
+        let mut challenge = test_challenge().sign(&key);
+        challenge.auth_id = "1b7a8f4e-5f0e-4b8e-9a59-1f3e6c2d7a10".to_string();
+        assert_eq!(
+            challenge.verify_signature(&key.verifying_key()),
+            Err(ChallengeSignatureError::InvalidSignature)
+        );
+
+        let mut challenge = test_challenge().sign(&key);
+        challenge.signature = "zz".to_string();
+        assert_eq!(
+            challenge.verify_signature(&key.verifying_key()),
+            Err(ChallengeSignatureError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn verify_fails_with_other_key() {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let other = SigningKey::from_bytes(&[8u8; 32]);
+        let challenge = test_challenge().sign(&key);
+        assert_eq!(
+            challenge.verify_signature(&other.verifying_key()),
+            Err(ChallengeSignatureError::InvalidSignature)
+        );
+    }
+}
@@ -0,0 +1,321 @@
+//! The versioned on-disk format of a configuration file.
+//!
+//! Configuration files are written with an explicit schema rather than the serde implementation
+//! generated for the protobuf [`Configuration`], so the file format does not shift if the
+//! protobuf code is regenerated with different options. A version 1 file in JSON looks like:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "discrete_logarithm": {
+//!     "p": "23",
+//!     "q": "11",
+//!     "g": "4",
+//!     "h": "9"
+//!   }
+//! }
+//! ```
+//!
+//! or, for the elliptic curve flavor, has an `elliptic_curve` object with `g` and `h`, and `curve`
+//! unless it is the default ristretto255. Files without a `version` field are in the legacy format,
+//! which is the generated protobuf JSON, and are still accepted when loading.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::v1::{configuration, Configuration};
+
+/// The version of the configuration file format that is written.
+pub const CONFIG_FILE_VERSION: u32 = 1;
+
+/// A configuration as it is stored on disk.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigFile {
+    /// The version of the file format.
+    #[serde(rename = "version")]
+    pub version: u32,
+
+    /// The protocol version of the configuration, omitted when unset.
+    #[serde(
+        rename = "protocol_version",
+        default,
+        skip_serializing_if = "is_unset_protocol_version"
+    )]
+    pub protocol_version: u32,
+
+    /// The flavor and public parameters of the configuration.
+    #[serde(flatten)]
+    pub flavor: ConfigFileFlavor,
+}
+
+/// The flavor and public parameters of a configuration file, keyed by the flavor name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConfigFileFlavor {
+    /// Discrete logarithm parameters, as decimal strings.
+    #[serde(rename = "discrete_logarithm")]
+    DiscreteLogarithm {
+        #[serde(rename = "p")]
+        p: String,
+        #[serde(rename = "q")]
+        q: String,
+        #[serde(rename = "g")]
+        g: String,
+        #[serde(rename = "h")]
+        h: String,
+    },
+
+    /// Elliptic curve parameters, as compressed points encoded as decimal strings.
+    #[serde(rename = "elliptic_curve")]
+    EllipticCurve {
+        #[serde(rename = "g")]
+        g: String,
+        #[serde(rename = "h")]
+        h: String,
+        #[serde(rename = "curve", default, skip_serializing_if = "String::is_empty")]
+        curve: String,
+    },
+}
+
+/// Returns whether a protocol version is unset, so it is omitted from the file.
+fn is_unset_protocol_version(version: &u32) -> bool {
+    *version == 0
+}
+
+/// An error for when a configuration cannot be converted to or from a configuration file.
+#[derive(Debug)]
+pub enum ConfigFileError {
+    /// The configuration has no flavor, so it cannot be written or served.
+    MissingFlavor,
+
+    /// The file has a format version that is not supported.
+    UnsupportedVersion(u32),
+
+    /// The file could not be deserialized.
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for ConfigFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigFileError::MissingFlavor => write!(f, "configuration has no flavor"),
+            ConfigFileError::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported configuration file version {}, expected {}",
+                version, CONFIG_FILE_VERSION
+            ),
+            ConfigFileError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ConfigFileError {}
+
+impl From<serde_json::Error> for ConfigFileError {
+    fn from(err: serde_json::Error) -> Self {
+        ConfigFileError::Parse(err)
+    }
+}
+
+impl ConfigFile {
+    /// Deserializes a configuration from a JSON value in either the versioned file format or the
+    /// legacy protobuf JSON format, depending on whether it has a `version` field. A legacy file
+    /// without a flavor is rejected, since it usually means the file is corrupt.
+    pub fn parse_value(value: serde_json::Value) -> Result<Configuration, ConfigFileError> {
+        if value.get("version").is_none() {
+            let config: Configuration = serde_json::from_value(value)?;
+            if config.flavor.is_none() {
+                return Err(ConfigFileError::MissingFlavor);
+            }
+            return Ok(config);
+        }
+        let file: ConfigFile = serde_json::from_value(value)?;
+        file.try_into()
+    }
+}
+
+impl TryFrom<&Configuration> for ConfigFile {
+    type Error = ConfigFileError;
+
+    fn try_from(config: &Configuration) -> Result<Self, Self::Error> {
+        let flavor = match &config.flavor {
+            Some(configuration::Flavor::DiscreteLogarithm(params)) => {
+                ConfigFileFlavor::DiscreteLogarithm {
+                    p: params.p.clone(),
+                    q: params.q.clone(),
+                    g: params.g.clone(),
+                    h: params.h.clone(),
+                }
+            }
+            Some(configuration::Flavor::EllipticCurve(params)) => ConfigFileFlavor::EllipticCurve {
+                g: params.g.clone(),
+                h: params.h.clone(),
+                curve: params.curve.clone(),
+            },
+            None => return Err(ConfigFileError::MissingFlavor),
+        };
+        Ok(ConfigFile {
+            version: CONFIG_FILE_VERSION,
+            protocol_version: config.protocol_version,
+            flavor,
+        })
+    }
+}
+
+impl TryFrom<ConfigFile> for Configuration {
+    type Error = ConfigFileError;
+
+    fn try_from(file: ConfigFile) -> Result<Self, Self::Error> {
+        if file.version != CONFIG_FILE_VERSION {
+            return Err(ConfigFileError::UnsupportedVersion(file.version));
+        }
+        let flavor = match file.flavor {
+            ConfigFileFlavor::DiscreteLogarithm { p, q, g, h } => {
+                configuration::Flavor::DiscreteLogarithm(configuration::DiscreteLogarithm {
+                    p,
+                    q,
+                    g,
+                    h,
+                })
+            }
+            ConfigFileFlavor::EllipticCurve { g, h, curve } => {
+                configuration::Flavor::EllipticCurve(configuration::EllipticCurve { g, h, curve })
+            }
+        };
+        Ok(Configuration {
+            flavor: Some(flavor),
+            protocol_version: file.protocol_version,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn discrete_logarithm_config() -> Configuration {
+        Configuration {
+            flavor: Some(configuration::Flavor::DiscreteLogarithm(
+                configuration::DiscreteLogarithm {
+                    p: "23".to_string(),
+                    q: "11".to_string(),
+                    g: "4".to_string(),
+                    h: "9".to_string(),
+                },
+            )),
+            protocol_version: 0,
+        }
+    }
+
+    fn elliptic_curve_config() -> Configuration {
+        Configuration {
+            flavor: Some(configuration::Flavor::EllipticCurve(
+                configuration::EllipticCurve {
+                    g: "102651481954198948695408991041606107487729423467545670950322577403614277217654"
+                        .to_string(),
+                    h: "48074487330273963923922157365918574336273011965350013600919792151764305951001"
+                        .to_string(),
+                    curve: String::new(),
+                },
+            )),
+            protocol_version: 0,
+        }
+    }
+
+    fn secp256k1_config() -> Configuration {
+        let mut config = elliptic_curve_config();
+        if let Some(configuration::Flavor::EllipticCurve(ref mut params)) = config.flavor {
+            params.curve = "secp256k1".to_string();
+        }
+        config
+    }
+
+    /// Tests that each flavor is written exactly as in its golden file, so the on-disk format does
+    /// not change unnoticed, and that the golden files load back to the same configuration.
+    #[test]
+    fn json_schema_matches_golden_files() -> Result<(), ConfigFileError> {
+        for (config, golden) in [
+            (
+                discrete_logarithm_config(),
+                include_str!("testdata/config_file_v1_discrete_logarithm.json"),
+            ),
+            (
+                elliptic_curve_config(),
+                include_str!("testdata/config_file_v1_elliptic_curve.json"),
+            ),
+        ] {
+            let contents = serde_json::to_string_pretty(&ConfigFile::try_from(&config)?)?;
+            assert_eq!(format!("{}\n", contents), golden);
+            assert_eq!(
+                ConfigFile::parse_value(serde_json::from_str(golden)?)?,
+                config
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), ConfigFileError> {
+        for config in [
+            discrete_logarithm_config(),
+            elliptic_curve_config(),
+            Configuration {
+                protocol_version: 1,
+                ..discrete_logarithm_config()
+            },
+            secp256k1_config(),
+        ] {
+            let value = serde_json::to_value(ConfigFile::try_from(&config)?)?;
+            assert_eq!(ConfigFile::parse_value(value)?, config);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parses_legacy_format() -> Result<(), ConfigFileError> {
+        for config in [discrete_logarithm_config(), elliptic_curve_config()] {
+            let value = serde_json::to_value(&config)?;
+            assert!(value.get("version").is_none());
+            assert_eq!(ConfigFile::parse_value(value)?, config);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_unsupported_version() {
+        let value = serde_json::json!({
+            "version": 2,
+            "elliptic_curve": { "g": "1", "h": "2" },
+        });
+        let err = ConfigFile::parse_value(value).unwrap_err();
+        assert!(matches!(err, ConfigFileError::UnsupportedVersion(2)));
+        assert_eq!(
+            err.to_string(),
+            "unsupported configuration file version 2, expected 1"
+        );
+    }
+
+    #[test]
+    fn rejects_configuration_without_flavor() {
+        let config = Configuration {
+            flavor: None,
+            protocol_version: 0,
+        };
+        assert!(matches!(
+            ConfigFile::try_from(&config),
+            Err(ConfigFileError::MissingFlavor)
+        ));
+    }
+
+    #[test]
+    fn rejects_legacy_format_without_flavor() {
+        for value in [
+            serde_json::json!({}),
+            serde_json::json!({ "protocol_version": 1 }),
+        ] {
+            assert!(matches!(
+                ConfigFile::parse_value(value),
+                Err(ConfigFileError::MissingFlavor)
+            ));
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! A unified error type for the zkauth client and server, with conversions to and from gRPC
+//! statuses.
+
+use std::fmt;
+use tonic::{Code, Status};
+
+/// An error from the zkauth protocol, shared by the client and server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthError {
+    /// An argument is invalid, such as an empty user or a malformed value.
+    InvalidArgument(String),
+
+    /// A user or challenge was not found.
+    NotFound(String),
+
+    /// A user is already registered.
+    AlreadyExists(String),
+
+    /// The operation was rejected in the current state, such as a failed verification.
+    FailedPrecondition(String),
+
+    /// The other party could not be authenticated, such as a challenge with an invalid server
+    /// signature.
+    Unauthenticated(String),
+
+    /// The server could not be reached, or is temporarily unable to handle the request.
+    Unavailable(String),
+
+    /// An unexpected internal error, such as a failed conversion or computation.
+    Internal(String),
+
+    /// Any other gRPC status, keeping its code and message.
+    Other(Code, String),
+}
+
+/// Implementation of the auth error.
+impl AuthError {
+    /// Returns the gRPC status code of the error.
+    pub fn code(&self) -> Code {
+        match self {
+            AuthError::InvalidArgument(_) => Code::InvalidArgument,
+            AuthError::NotFound(_) => Code::NotFound,
+            AuthError::AlreadyExists(_) => Code::AlreadyExists,
+            AuthError::FailedPrecondition(_) => Code::FailedPrecondition,
+            AuthError::Unauthenticated(_) => Code::Unauthenticated,
+            AuthError::Unavailable(_) => Code::Unavailable,
+            AuthError::Internal(_) => Code::Internal,
+            AuthError::Other(code, _) => *code,
+        }
+    }
+
+    /// Returns the message of the error.
+    pub fn message(&self) -> &str {
+        match self {
+            AuthError::InvalidArgument(message)
+            | AuthError::NotFound(message)
+            | AuthError::AlreadyExists(message)
+            | AuthError::FailedPrecondition(message)
+            | AuthError::Unauthenticated(message)
+            | AuthError::Unavailable(message)
+            | AuthError::Internal(message)
+            | AuthError::Other(_, message) => message,
+        }
+    }
+}
+
+/// Formats the error as its code description followed by its message.
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.code().description(), self.message())
+    }
+}
+
+impl std::error::Error for AuthError {}
+
+/// Converts a gRPC status to an auth error, keeping its code and message.
+impl From<Status> for AuthError {
+    fn from(status: Status) -> Self {
+        let message = status.message().to_string();
+        match status.code() {
+            Code::InvalidArgument => AuthError::InvalidArgument(message),
+            Code::NotFound => AuthError::NotFound(message),
+            Code::AlreadyExists => AuthError::AlreadyExists(message),
+            Code::FailedPrecondition => AuthError::FailedPrecondition(message),
+            Code::Unauthenticated => AuthError::Unauthenticated(message),
+            Code::Unavailable => AuthError::Unavailable(message),
+            Code::Internal => AuthError::Internal(message),
+            code => AuthError::Other(code, message),
+        }
+    }
+}
+
+/// Converts an auth error to a gRPC status with the same code and message.
+impl From<AuthError> for Status {
+    fn from(err: AuthError) -> Self {
+        Status::new(err.code(), err.message())
+    }
+}
+
+/// Converts a failed conversion of a protocol value to an internal error.
+impl From<zkauth::ConversionError> for AuthError {
+    fn from(_: zkauth::ConversionError) -> Self {
+        AuthError::Internal("failed to convert value".to_string())
+    }
+}
+
+/// Converts a transport error, such as a failed connection, to an unavailable error. The causes of
+/// the error are appended to its message, since a transport error alone only says that the
+/// transport failed, not why, such as a server certificate that is not valid for its name.
+impl From<tonic::transport::Error> for AuthError {
+    fn from(err: tonic::transport::Error) -> Self {
+        let mut message = format!("failed to connect: {}", err);
+        let mut source = std::error::Error::source(&err);
+        while let Some(cause) = source {
+            message.push_str(&format!(": {}", cause));
+            source = cause.source();
+        }
+        AuthError::Unavailable(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_round_trip() {
+        for (status, err) in [
+            (
+                Status::invalid_argument("Invalid user argument"),
+                AuthError::InvalidArgument("Invalid user argument".to_string()),
+            ),
+            (
+                Status::not_found("User not found"),
+                AuthError::NotFound("User not found".to_string()),
+            ),
+            (
+                Status::already_exists("User already registered"),
+                AuthError::AlreadyExists("User already registered".to_string()),
+            ),
+            (
+                Status::failed_precondition("Verification failed"),
+                AuthError::FailedPrecondition("Verification failed".to_string()),
+            ),
+            (
+                Status::unauthenticated("Invalid server signature"),
+                AuthError::Unauthenticated("Invalid server signature".to_string()),
+            ),
+            (
+                Status::unavailable("failed to connect"),
+                AuthError::Unavailable("failed to connect".to_string()),
+            ),
+            (
+                Status::internal("failed to parse c"),
+                AuthError::Internal("failed to parse c".to_string()),
+            ),
+            (
+                Status::out_of_range("message too large"),
+                AuthError::Other(Code::OutOfRange, "message too large".to_string()),
+            ),
+        ] {
+            let code = status.code();
+            let converted = AuthError::from(status);
+            assert_eq!(converted, err);
+            assert_eq!(converted.code(), code);
+
+            let status = Status::from(converted);
+            assert_eq!(status.code(), code);
+            assert_eq!(status.message(), err.message());
+        }
+    }
+
+    #[test]
+    fn display_includes_code_and_message() {
+        let err = AuthError::NotFound("User not found".to_string());
+        assert_eq!(
+            err.to_string(),
+            "Some requested entity was not found: User not found"
+        );
+    }
+
+    #[test]
+    fn conversion_error_is_internal() {
+        let err = AuthError::from(zkauth::ConversionError);
+        assert_eq!(err.code(), Code::Internal);
+    }
+}
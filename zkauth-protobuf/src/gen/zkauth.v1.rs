@@ -8,6 +8,10 @@ pub struct RegisterRequest {
     pub y1: ::prost::alloc::string::String,
     #[prost(string, tag="3")]
     pub y2: ::prost::alloc::string::String,
+    /// Optional non-secret metadata about the user, such as a display name or roles, stored
+    /// alongside the registration without affecting the cryptographic fields.
+    #[prost(map="string, string", tag="4")]
+    pub metadata: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -30,6 +34,32 @@ pub struct AuthenticationChallengeResponse {
     pub auth_id: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
     pub c: ::prost::alloc::string::String,
+    /// Hex-encoded Ed25519 signature over (auth_id, c) by the server's long-term key, if configured.
+    #[prost(string, tag="3")]
+    pub signature: ::prost::alloc::string::String,
+    /// Hex-encoded HMAC-SHA256 over (auth_id, c) with the server's challenge MAC secret, if
+    /// configured. The client echoes it back with c in the answer.
+    #[prost(string, tag="4")]
+    pub mac: ::prost::alloc::string::String,
+}
+/// Registers a user and creates an authentication challenge for it in one round trip, with the
+/// fields of a RegisterRequest followed by those of an AuthenticationChallengeRequest. The
+/// response is the challenge, as from CreateAuthenticationChallenge.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RegisterAndChallengeRequest {
+    #[prost(string, tag="1")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub y1: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub y2: ::prost::alloc::string::String,
+    #[prost(map="string, string", tag="4")]
+    pub metadata: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    #[prost(string, tag="5")]
+    pub r1: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub r2: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -38,12 +68,73 @@ pub struct AuthenticationAnswerRequest {
     pub auth_id: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
     pub s: ::prost::alloc::string::String,
+    /// The challenge c and its MAC, echoed back from the challenge response so the server can check
+    /// that c was not altered in transit.
+    #[prost(string, tag="3")]
+    pub c: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub mac: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AuthenticationAnswerResponse {
     #[prost(string, tag="1")]
     pub session_id: ::prost::alloc::string::String,
+    /// A short-lived HS256 JWT with the user in `sub`, the session id in `sid`, and its expiry in
+    /// `exp`, which can be validated offline. Empty unless the server has a JWT signing key.
+    #[prost(string, tag="2")]
+    pub token: ::prost::alloc::string::String,
+}
+/// Authenticates a user in a single round trip with a non-interactive proof, whose challenge c is
+/// derived by the client from the hash of g, h, the user's y1 and y2, r1 and r2, the user as the
+/// server normalizes it, and the timestamp, instead of being requested from the server. The
+/// response is the session, as from VerifyAuthentication. The server rejects a proof whose
+/// timestamp is outside its freshness window, or whose r1 and r2 were already used for the user
+/// within it, so a captured request cannot be replayed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthenticateRequest {
+    #[prost(string, tag="1")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub r1: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub r2: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub s: ::prost::alloc::string::String,
+    /// The time the proof was computed, in seconds since the Unix epoch.
+    #[prost(uint64, tag="5")]
+    pub timestamp: u64,
+}
+/// Replaces a user's y1 and y2, such as when they change their password. The change is authorized
+/// by an answer to a challenge created for the user with their current registration, and applies
+/// to the user the challenge was created for. Their metadata is kept.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateCredentialsRequest {
+    #[prost(message, optional, tag="1")]
+    pub answer: ::core::option::Option<AuthenticationAnswerRequest>,
+    #[prost(string, tag="2")]
+    pub y1: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub y2: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateCredentialsResponse {
+}
+/// Removes a user's registration, after which the username can be registered again. Like
+/// UpdateCredentials, the removal is authorized by an answer to a challenge created for the user
+/// with their current registration, and applies to the user the challenge was created for.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnregisterRequest {
+    #[prost(message, optional, tag="1")]
+    pub answer: ::core::option::Option<AuthenticationAnswerRequest>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnregisterResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -51,7 +142,84 @@ pub struct GetConfigurationRequest {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchConfigurationRequest {
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SelfTestRequest {
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SelfTestResponse {
+    #[prost(bool, tag="1")]
+    pub passed: bool,
+    #[prost(string, tag="2")]
+    pub error: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetUserRequest {
+    #[prost(string, tag="1")]
+    pub user: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetUserResponse {
+    #[prost(string, tag="1")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(map="string, string", tag="2")]
+    pub metadata: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchSessionsRequest {
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SessionCreated {
+    #[prost(string, tag="1")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub session_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SessionRevoked {
+    #[prost(string, tag="1")]
+    pub session_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SessionExpired {
+    #[prost(string, tag="1")]
+    pub session_id: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchSessionsResponse {
+    #[prost(oneof="watch_sessions_response::Event", tags="1, 2, 3")]
+    pub event: ::core::option::Option<watch_sessions_response::Event>,
+}
+/// Nested message and enum types in `WatchSessionsResponse`.
+pub mod watch_sessions_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag="1")]
+        Created(super::SessionCreated),
+        #[prost(message, tag="2")]
+        Revoked(super::SessionRevoked),
+        #[prost(message, tag="3")]
+        Expired(super::SessionExpired),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Configuration {
+    /// The protocol version the server speaks, set when served by GetConfiguration. Unset (0) means
+    /// version 1, the version before protocol versions were advertised.
+    #[prost(uint32, tag="3")]
+    pub protocol_version: u32,
     #[prost(oneof="configuration::Flavor", tags="1, 2")]
     pub flavor: ::core::option::Option<configuration::Flavor>,
 }
@@ -76,6 +244,10 @@ pub mod configuration {
         pub g: ::prost::alloc::string::String,
         #[prost(string, tag="2")]
         pub h: ::prost::alloc::string::String,
+        /// The curve of the points: ristretto255 or secp256k1. Unset (empty) means ristretto255, the
+        /// curve before others were supported.
+        #[prost(string, tag="3")]
+        pub curve: ::prost::alloc::string::String,
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Oneof)]
@@ -88,188 +260,675 @@ pub mod configuration {
 }
 /// Encoded file descriptor set for the `zkauth.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0xce, 0x16, 0x0a, 0x08, 0x76, 0x31, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x09, 0x7a,
-    0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x22, 0x45, 0x0a, 0x0f, 0x52, 0x65, 0x67, 0x69,
-    0x73, 0x74, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x75,
-    0x73, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72, 0x12,
-    0x0e, 0x0a, 0x02, 0x79, 0x31, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x79, 0x31, 0x12,
-    0x0e, 0x0a, 0x02, 0x79, 0x32, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x79, 0x32, 0x22,
-    0x12, 0x0a, 0x10, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f,
-    0x6e, 0x73, 0x65, 0x22, 0x54, 0x0a, 0x1e, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63,
-    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x52, 0x65,
-    0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72, 0x18, 0x01, 0x20,
-    0x01, 0x28, 0x09, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72, 0x12, 0x0e, 0x0a, 0x02, 0x72, 0x31, 0x18,
-    0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x72, 0x31, 0x12, 0x0e, 0x0a, 0x02, 0x72, 0x32, 0x18,
-    0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x72, 0x32, 0x22, 0x48, 0x0a, 0x1f, 0x41, 0x75, 0x74,
-    0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x68, 0x61, 0x6c, 0x6c,
-    0x65, 0x6e, 0x67, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x17, 0x0a, 0x07,
-    0x61, 0x75, 0x74, 0x68, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x61,
-    0x75, 0x74, 0x68, 0x49, 0x64, 0x12, 0x0c, 0x0a, 0x01, 0x63, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
-    0x52, 0x01, 0x63, 0x22, 0x44, 0x0a, 0x1b, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63,
+    0x0a, 0xc2, 0x53, 0x0a, 0x08, 0x76, 0x31, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x09, 0x7a,
+    0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x22, 0xc8, 0x01, 0x0a, 0x0f, 0x52, 0x65, 0x67,
+    0x69, 0x73, 0x74, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04,
+    0x75, 0x73, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72,
+    0x12, 0x0e, 0x0a, 0x02, 0x79, 0x31, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x79, 0x31,
+    0x12, 0x0e, 0x0a, 0x02, 0x79, 0x32, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x79, 0x32,
+    0x12, 0x44, 0x0a, 0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x18, 0x04, 0x20, 0x03,
+    0x28, 0x0b, 0x32, 0x28, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x52,
+    0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x4d,
+    0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x08, 0x6d, 0x65,
+    0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x1a, 0x3b, 0x0a, 0x0d, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61,
+    0x74, 0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c,
+    0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a,
+    0x02, 0x38, 0x01, 0x22, 0x12, 0x0a, 0x10, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x54, 0x0a, 0x1e, 0x41, 0x75, 0x74, 0x68, 0x65,
+    0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e,
+    0x67, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x75, 0x73, 0x65,
+    0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72, 0x12, 0x0e, 0x0a,
+    0x02, 0x72, 0x31, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x72, 0x31, 0x12, 0x0e, 0x0a,
+    0x02, 0x72, 0x32, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x72, 0x32, 0x22, 0x78, 0x0a,
+    0x1f, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43,
+    0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x12, 0x17, 0x0a, 0x07, 0x61, 0x75, 0x74, 0x68, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x06, 0x61, 0x75, 0x74, 0x68, 0x49, 0x64, 0x12, 0x0c, 0x0a, 0x01, 0x63, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x01, 0x63, 0x12, 0x1c, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61,
+    0x74, 0x75, 0x72, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x73, 0x69, 0x67, 0x6e,
+    0x61, 0x74, 0x75, 0x72, 0x65, 0x12, 0x10, 0x0a, 0x03, 0x6d, 0x61, 0x63, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x03, 0x6d, 0x61, 0x63, 0x22, 0x80, 0x02, 0x0a, 0x1b, 0x52, 0x65, 0x67, 0x69,
+    0x73, 0x74, 0x65, 0x72, 0x41, 0x6e, 0x64, 0x43, 0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72, 0x12, 0x0e, 0x0a, 0x02, 0x79,
+    0x31, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x79, 0x31, 0x12, 0x0e, 0x0a, 0x02, 0x79,
+    0x32, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x79, 0x32, 0x12, 0x50, 0x0a, 0x08, 0x6d,
+    0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x34, 0x2e,
+    0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74,
+    0x65, 0x72, 0x41, 0x6e, 0x64, 0x43, 0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x45, 0x6e,
+    0x74, 0x72, 0x79, 0x52, 0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x12, 0x0e, 0x0a,
+    0x02, 0x72, 0x31, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x72, 0x31, 0x12, 0x0e, 0x0a,
+    0x02, 0x72, 0x32, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x72, 0x32, 0x1a, 0x3b, 0x0a,
+    0x0d, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10,
+    0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79,
+    0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x22, 0x64, 0x0a, 0x1b, 0x41, 0x75,
+    0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x6e, 0x73, 0x77,
+    0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x17, 0x0a, 0x07, 0x61, 0x75, 0x74,
+    0x68, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x61, 0x75, 0x74, 0x68,
+    0x49, 0x64, 0x12, 0x0c, 0x0a, 0x01, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x01, 0x73,
+    0x12, 0x0c, 0x0a, 0x01, 0x63, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x01, 0x63, 0x12, 0x10,
+    0x0a, 0x03, 0x6d, 0x61, 0x63, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6d, 0x61, 0x63,
+    0x22, 0x53, 0x0a, 0x1c, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x41, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x12,
+    0x14, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x22, 0x75, 0x0a, 0x13, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74,
+    0x69, 0x63, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x12, 0x0a, 0x04,
+    0x75, 0x73, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72,
+    0x12, 0x0e, 0x0a, 0x02, 0x72, 0x31, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x72, 0x31,
+    0x12, 0x0e, 0x0a, 0x02, 0x72, 0x32, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x72, 0x32,
+    0x12, 0x0c, 0x0a, 0x01, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x01, 0x73, 0x12, 0x1c,
+    0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x05, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x22, 0x7a, 0x0a, 0x18,
+    0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c,
+    0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x3e, 0x0a, 0x06, 0x61, 0x6e, 0x73, 0x77,
+    0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74,
+    0x68, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74,
+    0x69, 0x6f, 0x6e, 0x41, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x52, 0x06, 0x61, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x12, 0x0e, 0x0a, 0x02, 0x79, 0x31, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x79, 0x31, 0x12, 0x0e, 0x0a, 0x02, 0x79, 0x32, 0x18, 0x03,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x79, 0x32, 0x22, 0x1b, 0x0a, 0x19, 0x55, 0x70, 0x64, 0x61,
+    0x74, 0x65, 0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c, 0x73, 0x52, 0x65, 0x73,
+    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x53, 0x0a, 0x11, 0x55, 0x6e, 0x72, 0x65, 0x67, 0x69, 0x73,
+    0x74, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x3e, 0x0a, 0x06, 0x61, 0x6e,
+    0x73, 0x77, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x7a, 0x6b, 0x61,
+    0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63,
     0x61, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65,
-    0x73, 0x74, 0x12, 0x17, 0x0a, 0x07, 0x61, 0x75, 0x74, 0x68, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
-    0x01, 0x28, 0x09, 0x52, 0x06, 0x61, 0x75, 0x74, 0x68, 0x49, 0x64, 0x12, 0x0c, 0x0a, 0x01, 0x73,
-    0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x01, 0x73, 0x22, 0x3d, 0x0a, 0x1c, 0x41, 0x75, 0x74,
-    0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x6e, 0x73, 0x77, 0x65,
-    0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x65, 0x73,
-    0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x73,
-    0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x22, 0x19, 0x0a, 0x17, 0x47, 0x65, 0x74, 0x43,
+    0x73, 0x74, 0x52, 0x06, 0x61, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x22, 0x14, 0x0a, 0x12, 0x55, 0x6e,
+    0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x22, 0x19, 0x0a, 0x17, 0x47, 0x65, 0x74, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x1b, 0x0a, 0x19, 0x57,
+    0x61, 0x74, 0x63, 0x68, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x11, 0x0a, 0x0f, 0x53, 0x65, 0x6c, 0x66,
+    0x54, 0x65, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x40, 0x0a, 0x10, 0x53,
+    0x65, 0x6c, 0x66, 0x54, 0x65, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+    0x16, 0x0a, 0x06, 0x70, 0x61, 0x73, 0x73, 0x65, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x08, 0x52,
+    0x06, 0x70, 0x61, 0x73, 0x73, 0x65, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x24, 0x0a,
+    0x0e, 0x47, 0x65, 0x74, 0x55, 0x73, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+    0x12, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75,
+    0x73, 0x65, 0x72, 0x22, 0xa8, 0x01, 0x0a, 0x0f, 0x47, 0x65, 0x74, 0x55, 0x73, 0x65, 0x72, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72, 0x12, 0x44, 0x0a, 0x08, 0x6d,
+    0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x28, 0x2e,
+    0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x55, 0x73, 0x65,
+    0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x2e, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61,
+    0x74, 0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x08, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74,
+    0x61, 0x1a, 0x3b, 0x0a, 0x0d, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x45, 0x6e, 0x74,
+    0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x22, 0x16,
+    0x0a, 0x14, 0x57, 0x61, 0x74, 0x63, 0x68, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x43, 0x0a, 0x0e, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f,
+    0x6e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x75, 0x73, 0x65, 0x72,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72, 0x12, 0x1d, 0x0a, 0x0a,
+    0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x09, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x22, 0x2f, 0x0a, 0x0e, 0x53,
+    0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x64, 0x12, 0x1d, 0x0a,
+    0x0a, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x09, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x22, 0x2f, 0x0a, 0x0e,
+    0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x45, 0x78, 0x70, 0x69, 0x72, 0x65, 0x64, 0x12, 0x1d,
+    0x0a, 0x0a, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x09, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x22, 0xc5, 0x01,
+    0x0a, 0x15, 0x57, 0x61, 0x74, 0x63, 0x68, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x35, 0x0a, 0x07, 0x63, 0x72, 0x65, 0x61, 0x74,
+    0x65, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74,
+    0x68, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x43, 0x72, 0x65, 0x61,
+    0x74, 0x65, 0x64, 0x48, 0x00, 0x52, 0x07, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x12, 0x35,
+    0x0a, 0x07, 0x72, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x19, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x65, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x52, 0x65, 0x76, 0x6f, 0x6b, 0x65, 0x64, 0x48, 0x00, 0x52, 0x07, 0x72, 0x65,
+    0x76, 0x6f, 0x6b, 0x65, 0x64, 0x12, 0x35, 0x0a, 0x07, 0x65, 0x78, 0x70, 0x69, 0x72, 0x65, 0x64,
+    0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e,
+    0x76, 0x31, 0x2e, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x45, 0x78, 0x70, 0x69, 0x72, 0x65,
+    0x64, 0x48, 0x00, 0x52, 0x07, 0x65, 0x78, 0x70, 0x69, 0x72, 0x65, 0x64, 0x42, 0x07, 0x0a, 0x05,
+    0x65, 0x76, 0x65, 0x6e, 0x74, 0x22, 0x82, 0x03, 0x0a, 0x0d, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67,
+    0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x5b, 0x0a, 0x12, 0x64, 0x69, 0x73, 0x63, 0x72,
+    0x65, 0x74, 0x65, 0x5f, 0x6c, 0x6f, 0x67, 0x61, 0x72, 0x69, 0x74, 0x68, 0x6d, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x2a, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e,
+    0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x44, 0x69,
+    0x73, 0x63, 0x72, 0x65, 0x74, 0x65, 0x4c, 0x6f, 0x67, 0x61, 0x72, 0x69, 0x74, 0x68, 0x6d, 0x48,
+    0x00, 0x52, 0x11, 0x64, 0x69, 0x73, 0x63, 0x72, 0x65, 0x74, 0x65, 0x4c, 0x6f, 0x67, 0x61, 0x72,
+    0x69, 0x74, 0x68, 0x6d, 0x12, 0x4f, 0x0a, 0x0e, 0x65, 0x6c, 0x6c, 0x69, 0x70, 0x74, 0x69, 0x63,
+    0x5f, 0x63, 0x75, 0x72, 0x76, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x7a,
+    0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75,
+    0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x45, 0x6c, 0x6c, 0x69, 0x70, 0x74, 0x69, 0x63, 0x43,
+    0x75, 0x72, 0x76, 0x65, 0x48, 0x00, 0x52, 0x0d, 0x65, 0x6c, 0x6c, 0x69, 0x70, 0x74, 0x69, 0x63,
+    0x43, 0x75, 0x72, 0x76, 0x65, 0x12, 0x29, 0x0a, 0x10, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f,
+    0x6c, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52,
+    0x0f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e,
+    0x1a, 0x4b, 0x0a, 0x11, 0x44, 0x69, 0x73, 0x63, 0x72, 0x65, 0x74, 0x65, 0x4c, 0x6f, 0x67, 0x61,
+    0x72, 0x69, 0x74, 0x68, 0x6d, 0x12, 0x0c, 0x0a, 0x01, 0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x01, 0x70, 0x12, 0x0c, 0x0a, 0x01, 0x71, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x01,
+    0x71, 0x12, 0x0c, 0x0a, 0x01, 0x67, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x01, 0x67, 0x12,
+    0x0c, 0x0a, 0x01, 0x68, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x01, 0x68, 0x1a, 0x41, 0x0a,
+    0x0d, 0x45, 0x6c, 0x6c, 0x69, 0x70, 0x74, 0x69, 0x63, 0x43, 0x75, 0x72, 0x76, 0x65, 0x12, 0x0c,
+    0x0a, 0x01, 0x67, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x01, 0x67, 0x12, 0x0c, 0x0a, 0x01,
+    0x68, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x01, 0x68, 0x12, 0x14, 0x0a, 0x05, 0x63, 0x75,
+    0x72, 0x76, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x63, 0x75, 0x72, 0x76, 0x65,
+    0x42, 0x08, 0x0a, 0x06, 0x66, 0x6c, 0x61, 0x76, 0x6f, 0x72, 0x32, 0xbb, 0x08, 0x0a, 0x04, 0x41,
+    0x75, 0x74, 0x68, 0x12, 0x52, 0x0a, 0x10, 0x47, 0x65, 0x74, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67,
+    0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x22, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68,
+    0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x18, 0x2e, 0x7a, 0x6b,
+    0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x00, 0x12, 0x58, 0x0a, 0x12, 0x57, 0x61, 0x74, 0x63, 0x68,
+    0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x24, 0x2e,
+    0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x57, 0x61, 0x74, 0x63, 0x68, 0x43,
     0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75,
-    0x65, 0x73, 0x74, 0x22, 0xc1, 0x02, 0x0a, 0x0d, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72,
-    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x5b, 0x0a, 0x12, 0x64, 0x69, 0x73, 0x63, 0x72, 0x65, 0x74,
-    0x65, 0x5f, 0x6c, 0x6f, 0x67, 0x61, 0x72, 0x69, 0x74, 0x68, 0x6d, 0x18, 0x01, 0x20, 0x01, 0x28,
-    0x0b, 0x32, 0x2a, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f,
-    0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x44, 0x69, 0x73, 0x63,
-    0x72, 0x65, 0x74, 0x65, 0x4c, 0x6f, 0x67, 0x61, 0x72, 0x69, 0x74, 0x68, 0x6d, 0x48, 0x00, 0x52,
-    0x11, 0x64, 0x69, 0x73, 0x63, 0x72, 0x65, 0x74, 0x65, 0x4c, 0x6f, 0x67, 0x61, 0x72, 0x69, 0x74,
-    0x68, 0x6d, 0x12, 0x4f, 0x0a, 0x0e, 0x65, 0x6c, 0x6c, 0x69, 0x70, 0x74, 0x69, 0x63, 0x5f, 0x63,
-    0x75, 0x72, 0x76, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x7a, 0x6b, 0x61,
-    0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61,
-    0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x45, 0x6c, 0x6c, 0x69, 0x70, 0x74, 0x69, 0x63, 0x43, 0x75, 0x72,
-    0x76, 0x65, 0x48, 0x00, 0x52, 0x0d, 0x65, 0x6c, 0x6c, 0x69, 0x70, 0x74, 0x69, 0x63, 0x43, 0x75,
-    0x72, 0x76, 0x65, 0x1a, 0x4b, 0x0a, 0x11, 0x44, 0x69, 0x73, 0x63, 0x72, 0x65, 0x74, 0x65, 0x4c,
-    0x6f, 0x67, 0x61, 0x72, 0x69, 0x74, 0x68, 0x6d, 0x12, 0x0c, 0x0a, 0x01, 0x70, 0x18, 0x01, 0x20,
-    0x01, 0x28, 0x09, 0x52, 0x01, 0x70, 0x12, 0x0c, 0x0a, 0x01, 0x71, 0x18, 0x02, 0x20, 0x01, 0x28,
-    0x09, 0x52, 0x01, 0x71, 0x12, 0x0c, 0x0a, 0x01, 0x67, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52,
-    0x01, 0x67, 0x12, 0x0c, 0x0a, 0x01, 0x68, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x01, 0x68,
-    0x1a, 0x2b, 0x0a, 0x0d, 0x45, 0x6c, 0x6c, 0x69, 0x70, 0x74, 0x69, 0x63, 0x43, 0x75, 0x72, 0x76,
-    0x65, 0x12, 0x0c, 0x0a, 0x01, 0x67, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x01, 0x67, 0x12,
-    0x0c, 0x0a, 0x01, 0x68, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x01, 0x68, 0x42, 0x08, 0x0a,
-    0x06, 0x66, 0x6c, 0x61, 0x76, 0x6f, 0x72, 0x32, 0x86, 0x03, 0x0a, 0x04, 0x41, 0x75, 0x74, 0x68,
-    0x12, 0x52, 0x0a, 0x10, 0x47, 0x65, 0x74, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61,
-    0x74, 0x69, 0x6f, 0x6e, 0x12, 0x22, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31,
-    0x2e, 0x47, 0x65, 0x74, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f,
-    0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x18, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74,
-    0x68, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69,
-    0x6f, 0x6e, 0x22, 0x00, 0x12, 0x45, 0x0a, 0x08, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72,
-    0x12, 0x1a, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x67,
-    0x69, 0x73, 0x74, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x7a,
-    0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65,
-    0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x78, 0x0a, 0x1d, 0x43,
-    0x72, 0x65, 0x61, 0x74, 0x65, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74,
-    0x69, 0x6f, 0x6e, 0x43, 0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x12, 0x29, 0x2e, 0x7a,
-    0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74,
-    0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65,
-    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2a, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68,
-    0x2e, 0x76, 0x31, 0x2e, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69,
-    0x6f, 0x6e, 0x43, 0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f,
-    0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x69, 0x0a, 0x14, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x41,
-    0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x26, 0x2e,
-    0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e,
-    0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x52, 0x65,
-    0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x27, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76,
-    0x31, 0x2e, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e,
-    0x41, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00,
-    0x42, 0x5d, 0x0a, 0x0d, 0x63, 0x6f, 0x6d, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76,
-    0x31, 0x42, 0x07, 0x56, 0x31, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01, 0xa2, 0x02, 0x03, 0x5a,
-    0x58, 0x58, 0xaa, 0x02, 0x09, 0x5a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x56, 0x31, 0xca, 0x02,
-    0x09, 0x5a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x15, 0x5a, 0x6b, 0x61,
-    0x75, 0x74, 0x68, 0x5c, 0x56, 0x31, 0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61,
-    0x74, 0x61, 0xea, 0x02, 0x0a, 0x5a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x3a, 0x3a, 0x56, 0x31, 0x4a,
-    0xe7, 0x0c, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x38, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12,
-    0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x01, 0x00, 0x12, 0x0a, 0x0a,
-    0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x03, 0x00, 0x07, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00,
-    0x01, 0x12, 0x03, 0x03, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03,
-    0x04, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x04, 0x02,
-    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x04, 0x09, 0x0d, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x04, 0x10, 0x11, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x05, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x01, 0x05, 0x12, 0x03, 0x05, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
-    0x01, 0x12, 0x03, 0x05, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12,
-    0x03, 0x05, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x06, 0x02,
-    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x06, 0x02, 0x08, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x06, 0x09, 0x0b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x06, 0x0e, 0x0f, 0x0a, 0x09, 0x0a, 0x02, 0x04,
-    0x01, 0x12, 0x03, 0x09, 0x00, 0x1b, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x09,
-    0x08, 0x18, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x0b, 0x00, 0x0f, 0x01, 0x0a, 0x0a,
-    0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x0b, 0x08, 0x26, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02,
-    0x02, 0x00, 0x12, 0x03, 0x0c, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05,
-    0x12, 0x03, 0x0c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x0c, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0c, 0x10,
-    0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x02, 0x10, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0d, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x01, 0x03, 0x12, 0x03, 0x0d, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x02,
-    0x12, 0x03, 0x0e, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x05, 0x12, 0x03,
-    0x0e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0e, 0x09,
-    0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x0e, 0x0e, 0x0f, 0x0a,
-    0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x11, 0x00, 0x14, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
-    0x03, 0x01, 0x12, 0x03, 0x11, 0x08, 0x27, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12,
-    0x03, 0x12, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x12,
-    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x12, 0x09, 0x10,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x12, 0x13, 0x14, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x13, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x13, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x13, 0x09, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03,
-    0x12, 0x03, 0x13, 0x0d, 0x0e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x16, 0x00, 0x19,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x16, 0x08, 0x23, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x17, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x17, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x17, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x17, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x18, 0x02,
-    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x03, 0x18, 0x02, 0x08, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x18, 0x09, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x18, 0x0d, 0x0e, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
-    0x05, 0x12, 0x04, 0x1b, 0x00, 0x1d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03,
-    0x1b, 0x08, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x1c, 0x02, 0x18,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x1c, 0x02, 0x08, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1c, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1c, 0x16, 0x17, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x06,
-    0x12, 0x03, 0x1f, 0x00, 0x22, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x1f, 0x08,
-    0x1f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x21, 0x00, 0x31, 0x01, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x21, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x07, 0x03,
-    0x00, 0x12, 0x04, 0x22, 0x02, 0x27, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x03, 0x00, 0x01,
-    0x12, 0x03, 0x22, 0x0a, 0x1b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x03, 0x00, 0x02, 0x00, 0x12,
-    0x03, 0x23, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x03, 0x00, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x23, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x03, 0x00, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x23, 0x0b, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x03, 0x00, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x23, 0x0f, 0x10, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x03, 0x00, 0x02, 0x01, 0x12, 0x03,
-    0x24, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x03, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03,
-    0x24, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x03, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03,
-    0x24, 0x0b, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x03, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03,
-    0x24, 0x0f, 0x10, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x03, 0x00, 0x02, 0x02, 0x12, 0x03, 0x25,
-    0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x03, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x25,
-    0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x03, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x25,
-    0x0b, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x03, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x25,
-    0x0f, 0x10, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x03, 0x00, 0x02, 0x03, 0x12, 0x03, 0x26, 0x04,
-    0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x03, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x26, 0x04,
-    0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x03, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x26, 0x0b,
-    0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x03, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x26, 0x0f,
-    0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x07, 0x03, 0x01, 0x12, 0x04, 0x28, 0x02, 0x2b, 0x03, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x03, 0x01, 0x01, 0x12, 0x03, 0x28, 0x0a, 0x17, 0x0a, 0x0d, 0x0a,
-    0x06, 0x04, 0x07, 0x03, 0x01, 0x02, 0x00, 0x12, 0x03, 0x29, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07,
-    0x04, 0x07, 0x03, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x29, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07,
-    0x04, 0x07, 0x03, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x29, 0x0b, 0x0c, 0x0a, 0x0e, 0x0a, 0x07,
-    0x04, 0x07, 0x03, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x29, 0x0f, 0x10, 0x0a, 0x0d, 0x0a, 0x06,
-    0x04, 0x07, 0x03, 0x01, 0x02, 0x01, 0x12, 0x03, 0x2a, 0x04, 0x11, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
-    0x07, 0x03, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x2a, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
-    0x07, 0x03, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2a, 0x0b, 0x0c, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
-    0x07, 0x03, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x2a, 0x0f, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x07, 0x08, 0x00, 0x12, 0x04, 0x2d, 0x02, 0x30, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x08,
-    0x00, 0x01, 0x12, 0x03, 0x2d, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12,
-    0x03, 0x2e, 0x04, 0x2d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x06, 0x12, 0x03, 0x2e,
-    0x04, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2e, 0x16, 0x28,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2e, 0x2b, 0x2c, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x07, 0x02, 0x01, 0x12, 0x03, 0x2f, 0x04, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x07, 0x02, 0x01, 0x06, 0x12, 0x03, 0x2f, 0x04, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x2f, 0x12, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03,
-    0x12, 0x03, 0x2f, 0x23, 0x24, 0x0a, 0x0a, 0x0a, 0x02, 0x06, 0x00, 0x12, 0x04, 0x33, 0x00, 0x38,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x03, 0x33, 0x08, 0x0c, 0x0a, 0x0b, 0x0a,
-    0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03, 0x34, 0x02, 0x4a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x34, 0x06, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00,
-    0x02, 0x12, 0x03, 0x34, 0x17, 0x2e, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x34, 0x39, 0x46, 0x0a, 0x0b, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x03, 0x35, 0x02,
-    0x3d, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x35, 0x06, 0x0e, 0x0a,
-    0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x35, 0x0f, 0x1e, 0x0a, 0x0c, 0x0a,
-    0x05, 0x06, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x35, 0x29, 0x39, 0x0a, 0x0b, 0x0a, 0x04, 0x06,
-    0x00, 0x02, 0x02, 0x12, 0x03, 0x36, 0x02, 0x70, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02,
-    0x01, 0x12, 0x03, 0x36, 0x06, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x02, 0x12,
-    0x03, 0x36, 0x24, 0x42, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x36,
-    0x4d, 0x6c, 0x0a, 0x0b, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x03, 0x12, 0x03, 0x37, 0x02, 0x61, 0x0a,
-    0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x37, 0x06, 0x1a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x06, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x37, 0x1b, 0x36, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
-    0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x37, 0x41, 0x5d, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f,
-    0x33,
+    0x65, 0x73, 0x74, 0x1a, 0x18, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e,
+    0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x22, 0x00, 0x30,
+    0x01, 0x12, 0x45, 0x0a, 0x08, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x12, 0x1a, 0x2e,
+    0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74,
+    0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x7a, 0x6b, 0x61, 0x75,
+    0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x78, 0x0a, 0x1d, 0x43, 0x72, 0x65, 0x61,
+    0x74, 0x65, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x43, 0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x12, 0x29, 0x2e, 0x7a, 0x6b, 0x61, 0x75,
+    0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x43, 0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x52, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x1a, 0x2a, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31,
+    0x2e, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43,
+    0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x22, 0x00, 0x12, 0x69, 0x0a, 0x14, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x41, 0x75, 0x74, 0x68,
+    0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x26, 0x2e, 0x7a, 0x6b, 0x61,
+    0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x1a, 0x27, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x41,
+    0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x6e, 0x73,
+    0x77, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x45, 0x0a,
+    0x08, 0x53, 0x65, 0x6c, 0x66, 0x54, 0x65, 0x73, 0x74, 0x12, 0x1a, 0x2e, 0x7a, 0x6b, 0x61, 0x75,
+    0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x65, 0x6c, 0x66, 0x54, 0x65, 0x73, 0x74, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1b, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76,
+    0x31, 0x2e, 0x53, 0x65, 0x6c, 0x66, 0x54, 0x65, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+    0x73, 0x65, 0x22, 0x00, 0x12, 0x56, 0x0a, 0x0d, 0x57, 0x61, 0x74, 0x63, 0x68, 0x53, 0x65, 0x73,
+    0x73, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x1f, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76,
+    0x31, 0x2e, 0x57, 0x61, 0x74, 0x63, 0x68, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x20, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e,
+    0x76, 0x31, 0x2e, 0x57, 0x61, 0x74, 0x63, 0x68, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x73,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x30, 0x01, 0x12, 0x42, 0x0a, 0x07,
+    0x47, 0x65, 0x74, 0x55, 0x73, 0x65, 0x72, 0x12, 0x19, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68,
+    0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x55, 0x73, 0x65, 0x72, 0x52, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x1a, 0x1a, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x47,
+    0x65, 0x74, 0x55, 0x73, 0x65, 0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00,
+    0x12, 0x6c, 0x0a, 0x14, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x41, 0x6e, 0x64, 0x43,
+    0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x12, 0x26, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74,
+    0x68, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x41, 0x6e, 0x64,
+    0x43, 0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x1a, 0x2a, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x75, 0x74,
+    0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x68, 0x61, 0x6c, 0x6c,
+    0x65, 0x6e, 0x67, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x60,
+    0x0a, 0x11, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69,
+    0x61, 0x6c, 0x73, 0x12, 0x23, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e,
+    0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c,
+    0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x24, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74,
+    0x68, 0x2e, 0x76, 0x31, 0x2e, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x43, 0x72, 0x65, 0x64, 0x65,
+    0x6e, 0x74, 0x69, 0x61, 0x6c, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00,
+    0x12, 0x59, 0x0a, 0x0c, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x65,
+    0x12, 0x1e, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x75, 0x74,
+    0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x1a, 0x27, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x75, 0x74,
+    0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x41, 0x6e, 0x73, 0x77, 0x65,
+    0x72, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x12, 0x4b, 0x0a, 0x0a, 0x55,
+    0x6e, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x12, 0x1c, 0x2e, 0x7a, 0x6b, 0x61, 0x75,
+    0x74, 0x68, 0x2e, 0x76, 0x31, 0x2e, 0x55, 0x6e, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1d, 0x2e, 0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68,
+    0x2e, 0x76, 0x31, 0x2e, 0x55, 0x6e, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x00, 0x42, 0x5d, 0x0a, 0x0d, 0x63, 0x6f, 0x6d, 0x2e,
+    0x7a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x2e, 0x76, 0x31, 0x42, 0x07, 0x56, 0x31, 0x50, 0x72, 0x6f,
+    0x74, 0x6f, 0x50, 0x01, 0xa2, 0x02, 0x03, 0x5a, 0x58, 0x58, 0xaa, 0x02, 0x09, 0x5a, 0x6b, 0x61,
+    0x75, 0x74, 0x68, 0x2e, 0x56, 0x31, 0xca, 0x02, 0x09, 0x5a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x5c,
+    0x56, 0x31, 0xe2, 0x02, 0x15, 0x5a, 0x6b, 0x61, 0x75, 0x74, 0x68, 0x5c, 0x56, 0x31, 0x5c, 0x47,
+    0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x0a, 0x5a, 0x6b, 0x61,
+    0x75, 0x74, 0x68, 0x3a, 0x3a, 0x56, 0x31, 0x4a, 0xb3, 0x37, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00,
+    0xb0, 0x01, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a,
+    0x01, 0x02, 0x12, 0x03, 0x01, 0x00, 0x12, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x03,
+    0x00, 0x0a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x03, 0x08, 0x17, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x04, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x04, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x04, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x04, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03,
+    0x05, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x05, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x05, 0x09, 0x0b, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x05, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x06, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x06, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x06, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x06, 0x0e, 0x0f, 0x0a, 0xac, 0x01, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x09,
+    0x02, 0x23, 0x1a, 0x9e, 0x01, 0x20, 0x4f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x20, 0x6e,
+    0x6f, 0x6e, 0x2d, 0x73, 0x65, 0x63, 0x72, 0x65, 0x74, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61,
+    0x74, 0x61, 0x20, 0x61, 0x62, 0x6f, 0x75, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65,
+    0x72, 0x2c, 0x20, 0x73, 0x75, 0x63, 0x68, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x64, 0x69, 0x73,
+    0x70, 0x6c, 0x61, 0x79, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x6f, 0x72, 0x20, 0x72, 0x6f, 0x6c,
+    0x65, 0x73, 0x2c, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x0a, 0x20, 0x61, 0x6c, 0x6f, 0x6e,
+    0x67, 0x73, 0x69, 0x64, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74,
+    0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x77, 0x69, 0x74, 0x68, 0x6f, 0x75, 0x74, 0x20, 0x61,
+    0x66, 0x66, 0x65, 0x63, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x72, 0x79,
+    0x70, 0x74, 0x6f, 0x67, 0x72, 0x61, 0x70, 0x68, 0x69, 0x63, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64,
+    0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x06, 0x12, 0x03, 0x09, 0x02,
+    0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x09, 0x16, 0x1e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x09, 0x21, 0x22, 0x0a, 0x09, 0x0a,
+    0x02, 0x04, 0x01, 0x12, 0x03, 0x0c, 0x00, 0x1b, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12,
+    0x03, 0x0c, 0x08, 0x18, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x0e, 0x00, 0x12, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x0e, 0x08, 0x26, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x0f, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x00, 0x05, 0x12, 0x03, 0x0f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x0f, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x0f, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x10, 0x02, 0x10,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x10, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x10, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x10, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02,
+    0x02, 0x02, 0x12, 0x03, 0x11, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x05,
+    0x12, 0x03, 0x11, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x11, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x11, 0x0e,
+    0x0f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x14, 0x00, 0x1c, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x14, 0x08, 0x27, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02,
+    0x00, 0x12, 0x03, 0x15, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12,
+    0x03, 0x15, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x15,
+    0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x15, 0x13, 0x14,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x16, 0x02, 0x0f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x16, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x16, 0x09, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x16, 0x0d, 0x0e, 0x0a, 0x6c, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x02, 0x12,
+    0x03, 0x18, 0x02, 0x17, 0x1a, 0x5f, 0x20, 0x48, 0x65, 0x78, 0x2d, 0x65, 0x6e, 0x63, 0x6f, 0x64,
+    0x65, 0x64, 0x20, 0x45, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x61,
+    0x74, 0x75, 0x72, 0x65, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x28, 0x61, 0x75, 0x74, 0x68, 0x5f,
+    0x69, 0x64, 0x2c, 0x20, 0x63, 0x29, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65,
+    0x72, 0x76, 0x65, 0x72, 0x27, 0x73, 0x20, 0x6c, 0x6f, 0x6e, 0x67, 0x2d, 0x74, 0x65, 0x72, 0x6d,
+    0x20, 0x6b, 0x65, 0x79, 0x2c, 0x20, 0x69, 0x66, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75,
+    0x72, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x05, 0x12, 0x03,
+    0x18, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x18, 0x09,
+    0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x18, 0x15, 0x16, 0x0a,
+    0xa1, 0x01, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x03, 0x12, 0x03, 0x1b, 0x02, 0x11, 0x1a, 0x93, 0x01,
+    0x20, 0x48, 0x65, 0x78, 0x2d, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x20, 0x48, 0x4d, 0x41,
+    0x43, 0x2d, 0x53, 0x48, 0x41, 0x32, 0x35, 0x36, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x28, 0x61,
+    0x75, 0x74, 0x68, 0x5f, 0x69, 0x64, 0x2c, 0x20, 0x63, 0x29, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x27, 0x73, 0x20, 0x63, 0x68, 0x61,
+    0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x20, 0x4d, 0x41, 0x43, 0x20, 0x73, 0x65, 0x63, 0x72, 0x65,
+    0x74, 0x2c, 0x20, 0x69, 0x66, 0x0a, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x65,
+    0x64, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x65, 0x63,
+    0x68, 0x6f, 0x65, 0x73, 0x20, 0x69, 0x74, 0x20, 0x62, 0x61, 0x63, 0x6b, 0x20, 0x77, 0x69, 0x74,
+    0x68, 0x20, 0x63, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x6e, 0x73, 0x77, 0x65,
+    0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x05, 0x12, 0x03, 0x1b, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x01, 0x12, 0x03, 0x1b, 0x09, 0x0c, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x03, 0x12, 0x03, 0x1b, 0x0f, 0x10, 0x0a, 0x86, 0x02,
+    0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x21, 0x00, 0x28, 0x01, 0x1a, 0xf9, 0x01, 0x20, 0x52, 0x65,
+    0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x73, 0x20, 0x61, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x61,
+    0x6e, 0x64, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x75,
+    0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x63, 0x68, 0x61,
+    0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x69, 0x74, 0x20, 0x69, 0x6e,
+    0x20, 0x6f, 0x6e, 0x65, 0x20, 0x72, 0x6f, 0x75, 0x6e, 0x64, 0x20, 0x74, 0x72, 0x69, 0x70, 0x2c,
+    0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64,
+    0x73, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x52, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x66, 0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64, 0x20,
+    0x62, 0x79, 0x20, 0x74, 0x68, 0x6f, 0x73, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x41,
+    0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x68, 0x61,
+    0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2e, 0x20, 0x54,
+    0x68, 0x65, 0x0a, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x69, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x2c, 0x20, 0x61,
+    0x73, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x41, 0x75, 0x74,
+    0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x68, 0x61, 0x6c, 0x6c,
+    0x65, 0x6e, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x21,
+    0x08, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x22, 0x02, 0x12, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x22, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x22, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x22, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02,
+    0x01, 0x12, 0x03, 0x23, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12,
+    0x03, 0x23, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x23,
+    0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x23, 0x0e, 0x0f,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x02, 0x12, 0x03, 0x24, 0x02, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x02, 0x05, 0x12, 0x03, 0x24, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x24, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x02, 0x03, 0x12, 0x03, 0x24, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x03, 0x12,
+    0x03, 0x25, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x06, 0x12, 0x03, 0x25,
+    0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x01, 0x12, 0x03, 0x25, 0x16, 0x1e,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x03, 0x12, 0x03, 0x25, 0x21, 0x22, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x04, 0x02, 0x04, 0x12, 0x03, 0x26, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x04, 0x05, 0x12, 0x03, 0x26, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x04, 0x01, 0x12, 0x03, 0x26, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x04, 0x03,
+    0x12, 0x03, 0x26, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x05, 0x12, 0x03, 0x27,
+    0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x05, 0x05, 0x12, 0x03, 0x27, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x05, 0x01, 0x12, 0x03, 0x27, 0x09, 0x0b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x05, 0x03, 0x12, 0x03, 0x27, 0x0e, 0x0f, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x05, 0x12, 0x04, 0x2a, 0x00, 0x31, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12,
+    0x03, 0x2a, 0x08, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x2b, 0x02,
+    0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2b, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2b, 0x09, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2b, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x05, 0x02, 0x01, 0x12, 0x03, 0x2c, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01,
+    0x05, 0x12, 0x03, 0x2c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x2c, 0x09, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x2c,
+    0x0d, 0x0e, 0x0a, 0x90, 0x01, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03, 0x2f, 0x02, 0x0f,
+    0x1a, 0x82, 0x01, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67,
+    0x65, 0x20, 0x63, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x69, 0x74, 0x73, 0x20, 0x4d, 0x41, 0x43, 0x2c,
+    0x20, 0x65, 0x63, 0x68, 0x6f, 0x65, 0x64, 0x20, 0x62, 0x61, 0x63, 0x6b, 0x20, 0x66, 0x72, 0x6f,
+    0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x20,
+    0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x73, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x63, 0x61, 0x6e, 0x20, 0x63, 0x68, 0x65, 0x63, 0x6b,
+    0x0a, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63, 0x20, 0x77, 0x61, 0x73, 0x20, 0x6e, 0x6f, 0x74,
+    0x20, 0x61, 0x6c, 0x74, 0x65, 0x72, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x72, 0x61, 0x6e,
+    0x73, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03,
+    0x2f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x2f, 0x09,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x2f, 0x0d, 0x0e, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03, 0x12, 0x03, 0x30, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x03, 0x05, 0x12, 0x03, 0x30, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x30, 0x09, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03,
+    0x03, 0x12, 0x03, 0x30, 0x0f, 0x10, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x33, 0x00,
+    0x38, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x33, 0x08, 0x24, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x34, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x34, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x34, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x34, 0x16, 0x17, 0x0a, 0xc1, 0x01, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03,
+    0x37, 0x02, 0x13, 0x1a, 0xb3, 0x01, 0x20, 0x41, 0x20, 0x73, 0x68, 0x6f, 0x72, 0x74, 0x2d, 0x6c,
+    0x69, 0x76, 0x65, 0x64, 0x20, 0x48, 0x53, 0x32, 0x35, 0x36, 0x20, 0x4a, 0x57, 0x54, 0x20, 0x77,
+    0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x69, 0x6e, 0x20,
+    0x60, 0x73, 0x75, 0x62, 0x60, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69,
+    0x6f, 0x6e, 0x20, 0x69, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x60, 0x73, 0x69, 0x64, 0x60, 0x2c, 0x20,
+    0x61, 0x6e, 0x64, 0x20, 0x69, 0x74, 0x73, 0x20, 0x65, 0x78, 0x70, 0x69, 0x72, 0x79, 0x20, 0x69,
+    0x6e, 0x0a, 0x20, 0x60, 0x65, 0x78, 0x70, 0x60, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20,
+    0x63, 0x61, 0x6e, 0x20, 0x62, 0x65, 0x20, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x65, 0x64,
+    0x20, 0x6f, 0x66, 0x66, 0x6c, 0x69, 0x6e, 0x65, 0x2e, 0x20, 0x45, 0x6d, 0x70, 0x74, 0x79, 0x20,
+    0x75, 0x6e, 0x6c, 0x65, 0x73, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65,
+    0x72, 0x20, 0x68, 0x61, 0x73, 0x20, 0x61, 0x20, 0x4a, 0x57, 0x54, 0x20, 0x73, 0x69, 0x67, 0x6e,
+    0x69, 0x6e, 0x67, 0x20, 0x6b, 0x65, 0x79, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x01, 0x05, 0x12, 0x03, 0x37, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x37, 0x09, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03,
+    0x37, 0x11, 0x12, 0x0a, 0x93, 0x04, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x40, 0x00, 0x47, 0x01,
+    0x1a, 0x86, 0x04, 0x20, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x65,
+    0x73, 0x20, 0x61, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x69, 0x6e, 0x20, 0x61, 0x20, 0x73, 0x69,
+    0x6e, 0x67, 0x6c, 0x65, 0x20, 0x72, 0x6f, 0x75, 0x6e, 0x64, 0x20, 0x74, 0x72, 0x69, 0x70, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x20, 0x6e, 0x6f, 0x6e, 0x2d, 0x69, 0x6e, 0x74, 0x65, 0x72,
+    0x61, 0x63, 0x74, 0x69, 0x76, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2c, 0x20, 0x77, 0x68,
+    0x6f, 0x73, 0x65, 0x20, 0x63, 0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x20, 0x63, 0x20,
+    0x69, 0x73, 0x0a, 0x20, 0x64, 0x65, 0x72, 0x69, 0x76, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x67, 0x2c, 0x20, 0x68, 0x2c,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x27, 0x73, 0x20, 0x79, 0x31, 0x20, 0x61,
+    0x6e, 0x64, 0x20, 0x79, 0x32, 0x2c, 0x20, 0x72, 0x31, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x72, 0x32,
+    0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68,
+    0x65, 0x0a, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x6e, 0x6f, 0x72, 0x6d, 0x61, 0x6c,
+    0x69, 0x7a, 0x65, 0x73, 0x20, 0x69, 0x74, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x2c, 0x20, 0x69, 0x6e, 0x73, 0x74,
+    0x65, 0x61, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x72, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x0a, 0x20, 0x72, 0x65, 0x73,
+    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x73,
+    0x73, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x56, 0x65,
+    0x72, 0x69, 0x66, 0x79, 0x41, 0x75, 0x74, 0x68, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20, 0x72,
+    0x65, 0x6a, 0x65, 0x63, 0x74, 0x73, 0x20, 0x61, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x77,
+    0x68, 0x6f, 0x73, 0x65, 0x0a, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20,
+    0x69, 0x73, 0x20, 0x6f, 0x75, 0x74, 0x73, 0x69, 0x64, 0x65, 0x20, 0x69, 0x74, 0x73, 0x20, 0x66,
+    0x72, 0x65, 0x73, 0x68, 0x6e, 0x65, 0x73, 0x73, 0x20, 0x77, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x2c,
+    0x20, 0x6f, 0x72, 0x20, 0x77, 0x68, 0x6f, 0x73, 0x65, 0x20, 0x72, 0x31, 0x20, 0x61, 0x6e, 0x64,
+    0x20, 0x72, 0x32, 0x20, 0x77, 0x65, 0x72, 0x65, 0x20, 0x61, 0x6c, 0x72, 0x65, 0x61, 0x64, 0x79,
+    0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73,
+    0x65, 0x72, 0x0a, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x69, 0x74, 0x2c, 0x20, 0x73,
+    0x6f, 0x20, 0x61, 0x20, 0x63, 0x61, 0x70, 0x74, 0x75, 0x72, 0x65, 0x64, 0x20, 0x72, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x20, 0x63, 0x61, 0x6e, 0x6e, 0x6f, 0x74, 0x20, 0x62, 0x65, 0x20, 0x72,
+    0x65, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01,
+    0x12, 0x03, 0x40, 0x08, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x41,
+    0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x41, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x41, 0x09, 0x0d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x41, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x07, 0x02, 0x01, 0x12, 0x03, 0x42, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x01, 0x05, 0x12, 0x03, 0x42, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x42, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03,
+    0x42, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x43, 0x02, 0x10,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x05, 0x12, 0x03, 0x43, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x01, 0x12, 0x03, 0x43, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x02, 0x03, 0x12, 0x03, 0x43, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07,
+    0x02, 0x03, 0x12, 0x03, 0x44, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x05,
+    0x12, 0x03, 0x44, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x44, 0x09, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x03, 0x12, 0x03, 0x44, 0x0d,
+    0x0e, 0x0a, 0x50, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x04, 0x12, 0x03, 0x46, 0x02, 0x17, 0x1a, 0x43,
+    0x20, 0x54, 0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72,
+    0x6f, 0x6f, 0x66, 0x20, 0x77, 0x61, 0x73, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x75, 0x74, 0x65, 0x64,
+    0x2c, 0x20, 0x69, 0x6e, 0x20, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x73, 0x69, 0x6e,
+    0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x55, 0x6e, 0x69, 0x78, 0x20, 0x65, 0x70, 0x6f, 0x63,
+    0x68, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x05, 0x12, 0x03, 0x46, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x01, 0x12, 0x03, 0x46, 0x09, 0x12, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x03, 0x12, 0x03, 0x46, 0x15, 0x16, 0x0a, 0x90, 0x02,
+    0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x4c, 0x00, 0x50, 0x01, 0x1a, 0x83, 0x02, 0x20, 0x52, 0x65,
+    0x70, 0x6c, 0x61, 0x63, 0x65, 0x73, 0x20, 0x61, 0x20, 0x75, 0x73, 0x65, 0x72, 0x27, 0x73, 0x20,
+    0x79, 0x31, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x79, 0x32, 0x2c, 0x20, 0x73, 0x75, 0x63, 0x68, 0x20,
+    0x61, 0x73, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x79, 0x20, 0x63, 0x68, 0x61,
+    0x6e, 0x67, 0x65, 0x20, 0x74, 0x68, 0x65, 0x69, 0x72, 0x20, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f,
+    0x72, 0x64, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x69,
+    0x73, 0x20, 0x61, 0x75, 0x74, 0x68, 0x6f, 0x72, 0x69, 0x7a, 0x65, 0x64, 0x0a, 0x20, 0x62, 0x79,
+    0x20, 0x61, 0x6e, 0x20, 0x61, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x20,
+    0x63, 0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65,
+    0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x77,
+    0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x69, 0x72, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e,
+    0x74, 0x20, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20,
+    0x61, 0x6e, 0x64, 0x20, 0x61, 0x70, 0x70, 0x6c, 0x69, 0x65, 0x73, 0x0a, 0x20, 0x74, 0x6f, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61,
+    0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x20, 0x77, 0x61, 0x73, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74,
+    0x65, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x69, 0x72, 0x20, 0x6d, 0x65,
+    0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x20, 0x69, 0x73, 0x20, 0x6b, 0x65, 0x70, 0x74, 0x2e, 0x0a,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x4c, 0x08, 0x20, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x4d, 0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x00, 0x06, 0x12, 0x03, 0x4d, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x4d, 0x1e, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x4d, 0x27, 0x28, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x4e, 0x02, 0x10,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03, 0x4e, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4e, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4e, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08,
+    0x02, 0x02, 0x12, 0x03, 0x4f, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05,
+    0x12, 0x03, 0x4f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x4f, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03, 0x12, 0x03, 0x4f, 0x0e,
+    0x0f, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x03, 0x52, 0x00, 0x24, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x09, 0x01, 0x12, 0x03, 0x52, 0x08, 0x21, 0x0a, 0x9c, 0x02, 0x0a, 0x02, 0x04, 0x0a, 0x12,
+    0x04, 0x57, 0x00, 0x59, 0x01, 0x1a, 0x8f, 0x02, 0x20, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x73,
+    0x20, 0x61, 0x20, 0x75, 0x73, 0x65, 0x72, 0x27, 0x73, 0x20, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74,
+    0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x77, 0x68,
+    0x69, 0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x6e, 0x61, 0x6d, 0x65,
+    0x20, 0x63, 0x61, 0x6e, 0x20, 0x62, 0x65, 0x20, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x65, 0x72,
+    0x65, 0x64, 0x20, 0x61, 0x67, 0x61, 0x69, 0x6e, 0x2e, 0x20, 0x4c, 0x69, 0x6b, 0x65, 0x0a, 0x20,
+    0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x43, 0x72, 0x65, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x61, 0x6c,
+    0x73, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x61, 0x6c, 0x20, 0x69,
+    0x73, 0x20, 0x61, 0x75, 0x74, 0x68, 0x6f, 0x72, 0x69, 0x7a, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20,
+    0x61, 0x6e, 0x20, 0x61, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x20, 0x63,
+    0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64,
+    0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x0a, 0x20, 0x77,
+    0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x69, 0x72, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e,
+    0x74, 0x20, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20,
+    0x61, 0x6e, 0x64, 0x20, 0x61, 0x70, 0x70, 0x6c, 0x69, 0x65, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x6c,
+    0x6c, 0x65, 0x6e, 0x67, 0x65, 0x20, 0x77, 0x61, 0x73, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65,
+    0x64, 0x20, 0x66, 0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03,
+    0x57, 0x08, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x58, 0x02, 0x29,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x06, 0x12, 0x03, 0x58, 0x02, 0x1d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x58, 0x1e, 0x24, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x58, 0x27, 0x28, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x0b,
+    0x12, 0x03, 0x5b, 0x00, 0x1d, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x03, 0x5b, 0x08,
+    0x1a, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x03, 0x5d, 0x00, 0x22, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x0c, 0x01, 0x12, 0x03, 0x5d, 0x08, 0x1f, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x03,
+    0x5f, 0x00, 0x24, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x03, 0x5f, 0x08, 0x21, 0x0a,
+    0x09, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x03, 0x61, 0x00, 0x1a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0e,
+    0x01, 0x12, 0x03, 0x61, 0x08, 0x17, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x04, 0x63, 0x00,
+    0x66, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x03, 0x63, 0x08, 0x18, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x03, 0x64, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0f, 0x02, 0x00, 0x05, 0x12, 0x03, 0x64, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x64, 0x07, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x64, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x01, 0x12, 0x03, 0x65,
+    0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x05, 0x12, 0x03, 0x65, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x01, 0x12, 0x03, 0x65, 0x09, 0x0e, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x03, 0x12, 0x03, 0x65, 0x11, 0x12, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x10, 0x12, 0x04, 0x68, 0x00, 0x6a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12,
+    0x03, 0x68, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x03, 0x69, 0x02,
+    0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x05, 0x12, 0x03, 0x69, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x01, 0x12, 0x03, 0x69, 0x09, 0x0d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x10, 0x02, 0x00, 0x03, 0x12, 0x03, 0x69, 0x10, 0x11, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
+    0x11, 0x12, 0x04, 0x6c, 0x00, 0x6f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x11, 0x01, 0x12, 0x03,
+    0x6c, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x00, 0x12, 0x03, 0x6d, 0x02, 0x12,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x05, 0x12, 0x03, 0x6d, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6d, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x11, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6d, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x11,
+    0x02, 0x01, 0x12, 0x03, 0x6e, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x06,
+    0x12, 0x03, 0x6e, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x6e, 0x16, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x03, 0x12, 0x03, 0x6e, 0x21,
+    0x22, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x12, 0x12, 0x03, 0x71, 0x00, 0x1f, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x12, 0x01, 0x12, 0x03, 0x71, 0x08, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x13, 0x12, 0x04,
+    0x73, 0x00, 0x76, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x13, 0x01, 0x12, 0x03, 0x73, 0x08, 0x16,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x00, 0x12, 0x03, 0x74, 0x02, 0x12, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x13, 0x02, 0x00, 0x05, 0x12, 0x03, 0x74, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x13, 0x02, 0x00, 0x01, 0x12, 0x03, 0x74, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x13, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x74, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x01, 0x12,
+    0x03, 0x75, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x05, 0x12, 0x03, 0x75,
+    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x01, 0x12, 0x03, 0x75, 0x09, 0x13,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x03, 0x12, 0x03, 0x75, 0x16, 0x17, 0x0a, 0x0a,
+    0x0a, 0x02, 0x04, 0x14, 0x12, 0x04, 0x78, 0x00, 0x7a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x14,
+    0x01, 0x12, 0x03, 0x78, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x00, 0x12, 0x03,
+    0x79, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x05, 0x12, 0x03, 0x79, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x01, 0x12, 0x03, 0x79, 0x09, 0x13, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x03, 0x12, 0x03, 0x79, 0x16, 0x17, 0x0a, 0x0a, 0x0a,
+    0x02, 0x04, 0x15, 0x12, 0x04, 0x7c, 0x00, 0x7e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x15, 0x01,
+    0x12, 0x03, 0x7c, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x00, 0x12, 0x03, 0x7d,
+    0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x05, 0x12, 0x03, 0x7d, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7d, 0x09, 0x13, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x03, 0x12, 0x03, 0x7d, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x02,
+    0x04, 0x16, 0x12, 0x06, 0x80, 0x01, 0x00, 0x86, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x16,
+    0x01, 0x12, 0x04, 0x80, 0x01, 0x08, 0x1d, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x16, 0x08, 0x00, 0x12,
+    0x06, 0x81, 0x01, 0x02, 0x85, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x08, 0x00, 0x01,
+    0x12, 0x04, 0x81, 0x01, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x00, 0x12, 0x04,
+    0x82, 0x01, 0x04, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x06, 0x12, 0x04, 0x82,
+    0x01, 0x04, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x01, 0x12, 0x04, 0x82, 0x01,
+    0x13, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x03, 0x12, 0x04, 0x82, 0x01, 0x1d,
+    0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x01, 0x12, 0x04, 0x83, 0x01, 0x04, 0x1f, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x06, 0x12, 0x04, 0x83, 0x01, 0x04, 0x12, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x01, 0x12, 0x04, 0x83, 0x01, 0x13, 0x1a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x16, 0x02, 0x01, 0x03, 0x12, 0x04, 0x83, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x16, 0x02, 0x02, 0x12, 0x04, 0x84, 0x01, 0x04, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16,
+    0x02, 0x02, 0x06, 0x12, 0x04, 0x84, 0x01, 0x04, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0x84, 0x01, 0x13, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0x84, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x17, 0x12, 0x06, 0x88,
+    0x01, 0x00, 0x9f, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x17, 0x01, 0x12, 0x04, 0x88, 0x01,
+    0x08, 0x15, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x17, 0x03, 0x00, 0x12, 0x06, 0x89, 0x01, 0x02, 0x8e,
+    0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x03, 0x00, 0x01, 0x12, 0x04, 0x89, 0x01, 0x0a,
+    0x1b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x17, 0x03, 0x00, 0x02, 0x00, 0x12, 0x04, 0x8a, 0x01, 0x04,
+    0x11, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x03, 0x00, 0x02, 0x00, 0x05, 0x12, 0x04, 0x8a, 0x01,
+    0x04, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x03, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8a,
+    0x01, 0x0b, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x03, 0x00, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0x8a, 0x01, 0x0f, 0x10, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x17, 0x03, 0x00, 0x02, 0x01, 0x12, 0x04,
+    0x8b, 0x01, 0x04, 0x11, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x03, 0x00, 0x02, 0x01, 0x05, 0x12,
+    0x04, 0x8b, 0x01, 0x04, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x03, 0x00, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0x8b, 0x01, 0x0b, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x03, 0x00, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0x8b, 0x01, 0x0f, 0x10, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x17, 0x03, 0x00, 0x02,
+    0x02, 0x12, 0x04, 0x8c, 0x01, 0x04, 0x11, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x03, 0x00, 0x02,
+    0x02, 0x05, 0x12, 0x04, 0x8c, 0x01, 0x04, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x03, 0x00,
+    0x02, 0x02, 0x01, 0x12, 0x04, 0x8c, 0x01, 0x0b, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x03,
+    0x00, 0x02, 0x02, 0x03, 0x12, 0x04, 0x8c, 0x01, 0x0f, 0x10, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x17,
+    0x03, 0x00, 0x02, 0x03, 0x12, 0x04, 0x8d, 0x01, 0x04, 0x11, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17,
+    0x03, 0x00, 0x02, 0x03, 0x05, 0x12, 0x04, 0x8d, 0x01, 0x04, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x17, 0x03, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0x8d, 0x01, 0x0b, 0x0c, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x17, 0x03, 0x00, 0x02, 0x03, 0x03, 0x12, 0x04, 0x8d, 0x01, 0x0f, 0x10, 0x0a, 0x0e, 0x0a,
+    0x04, 0x04, 0x17, 0x03, 0x01, 0x12, 0x06, 0x8f, 0x01, 0x02, 0x95, 0x01, 0x03, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x17, 0x03, 0x01, 0x01, 0x12, 0x04, 0x8f, 0x01, 0x0a, 0x17, 0x0a, 0x0e, 0x0a, 0x06,
+    0x04, 0x17, 0x03, 0x01, 0x02, 0x00, 0x12, 0x04, 0x90, 0x01, 0x04, 0x11, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x17, 0x03, 0x01, 0x02, 0x00, 0x05, 0x12, 0x04, 0x90, 0x01, 0x04, 0x0a, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x17, 0x03, 0x01, 0x02, 0x00, 0x01, 0x12, 0x04, 0x90, 0x01, 0x0b, 0x0c, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x17, 0x03, 0x01, 0x02, 0x00, 0x03, 0x12, 0x04, 0x90, 0x01, 0x0f, 0x10, 0x0a,
+    0x0e, 0x0a, 0x06, 0x04, 0x17, 0x03, 0x01, 0x02, 0x01, 0x12, 0x04, 0x91, 0x01, 0x04, 0x11, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x17, 0x03, 0x01, 0x02, 0x01, 0x05, 0x12, 0x04, 0x91, 0x01, 0x04, 0x0a,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x03, 0x01, 0x02, 0x01, 0x01, 0x12, 0x04, 0x91, 0x01, 0x0b,
+    0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x03, 0x01, 0x02, 0x01, 0x03, 0x12, 0x04, 0x91, 0x01,
+    0x0f, 0x10, 0x0a, 0x91, 0x01, 0x0a, 0x06, 0x04, 0x17, 0x03, 0x01, 0x02, 0x02, 0x12, 0x04, 0x94,
+    0x01, 0x04, 0x15, 0x1a, 0x80, 0x01, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x76, 0x65,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x73, 0x3a, 0x20,
+    0x72, 0x69, 0x73, 0x74, 0x72, 0x65, 0x74, 0x74, 0x6f, 0x32, 0x35, 0x35, 0x20, 0x6f, 0x72, 0x20,
+    0x73, 0x65, 0x63, 0x70, 0x32, 0x35, 0x36, 0x6b, 0x31, 0x2e, 0x20, 0x55, 0x6e, 0x73, 0x65, 0x74,
+    0x20, 0x28, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x29, 0x20, 0x6d, 0x65, 0x61, 0x6e, 0x73, 0x20, 0x72,
+    0x69, 0x73, 0x74, 0x72, 0x65, 0x74, 0x74, 0x6f, 0x32, 0x35, 0x35, 0x2c, 0x20, 0x74, 0x68, 0x65,
+    0x0a, 0x20, 0x63, 0x75, 0x72, 0x76, 0x65, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x6f,
+    0x74, 0x68, 0x65, 0x72, 0x73, 0x20, 0x77, 0x65, 0x72, 0x65, 0x20, 0x73, 0x75, 0x70, 0x70, 0x6f,
+    0x72, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x03, 0x01, 0x02, 0x02,
+    0x05, 0x12, 0x04, 0x94, 0x01, 0x04, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x03, 0x01, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0x94, 0x01, 0x0b, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x03, 0x01,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0x94, 0x01, 0x13, 0x14, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x17, 0x08,
+    0x00, 0x12, 0x06, 0x97, 0x01, 0x02, 0x9a, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x08,
+    0x00, 0x01, 0x12, 0x04, 0x97, 0x01, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x00,
+    0x12, 0x04, 0x98, 0x01, 0x04, 0x2d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x06, 0x12,
+    0x04, 0x98, 0x01, 0x04, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0x98, 0x01, 0x16, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x03, 0x12, 0x04, 0x98,
+    0x01, 0x2b, 0x2c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x01, 0x12, 0x04, 0x99, 0x01, 0x04,
+    0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x06, 0x12, 0x04, 0x99, 0x01, 0x04, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x01, 0x12, 0x04, 0x99, 0x01, 0x12, 0x20, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x03, 0x12, 0x04, 0x99, 0x01, 0x23, 0x24, 0x0a, 0xaf,
+    0x01, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x02, 0x12, 0x04, 0x9e, 0x01, 0x02, 0x1e, 0x1a, 0xa0, 0x01,
+    0x20, 0x54, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x20, 0x76, 0x65,
+    0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72,
+    0x20, 0x73, 0x70, 0x65, 0x61, 0x6b, 0x73, 0x2c, 0x20, 0x73, 0x65, 0x74, 0x20, 0x77, 0x68, 0x65,
+    0x6e, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x47, 0x65, 0x74, 0x43,
+    0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x20, 0x55, 0x6e,
+    0x73, 0x65, 0x74, 0x20, 0x28, 0x30, 0x29, 0x20, 0x6d, 0x65, 0x61, 0x6e, 0x73, 0x0a, 0x20, 0x76,
+    0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x31, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x76, 0x65,
+    0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x20, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x77,
+    0x65, 0x72, 0x65, 0x20, 0x61, 0x64, 0x76, 0x65, 0x72, 0x74, 0x69, 0x73, 0x65, 0x64, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x05, 0x12, 0x04, 0x9e, 0x01, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x01, 0x12, 0x04, 0x9e, 0x01, 0x09, 0x19, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x03, 0x12, 0x04, 0x9e, 0x01, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a,
+    0x02, 0x06, 0x00, 0x12, 0x06, 0xa1, 0x01, 0x00, 0xb0, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x06,
+    0x00, 0x01, 0x12, 0x04, 0xa1, 0x01, 0x08, 0x0c, 0x0a, 0x0c, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x00,
+    0x12, 0x04, 0xa2, 0x01, 0x02, 0x4a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xa2, 0x01, 0x06, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04,
+    0xa2, 0x01, 0x17, 0x2e, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa2,
+    0x01, 0x39, 0x46, 0x0a, 0x7f, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x04, 0xa5, 0x01, 0x02,
+    0x55, 0x1a, 0x71, 0x20, 0x53, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x27, 0x73, 0x20, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x69,
+    0x76, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x2c, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x6f, 0x6e, 0x65, 0x20,
+    0x61, 0x6e, 0x64, 0x20, 0x66, 0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64, 0x20, 0x62, 0x79, 0x0a,
+    0x20, 0x61, 0x6e, 0x79, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x73, 0x20, 0x74, 0x6f, 0x20,
+    0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa5,
+    0x01, 0x06, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0xa5, 0x01,
+    0x19, 0x32, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x06, 0x12, 0x04, 0xa5, 0x01, 0x3d,
+    0x43, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa5, 0x01, 0x44, 0x51,
+    0x0a, 0x0c, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x02, 0x12, 0x04, 0xa6, 0x01, 0x02, 0x3d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa6, 0x01, 0x06, 0x0e, 0x0a, 0x0d, 0x0a,
+    0x05, 0x06, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xa6, 0x01, 0x0f, 0x1e, 0x0a, 0x0d, 0x0a, 0x05,
+    0x06, 0x00, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa6, 0x01, 0x29, 0x39, 0x0a, 0x0c, 0x0a, 0x04, 0x06,
+    0x00, 0x02, 0x03, 0x12, 0x04, 0xa7, 0x01, 0x02, 0x70, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02,
+    0x03, 0x01, 0x12, 0x04, 0xa7, 0x01, 0x06, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03,
+    0x02, 0x12, 0x04, 0xa7, 0x01, 0x24, 0x42, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x03, 0x03,
+    0x12, 0x04, 0xa7, 0x01, 0x4d, 0x6c, 0x0a, 0x0c, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x04, 0x12, 0x04,
+    0xa8, 0x01, 0x02, 0x61, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0xa8,
+    0x01, 0x06, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0xa8, 0x01,
+    0x1b, 0x36, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x04, 0x03, 0x12, 0x04, 0xa8, 0x01, 0x41,
+    0x5d, 0x0a, 0x0c, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x05, 0x12, 0x04, 0xa9, 0x01, 0x02, 0x3d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x05, 0x01, 0x12, 0x04, 0xa9, 0x01, 0x06, 0x0e, 0x0a, 0x0d,
+    0x0a, 0x05, 0x06, 0x00, 0x02, 0x05, 0x02, 0x12, 0x04, 0xa9, 0x01, 0x0f, 0x1e, 0x0a, 0x0d, 0x0a,
+    0x05, 0x06, 0x00, 0x02, 0x05, 0x03, 0x12, 0x04, 0xa9, 0x01, 0x29, 0x39, 0x0a, 0x0c, 0x0a, 0x04,
+    0x06, 0x00, 0x02, 0x06, 0x12, 0x04, 0xaa, 0x01, 0x02, 0x53, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00,
+    0x02, 0x06, 0x01, 0x12, 0x04, 0xaa, 0x01, 0x06, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02,
+    0x06, 0x02, 0x12, 0x04, 0xaa, 0x01, 0x14, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x06,
+    0x06, 0x12, 0x04, 0xaa, 0x01, 0x33, 0x39, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x06, 0x03,
+    0x12, 0x04, 0xaa, 0x01, 0x3a, 0x4f, 0x0a, 0x0c, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x07, 0x12, 0x04,
+    0xab, 0x01, 0x02, 0x3a, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x07, 0x01, 0x12, 0x04, 0xab,
+    0x01, 0x06, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x07, 0x02, 0x12, 0x04, 0xab, 0x01,
+    0x0e, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x07, 0x03, 0x12, 0x04, 0xab, 0x01, 0x27,
+    0x36, 0x0a, 0x0c, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x08, 0x12, 0x04, 0xac, 0x01, 0x02, 0x64, 0x0a,
+    0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x08, 0x01, 0x12, 0x04, 0xac, 0x01, 0x06, 0x1a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x06, 0x00, 0x02, 0x08, 0x02, 0x12, 0x04, 0xac, 0x01, 0x1b, 0x36, 0x0a, 0x0d, 0x0a,
+    0x05, 0x06, 0x00, 0x02, 0x08, 0x03, 0x12, 0x04, 0xac, 0x01, 0x41, 0x60, 0x0a, 0x0c, 0x0a, 0x04,
+    0x06, 0x00, 0x02, 0x09, 0x12, 0x04, 0xad, 0x01, 0x02, 0x58, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00,
+    0x02, 0x09, 0x01, 0x12, 0x04, 0xad, 0x01, 0x06, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02,
+    0x09, 0x02, 0x12, 0x04, 0xad, 0x01, 0x18, 0x30, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x09,
+    0x03, 0x12, 0x04, 0xad, 0x01, 0x3b, 0x54, 0x0a, 0x0c, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x0a, 0x12,
+    0x04, 0xae, 0x01, 0x02, 0x51, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x0a, 0x01, 0x12, 0x04,
+    0xae, 0x01, 0x06, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x0a, 0x02, 0x12, 0x04, 0xae,
+    0x01, 0x13, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x0a, 0x03, 0x12, 0x04, 0xae, 0x01,
+    0x31, 0x4d, 0x0a, 0x0c, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x0b, 0x12, 0x04, 0xaf, 0x01, 0x02, 0x43,
+    0x0a, 0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x0b, 0x01, 0x12, 0x04, 0xaf, 0x01, 0x06, 0x10, 0x0a,
+    0x0d, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x0b, 0x02, 0x12, 0x04, 0xaf, 0x01, 0x11, 0x22, 0x0a, 0x0d,
+    0x0a, 0x05, 0x06, 0x00, 0x02, 0x0b, 0x03, 0x12, 0x04, 0xaf, 0x01, 0x2d, 0x3f, 0x62, 0x06, 0x70,
+    0x72, 0x6f, 0x74, 0x6f, 0x33,
 ];
 include!("zkauth.v1.serde.rs");
 include!("zkauth.v1.tonic.rs");
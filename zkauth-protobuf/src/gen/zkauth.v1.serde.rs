@@ -1,4 +1,166 @@
 // @generated
+impl serde::Serialize for AuthenticateRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user.is_empty() {
+            len += 1;
+        }
+        if !self.r1.is_empty() {
+            len += 1;
+        }
+        if !self.r2.is_empty() {
+            len += 1;
+        }
+        if !self.s.is_empty() {
+            len += 1;
+        }
+        if self.timestamp != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("zkauth.v1.AuthenticateRequest", len)?;
+        if !self.user.is_empty() {
+            struct_ser.serialize_field("user", &self.user)?;
+        }
+        if !self.r1.is_empty() {
+            struct_ser.serialize_field("r1", &self.r1)?;
+        }
+        if !self.r2.is_empty() {
+            struct_ser.serialize_field("r2", &self.r2)?;
+        }
+        if !self.s.is_empty() {
+            struct_ser.serialize_field("s", &self.s)?;
+        }
+        if self.timestamp != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AuthenticateRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user",
+            "r1",
+            "r2",
+            "s",
+            "timestamp",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            User,
+            R1,
+            R2,
+            S,
+            Timestamp,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "user" => Ok(GeneratedField::User),
+                            "r1" => Ok(GeneratedField::R1),
+                            "r2" => Ok(GeneratedField::R2),
+                            "s" => Ok(GeneratedField::S),
+                            "timestamp" => Ok(GeneratedField::Timestamp),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AuthenticateRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct zkauth.v1.AuthenticateRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuthenticateRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user__ = None;
+                let mut r1__ = None;
+                let mut r2__ = None;
+                let mut s__ = None;
+                let mut timestamp__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::User => {
+                            if user__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("user"));
+                            }
+                            user__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::R1 => {
+                            if r1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("r1"));
+                            }
+                            r1__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::R2 => {
+                            if r2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("r2"));
+                            }
+                            r2__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::S => {
+                            if s__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("s"));
+                            }
+                            s__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Timestamp => {
+                            if timestamp__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timestamp"));
+                            }
+                            timestamp__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(AuthenticateRequest {
+                    user: user__.unwrap_or_default(),
+                    r1: r1__.unwrap_or_default(),
+                    r2: r2__.unwrap_or_default(),
+                    s: s__.unwrap_or_default(),
+                    timestamp: timestamp__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("zkauth.v1.AuthenticateRequest", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for AuthenticationAnswerRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -13,6 +175,12 @@ impl serde::Serialize for AuthenticationAnswerRequest {
         if !self.s.is_empty() {
             len += 1;
         }
+        if !self.c.is_empty() {
+            len += 1;
+        }
+        if !self.mac.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("zkauth.v1.AuthenticationAnswerRequest", len)?;
         if !self.auth_id.is_empty() {
             struct_ser.serialize_field("authId", &self.auth_id)?;
@@ -20,6 +188,12 @@ impl serde::Serialize for AuthenticationAnswerRequest {
         if !self.s.is_empty() {
             struct_ser.serialize_field("s", &self.s)?;
         }
+        if !self.c.is_empty() {
+            struct_ser.serialize_field("c", &self.c)?;
+        }
+        if !self.mac.is_empty() {
+            struct_ser.serialize_field("mac", &self.mac)?;
+        }
         struct_ser.end()
     }
 }
@@ -33,12 +207,16 @@ impl<'de> serde::Deserialize<'de> for AuthenticationAnswerRequest {
             "auth_id",
             "authId",
             "s",
+            "c",
+            "mac",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             AuthId,
             S,
+            C,
+            Mac,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -62,6 +240,8 @@ impl<'de> serde::Deserialize<'de> for AuthenticationAnswerRequest {
                         match value {
                             "authId" | "auth_id" => Ok(GeneratedField::AuthId),
                             "s" => Ok(GeneratedField::S),
+                            "c" => Ok(GeneratedField::C),
+                            "mac" => Ok(GeneratedField::Mac),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -83,6 +263,8 @@ impl<'de> serde::Deserialize<'de> for AuthenticationAnswerRequest {
             {
                 let mut auth_id__ = None;
                 let mut s__ = None;
+                let mut c__ = None;
+                let mut mac__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::AuthId => {
@@ -97,11 +279,25 @@ impl<'de> serde::Deserialize<'de> for AuthenticationAnswerRequest {
                             }
                             s__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::C => {
+                            if c__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("c"));
+                            }
+                            c__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Mac => {
+                            if mac__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("mac"));
+                            }
+                            mac__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(AuthenticationAnswerRequest {
                     auth_id: auth_id__.unwrap_or_default(),
                     s: s__.unwrap_or_default(),
+                    c: c__.unwrap_or_default(),
+                    mac: mac__.unwrap_or_default(),
                 })
             }
         }
@@ -119,10 +315,16 @@ impl serde::Serialize for AuthenticationAnswerResponse {
         if !self.session_id.is_empty() {
             len += 1;
         }
+        if !self.token.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("zkauth.v1.AuthenticationAnswerResponse", len)?;
         if !self.session_id.is_empty() {
             struct_ser.serialize_field("sessionId", &self.session_id)?;
         }
+        if !self.token.is_empty() {
+            struct_ser.serialize_field("token", &self.token)?;
+        }
         struct_ser.end()
     }
 }
@@ -135,11 +337,13 @@ impl<'de> serde::Deserialize<'de> for AuthenticationAnswerResponse {
         const FIELDS: &[&str] = &[
             "session_id",
             "sessionId",
+            "token",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             SessionId,
+            Token,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -162,6 +366,7 @@ impl<'de> serde::Deserialize<'de> for AuthenticationAnswerResponse {
                     {
                         match value {
                             "sessionId" | "session_id" => Ok(GeneratedField::SessionId),
+                            "token" => Ok(GeneratedField::Token),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -182,6 +387,7 @@ impl<'de> serde::Deserialize<'de> for AuthenticationAnswerResponse {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut session_id__ = None;
+                let mut token__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::SessionId => {
@@ -190,10 +396,17 @@ impl<'de> serde::Deserialize<'de> for AuthenticationAnswerResponse {
                             }
                             session_id__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Token => {
+                            if token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("token"));
+                            }
+                            token__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(AuthenticationAnswerResponse {
                     session_id: session_id__.unwrap_or_default(),
+                    token: token__.unwrap_or_default(),
                 })
             }
         }
@@ -339,6 +552,12 @@ impl serde::Serialize for AuthenticationChallengeResponse {
         if !self.c.is_empty() {
             len += 1;
         }
+        if !self.signature.is_empty() {
+            len += 1;
+        }
+        if !self.mac.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("zkauth.v1.AuthenticationChallengeResponse", len)?;
         if !self.auth_id.is_empty() {
             struct_ser.serialize_field("authId", &self.auth_id)?;
@@ -346,6 +565,12 @@ impl serde::Serialize for AuthenticationChallengeResponse {
         if !self.c.is_empty() {
             struct_ser.serialize_field("c", &self.c)?;
         }
+        if !self.signature.is_empty() {
+            struct_ser.serialize_field("signature", &self.signature)?;
+        }
+        if !self.mac.is_empty() {
+            struct_ser.serialize_field("mac", &self.mac)?;
+        }
         struct_ser.end()
     }
 }
@@ -359,12 +584,16 @@ impl<'de> serde::Deserialize<'de> for AuthenticationChallengeResponse {
             "auth_id",
             "authId",
             "c",
+            "signature",
+            "mac",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             AuthId,
             C,
+            Signature,
+            Mac,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -388,6 +617,8 @@ impl<'de> serde::Deserialize<'de> for AuthenticationChallengeResponse {
                         match value {
                             "authId" | "auth_id" => Ok(GeneratedField::AuthId),
                             "c" => Ok(GeneratedField::C),
+                            "signature" => Ok(GeneratedField::Signature),
+                            "mac" => Ok(GeneratedField::Mac),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -409,6 +640,8 @@ impl<'de> serde::Deserialize<'de> for AuthenticationChallengeResponse {
             {
                 let mut auth_id__ = None;
                 let mut c__ = None;
+                let mut signature__ = None;
+                let mut mac__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::AuthId => {
@@ -423,11 +656,25 @@ impl<'de> serde::Deserialize<'de> for AuthenticationChallengeResponse {
                             }
                             c__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            signature__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Mac => {
+                            if mac__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("mac"));
+                            }
+                            mac__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(AuthenticationChallengeResponse {
                     auth_id: auth_id__.unwrap_or_default(),
                     c: c__.unwrap_or_default(),
+                    signature: signature__.unwrap_or_default(),
+                    mac: mac__.unwrap_or_default(),
                 })
             }
         }
@@ -442,10 +689,16 @@ impl serde::Serialize for Configuration {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
+        if self.protocol_version != 0 {
+            len += 1;
+        }
         if self.flavor.is_some() {
             len += 1;
         }
         let mut struct_ser = serializer.serialize_struct("zkauth.v1.Configuration", len)?;
+        if self.protocol_version != 0 {
+            struct_ser.serialize_field("protocolVersion", &self.protocol_version)?;
+        }
         if let Some(v) = self.flavor.as_ref() {
             match v {
                 configuration::Flavor::DiscreteLogarithm(v) => {
@@ -466,6 +719,8 @@ impl<'de> serde::Deserialize<'de> for Configuration {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "protocol_version",
+            "protocolVersion",
             "discrete_logarithm",
             "discreteLogarithm",
             "elliptic_curve",
@@ -474,6 +729,7 @@ impl<'de> serde::Deserialize<'de> for Configuration {
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            ProtocolVersion,
             DiscreteLogarithm,
             EllipticCurve,
         }
@@ -497,6 +753,7 @@ impl<'de> serde::Deserialize<'de> for Configuration {
                         E: serde::de::Error,
                     {
                         match value {
+                            "protocolVersion" | "protocol_version" => Ok(GeneratedField::ProtocolVersion),
                             "discreteLogarithm" | "discrete_logarithm" => Ok(GeneratedField::DiscreteLogarithm),
                             "ellipticCurve" | "elliptic_curve" => Ok(GeneratedField::EllipticCurve),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
@@ -518,9 +775,18 @@ impl<'de> serde::Deserialize<'de> for Configuration {
                 where
                     V: serde::de::MapAccess<'de>,
             {
+                let mut protocol_version__ = None;
                 let mut flavor__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
+                        GeneratedField::ProtocolVersion => {
+                            if protocol_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("protocolVersion"));
+                            }
+                            protocol_version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::DiscreteLogarithm => {
                             if flavor__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("discreteLogarithm"));
@@ -538,6 +804,7 @@ impl<'de> serde::Deserialize<'de> for Configuration {
                     }
                 }
                 Ok(Configuration {
+                    protocol_version: protocol_version__.unwrap_or_default(),
                     flavor: flavor__,
                 })
             }
@@ -701,6 +968,9 @@ impl serde::Serialize for configuration::EllipticCurve {
         if !self.h.is_empty() {
             len += 1;
         }
+        if !self.curve.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("zkauth.v1.Configuration.EllipticCurve", len)?;
         if !self.g.is_empty() {
             struct_ser.serialize_field("g", &self.g)?;
@@ -708,6 +978,9 @@ impl serde::Serialize for configuration::EllipticCurve {
         if !self.h.is_empty() {
             struct_ser.serialize_field("h", &self.h)?;
         }
+        if !self.curve.is_empty() {
+            struct_ser.serialize_field("curve", &self.curve)?;
+        }
         struct_ser.end()
     }
 }
@@ -720,12 +993,14 @@ impl<'de> serde::Deserialize<'de> for configuration::EllipticCurve {
         const FIELDS: &[&str] = &[
             "g",
             "h",
+            "curve",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             G,
             H,
+            Curve,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -749,6 +1024,7 @@ impl<'de> serde::Deserialize<'de> for configuration::EllipticCurve {
                         match value {
                             "g" => Ok(GeneratedField::G),
                             "h" => Ok(GeneratedField::H),
+                            "curve" => Ok(GeneratedField::Curve),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -770,6 +1046,7 @@ impl<'de> serde::Deserialize<'de> for configuration::EllipticCurve {
             {
                 let mut g__ = None;
                 let mut h__ = None;
+                let mut curve__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::G => {
@@ -784,11 +1061,18 @@ impl<'de> serde::Deserialize<'de> for configuration::EllipticCurve {
                             }
                             h__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Curve => {
+                            if curve__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("curve"));
+                            }
+                            curve__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(configuration::EllipticCurve {
                     g: g__.unwrap_or_default(),
                     h: h__.unwrap_or_default(),
+                    curve: curve__.unwrap_or_default(),
                 })
             }
         }
@@ -866,7 +1150,7 @@ impl<'de> serde::Deserialize<'de> for GetConfigurationRequest {
         deserializer.deserialize_struct("zkauth.v1.GetConfigurationRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for RegisterRequest {
+impl serde::Serialize for GetUserRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -877,26 +1161,14 @@ impl serde::Serialize for RegisterRequest {
         if !self.user.is_empty() {
             len += 1;
         }
-        if !self.y1.is_empty() {
-            len += 1;
-        }
-        if !self.y2.is_empty() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("zkauth.v1.RegisterRequest", len)?;
+        let mut struct_ser = serializer.serialize_struct("zkauth.v1.GetUserRequest", len)?;
         if !self.user.is_empty() {
             struct_ser.serialize_field("user", &self.user)?;
         }
-        if !self.y1.is_empty() {
-            struct_ser.serialize_field("y1", &self.y1)?;
-        }
-        if !self.y2.is_empty() {
-            struct_ser.serialize_field("y2", &self.y2)?;
-        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for RegisterRequest {
+impl<'de> serde::Deserialize<'de> for GetUserRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -904,15 +1176,11 @@ impl<'de> serde::Deserialize<'de> for RegisterRequest {
     {
         const FIELDS: &[&str] = &[
             "user",
-            "y1",
-            "y2",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             User,
-            Y1,
-            Y2,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -935,8 +1203,6 @@ impl<'de> serde::Deserialize<'de> for RegisterRequest {
                     {
                         match value {
                             "user" => Ok(GeneratedField::User),
-                            "y1" => Ok(GeneratedField::Y1),
-                            "y2" => Ok(GeneratedField::Y2),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -946,19 +1212,17 @@ impl<'de> serde::Deserialize<'de> for RegisterRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = RegisterRequest;
+            type Value = GetUserRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct zkauth.v1.RegisterRequest")
+                formatter.write_str("struct zkauth.v1.GetUserRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RegisterRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetUserRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut user__ = None;
-                let mut y1__ = None;
-                let mut y2__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::User => {
@@ -967,53 +1231,55 @@ impl<'de> serde::Deserialize<'de> for RegisterRequest {
                             }
                             user__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::Y1 => {
-                            if y1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("y1"));
-                            }
-                            y1__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Y2 => {
-                            if y2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("y2"));
-                            }
-                            y2__ = Some(map_.next_value()?);
-                        }
                     }
                 }
-                Ok(RegisterRequest {
+                Ok(GetUserRequest {
                     user: user__.unwrap_or_default(),
-                    y1: y1__.unwrap_or_default(),
-                    y2: y2__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("zkauth.v1.RegisterRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("zkauth.v1.GetUserRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for RegisterResponse {
+impl serde::Serialize for GetUserResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("zkauth.v1.RegisterResponse", len)?;
+        let mut len = 0;
+        if !self.user.is_empty() {
+            len += 1;
+        }
+        if !self.metadata.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("zkauth.v1.GetUserResponse", len)?;
+        if !self.user.is_empty() {
+            struct_ser.serialize_field("user", &self.user)?;
+        }
+        if !self.metadata.is_empty() {
+            struct_ser.serialize_field("metadata", &self.metadata)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for RegisterResponse {
+impl<'de> serde::Deserialize<'de> for GetUserResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "user",
+            "metadata",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            User,
+            Metadata,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1034,7 +1300,11 @@ impl<'de> serde::Deserialize<'de> for RegisterResponse {
                     where
                         E: serde::de::Error,
                     {
-                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                        match value {
+                            "user" => Ok(GeneratedField::User),
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -1042,23 +1312,1529 @@ impl<'de> serde::Deserialize<'de> for RegisterResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = RegisterResponse;
+            type Value = GetUserResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct zkauth.v1.RegisterResponse")
+                formatter.write_str("struct zkauth.v1.GetUserResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RegisterResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetUserResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                }
-                Ok(RegisterResponse {
+                let mut user__ = None;
+                let mut metadata__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::User => {
+                            if user__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("user"));
+                            }
+                            user__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                    }
+                }
+                Ok(GetUserResponse {
+                    user: user__.unwrap_or_default(),
+                    metadata: metadata__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("zkauth.v1.GetUserResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RegisterAndChallengeRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user.is_empty() {
+            len += 1;
+        }
+        if !self.y1.is_empty() {
+            len += 1;
+        }
+        if !self.y2.is_empty() {
+            len += 1;
+        }
+        if !self.metadata.is_empty() {
+            len += 1;
+        }
+        if !self.r1.is_empty() {
+            len += 1;
+        }
+        if !self.r2.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("zkauth.v1.RegisterAndChallengeRequest", len)?;
+        if !self.user.is_empty() {
+            struct_ser.serialize_field("user", &self.user)?;
+        }
+        if !self.y1.is_empty() {
+            struct_ser.serialize_field("y1", &self.y1)?;
+        }
+        if !self.y2.is_empty() {
+            struct_ser.serialize_field("y2", &self.y2)?;
+        }
+        if !self.metadata.is_empty() {
+            struct_ser.serialize_field("metadata", &self.metadata)?;
+        }
+        if !self.r1.is_empty() {
+            struct_ser.serialize_field("r1", &self.r1)?;
+        }
+        if !self.r2.is_empty() {
+            struct_ser.serialize_field("r2", &self.r2)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RegisterAndChallengeRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user",
+            "y1",
+            "y2",
+            "metadata",
+            "r1",
+            "r2",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            User,
+            Y1,
+            Y2,
+            Metadata,
+            R1,
+            R2,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "user" => Ok(GeneratedField::User),
+                            "y1" => Ok(GeneratedField::Y1),
+                            "y2" => Ok(GeneratedField::Y2),
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            "r1" => Ok(GeneratedField::R1),
+                            "r2" => Ok(GeneratedField::R2),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RegisterAndChallengeRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct zkauth.v1.RegisterAndChallengeRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RegisterAndChallengeRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user__ = None;
+                let mut y1__ = None;
+                let mut y2__ = None;
+                let mut metadata__ = None;
+                let mut r1__ = None;
+                let mut r2__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::User => {
+                            if user__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("user"));
+                            }
+                            user__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Y1 => {
+                            if y1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("y1"));
+                            }
+                            y1__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Y2 => {
+                            if y2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("y2"));
+                            }
+                            y2__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                        GeneratedField::R1 => {
+                            if r1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("r1"));
+                            }
+                            r1__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::R2 => {
+                            if r2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("r2"));
+                            }
+                            r2__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(RegisterAndChallengeRequest {
+                    user: user__.unwrap_or_default(),
+                    y1: y1__.unwrap_or_default(),
+                    y2: y2__.unwrap_or_default(),
+                    metadata: metadata__.unwrap_or_default(),
+                    r1: r1__.unwrap_or_default(),
+                    r2: r2__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("zkauth.v1.RegisterAndChallengeRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RegisterRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user.is_empty() {
+            len += 1;
+        }
+        if !self.y1.is_empty() {
+            len += 1;
+        }
+        if !self.y2.is_empty() {
+            len += 1;
+        }
+        if !self.metadata.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("zkauth.v1.RegisterRequest", len)?;
+        if !self.user.is_empty() {
+            struct_ser.serialize_field("user", &self.user)?;
+        }
+        if !self.y1.is_empty() {
+            struct_ser.serialize_field("y1", &self.y1)?;
+        }
+        if !self.y2.is_empty() {
+            struct_ser.serialize_field("y2", &self.y2)?;
+        }
+        if !self.metadata.is_empty() {
+            struct_ser.serialize_field("metadata", &self.metadata)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RegisterRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user",
+            "y1",
+            "y2",
+            "metadata",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            User,
+            Y1,
+            Y2,
+            Metadata,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "user" => Ok(GeneratedField::User),
+                            "y1" => Ok(GeneratedField::Y1),
+                            "y2" => Ok(GeneratedField::Y2),
+                            "metadata" => Ok(GeneratedField::Metadata),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RegisterRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct zkauth.v1.RegisterRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RegisterRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user__ = None;
+                let mut y1__ = None;
+                let mut y2__ = None;
+                let mut metadata__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::User => {
+                            if user__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("user"));
+                            }
+                            user__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Y1 => {
+                            if y1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("y1"));
+                            }
+                            y1__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Y2 => {
+                            if y2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("y2"));
+                            }
+                            y2__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Metadata => {
+                            if metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("metadata"));
+                            }
+                            metadata__ = Some(
+                                map_.next_value::<std::collections::HashMap<_, _>>()?
+                            );
+                        }
+                    }
+                }
+                Ok(RegisterRequest {
+                    user: user__.unwrap_or_default(),
+                    y1: y1__.unwrap_or_default(),
+                    y2: y2__.unwrap_or_default(),
+                    metadata: metadata__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("zkauth.v1.RegisterRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RegisterResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("zkauth.v1.RegisterResponse", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RegisterResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RegisterResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct zkauth.v1.RegisterResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RegisterResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(RegisterResponse {
+                })
+            }
+        }
+        deserializer.deserialize_struct("zkauth.v1.RegisterResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SelfTestRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("zkauth.v1.SelfTestRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SelfTestRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SelfTestRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct zkauth.v1.SelfTestRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SelfTestRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(SelfTestRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("zkauth.v1.SelfTestRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SelfTestResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.passed {
+            len += 1;
+        }
+        if !self.error.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("zkauth.v1.SelfTestResponse", len)?;
+        if self.passed {
+            struct_ser.serialize_field("passed", &self.passed)?;
+        }
+        if !self.error.is_empty() {
+            struct_ser.serialize_field("error", &self.error)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SelfTestResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "passed",
+            "error",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Passed,
+            Error,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "passed" => Ok(GeneratedField::Passed),
+                            "error" => Ok(GeneratedField::Error),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SelfTestResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct zkauth.v1.SelfTestResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SelfTestResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut passed__ = None;
+                let mut error__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Passed => {
+                            if passed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("passed"));
+                            }
+                            passed__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Error => {
+                            if error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("error"));
+                            }
+                            error__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SelfTestResponse {
+                    passed: passed__.unwrap_or_default(),
+                    error: error__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("zkauth.v1.SelfTestResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SessionCreated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.user.is_empty() {
+            len += 1;
+        }
+        if !self.session_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("zkauth.v1.SessionCreated", len)?;
+        if !self.user.is_empty() {
+            struct_ser.serialize_field("user", &self.user)?;
+        }
+        if !self.session_id.is_empty() {
+            struct_ser.serialize_field("sessionId", &self.session_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SessionCreated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "user",
+            "session_id",
+            "sessionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            User,
+            SessionId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "user" => Ok(GeneratedField::User),
+                            "sessionId" | "session_id" => Ok(GeneratedField::SessionId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SessionCreated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct zkauth.v1.SessionCreated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SessionCreated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut user__ = None;
+                let mut session_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::User => {
+                            if user__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("user"));
+                            }
+                            user__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SessionId => {
+                            if session_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sessionId"));
+                            }
+                            session_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SessionCreated {
+                    user: user__.unwrap_or_default(),
+                    session_id: session_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("zkauth.v1.SessionCreated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SessionExpired {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.session_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("zkauth.v1.SessionExpired", len)?;
+        if !self.session_id.is_empty() {
+            struct_ser.serialize_field("sessionId", &self.session_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SessionExpired {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "session_id",
+            "sessionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SessionId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "sessionId" | "session_id" => Ok(GeneratedField::SessionId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SessionExpired;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct zkauth.v1.SessionExpired")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SessionExpired, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut session_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SessionId => {
+                            if session_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sessionId"));
+                            }
+                            session_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SessionExpired {
+                    session_id: session_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("zkauth.v1.SessionExpired", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SessionRevoked {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.session_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("zkauth.v1.SessionRevoked", len)?;
+        if !self.session_id.is_empty() {
+            struct_ser.serialize_field("sessionId", &self.session_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SessionRevoked {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "session_id",
+            "sessionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SessionId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "sessionId" | "session_id" => Ok(GeneratedField::SessionId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SessionRevoked;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct zkauth.v1.SessionRevoked")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SessionRevoked, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut session_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SessionId => {
+                            if session_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sessionId"));
+                            }
+                            session_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(SessionRevoked {
+                    session_id: session_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("zkauth.v1.SessionRevoked", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UnregisterRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.answer.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("zkauth.v1.UnregisterRequest", len)?;
+        if let Some(v) = self.answer.as_ref() {
+            struct_ser.serialize_field("answer", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UnregisterRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "answer",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Answer,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "answer" => Ok(GeneratedField::Answer),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UnregisterRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct zkauth.v1.UnregisterRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnregisterRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut answer__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Answer => {
+                            if answer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("answer"));
+                            }
+                            answer__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(UnregisterRequest {
+                    answer: answer__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("zkauth.v1.UnregisterRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UnregisterResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("zkauth.v1.UnregisterResponse", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UnregisterResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UnregisterResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct zkauth.v1.UnregisterResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnregisterResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(UnregisterResponse {
+                })
+            }
+        }
+        deserializer.deserialize_struct("zkauth.v1.UnregisterResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpdateCredentialsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.answer.is_some() {
+            len += 1;
+        }
+        if !self.y1.is_empty() {
+            len += 1;
+        }
+        if !self.y2.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("zkauth.v1.UpdateCredentialsRequest", len)?;
+        if let Some(v) = self.answer.as_ref() {
+            struct_ser.serialize_field("answer", v)?;
+        }
+        if !self.y1.is_empty() {
+            struct_ser.serialize_field("y1", &self.y1)?;
+        }
+        if !self.y2.is_empty() {
+            struct_ser.serialize_field("y2", &self.y2)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdateCredentialsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "answer",
+            "y1",
+            "y2",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Answer,
+            Y1,
+            Y2,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "answer" => Ok(GeneratedField::Answer),
+                            "y1" => Ok(GeneratedField::Y1),
+                            "y2" => Ok(GeneratedField::Y2),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdateCredentialsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct zkauth.v1.UpdateCredentialsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UpdateCredentialsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut answer__ = None;
+                let mut y1__ = None;
+                let mut y2__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Answer => {
+                            if answer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("answer"));
+                            }
+                            answer__ = map_.next_value()?;
+                        }
+                        GeneratedField::Y1 => {
+                            if y1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("y1"));
+                            }
+                            y1__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Y2 => {
+                            if y2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("y2"));
+                            }
+                            y2__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(UpdateCredentialsRequest {
+                    answer: answer__,
+                    y1: y1__.unwrap_or_default(),
+                    y2: y2__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("zkauth.v1.UpdateCredentialsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UpdateCredentialsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("zkauth.v1.UpdateCredentialsResponse", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UpdateCredentialsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UpdateCredentialsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct zkauth.v1.UpdateCredentialsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UpdateCredentialsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(UpdateCredentialsResponse {
                 })
             }
         }
-        deserializer.deserialize_struct("zkauth.v1.RegisterResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("zkauth.v1.UpdateCredentialsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WatchConfigurationRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("zkauth.v1.WatchConfigurationRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WatchConfigurationRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WatchConfigurationRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct zkauth.v1.WatchConfigurationRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WatchConfigurationRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(WatchConfigurationRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("zkauth.v1.WatchConfigurationRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WatchSessionsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("zkauth.v1.WatchSessionsRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WatchSessionsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Err(serde::de::Error::unknown_field(value, FIELDS))
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WatchSessionsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct zkauth.v1.WatchSessionsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WatchSessionsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(WatchSessionsRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("zkauth.v1.WatchSessionsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WatchSessionsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.event.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("zkauth.v1.WatchSessionsResponse", len)?;
+        if let Some(v) = self.event.as_ref() {
+            match v {
+                watch_sessions_response::Event::Created(v) => {
+                    struct_ser.serialize_field("created", v)?;
+                }
+                watch_sessions_response::Event::Revoked(v) => {
+                    struct_ser.serialize_field("revoked", v)?;
+                }
+                watch_sessions_response::Event::Expired(v) => {
+                    struct_ser.serialize_field("expired", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WatchSessionsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "created",
+            "revoked",
+            "expired",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Created,
+            Revoked,
+            Expired,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "created" => Ok(GeneratedField::Created),
+                            "revoked" => Ok(GeneratedField::Revoked),
+                            "expired" => Ok(GeneratedField::Expired),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WatchSessionsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct zkauth.v1.WatchSessionsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WatchSessionsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut event__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Created => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("created"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(watch_sessions_response::Event::Created)
+;
+                        }
+                        GeneratedField::Revoked => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("revoked"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(watch_sessions_response::Event::Revoked)
+;
+                        }
+                        GeneratedField::Expired => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expired"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(watch_sessions_response::Event::Expired)
+;
+                        }
+                    }
+                }
+                Ok(WatchSessionsResponse {
+                    event: event__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("zkauth.v1.WatchSessionsResponse", FIELDS, GeneratedVisitor)
     }
 }
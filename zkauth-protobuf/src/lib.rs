@@ -1,16 +1,155 @@
 //! This module contains the generated protobuf code for the zkauth protocol and grpc service.
 
+use prost::Message;
+use sha2::{Digest, Sha256};
 use v1::{configuration, Configuration};
-use zkauth::{
-    discrete_logarithm::configuration::DiscreteLogarithmConfiguration,
-    elliptic_curve::configuration::EllipticCurveConfiguration, Element, Scalar,
+#[cfg(feature = "discrete_logarithm")]
+use zkauth::discrete_logarithm::configuration::DiscreteLogarithmConfiguration;
+#[cfg(feature = "elliptic_curve")]
+use zkauth::elliptic_curve::{
+    configuration::EllipticCurveConfiguration,
+    curve::{Curve, Ristretto},
 };
+use zkauth::{Element, RegistrationBlob, Scalar};
 
-include!("gen/mod.rs");
+// Declared here rather than through the generated gen/mod.rs so that lints can be relaxed for
+// the generated tonic code, which carries empty doc comments for undocumented RPCs.
+#[cfg(feature = "zkauth-v1")]
+#[allow(clippy::empty_docs)]
+pub mod v1 {
+    include!("gen/zkauth.v1.rs");
+}
+
+/// Parameter bundles for provisioning clients without a live `get_configuration` call.
+pub mod bundle;
+
+/// Server signatures over authentication challenges, for mutual authentication.
+pub mod challenge;
+
+/// The versioned on-disk format of configuration files, decoupled from the protobuf codegen.
+pub mod config_file;
+
+/// A unified error type for the client and server, with conversions to and from gRPC statuses.
+pub mod error;
 
 #[derive(Debug)]
 pub struct ConversionError;
 
+impl Configuration {
+    /// Returns the protocol version the configuration was served with, treating an unset version
+    /// as version 1, the version before protocol versions were advertised.
+    pub fn effective_protocol_version(&self) -> u32 {
+        match self.protocol_version {
+            0 => 1,
+            version => version,
+        }
+    }
+
+    /// Checks that the protocol version the configuration was served with matches the given
+    /// supported version.
+    /// # Errors
+    /// * Returns a failed precondition error if the versions differ.
+    pub fn check_protocol_version(&self, supported: u32) -> Result<(), error::AuthError> {
+        let version = self.effective_protocol_version();
+        if version != supported {
+            return Err(error::AuthError::FailedPrecondition(format!(
+                "Unsupported protocol version {}, expected {}",
+                version, supported
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the version of the configuration, which is the hex-encoded SHA-256 digest of its
+    /// protobuf encoding. Any change to the public parameters results in a different version. The
+    /// advertised protocol version is excluded, so a served configuration has the same version as
+    /// the one it was loaded from.
+    pub fn config_version(&self) -> String {
+        let parameters = Configuration {
+            protocol_version: 0,
+            ..self.clone()
+        };
+        hex::encode(Sha256::digest(parameters.encode_to_vec()))
+    }
+
+    /// Compares the configuration with another one, such as a refetched configuration, and
+    /// classifies the change between them.
+    pub fn diff(&self, other: &Configuration) -> ConfigurationChange {
+        if self == other {
+            return ConfigurationChange::Identical;
+        }
+
+        // Registrations remain valid as long as every public parameter has the same value, even
+        // if it is represented differently.
+        let same_parameters = match (&self.flavor, &other.flavor) {
+            (
+                Some(configuration::Flavor::DiscreteLogarithm(a)),
+                Some(configuration::Flavor::DiscreteLogarithm(b)),
+            ) => {
+                same_value::<Scalar>(&a.p, &b.p)
+                    && same_value::<Scalar>(&a.q, &b.q)
+                    && same_value::<Element>(&a.g, &b.g)
+                    && same_value::<Element>(&a.h, &b.h)
+            }
+            (
+                Some(configuration::Flavor::EllipticCurve(a)),
+                Some(configuration::Flavor::EllipticCurve(b)),
+            ) => {
+                a.curve_name() == b.curve_name()
+                    && same_value::<Element>(&a.g, &b.g)
+                    && same_value::<Element>(&a.h, &b.h)
+            }
+            _ => false,
+        };
+
+        if same_parameters {
+            ConfigurationChange::Benign
+        } else {
+            ConfigurationChange::Breaking
+        }
+    }
+
+    /// Returns whether registrations made with this configuration remain valid with the other
+    /// configuration.
+    pub fn is_compatible_with(&self, other: &Configuration) -> bool {
+        self.diff(other) != ConfigurationChange::Breaking
+    }
+}
+
+/// Implementation of the elliptic curve parameters.
+impl configuration::EllipticCurve {
+    /// Returns the name of the curve of the points, which is ristretto255 when unset.
+    pub fn curve_name(&self) -> &str {
+        if self.curve.is_empty() {
+            "ristretto255"
+        } else {
+            &self.curve
+        }
+    }
+}
+
+/// A classification of the change between two configurations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigurationChange {
+    /// The configurations are identical.
+    Identical,
+
+    /// The configurations differ, but existing registrations remain valid.
+    Benign,
+
+    /// The public parameters differ, and existing registrations are no longer valid.
+    Breaking,
+}
+
+/// Returns whether two parameter strings parse to the same value.
+fn same_value<T: std::str::FromStr + PartialEq>(a: &str, b: &str) -> bool {
+    match (a.parse::<T>(), b.parse::<T>()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+#[cfg(feature = "discrete_logarithm")]
 impl From<DiscreteLogarithmConfiguration> for Configuration {
     fn from(value: DiscreteLogarithmConfiguration) -> Self {
         let p: Scalar = value.p.into();
@@ -26,25 +165,37 @@ impl From<DiscreteLogarithmConfiguration> for Configuration {
                     h: h.to_string(),
                 },
             )),
+            protocol_version: 0,
         }
     }
 }
 
-impl From<EllipticCurveConfiguration> for Configuration {
-    fn from(value: EllipticCurveConfiguration) -> Self {
-        let g: Element = value.g.into();
-        let h: Element = value.h.into();
+#[cfg(feature = "elliptic_curve")]
+impl<C: Curve> From<EllipticCurveConfiguration<C>> for Configuration {
+    fn from(value: EllipticCurveConfiguration<C>) -> Self {
+        let g = C::point_into(value.g);
+        let h = C::point_into(value.h);
+        // The curve is left unset for ristretto255, so that existing configurations and their
+        // versions are unchanged.
+        let curve = if C::NAME == Ristretto::NAME {
+            String::new()
+        } else {
+            C::NAME.to_string()
+        };
         Configuration {
             flavor: Some(configuration::Flavor::EllipticCurve(
                 configuration::EllipticCurve {
                     g: g.to_string(),
                     h: h.to_string(),
+                    curve,
                 },
             )),
+            protocol_version: 0,
         }
     }
 }
 
+#[cfg(feature = "discrete_logarithm")]
 impl TryFrom<configuration::DiscreteLogarithm> for DiscreteLogarithmConfiguration {
     type Error = ConversionError;
 
@@ -58,23 +209,158 @@ impl TryFrom<configuration::DiscreteLogarithm> for DiscreteLogarithmConfiguratio
     }
 }
 
-impl TryFrom<configuration::EllipticCurve> for EllipticCurveConfiguration {
+#[cfg(feature = "elliptic_curve")]
+impl<C: Curve> TryFrom<configuration::EllipticCurve> for EllipticCurveConfiguration<C> {
     type Error = ConversionError;
 
     fn try_from(config: configuration::EllipticCurve) -> Result<Self, Self::Error> {
+        if config.curve_name() != C::NAME {
+            return Err(ConversionError);
+        }
         Ok(EllipticCurveConfiguration {
-            g: config
-                .g
-                .parse::<Element>()
-                .map_err(|_| ConversionError)?
-                .try_into()
+            g: C::point_from(config.g.parse::<Element>().map_err(|_| ConversionError)?)
                 .map_err(|_| ConversionError)?,
-            h: config
-                .h
-                .parse::<Element>()
-                .map_err(|_| ConversionError)?
-                .try_into()
+            h: C::point_from(config.h.parse::<Element>().map_err(|_| ConversionError)?)
                 .map_err(|_| ConversionError)?,
         })
     }
 }
+
+impl From<RegistrationBlob> for v1::RegisterRequest {
+    fn from(blob: RegistrationBlob) -> Self {
+        v1::RegisterRequest {
+            user: blob.user,
+            y1: blob.y1,
+            y2: blob.y2,
+            metadata: Default::default(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "discrete_logarithm", feature = "elliptic_curve"))]
+mod tests {
+    use super::*;
+    use zkauth::elliptic_curve::curve::Secp256k1;
+
+    #[test]
+    fn config_version_is_stable() {
+        let config: Configuration = DiscreteLogarithmConfiguration::generate(16).into();
+        assert_eq!(config.config_version(), config.clone().config_version());
+        assert_eq!(config.config_version().len(), 64);
+    }
+
+    #[test]
+    fn config_version_changes_with_parameters() {
+        let config: Configuration = DiscreteLogarithmConfiguration::generate(16).into();
+        let mut other = config.clone();
+        if let Some(configuration::Flavor::DiscreteLogarithm(ref mut params)) = other.flavor {
+            params.g = "0".to_string();
+        }
+        assert_ne!(config.config_version(), other.config_version());
+    }
+
+    #[test]
+    fn config_version_ignores_protocol_version() {
+        let config: Configuration = DiscreteLogarithmConfiguration::generate(16).into();
+        let served = Configuration {
+            protocol_version: 1,
+            ..config.clone()
+        };
+        assert_eq!(config.config_version(), served.config_version());
+    }
+
+    #[test]
+    fn effective_protocol_version_defaults_to_one() {
+        let config: Configuration = DiscreteLogarithmConfiguration::generate(16).into();
+        assert_eq!(config.effective_protocol_version(), 1);
+        assert!(config.check_protocol_version(1).is_ok());
+
+        let served = Configuration {
+            protocol_version: 2,
+            ..config
+        };
+        assert_eq!(served.effective_protocol_version(), 2);
+        assert_eq!(
+            served.check_protocol_version(1),
+            Err(error::AuthError::FailedPrecondition(
+                "Unsupported protocol version 2, expected 1".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn diff_identical() {
+        let config: Configuration = DiscreteLogarithmConfiguration::generate(16).into();
+        assert_eq!(config.diff(&config.clone()), ConfigurationChange::Identical);
+        assert!(config.is_compatible_with(&config.clone()));
+    }
+
+    #[test]
+    fn diff_benign() {
+        let config: Configuration = DiscreteLogarithmConfiguration::generate(16).into();
+        let mut other = config.clone();
+        if let Some(configuration::Flavor::DiscreteLogarithm(ref mut params)) = other.flavor {
+            params.p = format!("00{}", params.p);
+        }
+        assert_eq!(config.diff(&other), ConfigurationChange::Benign);
+        assert!(config.is_compatible_with(&other));
+    }
+
+    #[test]
+    fn diff_breaking_parameters() {
+        let config: Configuration = DiscreteLogarithmConfiguration::generate(16).into();
+        let mut other = config.clone();
+        if let Some(configuration::Flavor::DiscreteLogarithm(ref mut params)) = other.flavor {
+            params.g = "0".to_string();
+        }
+        assert_eq!(config.diff(&other), ConfigurationChange::Breaking);
+        assert!(!config.is_compatible_with(&other));
+    }
+
+    #[test]
+    fn diff_breaking_flavor() {
+        let config: Configuration = DiscreteLogarithmConfiguration::generate(16).into();
+        let other: Configuration = EllipticCurveConfiguration::generate().into();
+        assert_eq!(config.diff(&other), ConfigurationChange::Breaking);
+        assert!(!config.is_compatible_with(&other));
+    }
+
+    #[test]
+    fn diff_breaking_curve() {
+        let config: Configuration = EllipticCurveConfiguration::generate().into();
+        let mut other = config.clone();
+        if let Some(configuration::Flavor::EllipticCurve(ref mut params)) = other.flavor {
+            params.curve = "secp256k1".to_string();
+        }
+        assert_eq!(config.diff(&other), ConfigurationChange::Breaking);
+
+        // An explicit ristretto255 curve is the same as an unset one.
+        if let Some(configuration::Flavor::EllipticCurve(ref mut params)) = other.flavor {
+            params.curve = "ristretto255".to_string();
+        }
+        assert_eq!(config.diff(&other), ConfigurationChange::Benign);
+    }
+
+    #[test]
+    fn elliptic_curve_configuration_round_trip_by_curve() {
+        let config: Configuration = EllipticCurveConfiguration::generate().into();
+        let Some(configuration::Flavor::EllipticCurve(params)) = config.flavor else {
+            panic!("expected an elliptic curve configuration");
+        };
+        assert_eq!(params.curve, "");
+        assert_eq!(params.curve_name(), "ristretto255");
+        assert!(EllipticCurveConfiguration::<Ristretto>::try_from(params.clone()).is_ok());
+        assert!(EllipticCurveConfiguration::<Secp256k1>::try_from(params).is_err());
+
+        let secp256k1 = EllipticCurveConfiguration::<Secp256k1>::generate_on_curve();
+        let config: Configuration = secp256k1.clone().into();
+        let Some(configuration::Flavor::EllipticCurve(params)) = config.flavor else {
+            panic!("expected an elliptic curve configuration");
+        };
+        assert_eq!(params.curve, "secp256k1");
+        assert!(EllipticCurveConfiguration::<Ristretto>::try_from(params.clone()).is_err());
+        let decoded = EllipticCurveConfiguration::<Secp256k1>::try_from(params).unwrap();
+        assert_eq!(decoded.g, secp256k1.g);
+        assert_eq!(decoded.h, secp256k1.h);
+    }
+}
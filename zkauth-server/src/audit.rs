@@ -0,0 +1,242 @@
+//! Audit records of authentication attempts, along with selected request metadata so they can be
+//! correlated with upstream systems.
+//!
+//! The [`MetadataInterceptor`] copies an allowlist of request metadata keys, such as
+//! `x-request-id`, into the request extensions, and the service includes them in the audit entry
+//! it writes for each authentication attempt, such as a verify_authentication request.
+//!
+//! Failed attempts are also classified by the [`FailureStage`] they failed at, which is recorded
+//! in the audit entry and counted by [`FailureCounters`], but never revealed to the client. This
+//! tells malformed-input probes apart from genuine verification failures.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
+
+use crate::recorder::RecordedOutcome;
+
+/// The allowlisted metadata of a request, as stashed in its extensions by the interceptor.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequestMetadata(pub BTreeMap<String, String>);
+
+/// An interceptor that copies the allowlisted metadata keys of each request into its extensions.
+/// Keys that are missing or not valid ASCII are skipped.
+#[derive(Debug, Clone, Default)]
+pub struct MetadataInterceptor {
+    keys: Arc<[String]>,
+}
+
+/// Implementation of the metadata interceptor.
+impl MetadataInterceptor {
+    /// Creates an interceptor for the given metadata keys, which are matched case-insensitively.
+    pub fn new(keys: Vec<String>) -> Self {
+        Self {
+            keys: keys.into_iter().map(|key| key.to_lowercase()).collect(),
+        }
+    }
+}
+
+/// Implementation of the interceptor for the metadata interceptor.
+impl Interceptor for MetadataInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let metadata = self
+            .keys
+            .iter()
+            .filter_map(|key| {
+                let value = request.metadata().get(key.as_str())?.to_str().ok()?;
+                Some((key.clone(), value.to_string()))
+            })
+            .collect();
+        request.extensions_mut().insert(RequestMetadata(metadata));
+        Ok(request)
+    }
+}
+
+/// The stage at which an authentication attempt failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureStage {
+    /// The answer could not be parsed, such as a malformed s or auth_id.
+    Parse,
+
+    /// The challenge was for a user that is not registered.
+    UnknownUser,
+
+    /// The answer was well-formed but did not verify against the user's registration.
+    Verification,
+
+    /// Any other failure, such as a missing or expired challenge, or a store error.
+    Other,
+}
+
+/// The number of failed authentication attempts by stage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FailureCounts {
+    /// The number of attempts that failed to parse.
+    pub parse: u64,
+
+    /// The number of attempts for an unknown user.
+    pub unknown_user: u64,
+
+    /// The number of attempts that failed verification.
+    pub verification: u64,
+
+    /// The number of attempts that failed otherwise.
+    pub other: u64,
+}
+
+/// Counters of failed authentication attempts by stage.
+#[derive(Debug, Default)]
+pub struct FailureCounters {
+    parse: AtomicU64,
+    unknown_user: AtomicU64,
+    verification: AtomicU64,
+    other: AtomicU64,
+}
+
+/// Implementation of the failure counters.
+impl FailureCounters {
+    /// Counts a failure at the given stage.
+    pub fn increment(&self, stage: FailureStage) {
+        let counter = match stage {
+            FailureStage::Parse => &self.parse,
+            FailureStage::UnknownUser => &self.unknown_user,
+            FailureStage::Verification => &self.verification,
+            FailureStage::Other => &self.other,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the current counts.
+    pub fn counts(&self) -> FailureCounts {
+        FailureCounts {
+            parse: self.parse.load(Ordering::Relaxed),
+            unknown_user: self.unknown_user.load(Ordering::Relaxed),
+            verification: self.verification.load(Ordering::Relaxed),
+            other: self.other.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// An audit entry for an authentication attempt.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// The auth_id of the challenge being answered, or empty for a non-interactive proof.
+    pub auth_id: String,
+
+    /// The outcome of the attempt.
+    pub outcome: RecordedOutcome,
+
+    /// The stage the attempt failed at, if it failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure: Option<FailureStage>,
+
+    /// The allowlisted request metadata.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, String>,
+}
+
+/// A trait for writing audit entries.
+pub trait AuditLog: Sync + Send {
+    /// Writes an audit entry. Failures are handled by the log rather than failing the request.
+    fn write(&self, entry: &AuditEntry);
+}
+
+/// An audit log that writes each entry as a JSON line to the `zkauth_server::audit` log target.
+#[derive(Debug, Default)]
+pub struct LogAuditLog;
+
+/// Implementation of the audit log for the log audit log.
+impl AuditLog for LogAuditLog {
+    fn write(&self, entry: &AuditEntry) {
+        match serde_json::to_string(entry) {
+            Ok(line) => log::info!(target: "zkauth_server::audit", "{}", line),
+            Err(e) => log::warn!("Failed to serialize audit entry: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anyhow::Result;
+    use tonic::metadata::MetadataValue;
+
+    #[test]
+    fn interceptor_copies_allowlisted_metadata() -> Result<()> {
+        let mut interceptor =
+            MetadataInterceptor::new(vec!["X-Request-Id".to_string(), "x-tenant".to_string()]);
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert("x-request-id", MetadataValue::from_static("req-1"));
+        request
+            .metadata_mut()
+            .insert("x-other", MetadataValue::from_static("ignored"));
+
+        let request = interceptor.call(request)?;
+        assert_eq!(
+            request.extensions().get::<RequestMetadata>(),
+            Some(&RequestMetadata(BTreeMap::from([(
+                "x-request-id".to_string(),
+                "req-1".to_string()
+            )])))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn entry_omits_empty_metadata() -> Result<()> {
+        let entry = AuditEntry {
+            auth_id: "id".to_string(),
+            outcome: RecordedOutcome {
+                code: 0,
+                message: "".to_string(),
+            },
+            failure: None,
+            metadata: BTreeMap::new(),
+        };
+        assert_eq!(
+            serde_json::to_string(&entry)?,
+            r#"{"auth_id":"id","outcome":{"code":0,"message":""}}"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn entry_includes_failure_stage() -> Result<()> {
+        let entry = AuditEntry {
+            auth_id: "id".to_string(),
+            outcome: RecordedOutcome {
+                code: 9,
+                message: "Verification failed".to_string(),
+            },
+            failure: Some(FailureStage::UnknownUser),
+            metadata: BTreeMap::new(),
+        };
+        assert_eq!(
+            serde_json::to_string(&entry)?,
+            r#"{"auth_id":"id","outcome":{"code":9,"message":"Verification failed"},"failure":"unknown_user"}"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn counters_count_each_stage() {
+        let counters = FailureCounters::default();
+        counters.increment(FailureStage::Parse);
+        counters.increment(FailureStage::Parse);
+        counters.increment(FailureStage::Verification);
+        assert_eq!(
+            counters.counts(),
+            FailureCounts {
+                parse: 2,
+                verification: 1,
+                ..Default::default()
+            }
+        );
+    }
+}
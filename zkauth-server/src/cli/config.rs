@@ -1,55 +1,721 @@
-use anyhow::Result;
+use anyhow::{Error, Result};
 use clap::ValueEnum;
-use std::fs::File;
+use fs2::FileExt;
+use num_bigint::BigInt;
+use num_traits::One;
+use serde::Serialize;
+use std::fs::{File, OpenOptions};
+use std::path::Path;
+use std::time::Duration;
 use strum_macros::{Display, EnumString, VariantNames};
+use uuid::Uuid;
 use zkauth::{
-    discrete_logarithm::configuration::DiscreteLogarithmConfiguration,
-    elliptic_curve::configuration::EllipticCurveConfiguration,
+    discrete_logarithm::{
+        arithmetic::is_probable_prime,
+        configuration::{DiscreteLogarithmConfiguration, ModpGroup, PrimeSearchOptions},
+    },
+    elliptic_curve::{
+        configuration::EllipticCurveConfiguration,
+        curve::{Curve, Ristretto, Secp256k1},
+    },
 };
-use zkauth_protobuf::v1::Configuration;
+use zkauth_protobuf::{
+    bundle::{ParameterBundle, SigningKey},
+    config_file::ConfigFile,
+    v1::{
+        configuration::{self, Flavor},
+        Configuration,
+    },
+};
+
+/// The number of Miller-Rabin rounds used when validating primes.
+const PRIMALITY_ROUNDS: usize = 40;
+
+/// The number of times loading a configuration file is retried when it fails to parse, in case it
+/// is being written by another process.
+const CONFIG_LOAD_RETRIES: usize = 3;
+
+/// The delay before retrying to load a configuration file that failed to parse.
+const CONFIG_LOAD_RETRY_DELAY: Duration = Duration::from_millis(50);
+
+/// The error message for a configuration without a flavor, which the server refuses to serve.
+pub(crate) const NO_FLAVOR_MESSAGE: &str =
+    "configuration has no protocol flavor; the configuration file may be corrupt";
 
 /// Configuration flavor.
-#[derive(Debug, Clone, EnumString, Display, VariantNames, ValueEnum, PartialEq)]
+#[derive(
+    Debug, Clone, Copy, EnumString, Display, VariantNames, ValueEnum, PartialEq, Serialize,
+)]
 #[strum(serialize_all = "kebab-case")]
+#[serde(rename_all = "kebab-case")]
 pub enum ConfigFlavor {
     DiscreteLogarithm,
     EllipticCurve,
+
+    /// Both flavors at once, each with its own configuration and served under its own path. Only
+    /// valid when serving, since a configuration has a single flavor.
+    Both,
+
+    /// A discrete logarithm configuration served with a verifier that accepts any proof, for load
+    /// testing without the cost of verification. Only valid when serving.
+    #[cfg(feature = "null-verifier")]
+    Null,
+}
+
+/// Strategy for deriving the prime order q of the discrete logarithm subgroup.
+#[derive(
+    Debug, Clone, Copy, Default, EnumString, Display, VariantNames, ValueEnum, PartialEq, Eq,
+)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ParamStyle {
+    /// A safe prime p, with q = (p - 1) / 2.
+    #[default]
+    SafePrime,
+
+    /// A DSA-style prime p, with q a smaller prime factor of p - 1 of its own size.
+    Dsa,
+}
+
+/// Curve of elliptic curve configurations.
+#[derive(
+    Debug, Clone, Copy, Default, EnumString, Display, VariantNames, ValueEnum, PartialEq, Eq,
+)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ConfigCurve {
+    /// The ristretto255 group over curve25519.
+    #[default]
+    Ristretto255,
+
+    /// The secp256k1 curve, with points in the SEC1 compressed encoding used by Bitcoin tooling.
+    Secp256k1,
+}
+
+/// Options for generating a configuration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GenerateOptions {
+    /// The maximum number of candidates to try in the prime search, or `None` for no limit.
+    pub max_prime_trials: Option<usize>,
+
+    /// The strategy for deriving q, for discrete logarithms.
+    pub param_style: ParamStyle,
+
+    /// The number of bits of q, required for DSA-style parameters.
+    pub q_bits: Option<usize>,
+
+    /// The well-known MODP group to use instead of generating a prime, for discrete logarithms.
+    pub modp_group: Option<ModpGroup>,
+
+    /// The curve, for elliptic curves.
+    pub curve: ConfigCurve,
+}
+
+/// Configuration file format.
+#[derive(Debug, Clone, Copy, EnumString, Display, VariantNames, ValueEnum, PartialEq)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ConfigFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+/// Implementation of the configuration file format.
+impl ConfigFormat {
+    /// Returns the format for the given path based on its extension, defaulting to JSON.
+    pub fn from_path(path: &str) -> Self {
+        match Path::new(path).extension().and_then(|ext| ext.to_str()) {
+            Some("yaml") | Some("yml") => ConfigFormat::Yaml,
+            Some("toml") => ConfigFormat::Toml,
+            _ => ConfigFormat::Json,
+        }
+    }
+
+    /// Deserializes a configuration from a string in this format, ignoring unknown fields with a
+    /// warning.
+    pub fn parse(&self, contents: &str) -> Result<Configuration> {
+        self.parse_with_strictness(contents, false)
+    }
+
+    /// Deserializes a configuration from a string in this format. Unknown fields, such as a
+    /// misspelled key, are an error if strict, and are otherwise ignored with a warning.
+    pub fn parse_with_strictness(&self, contents: &str, strict: bool) -> Result<Configuration> {
+        let mut value: serde_json::Value = match self {
+            ConfigFormat::Json => serde_json::from_str(contents)?,
+            ConfigFormat::Yaml => serde_yaml::from_str(contents)?,
+            ConfigFormat::Toml => toml::from_str(contents)?,
+        };
+
+        let unknown = remove_unknown_config_fields(&mut value);
+        if !unknown.is_empty() {
+            if strict {
+                return Err(Error::msg(format!(
+                    "unknown fields in configuration: {}",
+                    unknown.join(", ")
+                )));
+            }
+            log::warn!(
+                "Ignoring unknown fields in configuration: {}",
+                unknown.join(", ")
+            );
+        }
+
+        Ok(ConfigFile::parse_value(value)?)
+    }
+
+    /// Serializes a configuration to a string in this format, using the versioned file schema.
+    pub fn format(&self, config: &Configuration) -> Result<String> {
+        let file = ConfigFile::try_from(config)?;
+        let contents = match self {
+            ConfigFormat::Json => serde_json::to_string_pretty(&file)?,
+            ConfigFormat::Yaml => serde_yaml::to_string(&file)?,
+            ConfigFormat::Toml => toml::to_string_pretty(&file)?,
+        };
+        Ok(contents)
+    }
 }
 
-/// Loads a configuration from a file.
+/// The fields of each configuration flavor, by every name the deserializer accepts for the flavor.
+const CONFIG_FIELDS: [(&[&str], &[&str]); 2] = [
+    (
+        &["discreteLogarithm", "discrete_logarithm"],
+        &["p", "q", "g", "h"],
+    ),
+    (&["ellipticCurve", "elliptic_curve"], &["g", "h", "curve"]),
+];
+
+/// The top-level configuration fields that are not flavors.
+const CONFIG_TOP_LEVEL_FIELDS: [&str; 3] = ["version", "protocolVersion", "protocol_version"];
+
+/// Removes the fields of a deserialized configuration value that are not configuration fields,
+/// and returns their paths. Values that are not objects are left for the deserializer to reject.
+fn remove_unknown_config_fields(value: &mut serde_json::Value) -> Vec<String> {
+    let mut unknown = Vec::new();
+    let Some(flavors) = value.as_object_mut() else {
+        return unknown;
+    };
+
+    flavors.retain(|flavor, params| {
+        if CONFIG_TOP_LEVEL_FIELDS.contains(&flavor.as_str()) {
+            return true;
+        }
+        let Some((_, fields)) = CONFIG_FIELDS
+            .iter()
+            .find(|(names, _)| names.contains(&flavor.as_str()))
+        else {
+            unknown.push(flavor.clone());
+            return false;
+        };
+        if let Some(params) = params.as_object_mut() {
+            params.retain(|field, _| {
+                let known = fields.contains(&field.as_str());
+                if !known {
+                    unknown.push(format!("{}.{}", flavor, field));
+                }
+                known
+            });
+        }
+        true
+    });
+    unknown
+}
+
+/// Loads a configuration from a file, in the format given by its extension.
 pub fn load_config_from_file(path: &str) -> Result<Configuration> {
-    let file = File::open(path)?;
-    let config: Configuration = serde_json::from_reader(file)?;
-    Ok(config)
+    load_config_from_file_with_format(path, ConfigFormat::from_path(path))
+}
+
+/// Loads a configuration from a file in the given format, ignoring unknown fields with a warning.
+pub fn load_config_from_file_with_format(
+    path: &str,
+    format: ConfigFormat,
+) -> Result<Configuration> {
+    load_config_from_file_with_strictness(path, format, false)
+}
+
+/// Loads a configuration from a file in the given format, rejecting unknown fields if strict.
+/// Since another process may be writing the file, a parse failure is retried a few times before
+/// it is returned.
+/// # Errors
+/// * Returns an error if the file cannot be read.
+/// * Returns an error naming the file if it still cannot be parsed after retrying.
+pub fn load_config_from_file_with_strictness(
+    path: &str,
+    format: ConfigFormat,
+    strict: bool,
+) -> Result<Configuration> {
+    let mut retries = 0;
+    loop {
+        let contents = std::fs::read_to_string(path)?;
+        match format.parse_with_strictness(&contents, strict) {
+            Ok(config) => return Ok(config),
+            Err(err) if retries < CONFIG_LOAD_RETRIES => {
+                log::debug!(
+                    "Failed to parse configuration file at '{}', retrying: {}",
+                    path,
+                    err
+                );
+                retries += 1;
+                std::thread::sleep(CONFIG_LOAD_RETRY_DELAY);
+            }
+            Err(err) => {
+                return Err(Error::msg(format!(
+                    "failed to parse configuration file '{}': {}",
+                    path, err
+                )))
+            }
+        }
+    }
 }
 
 /// Generates a configuration.
 pub fn generate_config(flavor: ConfigFlavor, prime_bits: usize) -> Result<Configuration> {
+    generate_config_with_max_prime_trials(flavor, prime_bits, None)
+}
+
+/// Generates a new configuration with the specified flavor, giving up on the prime search after
+/// the given maximum number of candidate trials, if any. The progress of the prime search is
+/// logged periodically, so that generating large primes does not appear hung.
+pub fn generate_config_with_max_prime_trials(
+    flavor: ConfigFlavor,
+    prime_bits: usize,
+    max_prime_trials: Option<usize>,
+) -> Result<Configuration> {
+    let options = GenerateOptions {
+        max_prime_trials,
+        ..Default::default()
+    };
+    generate_config_with_options(flavor, prime_bits, options)
+}
+
+/// Generates a new configuration with the specified flavor and options. For discrete logarithms,
+/// the parameter style selects between a safe prime and a DSA-style prime with a separately sized
+/// q.
+/// # Errors
+/// * Returns an error if DSA-style parameters are requested without q bits, or q bits are given
+///   for safe prime parameters.
+/// * Returns an error if the prime search fails.
+pub fn generate_config_with_options(
+    flavor: ConfigFlavor,
+    prime_bits: usize,
+    options: GenerateOptions,
+) -> Result<Configuration> {
     let config: Configuration = match flavor {
         ConfigFlavor::DiscreteLogarithm => {
-            let config = DiscreteLogarithmConfiguration::generate(prime_bits);
+            let search = PrimeSearchOptions {
+                max_trials: options.max_prime_trials,
+                ..Default::default()
+            };
+            let config = match (options.modp_group, options.param_style, options.q_bits) {
+                (Some(group), ParamStyle::SafePrime, None) => {
+                    log::info!("Using the RFC 3526 {} group.", group);
+                    DiscreteLogarithmConfiguration::from_modp_group(group)
+                }
+                (Some(_), _, _) => {
+                    return Err(Error::msg(
+                        "a MODP group cannot be combined with DSA-style parameters or q bits",
+                    ))
+                }
+                (None, ParamStyle::SafePrime, None) => {
+                    DiscreteLogarithmConfiguration::generate_with_options(
+                        prime_bits,
+                        search,
+                        |p| {
+                            log::info!(
+                                "Searching for a {}-bit safe prime, {} candidates tried so far.",
+                                p.bits,
+                                p.trials
+                            )
+                        },
+                    )?
+                }
+                (None, ParamStyle::SafePrime, Some(_)) => {
+                    return Err(Error::msg(
+                        "q bits can only be specified for DSA-style parameters",
+                    ))
+                }
+                (None, ParamStyle::Dsa, Some(q_bits)) => {
+                    DiscreteLogarithmConfiguration::generate_dsa_with_options(
+                        prime_bits,
+                        q_bits,
+                        search,
+                        |p| {
+                            log::info!(
+                                "Searching for a {}-bit DSA prime, {} candidates tried so far.",
+                                p.bits,
+                                p.trials
+                            )
+                        },
+                    )?
+                }
+                (None, ParamStyle::Dsa, None) => {
+                    return Err(Error::msg("DSA-style parameters require q bits"))
+                }
+            };
             config.into()
         }
-        ConfigFlavor::EllipticCurve => {
-            let config = EllipticCurveConfiguration::generate();
-            config.into()
+        ConfigFlavor::EllipticCurve if options.modp_group.is_some() => {
+            return Err(Error::msg(
+                "a MODP group can only be used for discrete logarithm configurations",
+            ))
+        }
+        ConfigFlavor::EllipticCurve => match options.curve {
+            ConfigCurve::Ristretto255 => EllipticCurveConfiguration::generate().into(),
+            ConfigCurve::Secp256k1 => {
+                EllipticCurveConfiguration::<Secp256k1>::generate_on_curve().into()
+            }
+        },
+        ConfigFlavor::Both => {
+            return Err(Error::msg(
+                "a configuration has a single flavor, so it cannot be generated for both",
+            ))
+        }
+        #[cfg(feature = "null-verifier")]
+        ConfigFlavor::Null => {
+            return Err(Error::msg(
+                "the null flavor has no configuration of its own, so it cannot be generated",
+            ))
         }
     };
     Ok(config)
 }
 
-/// Writes a configuration to a file.
+/// Writes a configuration to a file, in the format given by its extension.
 pub fn write_config_to_file(config: Configuration, path: &str) -> Result<()> {
-    serde_json::to_writer_pretty(File::create(path)?, &config)?;
+    write_config_to_file_with_format(config, path, ConfigFormat::from_path(path))
+}
+
+/// Writes a configuration to a file in the given format, atomically replacing any existing file.
+pub fn write_config_to_file_with_format(
+    config: Configuration,
+    path: &str,
+    format: ConfigFormat,
+) -> Result<()> {
+    write_file_atomically(path, format.format(&config)?.as_bytes())
+}
+
+/// Writes the contents to a temporary file next to the given path and renames it into place, so
+/// that concurrent readers see either the previous file or the complete new one, never a partially
+/// written file.
+fn write_file_atomically(path: &str, contents: &[u8]) -> Result<()> {
+    let tmp_path = format!("{}.{}.tmp", path, Uuid::new_v4());
+    let result = std::fs::write(&tmp_path, contents).and_then(|_| std::fs::rename(&tmp_path, path));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp_path);
+    }
+    Ok(result?)
+}
+
+/// The outcome of generating a configuration file under the generation lock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenerateOutcome {
+    /// The configuration was generated and written by this process.
+    Generated,
+
+    /// Another process wrote the configuration file while this one waited for the lock, so it was
+    /// reused instead of generating again.
+    Reused,
+}
+
+/// Generates a configuration with the given generator and writes it to the given path, holding an
+/// advisory lock on `<path>.lock` so that concurrent processes generate one at a time. The path is
+/// checked again once the lock is held: if the file exists by then, or with `overwrite`, if its
+/// contents changed while waiting, another process wrote it and it is reused instead. The lock
+/// file is left in place, since removing it would race with other waiters.
+/// # Errors
+/// * Returns an error if the lock file cannot be opened or locked.
+/// * Returns an error if generating or writing the configuration fails.
+pub fn generate_config_file_locked(
+    path: &str,
+    format: ConfigFormat,
+    overwrite: bool,
+    generate: impl FnOnce() -> Result<Configuration>,
+) -> Result<GenerateOutcome> {
+    // When overwriting, the file may already exist, so compare its contents instead.
+    let before = if overwrite {
+        std::fs::read(path).ok()
+    } else {
+        None
+    };
+
+    let lock = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(format!("{}.lock", path))?;
+    if lock.try_lock_exclusive().is_err() {
+        log::info!(
+            "Waiting for another process to finish generating the configuration at '{}'.",
+            path
+        );
+        lock.lock_exclusive()?;
+    }
+
+    let written_by_another = if overwrite {
+        std::fs::read(path).ok() != before
+    } else {
+        Path::new(path).exists()
+    };
+    if written_by_another {
+        return Ok(GenerateOutcome::Reused);
+    }
+    write_config_to_file_with_format(generate()?, path, format)?;
+    Ok(GenerateOutcome::Generated)
+}
+
+/// Validates the public parameters of a configuration. For discrete logarithms, this delegates to
+/// `DiscreteLogarithmConfiguration::validate`. For elliptic curves, this checks that g and h are
+/// distinct valid points.
+pub fn validate_config(config: &Configuration) -> Result<()> {
+    match &config.flavor {
+        Some(Flavor::DiscreteLogarithm(config)) => {
+            let config: DiscreteLogarithmConfiguration = config
+                .clone()
+                .try_into()
+                .map_err(|_| Error::msg("invalid discrete logarithm parameters"))?;
+            config.validate()?;
+        }
+        Some(Flavor::EllipticCurve(config)) if config.curve_name() == Secp256k1::NAME => {
+            validate_elliptic_curve_config::<Secp256k1>(config)?
+        }
+        Some(Flavor::EllipticCurve(config)) => validate_elliptic_curve_config::<Ristretto>(config)?,
+        None => return Err(Error::msg(NO_FLAVOR_MESSAGE)),
+    }
     Ok(())
 }
 
+/// Validates the points of an elliptic curve configuration on the given curve.
+fn validate_elliptic_curve_config<C: Curve>(config: &configuration::EllipticCurve) -> Result<()> {
+    let config: EllipticCurveConfiguration<C> = config
+        .clone()
+        .try_into()
+        .map_err(|_| Error::msg("invalid elliptic curve points"))?;
+    if config.g == config.h {
+        return Err(Error::msg("g and h must be distinct"));
+    }
+    Ok(())
+}
+
+/// Returns the path of the validation cache sidecar file for a configuration file.
+pub fn validation_cache_path(config_path: &str) -> String {
+    format!("{}.validated", config_path)
+}
+
+/// Validates a configuration loaded from the given path, unless its validation cache sidecar
+/// records a successful validation of the same parameters. The cache is keyed by the config
+/// version, so any parameter change triggers revalidation, as does `revalidate`. Returns whether
+/// validation ran. The cache is best effort: if it cannot be written, such as in a read-only
+/// config directory, a warning is logged and the configuration is validated again next time.
+pub fn validate_config_cached(
+    config: &Configuration,
+    config_path: &str,
+    revalidate: bool,
+) -> Result<bool> {
+    let cache_path = validation_cache_path(config_path);
+    let version = config.config_version();
+    if !revalidate {
+        if let Ok(cached) = std::fs::read_to_string(&cache_path) {
+            if cached.trim() == version {
+                return Ok(false);
+            }
+        }
+    }
+
+    validate_config(config)?;
+    if let Err(e) = std::fs::write(&cache_path, version) {
+        log::warn!(
+            "Failed to write the validation cache at '{}': {}",
+            cache_path,
+            e
+        );
+    }
+    Ok(true)
+}
+
+/// Values derived from a configuration's public parameters, written alongside a generated
+/// configuration to aid review.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigMeta {
+    /// The configuration flavor.
+    pub flavor: ConfigFlavor,
+
+    /// The version of the configuration.
+    pub config_version: String,
+
+    /// The size of p in bits, for discrete logarithms.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub p_bits: Option<u64>,
+
+    /// The size of q in bits, for discrete logarithms.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q_bits: Option<u64>,
+
+    /// Whether p is a safe prime, i.e. both p and (p - 1) / 2 are prime, for discrete logarithms.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub safe_prime: Option<bool>,
+}
+
+/// Returns the values derived from a configuration's public parameters.
+/// # Errors
+/// * Returns an error if the configuration is unknown or its parameters cannot be parsed.
+pub fn config_meta(config: &Configuration) -> Result<ConfigMeta> {
+    let summary = config_summary(config)?;
+    let mut meta = ConfigMeta {
+        flavor: summary.flavor,
+        config_version: summary.config_version,
+        p_bits: None,
+        q_bits: None,
+        safe_prime: None,
+    };
+    if let Some(Flavor::DiscreteLogarithm(params)) = &config.flavor {
+        let p: BigInt = params.p.parse()?;
+        let q: BigInt = params.q.parse()?;
+        let sophie_germain = (&p - BigInt::one()) / 2;
+        meta.p_bits = Some(p.bits());
+        meta.q_bits = Some(q.bits());
+        meta.safe_prime = Some(
+            is_probable_prime(&p, PRIMALITY_ROUNDS)
+                && is_probable_prime(&sophie_germain, PRIMALITY_ROUNDS),
+        );
+    }
+    Ok(meta)
+}
+
+/// Returns the path of the metadata sidecar file for a configuration file.
+pub fn config_meta_path(config_path: &str) -> String {
+    format!("{}.meta.json", config_path)
+}
+
+/// Writes the values derived from a configuration to the metadata sidecar file of the
+/// configuration file at the given path.
+pub fn write_config_meta_to_file(config: &Configuration, config_path: &str) -> Result<()> {
+    let meta = config_meta(config)?;
+    serde_json::to_writer_pretty(File::create(config_meta_path(config_path))?, &meta)?;
+    Ok(())
+}
+
+/// Loads an Ed25519 signing key from a file containing its hex-encoded 32-byte secret.
+pub fn load_signing_key_from_file(path: &str) -> Result<SigningKey> {
+    let contents = std::fs::read_to_string(path)?;
+    let bytes: [u8; 32] = hex::decode(contents.trim())?
+        .try_into()
+        .map_err(|_| anyhow::Error::msg("signing key must be 32 bytes"))?;
+    Ok(SigningKey::from_bytes(&bytes))
+}
+
+/// Writes a parameter bundle for a configuration to a file, signed if a key is given.
+pub fn write_bundle_to_file(
+    config: Configuration,
+    signing_key: Option<&SigningKey>,
+    path: &str,
+) -> Result<()> {
+    let mut bundle = ParameterBundle::new(config);
+    if let Some(key) = signing_key {
+        bundle = bundle.sign(key);
+    }
+    serde_json::to_writer_pretty(File::create(path)?, &bundle)?;
+    Ok(())
+}
+
+/// The fully resolved public parameters of a configuration, for audits.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ParameterReport {
+    /// The configuration flavor.
+    pub flavor: ConfigFlavor,
+
+    /// The version of the configuration.
+    pub config_version: String,
+
+    /// The public parameters of the flavor.
+    #[serde(flatten)]
+    pub parameters: ReportedParameters,
+}
+
+/// The public parameters of a configuration flavor, with their derived sizes.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ReportedParameters {
+    DiscreteLogarithm {
+        p: String,
+        p_bits: u64,
+        q: String,
+        q_bits: u64,
+        g: String,
+        h: String,
+    },
+    EllipticCurve {
+        curve: String,
+        g: String,
+        h: String,
+    },
+}
+
+/// Returns a report of the public parameters of a configuration.
+/// # Errors
+/// * Returns an error if the configuration is unknown or its parameters cannot be parsed.
+pub fn parameter_report(config: &Configuration) -> Result<ParameterReport> {
+    let (flavor, parameters) = match &config.flavor {
+        Some(Flavor::DiscreteLogarithm(params)) => {
+            let p: BigInt = params.p.parse()?;
+            let q: BigInt = params.q.parse()?;
+            (
+                ConfigFlavor::DiscreteLogarithm,
+                ReportedParameters::DiscreteLogarithm {
+                    p: params.p.clone(),
+                    p_bits: p.bits(),
+                    q: params.q.clone(),
+                    q_bits: q.bits(),
+                    g: params.g.clone(),
+                    h: params.h.clone(),
+                },
+            )
+        }
+        Some(Flavor::EllipticCurve(params)) => (
+            ConfigFlavor::EllipticCurve,
+            ReportedParameters::EllipticCurve {
+                curve: params.curve_name().to_string(),
+                g: params.g.clone(),
+                h: params.h.clone(),
+            },
+        ),
+        None => return Err(Error::msg("unknown configuration")),
+    };
+    Ok(ParameterReport {
+        flavor,
+        config_version: config.config_version(),
+        parameters,
+    })
+}
+
+/// A summary of a configuration, identifying it without its public parameters.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigSummary {
+    /// The configuration flavor.
+    pub flavor: ConfigFlavor,
+
+    /// The version of the configuration.
+    pub config_version: String,
+}
+
+/// Returns a summary of a configuration.
+/// # Errors
+/// * Returns an error if the configuration is unknown.
+pub fn config_summary(config: &Configuration) -> Result<ConfigSummary> {
+    let flavor = match &config.flavor {
+        Some(Flavor::DiscreteLogarithm(_)) => ConfigFlavor::DiscreteLogarithm,
+        Some(Flavor::EllipticCurve(_)) => ConfigFlavor::EllipticCurve,
+        None => return Err(Error::msg("unknown configuration")),
+    };
+    Ok(ConfigSummary {
+        flavor,
+        config_version: config.config_version(),
+    })
+}
+
 #[cfg(test)]
-mod config {
+mod test {
     use super::*;
     use anyhow::Result;
     use tempdir::TempDir;
-    use zkauth_protobuf::v1::configuration::Flavor;
+    use zkauth::discrete_logarithm::arithmetic::modpow;
 
     #[test]
     fn generate_discrete_logarithm_flavor() -> Result<()> {
@@ -69,6 +735,38 @@ mod config {
         Ok(())
     }
 
+    #[test]
+    fn generate_secp256k1_elliptic_curve_flavor() -> Result<()> {
+        let options = GenerateOptions {
+            curve: ConfigCurve::Secp256k1,
+            ..Default::default()
+        };
+        let config = generate_config_with_options(ConfigFlavor::EllipticCurve, 0, options)?;
+        let Some(Flavor::EllipticCurve(params)) = &config.flavor else {
+            panic!("expected an elliptic curve configuration");
+        };
+        assert_eq!(params.curve, "secp256k1");
+        validate_config(&config)?;
+
+        let report = serde_json::to_value(parameter_report(&config)?)?;
+        assert_eq!(report["curve"], "secp256k1");
+
+        // The curve is kept when written to and parsed from each format.
+        for format in [ConfigFormat::Json, ConfigFormat::Yaml, ConfigFormat::Toml] {
+            let contents = format.format(&config)?;
+            assert_eq!(format.parse_with_strictness(&contents, true)?, config);
+        }
+
+        // The same points are not valid ristretto points.
+        let mut config = config.clone();
+        if let Some(Flavor::EllipticCurve(ref mut params)) = config.flavor {
+            params.curve = String::new();
+        }
+        assert!(validate_config(&config).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn write_to_and_load_from_file() -> Result<()> {
         let tmp_dir = TempDir::new("zkauth-test")?;
@@ -84,4 +782,629 @@ mod config {
         tmp_dir.close()?;
         Ok(())
     }
+
+    #[test]
+    fn writes_versioned_format_and_loads_legacy_format() -> Result<()> {
+        let config = generate_config(ConfigFlavor::DiscreteLogarithm, 8)?;
+
+        let value: serde_json::Value = serde_json::from_str(&ConfigFormat::Json.format(&config)?)?;
+        assert_eq!(value["version"], 1);
+        assert!(value["discrete_logarithm"]["p"].is_string());
+
+        let legacy = serde_json::to_string(&config)?;
+        assert!(legacy.contains("discreteLogarithm"));
+        assert_eq!(
+            ConfigFormat::Json.parse_with_strictness(&legacy, true)?,
+            config
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_from_path() -> Result<()> {
+        assert_eq!(ConfigFormat::from_path("config.json"), ConfigFormat::Json);
+        assert_eq!(ConfigFormat::from_path("config.yaml"), ConfigFormat::Yaml);
+        assert_eq!(ConfigFormat::from_path("config.yml"), ConfigFormat::Yaml);
+        assert_eq!(ConfigFormat::from_path("config.toml"), ConfigFormat::Toml);
+        assert_eq!(ConfigFormat::from_path("config"), ConfigFormat::Json);
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_each_format() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+
+        for flavor in [ConfigFlavor::DiscreteLogarithm, ConfigFlavor::EllipticCurve] {
+            let config = generate_config(flavor, 8)?;
+            for format in [ConfigFormat::Json, ConfigFormat::Yaml, ConfigFormat::Toml] {
+                let config_path = tmp_dir.path().join(format!("config.{}", format));
+                let config_path = config_path.to_str().unwrap();
+
+                write_config_to_file(config.clone(), config_path)?;
+                let loaded_config = load_config_from_file(config_path)?;
+                assert_eq!(config, loaded_config);
+            }
+        }
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_with_explicit_format() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.conf");
+        let config_path = config_path.to_str().unwrap();
+
+        let config = generate_config(ConfigFlavor::DiscreteLogarithm, 8)?;
+        write_config_to_file_with_format(config.clone(), config_path, ConfigFormat::Toml)?;
+        let loaded_config = load_config_from_file_with_format(config_path, ConfigFormat::Toml)?;
+        assert_eq!(config, loaded_config);
+        assert!(load_config_from_file(config_path).is_err());
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn write_bundle_to_file_signed() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let key_path = tmp_dir.path().join("key.hex");
+        let key_path = key_path.to_str().unwrap();
+        let bundle_path = tmp_dir.path().join("bundle.json");
+        let bundle_path = bundle_path.to_str().unwrap();
+
+        std::fs::write(key_path, hex::encode([7u8; 32]))?;
+        let key = load_signing_key_from_file(key_path)?;
+
+        let config = generate_config(ConfigFlavor::DiscreteLogarithm, 8)?;
+        write_bundle_to_file(config.clone(), Some(&key), bundle_path)?;
+        let bundle: ParameterBundle = serde_json::from_reader(File::open(bundle_path)?)?;
+
+        assert_eq!(
+            bundle.configuration,
+            Configuration {
+                protocol_version: zkauth::PROTOCOL_VERSION,
+                ..config
+            }
+        );
+        assert!(bundle.verify_signature(&key.verifying_key()).is_ok());
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn validate_generated_configs() -> Result<()> {
+        validate_config(&generate_config(ConfigFlavor::DiscreteLogarithm, 16)?)?;
+        validate_config(&generate_config(ConfigFlavor::EllipticCurve, 0)?)?;
+        Ok(())
+    }
+
+    #[test]
+    fn validate_rejects_invalid_parameters() -> Result<()> {
+        let mut config = generate_config(ConfigFlavor::DiscreteLogarithm, 16)?;
+        if let Some(Flavor::DiscreteLogarithm(ref mut params)) = config.flavor {
+            params.g = "1".to_string();
+        }
+        assert!(validate_config(&config).is_err());
+
+        let mut config = generate_config(ConfigFlavor::DiscreteLogarithm, 16)?;
+        if let Some(Flavor::DiscreteLogarithm(ref mut params)) = config.flavor {
+            params.p = "21".to_string();
+        }
+        assert!(validate_config(&config).is_err());
+
+        let mut config = generate_config(ConfigFlavor::EllipticCurve, 0)?;
+        if let Some(Flavor::EllipticCurve(ref mut params)) = config.flavor {
+            params.h = params.g.clone();
+        }
+        assert!(validate_config(&config).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_config_cached_skips_second_load() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_path = config_path.to_str().unwrap();
+
+        let config = generate_config(ConfigFlavor::DiscreteLogarithm, 16)?;
+        write_config_to_file(config.clone(), config_path)?;
+
+        // The first load validates and records the result.
+        assert!(validate_config_cached(&config, config_path, false)?);
+        assert!(Path::new(&validation_cache_path(config_path)).exists());
+
+        // The second load skips validation.
+        assert!(!validate_config_cached(&config, config_path, false)?);
+
+        // Revalidation can be forced.
+        assert!(validate_config_cached(&config, config_path, true)?);
+
+        // Changed parameters are revalidated.
+        let other = generate_config(ConfigFlavor::DiscreteLogarithm, 16)?;
+        assert!(validate_config_cached(&other, config_path, false)?);
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn validate_config_cached_succeeds_when_cache_is_unwritable() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_path = config_path.to_str().unwrap();
+
+        let config = generate_config(ConfigFlavor::DiscreteLogarithm, 16)?;
+        write_config_to_file(config.clone(), config_path)?;
+
+        // A directory in place of the sidecar cannot be written, even with elevated permissions.
+        std::fs::create_dir(validation_cache_path(config_path))?;
+
+        // Validation passes, and runs again on every load since nothing is cached.
+        assert!(validate_config_cached(&config, config_path, false)?);
+        assert!(validate_config_cached(&config, config_path, false)?);
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn validate_config_cached_does_not_cache_failures() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_path = config_path.to_str().unwrap();
+
+        let mut config = generate_config(ConfigFlavor::DiscreteLogarithm, 16)?;
+        if let Some(Flavor::DiscreteLogarithm(ref mut params)) = config.flavor {
+            params.h = params.g.clone();
+        }
+        assert!(validate_config_cached(&config, config_path, false).is_err());
+        assert!(validate_config_cached(&config, config_path, false).is_err());
+        assert!(!Path::new(&validation_cache_path(config_path)).exists());
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_fields_rejected_only_when_strict() -> Result<()> {
+        let config = generate_config(ConfigFlavor::DiscreteLogarithm, 8)?;
+        let mut value = serde_json::to_value(&config)?;
+        value["discreteLogarithm"]["gg"] = "4".into();
+        value["typo"] = 1.into();
+
+        for format in [ConfigFormat::Json, ConfigFormat::Yaml, ConfigFormat::Toml] {
+            let contents = match format {
+                ConfigFormat::Json => serde_json::to_string(&value)?,
+                ConfigFormat::Yaml => serde_yaml::to_string(&value)?,
+                ConfigFormat::Toml => toml::to_string(&value)?,
+            };
+
+            let err = format.parse_with_strictness(&contents, true).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "unknown fields in configuration: discreteLogarithm.gg, typo",
+                "{}",
+                format
+            );
+
+            assert_eq!(format.parse_with_strictness(&contents, false)?, config);
+            assert_eq!(format.parse(&contents)?, config);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn strict_parse_accepts_known_field_names() -> Result<()> {
+        let config = generate_config(ConfigFlavor::DiscreteLogarithm, 8)?;
+        let contents =
+            serde_json::to_string(&config)?.replace("discreteLogarithm", "discrete_logarithm");
+        assert_eq!(
+            ConfigFormat::Json.parse_with_strictness(&contents, true)?,
+            config
+        );
+
+        let config = generate_config(ConfigFlavor::EllipticCurve, 0)?;
+        let contents = serde_json::to_string(&config)?;
+        assert_eq!(
+            ConfigFormat::Json.parse_with_strictness(&contents, true)?,
+            config
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parameter_report_discrete_logarithm() -> Result<()> {
+        // The options search guarantees the exact number of bits, where generate may fall short.
+        let config: Configuration = DiscreteLogarithmConfiguration::generate_with_options(
+            16,
+            PrimeSearchOptions::default(),
+            |_| {},
+        )?
+        .into();
+        let report = serde_json::to_value(parameter_report(&config)?)?;
+
+        let Some(Flavor::DiscreteLogarithm(params)) = &config.flavor else {
+            unreachable!();
+        };
+        assert_eq!(report["flavor"], "discrete-logarithm");
+        assert_eq!(report["config_version"], config.config_version());
+        assert_eq!(report["p"], params.p);
+        assert_eq!(report["q"], params.q);
+        assert_eq!(report["g"], params.g);
+        assert_eq!(report["h"], params.h);
+        assert_eq!(report["p_bits"], 16);
+        assert_eq!(report["q_bits"], 15);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parameter_report_elliptic_curve() -> Result<()> {
+        let config = generate_config(ConfigFlavor::EllipticCurve, 0)?;
+        let report = serde_json::to_value(parameter_report(&config)?)?;
+
+        let Some(Flavor::EllipticCurve(params)) = &config.flavor else {
+            unreachable!();
+        };
+        assert_eq!(report["flavor"], "elliptic-curve");
+        assert_eq!(report["config_version"], config.config_version());
+        assert_eq!(report["curve"], "ristretto255");
+        assert_eq!(report["g"], params.g);
+        assert_eq!(report["h"], params.h);
+
+        Ok(())
+    }
+
+    #[test]
+    fn generate_config_file_locked_generates_when_unlocked() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let path = tmp_dir.path().join("config.json");
+        let path = path.to_str().unwrap();
+
+        let config = generate_config(ConfigFlavor::DiscreteLogarithm, 8)?;
+        let outcome =
+            generate_config_file_locked(path, ConfigFormat::Json, false, || Ok(config.clone()))?;
+
+        assert_eq!(outcome, GenerateOutcome::Generated);
+        assert_eq!(load_config_from_file(path)?, config);
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn generate_config_file_locked_reuses_file_written_while_waiting() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let path = tmp_dir.path().join("config.json");
+        let path = path.to_str().unwrap().to_string();
+
+        // Hold the lock as another process generating the configuration would.
+        let lock = File::create(format!("{}.lock", path))?;
+        lock.lock_exclusive()?;
+
+        let waiter = {
+            let path = path.clone();
+            std::thread::spawn(move || {
+                generate_config_file_locked(&path, ConfigFormat::Json, false, || {
+                    Err(Error::msg("should not generate"))
+                })
+            })
+        };
+
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        let config = generate_config(ConfigFlavor::DiscreteLogarithm, 8)?;
+        write_config_to_file(config.clone(), &path)?;
+        FileExt::unlock(&lock)?;
+
+        assert_eq!(waiter.join().unwrap()?, GenerateOutcome::Reused);
+        assert_eq!(load_config_from_file(&path)?, config);
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn generate_config_file_locked_reuses_file_written_before_locking() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let path = tmp_dir.path().join("config.json");
+        let path = path.to_str().unwrap();
+
+        // Written by another process after the caller checked that the file did not exist.
+        let config = generate_config(ConfigFlavor::DiscreteLogarithm, 8)?;
+        write_config_to_file(config.clone(), path)?;
+
+        let outcome = generate_config_file_locked(path, ConfigFormat::Json, false, || {
+            Err(Error::msg("should not generate"))
+        })?;
+
+        assert_eq!(outcome, GenerateOutcome::Reused);
+        assert_eq!(load_config_from_file(path)?, config);
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn generate_config_file_locked_overwrites_unchanged_file() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let path = tmp_dir.path().join("config.json");
+        let path = path.to_str().unwrap();
+
+        write_config_to_file(generate_config(ConfigFlavor::DiscreteLogarithm, 8)?, path)?;
+
+        let config = generate_config(ConfigFlavor::EllipticCurve, 0)?;
+        let outcome =
+            generate_config_file_locked(path, ConfigFormat::Json, true, || Ok(config.clone()))?;
+
+        assert_eq!(outcome, GenerateOutcome::Generated);
+        assert_eq!(load_config_from_file(path)?, config);
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn config_meta_discrete_logarithm() -> Result<()> {
+        let config = generate_config(ConfigFlavor::DiscreteLogarithm, 16)?;
+        let meta = config_meta(&config)?;
+
+        assert_eq!(meta.flavor, ConfigFlavor::DiscreteLogarithm);
+        assert_eq!(meta.config_version, config.config_version());
+        assert_eq!(meta.p_bits, Some(16));
+        assert_eq!(meta.q_bits, Some(15));
+        assert_eq!(meta.safe_prime, Some(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn config_meta_elliptic_curve_omits_prime_fields() -> Result<()> {
+        let config = generate_config(ConfigFlavor::EllipticCurve, 0)?;
+        let meta = serde_json::to_value(config_meta(&config)?)?;
+
+        assert_eq!(
+            meta,
+            serde_json::json!({
+                "flavor": "elliptic-curve",
+                "config_version": config.config_version(),
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn config_meta_reports_unsafe_prime() -> Result<()> {
+        // 23 is a safe prime, since 11 is prime, but 29 is not, since 14 is not.
+        for (p, safe) in [("23", true), ("29", false)] {
+            let config = Configuration {
+                flavor: Some(Flavor::DiscreteLogarithm(
+                    zkauth_protobuf::v1::configuration::DiscreteLogarithm {
+                        p: p.to_string(),
+                        q: "11".to_string(),
+                        g: "4".to_string(),
+                        h: "9".to_string(),
+                    },
+                )),
+                protocol_version: 0,
+            };
+            assert_eq!(config_meta(&config)?.safe_prime, Some(safe), "{}", p);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn config_summary_omits_parameters() -> Result<()> {
+        let config: Configuration = DiscreteLogarithmConfiguration::generate(16).into();
+        let summary = serde_json::to_value(config_summary(&config)?)?;
+
+        assert_eq!(
+            summary,
+            serde_json::json!({
+                "flavor": "discrete-logarithm",
+                "config_version": config.config_version(),
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parameter_report_fails_with_unknown_configuration() {
+        assert!(parameter_report(&Configuration {
+            flavor: None,
+            protocol_version: 0,
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn generate_fails_after_max_prime_trials() -> Result<()> {
+        let result =
+            generate_config_with_max_prime_trials(ConfigFlavor::DiscreteLogarithm, 2048, Some(1));
+        assert!(result.is_err());
+
+        // The trial limit does not apply to elliptic curves.
+        generate_config_with_max_prime_trials(ConfigFlavor::EllipticCurve, 2048, Some(1))?;
+        Ok(())
+    }
+
+    #[test]
+    fn generate_each_param_style() -> Result<()> {
+        for (param_style, q_bits, expected_q_bits) in [
+            (ParamStyle::SafePrime, None, 63),
+            (ParamStyle::Dsa, Some(32), 32),
+        ] {
+            let options = GenerateOptions {
+                param_style,
+                q_bits,
+                ..Default::default()
+            };
+            let config =
+                generate_config_with_options(ConfigFlavor::DiscreteLogarithm, 64, options)?;
+            validate_config(&config)?;
+
+            let Some(Flavor::DiscreteLogarithm(params)) = config.flavor else {
+                panic!("expected a discrete logarithm configuration");
+            };
+            let config: DiscreteLogarithmConfiguration = params
+                .try_into()
+                .map_err(|_| Error::msg("invalid discrete logarithm parameters"))?;
+            assert_eq!(config.p.bits(), 64);
+            assert_eq!(config.q.bits(), expected_q_bits);
+            for generator in [&config.g, &config.h] {
+                // Since q is prime, an element other than 1 with g^q = 1 has order exactly q.
+                assert_ne!(*generator, BigInt::one());
+                assert_eq!(modpow(generator, &config.q, &config.p), BigInt::one());
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn generate_with_modp_group() -> Result<()> {
+        let options = GenerateOptions {
+            modp_group: Some(ModpGroup::Modp2048),
+            ..Default::default()
+        };
+        // The prime bits are ignored for a MODP group.
+        let config = generate_config_with_options(ConfigFlavor::DiscreteLogarithm, 64, options)?;
+        validate_config(&config)?;
+
+        let Some(Flavor::DiscreteLogarithm(params)) = config.flavor else {
+            panic!("expected a discrete logarithm configuration");
+        };
+        let config: DiscreteLogarithmConfiguration = params
+            .try_into()
+            .map_err(|_| Error::msg("invalid discrete logarithm parameters"))?;
+        assert_eq!(config.p, ModpGroup::Modp2048.prime());
+        assert_eq!(config.g, BigInt::from(2));
+        Ok(())
+    }
+
+    #[test]
+    fn generate_rejects_modp_group_with_other_parameters() {
+        let modp_group = Some(ModpGroup::Modp2048);
+        for (flavor, param_style, q_bits) in [
+            (ConfigFlavor::DiscreteLogarithm, ParamStyle::Dsa, Some(32)),
+            (
+                ConfigFlavor::DiscreteLogarithm,
+                ParamStyle::SafePrime,
+                Some(32),
+            ),
+            (ConfigFlavor::EllipticCurve, ParamStyle::SafePrime, None),
+        ] {
+            let options = GenerateOptions {
+                param_style,
+                q_bits,
+                modp_group,
+                ..Default::default()
+            };
+            assert!(generate_config_with_options(flavor, 64, options).is_err());
+        }
+    }
+
+    #[test]
+    fn generate_rejects_mismatched_q_bits() {
+        for (param_style, q_bits) in [(ParamStyle::SafePrime, Some(32)), (ParamStyle::Dsa, None)] {
+            let options = GenerateOptions {
+                param_style,
+                q_bits,
+                ..Default::default()
+            };
+            assert!(
+                generate_config_with_options(ConfigFlavor::DiscreteLogarithm, 64, options).is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn validate_rejects_q_not_dividing_p_minus_one() -> Result<()> {
+        let mut config = generate_config(ConfigFlavor::DiscreteLogarithm, 16)?;
+        if let Some(Flavor::DiscreteLogarithm(ref mut params)) = config.flavor {
+            // 65537 is prime, but cannot divide p - 1 for a 16-bit p.
+            params.q = "65537".to_string();
+        }
+        assert_eq!(
+            validate_config(&config).unwrap_err().to_string(),
+            "q is not a prime factor of p - 1"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn write_is_atomic_for_concurrent_loads() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_path = config_path.to_str().unwrap().to_string();
+
+        let configs = [
+            generate_config(ConfigFlavor::DiscreteLogarithm, 16)?,
+            generate_config(ConfigFlavor::EllipticCurve, 0)?,
+        ];
+        write_config_to_file(configs[0].clone(), &config_path)?;
+
+        // Loads never observe a partially written file while another thread keeps rewriting it.
+        std::thread::scope(|scope| -> Result<()> {
+            let writer = scope.spawn(|| -> Result<()> {
+                for i in 0..200 {
+                    write_config_to_file(configs[i % 2].clone(), &config_path)?;
+                }
+                Ok(())
+            });
+            for _ in 0..200 {
+                let config = ConfigFormat::Json.parse(&std::fs::read_to_string(&config_path)?)?;
+                assert!(configs.contains(&config));
+            }
+            writer.join().unwrap()
+        })?;
+
+        // No temporary files are left behind.
+        assert_eq!(std::fs::read_dir(tmp_dir.path())?.count(), 1);
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn validate_fails_without_flavor() {
+        let err = validate_config(&Configuration {
+            flavor: None,
+            protocol_version: 0,
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), NO_FLAVOR_MESSAGE);
+    }
+
+    #[test]
+    fn load_corrupt_file_returns_clear_error() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_path = config_path.to_str().unwrap();
+
+        std::fs::write(
+            config_path,
+            "{\"version\": 1, \"discrete_logarithm\": {\"p\": ",
+        )?;
+        let err = load_config_from_file(config_path).unwrap_err();
+        assert!(
+            err.to_string().starts_with(&format!(
+                "failed to parse configuration file '{}': ",
+                config_path
+            )),
+            "{}",
+            err
+        );
+
+        tmp_dir.close()?;
+        Ok(())
+    }
 }
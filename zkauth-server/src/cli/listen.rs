@@ -0,0 +1,298 @@
+use anyhow::{Error, Result};
+use std::fmt;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::sync::watch;
+use tokio::task::JoinSet;
+use tonic::transport::{Server, ServerTlsConfig};
+
+use crate::audit::MetadataInterceptor;
+use crate::gateway;
+use crate::metrics::{self, Metrics};
+use crate::router::FlavorRouter;
+use crate::service::Service;
+
+/// The prefix of a Unix domain socket listen address.
+const UNIX_PREFIX: &str = "unix:";
+
+/// An address the server listens on.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ListenAddress {
+    /// A TCP socket address, such as 127.0.0.1:50001 or [::1]:50001.
+    Tcp(String),
+
+    /// A Unix domain socket path, given as unix:/path/to/socket.
+    Unix(String),
+}
+
+/// Parses a listen address, which is a Unix domain socket path if prefixed with unix:, or a TCP
+/// socket address otherwise.
+impl FromStr for ListenAddress {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.strip_prefix(UNIX_PREFIX) {
+            Some("") => Err(Error::msg("Unix domain socket path is empty")),
+            Some(path) => Ok(ListenAddress::Unix(path.to_string())),
+            None if s.is_empty() => Err(Error::msg("listen address is empty")),
+            None => Ok(ListenAddress::Tcp(s.to_string())),
+        }
+    }
+}
+
+/// Displays the listen address in the same form it is parsed from.
+impl fmt::Display for ListenAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListenAddress::Tcp(addr) => write!(f, "{}", addr),
+            ListenAddress::Unix(path) => write!(f, "{}{}", UNIX_PREFIX, path),
+        }
+    }
+}
+
+/// Binds the address and spawns a task serving the server on it until shutdown is signalled, and
+/// returns the bound address, with any ephemeral port resolved. Servers spawned from clones of
+/// the same server share its service. The server is served over TLS if a TLS configuration is
+/// given.
+pub(crate) async fn spawn_listener(
+    address: &ListenAddress,
+    server: FlavorRouter,
+    tls_config: Option<&ServerTlsConfig>,
+    shutdown: watch::Receiver<bool>,
+    servers: &mut JoinSet<Result<()>>,
+) -> Result<ListenAddress> {
+    let shutdown = wait_for_shutdown(shutdown);
+    let mut builder = Server::builder();
+    if let Some(tls_config) = tls_config {
+        builder = builder.tls_config(tls_config.clone())?;
+    }
+
+    match address {
+        ListenAddress::Tcp(addr) => {
+            let listener = TcpListener::bind(addr).await?;
+            let local_addr = listener.local_addr()?;
+            log::info!("✅ Server listening on {}", local_addr);
+            let serve = builder
+                .add_routes(server.into_routes())
+                .serve_with_incoming_shutdown(
+                    tokio_stream::wrappers::TcpListenerStream::new(listener),
+                    shutdown,
+                );
+            servers.spawn(async move { Ok(serve.await?) });
+            Ok(ListenAddress::Tcp(local_addr.to_string()))
+        }
+        #[cfg(unix)]
+        ListenAddress::Unix(path) => {
+            remove_stale_socket(path)?;
+            let listener = tokio::net::UnixListener::bind(path)?;
+            log::info!("✅ Server listening on {}", address);
+            let serve = builder
+                .add_routes(server.into_routes())
+                .serve_with_incoming_shutdown(
+                    tokio_stream::wrappers::UnixListenerStream::new(listener),
+                    shutdown,
+                );
+            let path = path.clone();
+            servers.spawn(async move {
+                let result = serve.await;
+                if let Err(e) = std::fs::remove_file(&path) {
+                    log::warn!("Failed to remove socket at '{}': {}", path, e);
+                }
+                Ok(result?)
+            });
+            Ok(address.clone())
+        }
+        #[cfg(not(unix))]
+        ListenAddress::Unix(_) => Err(Error::msg(
+            "Unix domain sockets are not supported on this platform",
+        )),
+    }
+}
+
+/// Removes the socket left at the path by a previous run, so it can be bound again. Fails if the
+/// path exists but is not a socket, rather than removing some other file.
+#[cfg(unix)]
+fn remove_stale_socket(path: &str) -> Result<()> {
+    use std::os::unix::fs::FileTypeExt;
+
+    match std::fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_socket() => Ok(std::fs::remove_file(path)?),
+        Ok(_) => Err(Error::msg(format!(
+            "cannot listen on '{}', which exists and is not a socket",
+            path
+        ))),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Binds the TCP socket address and spawns a task serving the JSON over HTTP/1.1 gateway to the
+/// service on it, passing requests through the interceptor, until shutdown is signalled, and
+/// returns the bound address.
+pub(crate) async fn spawn_http_json_listener(
+    address: &str,
+    service: Arc<Service>,
+    interceptor: MetadataInterceptor,
+    shutdown: watch::Receiver<bool>,
+    servers: &mut JoinSet<Result<()>>,
+) -> Result<SocketAddr> {
+    let listener = TcpListener::bind(address).await?;
+    let local_addr = listener.local_addr()?;
+    log::info!("✅ JSON over HTTP gateway listening on {}", local_addr);
+    let listener = listener.into_std()?;
+    servers.spawn(async move {
+        Ok(gateway::serve(listener, service, interceptor, wait_for_shutdown(shutdown)).await?)
+    });
+    Ok(local_addr)
+}
+
+/// Binds the TCP socket address and spawns a task serving the Prometheus metrics endpoint on it
+/// until shutdown is signalled, and returns the bound address.
+pub(crate) async fn spawn_metrics_listener(
+    address: &str,
+    metrics: Arc<Metrics>,
+    shutdown: watch::Receiver<bool>,
+    servers: &mut JoinSet<Result<()>>,
+) -> Result<SocketAddr> {
+    let listener = TcpListener::bind(address).await?;
+    let local_addr = listener.local_addr()?;
+    log::info!("✅ Metrics listening on http://{}/metrics", local_addr);
+    let listener = listener.into_std()?;
+    servers.spawn(async move {
+        Ok(metrics::serve(listener, metrics, wait_for_shutdown(shutdown)).await?)
+    });
+    Ok(local_addr)
+}
+
+/// Waits until shutdown is signalled.
+async fn wait_for_shutdown(mut shutdown: watch::Receiver<bool>) {
+    let _ = shutdown.wait_for(|shutdown| *shutdown).await;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_tcp_addresses() -> Result<()> {
+        for addr in ["127.0.0.1:50001", "[::1]:50001", "localhost:0"] {
+            let address: ListenAddress = addr.parse()?;
+            assert_eq!(address, ListenAddress::Tcp(addr.to_string()));
+            assert_eq!(address.to_string(), addr);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parses_unix_addresses() -> Result<()> {
+        let address: ListenAddress = "unix:/tmp/zkauth.sock".parse()?;
+        assert_eq!(address, ListenAddress::Unix("/tmp/zkauth.sock".to_string()));
+        assert_eq!(address.to_string(), "unix:/tmp/zkauth.sock");
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_empty_addresses() {
+        assert!("".parse::<ListenAddress>().is_err());
+        assert!("unix:".parse::<ListenAddress>().is_err());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn serves_on_unix_socket_until_shutdown() -> Result<()> {
+        use std::os::unix::fs::FileTypeExt;
+
+        use crate::service::Service;
+        use zkauth::discrete_logarithm::{
+            configuration::DiscreteLogarithmConfiguration, verifier::DiscreteLogarithmVerifier,
+        };
+        use zkauth_protobuf::v1::auth_server::AuthServer;
+
+        let tmp_dir = tempdir::TempDir::new("zkauth-test")?;
+        let path = tmp_dir.path().join("zkauth.sock");
+        let address = ListenAddress::Unix(path.to_str().unwrap().to_string());
+
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+        let server = FlavorRouter::new(
+            AuthServer::new(Service::new(config.into(), verifier)),
+            MetadataInterceptor::default(),
+        );
+
+        // Start, stop, and restart on the same path.
+        for _ in 0..2 {
+            let (shutdown_sender, shutdown_receiver) = watch::channel(false);
+            let mut servers = JoinSet::new();
+            let bound = spawn_listener(
+                &address,
+                server.clone(),
+                None,
+                shutdown_receiver,
+                &mut servers,
+            )
+            .await?;
+            assert_eq!(bound, address);
+            assert!(std::fs::symlink_metadata(&path)?.file_type().is_socket());
+
+            shutdown_sender.send(true)?;
+            servers.join_next().await.unwrap()??;
+            assert!(!path.exists());
+        }
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn replaces_stale_unix_socket() -> Result<()> {
+        use crate::service::Service;
+        use zkauth::discrete_logarithm::{
+            configuration::DiscreteLogarithmConfiguration, verifier::DiscreteLogarithmVerifier,
+        };
+        use zkauth_protobuf::v1::auth_server::AuthServer;
+
+        let tmp_dir = tempdir::TempDir::new("zkauth-test")?;
+        let path = tmp_dir.path().join("zkauth.sock");
+        let address = ListenAddress::Unix(path.to_str().unwrap().to_string());
+
+        // A socket left behind by a run that did not clean up, such as one that was killed.
+        drop(std::os::unix::net::UnixListener::bind(&path)?);
+        assert!(path.exists());
+
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+        let server = FlavorRouter::new(
+            AuthServer::new(Service::new(config.into(), verifier)),
+            MetadataInterceptor::default(),
+        );
+        let (shutdown_sender, shutdown_receiver) = watch::channel(false);
+        let mut servers = JoinSet::new();
+        spawn_listener(&address, server, None, shutdown_receiver, &mut servers).await?;
+
+        shutdown_sender.send(true)?;
+        servers.join_next().await.unwrap()??;
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn does_not_remove_other_files_at_unix_socket_path() -> Result<()> {
+        let tmp_dir = tempdir::TempDir::new("zkauth-test")?;
+        let path = tmp_dir.path().join("zkauth.sock");
+        std::fs::write(&path, "not a socket")?;
+
+        let err = remove_stale_socket(path.to_str().unwrap()).unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("which exists and is not a socket"));
+        assert_eq!(std::fs::read_to_string(&path)?, "not a socket");
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 pub mod config;
+pub mod listen;
 pub mod options;
+pub mod store;
 
 mod run;
 
-pub use options::Options;
-pub use run::run;
+pub use options::{Command, Options, ReplayOptions};
+pub use run::{run, spawn, ServerHandle, StartupReport};
@@ -1,8 +1,18 @@
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
 use clap_verbosity_flag::{InfoLevel, Verbosity};
 use env_logger::Env;
+use zkauth::discrete_logarithm::configuration::ModpGroup;
 
-use super::config::ConfigFlavor;
+use super::config::{ConfigCurve, ConfigFlavor, ConfigFormat, ParamStyle};
+use super::listen::ListenAddress;
+use super::store::StoreKind;
+use crate::service::{
+    ChallengeLimitPolicy, DEFAULT_MAX_DECODING_MESSAGE_SIZE, DEFAULT_MAX_FIELD_LENGTH,
+};
+use crate::session::SessionKeyStrategy;
+use crate::store::memory::DEFAULT_CHALLENGES_TTL;
+use crate::token::DEFAULT_TOKEN_TTL_SECONDS;
+use crate::username::UsernamePolicy;
 
 /// Command line options for the server.
 #[derive(Parser, Debug)]
@@ -11,6 +21,9 @@ pub struct Options {
     #[command(flatten)]
     pub verbose: Verbosity<InfoLevel>,
 
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Specifies the IP address or name of the host to which the server is bound.
     #[arg(long, default_value = "127.0.0.1")]
     pub host: String,
@@ -19,6 +32,50 @@ pub struct Options {
     #[arg(short, long, env("PORT"), default_value_t = 0)]
     pub port: u16,
 
+    /// Specifies an address to listen on, either a TCP socket address such as [::1]:50001 or a
+    /// Unix domain socket path such as unix:/run/zkauth.sock. May be specified multiple times to
+    /// listen on several addresses at once. If not specified, --host and --port are used. A stale
+    /// socket at the path is replaced, and the socket is removed on shutdown.
+    #[arg(long)]
+    pub listen: Vec<ListenAddress>,
+
+    /// Specifies the file path of the PEM certificate chain the server presents to serve gRPC over
+    /// TLS. Requires --tls-key.
+    #[arg(long, requires = "tls_key")]
+    pub tls_cert: Option<String>,
+
+    /// Specifies the file path of the PEM private key of the --tls-cert certificate.
+    #[arg(long, requires = "tls_cert")]
+    pub tls_key: Option<String>,
+
+    /// Specifies the file path of a PEM CA certificate that clients must present a certificate
+    /// signed by over mutual TLS. Connections without one are rejected. Requires --tls-cert.
+    #[arg(long, requires = "tls_cert")]
+    pub tls_client_ca: Option<String>,
+
+    /// Specifies whether clients may only register the user named by the common name of their
+    /// client certificate. Requires --tls-client-ca.
+    #[arg(long, default_value_t = false, requires = "tls_client_ca")]
+    pub tls_client_scoped_registration: bool,
+
+    /// Specifies the maximum size in bytes of a decoded request message.
+    /// Compressed requests are not accepted.
+    #[arg(long, default_value_t = DEFAULT_MAX_DECODING_MESSAGE_SIZE)]
+    pub max_decoding_message_size: usize,
+
+    /// Specifies the maximum length in bytes of a request field, checked before it is parsed.
+    #[arg(long, default_value_t = DEFAULT_MAX_FIELD_LENGTH)]
+    pub max_field_length: usize,
+
+    /// Specifies how sessions are keyed: one per user, one per user and proof so only verifying
+    /// the same proof again returns the same session, or a new one for every verification.
+    #[arg(long, default_value_t = SessionKeyStrategy::UserProof, value_enum)]
+    pub session_key_strategy: SessionKeyStrategy,
+
+    /// Specifies the store backend for users, challenges, and sessions.
+    #[arg(long, default_value_t = StoreKind::Memory, value_enum)]
+    pub store: StoreKind,
+
     /// Specifies the configuration file path.
     /// If not specified, a non-persistent configuration will be generated and used.
     #[arg(long, env("CONFIG_PATH"))]
@@ -35,10 +92,36 @@ pub struct Options {
     #[arg(long, default_value_t = false)]
     pub config_overwrite: bool,
 
-    /// Specifies the configuration flavor to use.
+    /// Specifies whether to write values derived from the public parameters, such as the size of
+    /// p, whether it is a safe prime, and the configuration version, to a `.meta.json` file
+    /// alongside a generated configuration file.
+    #[arg(long, default_value_t = false)]
+    pub config_meta: bool,
+
+    /// Specifies the configuration file format.
+    /// If not specified, it is chosen by the file extension, defaulting to JSON.
+    #[arg(long, value_enum)]
+    pub config_format: Option<ConfigFormat>,
+
+    /// Specifies whether to reject unknown fields in the configuration file, such as a misspelled
+    /// key, instead of ignoring them with a warning.
+    #[arg(long, default_value_t = false)]
+    pub config_strict: bool,
+
+    /// Specifies the configuration flavor to use. With both, the discrete logarithm service is
+    /// served by default and under /discrete-logarithm, and the elliptic curve service under
+    /// /elliptic-curve, which clients select by connecting to an address with that path. With null,
+    /// only available in builds with the null-verifier feature, any proof is accepted, for load
+    /// testing.
     #[arg(long, default_value_t = ConfigFlavor::DiscreteLogarithm, value_enum)]
     pub config_flavor: ConfigFlavor,
 
+    /// Specifies the path of the elliptic curve configuration file when serving both flavors, in
+    /// which case --config-path is the discrete logarithm configuration file. If not specified, a
+    /// non-persistent elliptic curve configuration is generated.
+    #[arg(long)]
+    pub elliptic_curve_config_path: Option<String>,
+
     /// Specifies the number of bits to use for generating prime numbers for the public parameters.
     #[arg(long, default_value_t = 64)]
     pub config_prime_bits: usize,
@@ -46,6 +129,211 @@ pub struct Options {
     /// Specifies a prime number to use for generating the configuration.
     #[arg(long)]
     pub config_prime: Option<String>,
+
+    /// Specifies the maximum number of candidates to try when searching for a prime number, after
+    /// which generation fails rather than appearing hung. Unlimited if not specified.
+    #[arg(long)]
+    pub config_prime_max_trials: Option<usize>,
+
+    /// Specifies how q is derived for discrete logarithm parameters: as (p - 1) / 2 of a safe prime,
+    /// or DSA-style as a smaller prime factor of p - 1 sized by --config-q-bits.
+    #[arg(long, default_value_t = ParamStyle::SafePrime, value_enum)]
+    pub param_style: ParamStyle,
+
+    /// Specifies the number of bits of q for DSA-style parameters.
+    #[arg(long)]
+    pub config_q_bits: Option<usize>,
+
+    /// Specifies a well-known RFC 3526 MODP group to use for discrete logarithm parameters instead
+    /// of generating a prime: modp-2048, modp-3072, or modp-4096.
+    #[arg(long)]
+    pub config_modp_group: Option<ModpGroup>,
+
+    /// Specifies the curve to use for generating elliptic curve parameters: ristretto255, or
+    /// secp256k1 for interop with Bitcoin tooling.
+    #[arg(long, default_value_t = ConfigCurve::Ristretto255, value_enum)]
+    pub config_curve: ConfigCurve,
+
+    /// Specifies the minimum approximate security level in bits of the public parameters, below
+    /// which the server refuses to start unless --allow-weak-parameters is specified.
+    #[arg(long)]
+    pub min_security_level: Option<u32>,
+
+    /// Specifies whether to start the server even if the public parameters are below
+    /// --min-security-level, logging a warning instead.
+    #[arg(long, default_value_t = false)]
+    pub allow_weak_parameters: bool,
+
+    /// Specifies whether to revalidate the public parameters of a loaded configuration file, even
+    /// if its validation cache records that they were already validated.
+    #[arg(long, default_value_t = false)]
+    pub revalidate_params: bool,
+
+    /// Specifies whether to print a report of the resolved public parameters, loaded or generated
+    /// exactly as when running the server, and exit without binding a socket.
+    #[arg(long, default_value_t = false)]
+    pub dump_parameters: bool,
+
+    /// Specifies whether to run internal register, challenge, and verify cycles against the
+    /// resolved public parameters, print their timing percentiles, and exit without binding a
+    /// socket, to estimate the CPU cost of each authentication.
+    #[arg(long, default_value_t = false)]
+    pub profile: bool,
+
+    /// Specifies the number of cycles to time when profiling.
+    #[arg(long, default_value_t = 20)]
+    pub profile_iterations: usize,
+
+    /// Specifies whether to print the full configuration to stdout at startup, rather than only a
+    /// summary of its flavor and version.
+    #[arg(long, default_value_t = false)]
+    pub print_full_config: bool,
+
+    /// Specifies a file path to export the configuration to as a parameter bundle.
+    /// If specified, this will exit after writing the bundle, and not run the server.
+    #[arg(long)]
+    pub config_export_bundle: Option<String>,
+
+    /// Specifies a file containing a hex-encoded Ed25519 secret key to sign the exported parameter
+    /// bundle with.
+    #[arg(long)]
+    pub config_bundle_signing_key: Option<String>,
+
+    /// Specifies a file containing a hex-encoded Ed25519 secret key to sign each authentication
+    /// challenge with, so clients can verify they are talking to this server.
+    #[arg(long)]
+    pub server_signing_key: Option<String>,
+
+    /// Specifies a secret to MAC each authentication challenge with, so that a challenge altered
+    /// in transit is detected when the client echoes it back.
+    #[arg(long, env("CHALLENGE_MAC_SECRET"), hide_env_values = true)]
+    pub challenge_mac_secret: Option<String>,
+
+    /// Specifies the secret to sign session tokens with. If specified, each successful
+    /// verification also returns a short-lived HS256 JWT of the session in its token field.
+    #[arg(long, env("JWT_SIGNING_KEY"), hide_env_values = true)]
+    pub jwt_signing_key: Option<String>,
+
+    /// Specifies the number of seconds a session token is valid for.
+    #[arg(long, default_value_t = DEFAULT_TOKEN_TTL_SECONDS)]
+    pub jwt_ttl_seconds: u64,
+
+    /// Specifies whether to require y1, y2, r1, and r2 to fall within the full range of the group,
+    /// rejecting trivial values such as 1.
+    #[arg(long, default_value_t = false)]
+    pub require_full_range_commitments: bool,
+
+    /// Specifies whether to reject challenges whose r1 or r2 is the group identity, which signals
+    /// a broken client using k = 0. Such challenges are otherwise only logged as a warning.
+    #[arg(long, default_value_t = false)]
+    pub reject_trivial_commitments: bool,
+
+    /// Specifies the maximum random delay in milliseconds added before returning an error from
+    /// verify_authentication, to mitigate timing oracles. Disabled when 0.
+    #[arg(long, default_value_t = 0)]
+    pub auth_error_jitter_ms: u64,
+
+    /// Specifies the number of seconds a challenge is kept in the store after it is issued, after
+    /// which answering it fails with not found. Must be positive.
+    #[arg(long, default_value_t = DEFAULT_CHALLENGES_TTL.as_secs())]
+    pub challenge_ttl_seconds: u64,
+
+    /// Specifies the maximum number of seconds between issuing a challenge and verifying its
+    /// answer, after which verification fails with deadline exceeded. Unlimited if not specified,
+    /// although challenges still expire from the store.
+    #[arg(long)]
+    pub challenge_max_age_seconds: Option<u64>,
+
+    /// Specifies the maximum number of pending challenges per user, so a single user cannot fill
+    /// the store with challenges before they expire. Unlimited if not specified.
+    #[arg(long)]
+    pub max_challenges_per_user: Option<usize>,
+
+    /// Specifies what to do with a new challenge for a user at the maximum number of pending
+    /// challenges: evict the user's oldest pending challenge, or reject the new one.
+    #[arg(long, default_value_t = ChallengeLimitPolicy::EvictOldest, value_enum)]
+    pub challenge_limit_policy: ChallengeLimitPolicy,
+
+    /// Specifies the number of seconds between sweeps that purge expired challenges and sessions
+    /// from the store, including those never accessed again. If not specified, expired entries are
+    /// only removed as the store gets to them.
+    #[arg(long)]
+    pub cleanup_interval_seconds: Option<u64>,
+
+    /// Specifies whether to issue a throwaway challenge for unknown users instead of returning
+    /// not found, so valid usernames cannot be enumerated.
+    #[arg(long, default_value_t = false)]
+    pub allow_anonymous_challenge: bool,
+
+    /// Specifies whether to trim and NFC normalize usernames before they are stored or looked up,
+    /// so that whitespace and Unicode composition variants map to the same user.
+    #[arg(long, default_value_t = false)]
+    pub normalize_usernames: bool,
+
+    /// Specifies whether to fold the case of usernames before they are stored or looked up, so
+    /// that usernames differing only in case map to the same user.
+    #[arg(long, default_value_t = false)]
+    pub fold_username_case: bool,
+
+    /// Specifies a file path to record each register, challenge, and verify request to as JSON
+    /// lines, along with its outcome, so it can be reproduced with the replay subcommand. Replay
+    /// runs against a single flavor, so it cannot be used with --config-flavor=both.
+    #[arg(long)]
+    pub record_requests: Option<String>,
+
+    /// Specifies a request metadata key, such as x-request-id, to include in the audit entry of
+    /// each verify request. May be specified multiple times.
+    #[arg(long)]
+    pub audit_metadata_key: Vec<String>,
+
+    /// Specifies the number of threads used for parallel computations, such as batch registration
+    /// and parameter validation. Defaults to the number of CPUs when 0.
+    #[arg(long, default_value_t = 0)]
+    pub compute_threads: usize,
+
+    /// Specifies whether reloading the configuration file on SIGHUP may change the public
+    /// parameters, which invalidates every existing registration. Otherwise such a reload is
+    /// refused and the current configuration is kept.
+    #[arg(long, default_value_t = false)]
+    pub force_config_reload: bool,
+
+    /// Specifies whether to serve a JSON over HTTP/1.1 gateway to register, challenge, and verify
+    /// alongside the gRPC server, sharing its store and verifier. The gateway is served in plain
+    /// text, so it conflicts with --tls-client-ca, which it would otherwise bypass. It serves a
+    /// single flavor, so it cannot be used with --config-flavor=both.
+    #[arg(long, default_value_t = false, conflicts_with = "tls_client_ca")]
+    pub enable_http_json: bool,
+
+    /// Specifies the TCP socket address the JSON over HTTP/1.1 gateway listens on, when enabled
+    /// with --enable-http-json.
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    pub http_json_listen: String,
+
+    /// Specifies the port to serve Prometheus metrics on at /metrics, on the same host as the gRPC
+    /// server. Metrics are not collected if not specified.
+    #[arg(long)]
+    pub metrics_port: Option<u16>,
+
+    /// Specifies whether to install the tokio console layer for runtime introspection.
+    /// Requires the server to be built with the `console-subscriber` feature and
+    /// `RUSTFLAGS="--cfg tokio_unstable"`.
+    #[arg(long, default_value_t = false)]
+    pub enable_tokio_console: bool,
+}
+
+/// The subcommands of the server.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Replays requests recorded with --record-requests against a freshly constructed service
+    /// with the loaded configuration, and reports any whose outcome differs.
+    Replay(ReplayOptions),
+}
+
+/// Command line options for the replay subcommand.
+#[derive(Args, Debug)]
+pub struct ReplayOptions {
+    /// Specifies the file path of the recorded requests.
+    pub path: String,
 }
 
 /// Implementation of the options.
@@ -61,10 +349,69 @@ impl Options {
                 env_logger::Builder::from_env(Env::default().default_filter_or("info")).try_init();
         }
     }
+
+    /// Returns the addresses to listen on, which are the --listen addresses if any, or --host and
+    /// --port otherwise.
+    pub fn listen_addresses(&self) -> Vec<ListenAddress> {
+        if !self.listen.is_empty() {
+            return self.listen.clone();
+        }
+        vec![ListenAddress::Tcp(format!("{}:{}", self.host, self.port))]
+    }
+
+    /// Returns the policy that usernames are normalized with.
+    pub fn username_policy(&self) -> UsernamePolicy {
+        UsernamePolicy {
+            trim: self.normalize_usernames,
+            nfc: self.normalize_usernames,
+            case_fold: self.fold_username_case,
+        }
+    }
+
+    /// Sizes the global rayon thread pool used for parallel computations, if specified.
+    pub fn init_compute_threads(&self) {
+        if self.compute_threads == 0 {
+            return;
+        }
+
+        match rayon::ThreadPoolBuilder::new()
+            .num_threads(self.compute_threads)
+            .build_global()
+        {
+            Ok(()) => log::info!("Using {} compute threads.", self.compute_threads),
+            Err(e) => log::warn!("Failed to initialize compute threads: {}", e),
+        }
+    }
+
+    /// Installs the tokio console layer if enabled.
+    pub fn init_tokio_console(&self) {
+        if !self.enable_tokio_console {
+            return;
+        }
+
+        #[cfg(feature = "console-subscriber")]
+        {
+            use tracing_subscriber::layer::SubscriberExt;
+            use tracing_subscriber::util::SubscriberInitExt;
+
+            match tracing_subscriber::registry()
+                .with(console_subscriber::spawn())
+                .try_init()
+            {
+                Ok(()) => log::info!("Tokio console layer installed."),
+                Err(e) => log::warn!("Failed to install tokio console layer: {}", e),
+            }
+        }
+
+        #[cfg(not(feature = "console-subscriber"))]
+        log::warn!(
+            "--enable-tokio-console requires the server to be built with the console-subscriber feature."
+        );
+    }
 }
 
 #[cfg(test)]
-mod options {
+mod test {
     use super::*;
     use anyhow::Result;
 
@@ -74,6 +421,285 @@ mod options {
         assert_eq!(opts.port, 0);
         assert_eq!(opts.host, "127.0.0.1");
         assert_eq!(opts.config_prime_bits, 64);
+        assert_eq!(
+            opts.max_decoding_message_size,
+            DEFAULT_MAX_DECODING_MESSAGE_SIZE
+        );
+        assert_eq!(opts.max_field_length, DEFAULT_MAX_FIELD_LENGTH);
+        assert_eq!(opts.auth_error_jitter_ms, 0);
+        assert_eq!(opts.compute_threads, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn revalidate_params() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert!(!opts.revalidate_params);
+        let opts = Options::parse_from(vec!["bin", "--revalidate-params"]);
+        assert!(opts.revalidate_params);
+        Ok(())
+    }
+
+    #[test]
+    fn param_style() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert_eq!(opts.param_style, ParamStyle::SafePrime);
+        assert_eq!(opts.config_q_bits, None);
+        let opts = Options::parse_from(vec!["bin", "--param-style=dsa", "--config-q-bits=256"]);
+        assert_eq!(opts.param_style, ParamStyle::Dsa);
+        assert_eq!(opts.config_q_bits, Some(256));
+        Ok(())
+    }
+
+    #[test]
+    fn config_modp_group() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert_eq!(opts.config_modp_group, None);
+        let opts = Options::parse_from(vec!["bin", "--config-modp-group=modp-3072"]);
+        assert_eq!(opts.config_modp_group, Some(ModpGroup::Modp3072));
+        assert!(Options::try_parse_from(vec!["bin", "--config-modp-group=modp-1024"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn config_curve() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert_eq!(opts.config_curve, ConfigCurve::Ristretto255);
+        let opts = Options::parse_from(vec!["bin", "--config-curve=secp256k1"]);
+        assert_eq!(opts.config_curve, ConfigCurve::Secp256k1);
+        assert!(Options::try_parse_from(vec!["bin", "--config-curve=p256"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn config_prime_max_trials() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert_eq!(opts.config_prime_max_trials, None);
+        let opts = Options::parse_from(vec!["bin", "--config-prime-max-trials=1000"]);
+        assert_eq!(opts.config_prime_max_trials, Some(1000));
+        Ok(())
+    }
+
+    #[test]
+    fn compute_threads() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--compute-threads=4"]);
+        assert_eq!(opts.compute_threads, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn record_requests() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--record-requests=requests.jsonl"]);
+        assert_eq!(opts.record_requests, Some("requests.jsonl".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn audit_metadata_key() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert!(opts.audit_metadata_key.is_empty());
+        let opts = Options::parse_from(vec![
+            "bin",
+            "--audit-metadata-key=x-request-id",
+            "--audit-metadata-key=x-tenant",
+        ]);
+        assert_eq!(opts.audit_metadata_key, vec!["x-request-id", "x-tenant"]);
+        Ok(())
+    }
+
+    #[test]
+    fn replay() -> Result<()> {
+        let opts = Options::parse_from(vec![
+            "bin",
+            "--config-path=config.json",
+            "replay",
+            "requests.jsonl",
+        ]);
+        let Some(Command::Replay(replay_opts)) = opts.command else {
+            panic!("expected replay subcommand");
+        };
+        assert_eq!(replay_opts.path, "requests.jsonl");
+        Ok(())
+    }
+
+    #[test]
+    fn cleanup_interval_seconds() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--cleanup-interval-seconds=60"]);
+        assert_eq!(opts.cleanup_interval_seconds, Some(60));
+        assert_eq!(
+            Options::parse_from(vec!["bin"]).cleanup_interval_seconds,
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn challenge_ttl_seconds() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--challenge-ttl-seconds=60"]);
+        assert_eq!(opts.challenge_ttl_seconds, 60);
+        assert_eq!(
+            Options::parse_from(vec!["bin"]).challenge_ttl_seconds,
+            DEFAULT_CHALLENGES_TTL.as_secs()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn challenge_max_age_seconds() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--challenge-max-age-seconds=30"]);
+        assert_eq!(opts.challenge_max_age_seconds, Some(30));
+        assert_eq!(
+            Options::parse_from(vec!["bin"]).challenge_max_age_seconds,
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn max_challenges_per_user() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert_eq!(opts.max_challenges_per_user, None);
+        assert_eq!(
+            opts.challenge_limit_policy,
+            ChallengeLimitPolicy::EvictOldest
+        );
+
+        let opts = Options::parse_from(vec![
+            "bin",
+            "--max-challenges-per-user=5",
+            "--challenge-limit-policy=reject",
+        ]);
+        assert_eq!(opts.max_challenges_per_user, Some(5));
+        assert_eq!(opts.challenge_limit_policy, ChallengeLimitPolicy::Reject);
+        Ok(())
+    }
+
+    #[test]
+    fn force_config_reload() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert!(!opts.force_config_reload);
+        let opts = Options::parse_from(vec!["bin", "--force-config-reload"]);
+        assert!(opts.force_config_reload);
+        Ok(())
+    }
+
+    #[test]
+    fn normalize_usernames() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert!(!opts.normalize_usernames);
+        assert_eq!(opts.username_policy(), UsernamePolicy::default());
+        let opts = Options::parse_from(vec!["bin", "--normalize-usernames"]);
+        assert!(opts.normalize_usernames);
+        assert_eq!(opts.username_policy(), UsernamePolicy::normalized());
+        Ok(())
+    }
+
+    #[test]
+    fn fold_username_case() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert!(!opts.fold_username_case);
+        let opts =
+            Options::parse_from(vec!["bin", "--normalize-usernames", "--fold-username-case"]);
+        assert!(opts.fold_username_case);
+        assert_eq!(
+            opts.username_policy(),
+            UsernamePolicy::normalized().with_case_fold(true)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn allow_anonymous_challenge() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert!(!opts.allow_anonymous_challenge);
+        let opts = Options::parse_from(vec!["bin", "--allow-anonymous-challenge"]);
+        assert!(opts.allow_anonymous_challenge);
+        Ok(())
+    }
+
+    #[test]
+    fn auth_error_jitter_ms() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--auth-error-jitter-ms=50"]);
+        assert_eq!(opts.auth_error_jitter_ms, 50);
+        Ok(())
+    }
+
+    #[test]
+    fn max_decoding_message_size() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--max-decoding-message-size=1024"]);
+        assert_eq!(opts.max_decoding_message_size, 1024);
+        Ok(())
+    }
+
+    #[test]
+    fn max_field_length() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--max-field-length=128"]);
+        assert_eq!(opts.max_field_length, 128);
+        Ok(())
+    }
+
+    #[test]
+    fn listen_default() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--host=0.0.0.0", "--port=3000"]);
+        assert_eq!(opts.listen, vec![]);
+        assert_eq!(
+            opts.listen_addresses(),
+            vec![ListenAddress::Tcp("0.0.0.0:3000".to_string())]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn listen_multiple() -> Result<()> {
+        let opts = Options::parse_from(vec![
+            "bin",
+            "--listen=127.0.0.1:3000",
+            "--listen=[::1]:3000",
+            "--listen=unix:/tmp/zkauth.sock",
+        ]);
+        let expected = vec![
+            ListenAddress::Tcp("127.0.0.1:3000".to_string()),
+            ListenAddress::Tcp("[::1]:3000".to_string()),
+            ListenAddress::Unix("/tmp/zkauth.sock".to_string()),
+        ];
+        assert_eq!(opts.listen, expected);
+        assert_eq!(opts.listen_addresses(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn tls() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert_eq!(opts.tls_cert, None);
+        assert_eq!(opts.tls_key, None);
+        assert_eq!(opts.tls_client_ca, None);
+        assert!(!opts.tls_client_scoped_registration);
+        let opts = Options::parse_from(vec![
+            "bin",
+            "--tls-cert=server.pem",
+            "--tls-key=server.key",
+            "--tls-client-ca=ca.pem",
+            "--tls-client-scoped-registration",
+        ]);
+        assert_eq!(opts.tls_cert.as_deref(), Some("server.pem"));
+        assert_eq!(opts.tls_key.as_deref(), Some("server.key"));
+        assert_eq!(opts.tls_client_ca.as_deref(), Some("ca.pem"));
+        assert!(opts.tls_client_scoped_registration);
+        Ok(())
+    }
+
+    #[test]
+    fn tls_requires_cert_and_key() -> Result<()> {
+        assert!(Options::try_parse_from(vec!["bin", "--tls-cert=server.pem"]).is_err());
+        assert!(Options::try_parse_from(vec!["bin", "--tls-key=server.key"]).is_err());
+        assert!(Options::try_parse_from(vec!["bin", "--tls-client-ca=ca.pem"]).is_err());
+        assert!(Options::try_parse_from(vec![
+            "bin",
+            "--tls-cert=server.pem",
+            "--tls-key=server.key",
+            "--tls-client-scoped-registration",
+        ])
+        .is_err());
         Ok(())
     }
 
@@ -119,17 +745,42 @@ mod options {
         Ok(())
     }
 
+    #[test]
+    fn config_format() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--config-format=toml"]);
+        assert_eq!(opts.config_format, Some(ConfigFormat::Toml));
+        Ok(())
+    }
+
+    #[test]
+    fn config_strict() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--config-strict"]);
+        assert!(opts.config_strict);
+        assert!(!Options::parse_from(vec!["bin"]).config_strict);
+        Ok(())
+    }
+
     #[test]
     fn config_generate() -> Result<()> {
         let opts = Options::parse_from(vec!["bin", "--config-generate"]);
-        assert_eq!(opts.config_generate, true);
+        assert!(opts.config_generate);
         Ok(())
     }
 
     #[test]
     fn config_overwrite() -> Result<()> {
         let opts = Options::parse_from(vec!["bin", "--config-overwrite"]);
-        assert_eq!(opts.config_overwrite, true);
+        assert!(opts.config_overwrite);
+        Ok(())
+    }
+
+    #[test]
+    fn config_meta() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert!(!opts.config_meta);
+
+        let opts = Options::parse_from(vec!["bin", "--config-meta"]);
+        assert!(opts.config_meta);
         Ok(())
     }
 
@@ -147,6 +798,22 @@ mod options {
         Ok(())
     }
 
+    #[test]
+    fn config_flavor_both() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--config-flavor=both"]);
+        assert_eq!(opts.config_flavor, ConfigFlavor::Both);
+        Ok(())
+    }
+
+    #[test]
+    fn elliptic_curve_config_path() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert_eq!(opts.elliptic_curve_config_path, None);
+        let opts = Options::parse_from(vec!["bin", "--elliptic-curve-config-path=ec.json"]);
+        assert_eq!(opts.elliptic_curve_config_path, Some("ec.json".to_string()));
+        Ok(())
+    }
+
     #[test]
     fn config_flavor_default() -> Result<()> {
         let opts = Options::parse_from(vec!["bin"]);
@@ -154,6 +821,184 @@ mod options {
         Ok(())
     }
 
+    #[test]
+    fn min_security_level() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--min-security-level=112"]);
+        assert_eq!(opts.min_security_level, Some(112));
+        assert!(!opts.allow_weak_parameters);
+        assert_eq!(Options::parse_from(vec!["bin"]).min_security_level, None);
+        Ok(())
+    }
+
+    #[test]
+    fn allow_weak_parameters() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--allow-weak-parameters"]);
+        assert!(opts.allow_weak_parameters);
+        Ok(())
+    }
+
+    #[test]
+    fn dump_parameters() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--dump-parameters"]);
+        assert!(opts.dump_parameters);
+        Ok(())
+    }
+
+    #[test]
+    fn profile() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert!(!opts.profile);
+        assert_eq!(opts.profile_iterations, 20);
+        let opts = Options::parse_from(vec!["bin", "--profile", "--profile-iterations=5"]);
+        assert!(opts.profile);
+        assert_eq!(opts.profile_iterations, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn print_full_config() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert!(!opts.print_full_config);
+
+        let opts = Options::parse_from(vec!["bin", "--print-full-config"]);
+        assert!(opts.print_full_config);
+        Ok(())
+    }
+
+    #[test]
+    fn config_export_bundle() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--config-export-bundle=bundle.json"]);
+        assert_eq!(opts.config_export_bundle, Some("bundle.json".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn config_bundle_signing_key() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--config-bundle-signing-key=key.hex"]);
+        assert_eq!(opts.config_bundle_signing_key, Some("key.hex".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn session_key_strategy() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert_eq!(opts.session_key_strategy, SessionKeyStrategy::UserProof);
+
+        for (arg, strategy) in [
+            ("user", SessionKeyStrategy::User),
+            ("user-proof", SessionKeyStrategy::UserProof),
+            ("fresh", SessionKeyStrategy::Fresh),
+        ] {
+            let opts = Options::parse_from(vec!["bin", &format!("--session-key-strategy={}", arg)]);
+            assert_eq!(opts.session_key_strategy, strategy);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn store_default() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert_eq!(opts.store, StoreKind::Memory);
+        Ok(())
+    }
+
+    #[test]
+    fn server_signing_key() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--server-signing-key=server.hex"]);
+        assert_eq!(opts.server_signing_key, Some("server.hex".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn challenge_mac_secret() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--challenge-mac-secret=secret"]);
+        assert_eq!(opts.challenge_mac_secret, Some("secret".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn jwt_signing_key() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert_eq!(opts.jwt_signing_key, None);
+        assert_eq!(opts.jwt_ttl_seconds, DEFAULT_TOKEN_TTL_SECONDS);
+        let opts = Options::parse_from(vec![
+            "bin",
+            "--jwt-signing-key=secret",
+            "--jwt-ttl-seconds=60",
+        ]);
+        assert_eq!(opts.jwt_signing_key, Some("secret".to_string()));
+        assert_eq!(opts.jwt_ttl_seconds, 60);
+        Ok(())
+    }
+
+    #[test]
+    fn require_full_range_commitments() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--require-full-range-commitments"]);
+        assert!(opts.require_full_range_commitments);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_trivial_commitments() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert!(!opts.reject_trivial_commitments);
+        let opts = Options::parse_from(vec!["bin", "--reject-trivial-commitments"]);
+        assert!(opts.reject_trivial_commitments);
+        Ok(())
+    }
+
+    #[test]
+    fn enable_tokio_console() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--enable-tokio-console"]);
+        assert!(opts.enable_tokio_console);
+        Ok(())
+    }
+
+    #[test]
+    fn metrics_port() -> Result<()> {
+        assert_eq!(Options::parse_from(vec!["bin"]).metrics_port, None);
+        let opts = Options::parse_from(vec!["bin", "--metrics-port=9090"]);
+        assert_eq!(opts.metrics_port, Some(9090));
+        Ok(())
+    }
+
+    #[test]
+    fn enable_http_json() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert!(!opts.enable_http_json);
+        assert_eq!(opts.http_json_listen, "127.0.0.1:8080");
+        let opts = Options::parse_from(vec![
+            "bin",
+            "--enable-http-json",
+            "--http-json-listen=[::1]:8081",
+        ]);
+        assert!(opts.enable_http_json);
+        assert_eq!(opts.http_json_listen, "[::1]:8081");
+        let opts = Options::parse_from(vec![
+            "bin",
+            "--enable-http-json",
+            "--tls-cert=server.pem",
+            "--tls-key=server.key",
+        ]);
+        assert!(opts.enable_http_json);
+        assert!(Options::try_parse_from(vec![
+            "bin",
+            "--enable-http-json",
+            "--tls-cert=server.pem",
+            "--tls-key=server.key",
+            "--tls-client-ca=ca.pem",
+        ])
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn enable_tokio_console_default() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin"]);
+        assert!(!opts.enable_tokio_console);
+        Ok(())
+    }
+
     #[test]
     fn verbose() -> Result<()> {
         let opts = Options::parse_from(vec!["bin", "-v"]);
@@ -1,30 +1,151 @@
 use anyhow::{Error, Result};
-use futures_util::FutureExt;
+use serde::Serialize;
+use std::net::SocketAddr;
 use std::path::Path;
-use tokio::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::signal;
-use tokio::sync::oneshot;
-use tonic::transport::Server;
+use tokio::sync::{oneshot, watch};
+use tokio::task::{JoinHandle, JoinSet};
+use tonic::transport::{Certificate, Identity, ServerTlsConfig};
 use zkauth::discrete_logarithm::verifier::DiscreteLogarithmVerifier;
-use zkauth::elliptic_curve::verifier::EllipticCurveVerifier;
+use zkauth::elliptic_curve::{
+    curve::{Curve, Ristretto, Secp256k1},
+    verifier::EllipticCurveVerifier,
+};
 use zkauth::Verifier;
-use zkauth_protobuf::v1::auth_server::AuthServer;
 use zkauth_protobuf::v1::{configuration::Flavor, Configuration};
+use zkauth_protobuf::ConfigurationChange;
 
 use crate::{
+    audit::MetadataInterceptor,
     cli::{
-        config::{generate_config, load_config_from_file, write_config_to_file},
-        options::Options,
+        config::{
+            config_meta_path, config_summary, generate_config, generate_config_file_locked,
+            generate_config_with_options, load_config_from_file_with_strictness,
+            load_signing_key_from_file, parameter_report, validate_config_cached,
+            write_bundle_to_file, write_config_meta_to_file, ConfigFlavor, ConfigFormat,
+            ConfigSummary, GenerateOptions, GenerateOutcome, NO_FLAVOR_MESSAGE,
+        },
+        listen::{spawn_http_json_listener, spawn_listener, spawn_metrics_listener, ListenAddress},
+        options::{Command, Options},
+        store::build_store,
     },
+    metrics::Metrics,
+    recorder::{load_records, replay, RequestRecorder},
+    router::FlavorRouter,
     service::Service,
+    sweeper::Sweeper,
+    token::SessionTokenIssuer,
 };
 
+/// What a server reports once it is serving, for callers embedding it with `spawn`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StartupReport {
+    /// The addresses the gRPC server listens on, with any ephemeral port resolved.
+    pub listen_addresses: Vec<ListenAddress>,
+
+    /// The address the JSON over HTTP/1.1 gateway listens on, if it is enabled.
+    pub http_json_address: Option<SocketAddr>,
+
+    /// The address the Prometheus metrics endpoint listens on, if --metrics-port is set.
+    pub metrics_address: Option<SocketAddr>,
+
+    /// A summary of the served configuration.
+    pub config: ConfigSummary,
+}
+
+/// A handle to a server running in the background, started with `spawn`. Dropping the handle
+/// leaves the server running.
+#[derive(Debug)]
+pub struct ServerHandle {
+    report: StartupReport,
+    stop: watch::Sender<bool>,
+    task: JoinHandle<Result<()>>,
+}
+
+/// Implementation of the server handle.
+impl ServerHandle {
+    /// Returns what the server reported once it was serving.
+    pub fn report(&self) -> &StartupReport {
+        &self.report
+    }
+
+    /// Shuts the server down, and waits for it to finish.
+    /// # Errors
+    /// * Returns an error if the server failed while running or shutting down.
+    pub async fn shutdown(self) -> Result<()> {
+        let _ = self.stop.send(true);
+        self.task.await?
+    }
+}
+
+/// How a server started with `spawn` reports that it is serving, and is told to stop.
+struct Embedding {
+    report: oneshot::Sender<StartupReport>,
+    stop: watch::Receiver<bool>,
+}
+
 /// Runs the server CLI with the specified options.
 pub async fn run(opts: Options) -> Result<()> {
+    run_embedded(opts, None).await
+}
+
+/// Runs the server CLI with the specified options in the background, and returns a handle once
+/// it is serving, with the addresses it bound, so that it can be embedded such as in tests
+/// listening on ephemeral ports.
+/// # Errors
+/// * Returns an error if the server fails to start.
+/// * Returns an error if the options do not run a server, such as generating a configuration.
+pub async fn spawn(opts: Options) -> Result<ServerHandle> {
+    let (report_sender, report_receiver) = oneshot::channel();
+    let (stop_sender, stop) = watch::channel(false);
+    let task = tokio::spawn(run_embedded(
+        opts,
+        Some(Embedding {
+            report: report_sender,
+            stop,
+        }),
+    ));
+
+    match report_receiver.await {
+        Ok(report) => Ok(ServerHandle {
+            report,
+            stop: stop_sender,
+            task,
+        }),
+        Err(_) => {
+            task.await??;
+            Err(Error::msg("server exited without serving"))
+        }
+    }
+}
+
+/// Waits until a server started with `spawn` is told to stop. Never completes for a server that
+/// was not, or whose handle was dropped.
+async fn wait_for_stop(stop: Option<&mut watch::Receiver<bool>>) -> Option<()> {
+    stop?.wait_for(|stop| *stop).await.ok().map(|_| ())
+}
+
+/// Runs the server CLI with the specified options, reporting to and stopping on the embedding
+/// if it was started with `spawn`.
+async fn run_embedded(opts: Options, embedding: Option<Embedding>) -> Result<()> {
     opts.init_logger();
+    opts.init_tokio_console();
+    opts.init_compute_threads();
 
     // Check if a configuration file should be generated.
     let config_path = opts.config_path.clone().unwrap_or("".to_string());
+    let config_format = opts
+        .config_format
+        .unwrap_or_else(|| ConfigFormat::from_path(&config_path));
+    let generate_options = GenerateOptions {
+        max_prime_trials: opts.config_prime_max_trials,
+        param_style: opts.param_style,
+        q_bits: opts.config_q_bits,
+        modp_group: opts.config_modp_group,
+        curve: opts.config_curve,
+    };
     if opts.config_generate {
         if opts.config_path.is_none() {
             log::error!("Configuration file path is required when using --config-generate.");
@@ -42,14 +163,71 @@ pub async fn run(opts: Options) -> Result<()> {
                 log::warn!("Overwriting configuration file at '{}'.", config_path);
             }
 
-            // Generate and write a new configuration file, and exit.
-            let config = generate_config(opts.config_flavor, opts.config_prime_bits)?;
-            write_config_to_file(config, config_path.as_str())?;
-            log::info!("Configuration file generated at '{}'.", config_path);
+            // Generate and write a new configuration file, unless another process generates it
+            // first, and exit.
+            let outcome = generate_config_file_locked(
+                config_path.as_str(),
+                config_format,
+                opts.config_overwrite,
+                || {
+                    let config = generate_config_with_options(
+                        opts.config_flavor,
+                        opts.config_prime_bits,
+                        generate_options,
+                    )?;
+                    if opts.config_meta {
+                        write_config_meta_to_file(&config, config_path.as_str())?;
+                        log::info!(
+                            "Configuration metadata written to '{}'.",
+                            config_meta_path(config_path.as_str())
+                        );
+                    }
+                    Ok(config)
+                },
+            )?;
+            match outcome {
+                GenerateOutcome::Generated => {
+                    log::info!("Configuration file generated at '{}'.", config_path)
+                }
+                GenerateOutcome::Reused => log::info!(
+                    "Configuration file generated by another process at '{}', reusing it.",
+                    config_path
+                ),
+            }
             return Ok(());
         }
     }
 
+    // Replaying recorded requests requires the configuration they were recorded with.
+    if matches!(opts.command, Some(Command::Replay(_))) && opts.config_path.is_none() {
+        log::error!("Configuration file path is required when replaying requests.");
+        return Ok(());
+    }
+
+    // When serving both flavors, the main configuration is the discrete logarithm one.
+    let serve_both = opts.config_flavor == ConfigFlavor::Both;
+    if opts.elliptic_curve_config_path.is_some() && !serve_both {
+        return Err(Error::msg(
+            "--elliptic-curve-config-path requires --config-flavor=both",
+        ));
+    }
+    if opts.enable_http_json && serve_both {
+        return Err(Error::msg(
+            "--enable-http-json cannot be used with --config-flavor=both, since the gateway serves a single flavor",
+        ));
+    }
+    if opts.record_requests.is_some() && serve_both {
+        return Err(Error::msg(
+            "--record-requests cannot be used with --config-flavor=both, since recordings are replayed against a single flavor",
+        ));
+    }
+    let config_flavor = match opts.config_flavor {
+        ConfigFlavor::Both => ConfigFlavor::DiscreteLogarithm,
+        #[cfg(feature = "null-verifier")]
+        ConfigFlavor::Null => ConfigFlavor::DiscreteLogarithm,
+        flavor => flavor,
+    };
+
     // Load configuration from file if specified, or generate a non-persistent configuration.
     let config: Configuration = if opts.config_path.is_some() {
         // Load configuration from file.
@@ -58,70 +236,608 @@ pub async fn run(opts: Options) -> Result<()> {
             return Ok(());
         }
 
-        load_config_from_file(config_path.as_str())?
+        let config = load_config_from_file_with_strictness(
+            config_path.as_str(),
+            config_format,
+            opts.config_strict,
+        )?;
+
+        // Validate the public parameters, unless they were already validated.
+        let validated =
+            validate_config_cached(&config, config_path.as_str(), opts.revalidate_params).map_err(
+                |e| {
+                    Error::msg(format!(
+                        "configuration at '{}' failed validation: {}",
+                        config_path, e
+                    ))
+                },
+            )?;
+        if validated {
+            log::info!("Configuration parameters validated.");
+        } else {
+            log::info!("Configuration parameters previously validated, skipping validation.");
+        }
+        config
     } else {
         // Generate a non-persistent configuration.
         log::info!("No configuration file specified, generating non-persistent configuration.");
-        generate_config(opts.config_flavor, opts.config_prime_bits)?
+        generate_config_with_options(config_flavor, opts.config_prime_bits, generate_options)?
+    };
+    let elliptic_curve_config = if serve_both {
+        if !matches!(config.flavor, Some(Flavor::DiscreteLogarithm(_))) {
+            return Err(Error::msg(
+                "--config-path must be a discrete logarithm configuration with --config-flavor=both",
+            ));
+        }
+        Some(load_elliptic_curve_config(&opts)?)
+    } else {
+        None
     };
-    let config_json = serde_json::to_string_pretty(&config).map_err(|e| {
+
+    // Print the resolved public parameters if specified, and exit.
+    if opts.dump_parameters {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&parameter_report(&config)?)?
+        );
+        return Ok(());
+    }
+
+    // Time internal authentication cycles with the resolved parameters if specified, and exit.
+    if opts.profile {
+        let service = build_service(&opts, config)?;
+        let report = profile(&service, opts.profile_iterations)?;
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    // Replay recorded requests against a fresh service if specified, and exit.
+    if let Some(Command::Replay(replay_opts)) = &opts.command {
+        let records = load_records(&replay_opts.path)?;
+        let total = records.len();
+        let service = build_service(&opts, config)?;
+        let mismatches = replay(&service, records).await?;
+        for mismatch in &mismatches {
+            log::error!(
+                "Replayed request {} ({:?}) returned {:?}, but {:?} was recorded.",
+                mismatch.index,
+                mismatch.request,
+                mismatch.actual,
+                mismatch.expected
+            );
+        }
+        if !mismatches.is_empty() {
+            return Err(Error::msg(format!(
+                "{} of {} replayed requests did not match",
+                mismatches.len(),
+                total
+            )));
+        }
+        log::info!("All {} replayed requests matched.", total);
+        return Ok(());
+    }
+
+    // Export the configuration as a parameter bundle if specified, and exit.
+    if let Some(bundle_path) = opts.config_export_bundle.as_deref() {
+        let signing_key = match opts.config_bundle_signing_key.as_deref() {
+            Some(key_path) => Some(load_signing_key_from_file(key_path)?),
+            None => None,
+        };
+        write_bundle_to_file(config, signing_key.as_ref(), bundle_path)?;
+        log::info!("Parameter bundle exported to '{}'.", bundle_path);
+        return Ok(());
+    }
+
+    // Print the full configuration if specified, or only its summary, since the parameters of
+    // large configurations would flood the logs.
+    let config_json = if opts.print_full_config {
+        serde_json::to_string_pretty(&config)
+    } else {
+        serde_json::to_string(&config_summary(&config)?)
+    }
+    .map_err(|e| {
         log::error!("Failed to serialize configuration: {}", e);
         e
     })?;
     println!("{}", config_json);
+    if let Some(config) = &elliptic_curve_config {
+        println!("{}", serde_json::to_string(&config_summary(config)?)?);
+    }
 
-    // Create a channel to signal shutdown.
-    let (shutdown_sender, shutdown_receiver) = oneshot::channel();
-
-    // Spawn a task to listen for termination signals.
-    let signal_task = tokio::spawn(async move {
-        // Wait for SIGINT or SIGTERM signal.
-        signal::ctrl_c()
-            .await
-            .expect("Failed to listen for ctrl_c signal");
-        shutdown_sender
-            .send(())
-            .expect("Failed to send shutdown signal");
-    });
+    // Create a channel to signal shutdown to every listener.
+    let (shutdown_sender, shutdown_receiver) = watch::channel(false);
+
+    // Collect metrics, shared by the services of every flavor, if they are served.
+    let metrics = opts.metrics_port.map(|_| Arc::new(Metrics::new()));
+
+    // Initialize the service, shared by the servers on every listener.
+    let mut service = build_service(&opts, config)?;
+    if let Some(metrics) = &metrics {
+        service = service.with_metrics(metrics.clone());
+    }
+    if let Some(record_path) = opts.record_requests.as_deref() {
+        service = service.with_recorder(RequestRecorder::create(record_path)?);
+        log::info!("Recording requests to '{}'.", record_path);
+    }
+    let service = Arc::new(service);
+    let interceptor = MetadataInterceptor::new(opts.audit_metadata_key.clone());
+    let mut server = FlavorRouter::new(
+        service
+            .clone()
+            .into_shared_server(opts.max_decoding_message_size),
+        interceptor.clone(),
+    );
+
+    // Serve the elliptic curve service alongside under its own path if both flavors are served.
+    let elliptic_curve_service = match elliptic_curve_config {
+        Some(config) => {
+            let mut service = build_service(&opts, config)?;
+            if let Some(metrics) = &metrics {
+                service = service.with_metrics(metrics.clone());
+            }
+            Some(Arc::new(service))
+        }
+        None => None,
+    };
+    if let Some(elliptic_curve_service) = &elliptic_curve_service {
+        server = server
+            .with_flavor(
+                ConfigFlavor::DiscreteLogarithm,
+                service
+                    .clone()
+                    .into_shared_server(opts.max_decoding_message_size),
+                interceptor.clone(),
+            )
+            .with_flavor(
+                ConfigFlavor::EllipticCurve,
+                elliptic_curve_service
+                    .clone()
+                    .into_shared_server(opts.max_decoding_message_size),
+                interceptor.clone(),
+            );
+        log::info!(
+            "Serving both flavors, discrete logarithm by default and elliptic curve under /{}.",
+            ConfigFlavor::EllipticCurve
+        );
+    }
+    if !opts.audit_metadata_key.is_empty() {
+        log::info!(
+            "Including request metadata {} in audit entries.",
+            opts.audit_metadata_key.join(", ")
+        );
+    }
+
+    // Periodically purge expired entries from the store if specified.
+    if let Some(interval) = opts.cleanup_interval_seconds {
+        let interval = Duration::from_secs(interval);
+        if interval.is_zero() {
+            return Err(Error::msg("--cleanup-interval-seconds must be positive"));
+        }
+        let sweeper = |service: &Arc<Service>| match &metrics {
+            Some(metrics) => Sweeper::new(service.clone()).with_metrics(metrics.clone()),
+            None => Sweeper::new(service.clone()),
+        };
+        tokio::spawn(sweeper(&service).run(interval, shutdown_receiver.clone()));
+        if let Some(elliptic_curve_service) = &elliptic_curve_service {
+            tokio::spawn(sweeper(elliptic_curve_service).run(interval, shutdown_receiver.clone()));
+        }
+        log::info!(
+            "Sweeping expired challenges and sessions every {} seconds.",
+            interval.as_secs()
+        );
+    }
 
-    // Server setup.
-    let addr = format!("{}:{}", opts.host, opts.port);
-    let listener = TcpListener::bind(addr).await?;
-    log::info!("✅ Server listening on {}", listener.local_addr()?);
+    // Serve gRPC over TLS if specified, requiring client certificates if a client CA is given.
+    let tls_config = build_tls_config(&opts)?;
 
-    // Initialize the service verifier.
+    // Bind each listen address and start a server on it.
+    let mut servers = JoinSet::new();
+    let mut listen_addresses = Vec::new();
+    for address in opts.listen_addresses() {
+        let address = spawn_listener(
+            &address,
+            server.clone(),
+            tls_config.as_ref(),
+            shutdown_receiver.clone(),
+            &mut servers,
+        )
+        .await?;
+        listen_addresses.push(address);
+    }
+    let http_json_address = if opts.enable_http_json {
+        Some(
+            spawn_http_json_listener(
+                &opts.http_json_listen,
+                service.clone(),
+                interceptor,
+                shutdown_receiver.clone(),
+                &mut servers,
+            )
+            .await?,
+        )
+    } else {
+        None
+    };
+    let metrics_address = match (opts.metrics_port, metrics) {
+        (Some(port), Some(metrics)) => Some(
+            spawn_metrics_listener(
+                &format!("{}:{}", opts.host, port),
+                metrics,
+                shutdown_receiver.clone(),
+                &mut servers,
+            )
+            .await?,
+        ),
+        _ => None,
+    };
+
+    // Report the bound addresses to the caller embedding the server, if any.
+    let mut stop = match embedding {
+        Some(embedding) => {
+            let _ = embedding.report.send(StartupReport {
+                listen_addresses,
+                http_json_address,
+                metrics_address,
+                config: config_summary(&service.configuration())?,
+            });
+            Some(embedding.stop)
+        }
+        None => None,
+    };
+
+    // The configuration files reloaded on SIGHUP, along with the services they are swapped into.
+    let mut reloads = Vec::new();
+    if opts.config_path.is_some() {
+        reloads.push((service.clone(), config_path.clone(), config_format));
+    }
+    if let (Some(service), Some(path)) = (
+        &elliptic_curve_service,
+        opts.elliptic_curve_config_path.as_deref(),
+    ) {
+        reloads.push((
+            service.clone(),
+            path.to_string(),
+            ConfigFormat::from_path(path),
+        ));
+    }
+    let reloads = Arc::new(reloads);
+    let reload_lock = Arc::new(Mutex::new(()));
+    let opts = Arc::new(opts);
+
+    // Run the servers and wait for either one to stop or a shutdown signal (SIGINT), reloading the
+    // configuration file on SIGHUP.
+    let mut hangup = Hangup::new()?;
+    let result = loop {
+        tokio::select! {
+            Some(joined) = servers.join_next() => {
+                log::info!("Server has shut down.");
+                break joined?;
+            },
+            signalled = signal::ctrl_c() => {
+                signalled.map_err(|e| Error::msg(format!("Failed to listen for ctrl_c signal: {}", e)))?;
+                log::info!("Signal received, shutting down.");
+                break Ok(());
+            },
+            Some(()) = wait_for_stop(stop.as_mut()) => {
+                log::info!("Stop requested, shutting down.");
+                break Ok(());
+            },
+            Some(()) = hangup.recv() => {
+                if opts.config_path.is_none() {
+                    log::warn!("Hangup received, but there is no configuration file to reload.");
+                }
+                spawn_reload(opts.clone(), reloads.clone(), reload_lock.clone());
+            },
+        }
+    };
+
+    // Shut down the remaining servers and wait for them to finish.
+    let _ = shutdown_sender.send(true);
+    while let Some(joined) = servers.join_next().await {
+        joined??;
+    }
+
+    result
+}
+
+/// A listener for hangup signals (SIGHUP), which never arrive on platforms without them.
+struct Hangup {
+    #[cfg(unix)]
+    signal: signal::unix::Signal,
+}
+
+/// Implementation of the hangup listener.
+impl Hangup {
+    /// Starts listening for hangup signals.
+    fn new() -> Result<Self> {
+        Ok(Self {
+            #[cfg(unix)]
+            signal: signal::unix::signal(signal::unix::SignalKind::hangup())
+                .map_err(|e| Error::msg(format!("Failed to listen for hangup signal: {}", e)))?,
+        })
+    }
+
+    /// Waits for the next hangup signal.
+    async fn recv(&mut self) -> Option<()> {
+        #[cfg(unix)]
+        return self.signal.recv().await;
+        #[cfg(not(unix))]
+        std::future::pending().await
+    }
+}
+
+/// Timing percentiles of the self-test cycles run by `--profile`, in microseconds.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct ProfileReport {
+    iterations: usize,
+    p50_us: u128,
+    p90_us: u128,
+    p99_us: u128,
+    max_us: u128,
+}
+
+/// Runs and times the given number of register, challenge, and verify cycles of the service's
+/// self-test.
+/// # Errors
+/// * Returns an error if the number of iterations is zero.
+/// * Returns an error if a cycle fails.
+fn profile(service: &Service, iterations: usize) -> Result<ProfileReport> {
+    if iterations == 0 {
+        return Err(Error::msg("--profile-iterations must be positive"));
+    }
+    let mut durations = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        service.run_self_test()?;
+        durations.push(start.elapsed().as_micros());
+    }
+    durations.sort_unstable();
+
+    // The nearest-rank percentile of the sorted durations.
+    let percentile = |p: usize| durations[(p * iterations).div_ceil(100) - 1];
+    Ok(ProfileReport {
+        iterations,
+        p50_us: percentile(50),
+        p90_us: percentile(90),
+        p99_us: percentile(99),
+        max_us: durations[iterations - 1],
+    })
+}
+
+/// Logs the security level of the verifier's public parameters, and checks it against the minimum
+/// security level, if any.
+/// # Errors
+/// * Returns an error if the security level is below the minimum, unless weak parameters are
+///   allowed.
+fn check_security_level(opts: &Options, verifier: &dyn Verifier) -> Result<()> {
+    let security_level = verifier.security_level();
+    log::info!(
+        "Public parameters provide about {} bits of security.",
+        security_level
+    );
+
+    let Some(min_security_level) = opts.min_security_level else {
+        return Ok(());
+    };
+    if security_level >= min_security_level {
+        return Ok(());
+    }
+    if opts.allow_weak_parameters {
+        log::warn!(
+            "Public parameters provide about {} bits of security, below the minimum of {}; continuing since weak parameters are allowed.",
+            security_level,
+            min_security_level
+        );
+        return Ok(());
+    }
+    Err(Error::msg(format!(
+        "public parameters provide about {} bits of security, below the minimum of {}; use larger parameters or --allow-weak-parameters",
+        security_level, min_security_level
+    )))
+}
+
+/// Builds the verifier for the flavor of the given configuration, and checks its security level.
+fn build_verifier(opts: &Options, config: &Configuration) -> Result<Box<dyn Verifier>> {
     let verifier: Box<dyn Verifier> = match config.clone().flavor {
         Some(Flavor::DiscreteLogarithm(config)) => {
             Box::new(DiscreteLogarithmVerifier::new(config.try_into().map_err(
                 |_| Error::msg("Failed to convert discrete logarithm configuration"),
             )?))
         }
-        Some(Flavor::EllipticCurve(config)) => {
-            Box::new(EllipticCurveVerifier::new(config.try_into().map_err(
-                |_| Error::msg("Failed to convert elliptic curve configuration"),
-            )?))
+        Some(Flavor::EllipticCurve(config)) if config.curve_name() == Secp256k1::NAME => {
+            Box::new(EllipticCurveVerifier::<Secp256k1>::new(
+                config
+                    .try_into()
+                    .map_err(|_| Error::msg("Failed to convert elliptic curve configuration"))?,
+            ))
         }
-        None => return Err(Error::msg("unknown configuration")),
+        Some(Flavor::EllipticCurve(config)) => Box::new(EllipticCurveVerifier::<Ristretto>::new(
+            config
+                .try_into()
+                .map_err(|_| Error::msg("Failed to convert elliptic curve configuration"))?,
+        )),
+        None => return Err(Error::msg(NO_FLAVOR_MESSAGE)),
     };
+    #[cfg(feature = "null-verifier")]
+    let verifier: Box<dyn Verifier> = if opts.config_flavor == ConfigFlavor::Null {
+        log::warn!("Accepting any proof with the null verifier; only use this for load testing.");
+        Box::new(zkauth::null::NullVerifier::new(verifier))
+    } else {
+        verifier
+    };
+    check_security_level(opts, verifier.as_ref())?;
+    Ok(verifier)
+}
 
-    // Initialize service and start the server.
-    let service = Service::new(config, verifier);
-    let server = Server::builder()
-        .add_service(AuthServer::new(service))
-        .serve_with_incoming_shutdown(
-            tokio_stream::wrappers::TcpListenerStream::new(listener),
-            shutdown_receiver.map(|_| ()),
+/// Reloads the configuration file, validating it like at startup, and swaps it into the service.
+/// # Errors
+/// * Returns an error if the file cannot be loaded or its parameters are invalid.
+/// * Returns an error if the public parameters changed, unless reloads are forced.
+fn reload_config(
+    opts: &Options,
+    service: &Service,
+    config_path: &str,
+    config_format: ConfigFormat,
+) -> Result<ConfigurationChange> {
+    let config =
+        load_config_from_file_with_strictness(config_path, config_format, opts.config_strict)?;
+    validate_config_cached(&config, config_path, opts.revalidate_params)?;
+    let verifier = build_verifier(opts, &config)?;
+    service.reload(config, verifier, opts.force_config_reload)
+}
+
+/// Reloads a configuration file like `reload_config`, logging the outcome instead of returning it.
+fn reload_config_and_log(
+    opts: &Options,
+    service: &Service,
+    config_path: &str,
+    config_format: ConfigFormat,
+) {
+    match reload_config(opts, service, config_path, config_format) {
+        Ok(ConfigurationChange::Identical) => {
+            log::info!("Hangup received, configuration is unchanged.")
+        }
+        Ok(_) => log::info!(
+            "Configuration reloaded from '{}', version {}.",
+            config_path,
+            service.configuration().config_version()
+        ),
+        Err(e) => log::error!(
+            "Failed to reload configuration from '{}', keeping the current one: {}",
+            config_path,
+            e
+        ),
+    }
+}
+
+/// Reloads the configuration files on a blocking thread, since loading may retry with sleeps and
+/// validating changed parameters may run lengthy primality checks, and logs the outcomes once it
+/// finishes. The lock keeps overlapping reloads from racing, so they are applied in turn.
+fn spawn_reload(
+    opts: Arc<Options>,
+    reloads: Arc<Vec<(Arc<Service>, String, ConfigFormat)>>,
+    lock: Arc<Mutex<()>>,
+) {
+    tokio::task::spawn_blocking(move || {
+        let _guard = lock.lock().unwrap_or_else(|e| e.into_inner());
+        for (service, config_path, config_format) in reloads.iter() {
+            reload_config_and_log(&opts, service, config_path, *config_format);
+        }
+    });
+}
+
+/// Loads the elliptic curve configuration served alongside the discrete logarithm one, validating
+/// it like the main configuration, or generates a non-persistent one if no path is given.
+/// # Errors
+/// * Returns an error if the file cannot be loaded, is of another flavor, or is invalid.
+fn load_elliptic_curve_config(opts: &Options) -> Result<Configuration> {
+    let Some(path) = opts.elliptic_curve_config_path.as_deref() else {
+        log::info!(
+            "No elliptic curve configuration file specified, generating non-persistent configuration."
         );
+        return generate_config(ConfigFlavor::EllipticCurve, 0);
+    };
+    let config = load_config_from_file_with_strictness(
+        path,
+        ConfigFormat::from_path(path),
+        opts.config_strict,
+    )?;
+    if !matches!(config.flavor, Some(Flavor::EllipticCurve(_))) {
+        return Err(Error::msg(format!(
+            "--elliptic-curve-config-path '{}' is not an elliptic curve configuration",
+            path
+        )));
+    }
+    validate_config_cached(&config, path, opts.revalidate_params)?;
+    Ok(config)
+}
 
-    // Run the server and wait for either completion or a shutdown signal.
-    tokio::select! {
-        _ = server => {
-            log::info!("Server has shut down.");
-        },
-        _ = signal_task => {
-            log::info!("Signal received, shutting down.");
-        },
+/// Builds the TLS configuration of the gRPC server from the certificate, key, and client CA files,
+/// if a certificate is specified.
+/// # Errors
+/// * Returns an error if a file cannot be read.
+fn build_tls_config(opts: &Options) -> Result<Option<ServerTlsConfig>> {
+    let (Some(cert_path), Some(key_path)) = (opts.tls_cert.as_deref(), opts.tls_key.as_deref())
+    else {
+        return Ok(None);
+    };
+    let read = |path: &str| {
+        std::fs::read(path).map_err(|e| Error::msg(format!("failed to read '{}': {}", path, e)))
+    };
+    let mut tls_config =
+        ServerTlsConfig::new().identity(Identity::from_pem(read(cert_path)?, read(key_path)?));
+    log::info!(
+        "Serving gRPC over TLS with the certificate at '{}'.",
+        cert_path
+    );
+    if let Some(client_ca_path) = opts.tls_client_ca.as_deref() {
+        tls_config = tls_config.client_ca_root(Certificate::from_pem(read(client_ca_path)?));
+        log::info!(
+            "Requiring client certificates signed by the CA at '{}'.",
+            client_ca_path
+        );
+    }
+    if opts.tls_client_scoped_registration {
+        log::info!("Scoping registration to the common name of client certificates.");
     }
+    Ok(Some(tls_config))
+}
+
+/// Builds the service for the given configuration, with the verifier for its flavor and the
+/// service options.
+fn build_service(opts: &Options, config: Configuration) -> Result<Service> {
+    let verifier = build_verifier(opts, &config)?;
+    let store = build_store(opts.store, opts)?;
 
-    Ok(())
+    let mut service = Service::new(config, verifier)
+        .with_store(store)
+        .with_max_field_length(opts.max_field_length)
+        .with_session_key_strategy(opts.session_key_strategy)
+        .with_require_full_range_commitments(opts.require_full_range_commitments)
+        .with_reject_trivial_commitments(opts.reject_trivial_commitments)
+        .with_auth_error_jitter(Duration::from_millis(opts.auth_error_jitter_ms))
+        .with_allow_anonymous_challenge(opts.allow_anonymous_challenge)
+        .with_scope_registration_to_client_identity(opts.tls_client_scoped_registration)
+        .with_username_policy(opts.username_policy());
+    if let Some(max_age) = opts.challenge_max_age_seconds {
+        service = service.with_challenge_max_age(Duration::from_secs(max_age));
+    }
+    if let Some(max) = opts.max_challenges_per_user {
+        if max == 0 {
+            return Err(Error::msg("--max-challenges-per-user must be positive"));
+        }
+        service = service.with_max_challenges_per_user(max, opts.challenge_limit_policy);
+    }
+    if let Some(key_path) = opts.server_signing_key.as_deref() {
+        let signing_key = load_signing_key_from_file(key_path)?;
+        log::info!(
+            "Signing challenges with server key {}.",
+            hex::encode(signing_key.verifying_key().as_bytes())
+        );
+        service = service.with_signing_key(signing_key);
+    }
+    if let Some(secret) = opts.challenge_mac_secret.as_deref() {
+        if secret.is_empty() {
+            return Err(Error::msg("--challenge-mac-secret must not be empty"));
+        }
+        log::info!("MACing challenges with the challenge MAC secret.");
+        service = service.with_challenge_mac_secret(secret.as_bytes());
+    }
+    if let Some(key) = opts.jwt_signing_key.as_deref() {
+        if key.is_empty() {
+            return Err(Error::msg("--jwt-signing-key must not be empty"));
+        }
+        if opts.jwt_ttl_seconds == 0 {
+            return Err(Error::msg("--jwt-ttl-seconds must be positive"));
+        }
+        log::info!(
+            "Issuing session tokens valid for {} seconds.",
+            opts.jwt_ttl_seconds
+        );
+        service = service.with_session_token_issuer(SessionTokenIssuer::new(
+            key.as_bytes(),
+            Duration::from_secs(opts.jwt_ttl_seconds),
+        ));
+    }
+    Ok(service)
 }
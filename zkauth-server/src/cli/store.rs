@@ -0,0 +1,52 @@
+use anyhow::{Error, Result};
+use clap::ValueEnum;
+use std::time::Duration;
+use strum_macros::{Display, EnumString, VariantNames};
+
+use super::options::Options;
+use crate::store::memory::{MemoryStore, DEFAULT_SESSIONS_TTL};
+use crate::store::Store;
+
+/// Store backend.
+#[derive(Debug, Clone, Copy, EnumString, Display, VariantNames, ValueEnum, PartialEq)]
+#[strum(serialize_all = "kebab-case")]
+pub enum StoreKind {
+    /// An in-memory store, which loses all users, challenges, and sessions on restart.
+    Memory,
+}
+
+/// Builds the store for the given kind.
+/// # Errors
+/// * Returns an error if the challenge time to live is zero.
+pub(crate) fn build_store(kind: StoreKind, opts: &Options) -> Result<Box<dyn Store>> {
+    if opts.challenge_ttl_seconds == 0 {
+        return Err(Error::msg("--challenge-ttl-seconds must be positive"));
+    }
+    match kind {
+        StoreKind::Memory => Ok(Box::new(MemoryStore::new(
+            Duration::from_secs(opts.challenge_ttl_seconds),
+            DEFAULT_SESSIONS_TTL,
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn builds_memory_store() -> Result<()> {
+        let opts = Options::parse_from(vec!["bin", "--store=memory"]);
+        let store = build_store(opts.store, &opts)?;
+        assert_eq!(store.get_user("peggy").await?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_zero_challenge_ttl() {
+        let opts = Options::parse_from(vec!["bin", "--challenge-ttl-seconds=0"]);
+        let err = build_store(opts.store, &opts).err().unwrap();
+        assert_eq!(err.to_string(), "--challenge-ttl-seconds must be positive");
+    }
+}
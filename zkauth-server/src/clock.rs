@@ -0,0 +1,50 @@
+//! Wall-clock time for the server, such as when challenges are issued.
+
+use std::time::SystemTime;
+
+/// A trait for reading the current time, so time-dependent checks can be tested.
+pub trait Clock: Sync + Send {
+    /// Returns the current time.
+    fn now(&self) -> SystemTime;
+}
+
+/// A clock that reads the system time.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+/// Implementation of the system clock.
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock that returns a manually advanced time, starting at the Unix epoch. Clones share the
+/// same time.
+#[cfg(test)]
+#[derive(Debug, Clone)]
+pub(crate) struct ManualClock(std::sync::Arc<std::sync::Mutex<SystemTime>>);
+
+/// Implementation of the manual clock.
+#[cfg(test)]
+impl ManualClock {
+    /// Creates a clock at the Unix epoch.
+    pub(crate) fn new() -> Self {
+        ManualClock(std::sync::Arc::new(std::sync::Mutex::new(
+            SystemTime::UNIX_EPOCH,
+        )))
+    }
+
+    /// Advances the clock by the given duration.
+    pub(crate) fn advance(&self, duration: std::time::Duration) {
+        *self.0.lock().unwrap() += duration;
+    }
+}
+
+/// Implementation of the manual clock.
+#[cfg(test)]
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        *self.0.lock().unwrap()
+    }
+}
@@ -0,0 +1,22 @@
+//! Delays applied by the server, such as jitter on authentication errors.
+
+use std::time::Duration;
+
+/// A trait for sleeping for a duration, so delays can be observed in tests.
+#[tonic::async_trait]
+pub trait Sleeper: Sync + Send {
+    /// Sleeps for the given duration.
+    async fn sleep(&self, duration: Duration);
+}
+
+/// A sleeper that uses the tokio timer.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TokioSleeper;
+
+/// Implementation of the tokio sleeper.
+#[tonic::async_trait]
+impl Sleeper for TokioSleeper {
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await
+    }
+}
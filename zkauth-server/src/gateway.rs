@@ -0,0 +1,375 @@
+//! A JSON over HTTP/1.1 gateway to the service, for clients that cannot speak gRPC.
+//!
+//! Each endpoint accepts a POST with the JSON encoding of the request message and responds with
+//! the JSON encoding of the response message, using the same field names as the gRPC JSON mapping:
+//!
+//! * `POST /v1/register` calls register with a `RegisterRequest`.
+//! * `POST /v1/challenge` calls create_authentication_challenge with an
+//!   `AuthenticationChallengeRequest`.
+//! * `POST /v1/verify` calls verify_authentication with an `AuthenticationAnswerRequest`.
+//! * `POST /v1/authenticate` calls authenticate with an `AuthenticateRequest`.
+//!
+//! An error is returned as `{"code": ..., "message": ...}` with the gRPC status code, and an HTTP
+//! status mapped from it.
+//!
+//! The HTTP headers of each request are passed through the same [`MetadataInterceptor`] as the
+//! gRPC server, so the allowlisted keys reach the audit entries of gateway requests too.
+
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::post,
+    Router,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::future::Future;
+use std::sync::Arc;
+use tonic::metadata::MetadataMap;
+use tonic::service::Interceptor;
+use tonic::{Code, Extensions, Request, Status};
+use zkauth_protobuf::v1::auth_server::Auth;
+
+use crate::audit::MetadataInterceptor;
+use crate::service::Service;
+
+/// The state shared by the handlers of the gateway.
+#[derive(Clone)]
+struct Gateway {
+    service: Arc<Service>,
+    interceptor: MetadataInterceptor,
+}
+
+/// Implementation of the gateway.
+impl Gateway {
+    /// Returns a service request for the given message, with the HTTP headers as its metadata,
+    /// passed through the interceptor.
+    #[allow(clippy::result_large_err)]
+    fn request<T>(&self, headers: HeaderMap, message: T) -> Result<Request<T>, Status> {
+        let request = Request::from_parts(
+            MetadataMap::from_headers(headers),
+            Extensions::default(),
+            (),
+        );
+        let (metadata, extensions, _) = self.interceptor.clone().call(request)?.into_parts();
+        Ok(Request::from_parts(metadata, extensions, message))
+    }
+}
+
+/// Returns the router of the gateway, calling the given service with requests passed through the
+/// given interceptor.
+pub fn router(service: Arc<Service>, interceptor: MetadataInterceptor) -> Router {
+    Router::new()
+        .route("/v1/register", post(register))
+        .route("/v1/challenge", post(challenge))
+        .route("/v1/verify", post(verify))
+        .route("/v1/authenticate", post(authenticate))
+        .with_state(Gateway {
+            service,
+            interceptor,
+        })
+}
+
+/// Serves the gateway on the given listener until the shutdown future completes.
+pub async fn serve(
+    listener: std::net::TcpListener,
+    service: Arc<Service>,
+    interceptor: MetadataInterceptor,
+    shutdown: impl Future<Output = ()>,
+) -> Result<(), hyper::Error> {
+    axum::Server::from_tcp(listener)?
+        .serve(router(service, interceptor).into_make_service())
+        .with_graceful_shutdown(shutdown)
+        .await
+}
+
+/// Handles a register request.
+async fn register(State(gateway): State<Gateway>, headers: HeaderMap, body: Bytes) -> Response {
+    transcode(body, |request| async move {
+        let request = gateway.request(headers, request)?;
+        gateway.service.register(request).await
+    })
+    .await
+}
+
+/// Handles a create_authentication_challenge request.
+async fn challenge(State(gateway): State<Gateway>, headers: HeaderMap, body: Bytes) -> Response {
+    transcode(body, |request| async move {
+        let request = gateway.request(headers, request)?;
+        gateway
+            .service
+            .create_authentication_challenge(request)
+            .await
+    })
+    .await
+}
+
+/// Handles a verify_authentication request.
+async fn verify(State(gateway): State<Gateway>, headers: HeaderMap, body: Bytes) -> Response {
+    transcode(body, |request| async move {
+        let request = gateway.request(headers, request)?;
+        gateway.service.verify_authentication(request).await
+    })
+    .await
+}
+
+/// Handles an authenticate request.
+async fn authenticate(State(gateway): State<Gateway>, headers: HeaderMap, body: Bytes) -> Response {
+    transcode(body, |request| async move {
+        let request = gateway.request(headers, request)?;
+        gateway.service.authenticate(request).await
+    })
+    .await
+}
+
+/// Decodes the JSON request body, calls the service with it, and encodes its response or error.
+async fn transcode<T, U, F, Fut>(body: Bytes, call: F) -> Response
+where
+    T: DeserializeOwned,
+    U: Serialize,
+    F: FnOnce(T) -> Fut,
+    Fut: Future<Output = Result<tonic::Response<U>, Status>>,
+{
+    let request = match serde_json::from_slice(&body) {
+        Ok(request) => request,
+        Err(e) => {
+            return error_response(&Status::invalid_argument(format!(
+                "Invalid request body: {}",
+                e
+            )))
+        }
+    };
+    match call(request).await {
+        Ok(response) => json_response(StatusCode::OK, &response.into_inner()),
+        Err(status) => error_response(&status),
+    }
+}
+
+/// Returns a response with the JSON encoding of the given value.
+fn json_response<T: Serialize>(status: StatusCode, value: &T) -> Response {
+    match serde_json::to_vec(value) {
+        Ok(body) => (status, [(header::CONTENT_TYPE, "application/json")], body).into_response(),
+        Err(e) => {
+            log::error!("Failed to serialize response: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// The JSON body of an error response.
+#[derive(Serialize)]
+struct ErrorBody<'a> {
+    code: i32,
+    message: &'a str,
+}
+
+/// Returns a response for the given status, with an HTTP status mapped from its code.
+fn error_response(status: &Status) -> Response {
+    json_response(
+        http_status(status.code()),
+        &ErrorBody {
+            code: status.code() as i32,
+            message: status.message(),
+        },
+    )
+}
+
+/// Maps a gRPC status code to an HTTP status, following the mapping of the gRPC HTTP gateway.
+fn http_status(code: Code) -> StatusCode {
+    match code {
+        Code::Ok => StatusCode::OK,
+        Code::InvalidArgument | Code::FailedPrecondition | Code::OutOfRange => {
+            StatusCode::BAD_REQUEST
+        }
+        Code::Unauthenticated => StatusCode::UNAUTHORIZED,
+        Code::PermissionDenied => StatusCode::FORBIDDEN,
+        Code::NotFound => StatusCode::NOT_FOUND,
+        Code::AlreadyExists | Code::Aborted => StatusCode::CONFLICT,
+        Code::ResourceExhausted => StatusCode::TOO_MANY_REQUESTS,
+        Code::Unimplemented => StatusCode::NOT_IMPLEMENTED,
+        Code::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+        Code::DeadlineExceeded => StatusCode::GATEWAY_TIMEOUT,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anyhow::Result;
+    use axum::body::Body;
+    use axum::http::Request as HttpRequest;
+    use serde_json::{json, Value};
+    use std::time::SystemTime;
+    use tower::ServiceExt;
+    use zkauth::discrete_logarithm::{
+        configuration::DiscreteLogarithmConfiguration, prover::DiscreteLogarithmProver,
+        verifier::DiscreteLogarithmVerifier,
+    };
+    use zkauth::{ProofBinding, Prover, Scalar};
+
+    /// Returns a service with a small discrete logarithm configuration, and a prover for it.
+    fn service_and_prover() -> (Arc<Service>, DiscreteLogarithmProver) {
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+        let service = Arc::new(Service::new(config.clone().into(), verifier));
+        (service, DiscreteLogarithmProver::new(config))
+    }
+
+    /// Posts the JSON body to the path of the gateway, and returns the status and JSON response.
+    async fn post(service: &Arc<Service>, path: &str, body: &str) -> Result<(StatusCode, Value)> {
+        let response = router(service.clone(), MetadataInterceptor::default())
+            .oneshot(
+                HttpRequest::post(path)
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(body.to_string()))?,
+            )
+            .await?;
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await?;
+        Ok((status, serde_json::from_slice(&body)?))
+    }
+
+    #[tokio::test]
+    async fn authenticates_over_json() -> Result<()> {
+        let (service, prover) = service_and_prover();
+
+        let x = prover.compute_registration_x("password".to_string());
+        let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+        let body = json!({ "user": "peggy", "y1": y1.to_string(), "y2": y2.to_string() });
+        let (status, _) = post(&service, "/v1/register", &body.to_string()).await?;
+        assert_eq!(status, StatusCode::OK);
+
+        let k = prover.generate_challenge_k();
+        let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+        let body = json!({ "user": "peggy", "r1": r1.to_string(), "r2": r2.to_string() });
+        let (status, challenge) = post(&service, "/v1/challenge", &body.to_string()).await?;
+        assert_eq!(status, StatusCode::OK);
+
+        let c: Scalar = challenge["c"].as_str().unwrap().parse().unwrap();
+        let s = prover.compute_challenge_response_s(&x, &k, c)?;
+        let body = json!({ "authId": challenge["authId"], "s": s.to_string() });
+        let (status, answer) = post(&service, "/v1/verify", &body.to_string()).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert!(!answer["sessionId"].as_str().unwrap().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn authenticates_noninteractively_over_json() -> Result<()> {
+        let (service, prover) = service_and_prover();
+
+        let x = prover.compute_registration_x("password".to_string());
+        let timestamp = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?;
+        let binding = ProofBinding {
+            user: "peggy".to_string(),
+            timestamp: timestamp.as_secs(),
+        };
+        let proof = prover.compute_noninteractive_proof(&x, binding)?;
+        let context = &proof.context;
+        let body = json!({
+            "user": "peggy",
+            "y1": context.y1.to_string(),
+            "y2": context.y2.to_string(),
+        });
+        let (status, _) = post(&service, "/v1/register", &body.to_string()).await?;
+        assert_eq!(status, StatusCode::OK);
+
+        let body = json!({
+            "user": "peggy",
+            "r1": context.r1.to_string(),
+            "r2": context.r2.to_string(),
+            "s": proof.s.to_string(),
+            "timestamp": proof.binding.timestamp.to_string(),
+        });
+        let (status, answer) = post(&service, "/v1/authenticate", &body.to_string()).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert!(!answer["sessionId"].as_str().unwrap().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn writes_audit_entry_with_allowlisted_headers() -> Result<()> {
+        use crate::service::test::verify_authentication::MemoryAuditLog;
+        use std::sync::Mutex;
+
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+        let entries = Arc::new(Mutex::new(Vec::new()));
+        let service = Arc::new(
+            Service::new(config.into(), verifier)
+                .with_audit_log(Box::new(MemoryAuditLog(entries.clone()))),
+        );
+        let interceptor = MetadataInterceptor::new(vec!["x-request-id".to_string()]);
+
+        let body = json!({ "authId": uuid::Uuid::new_v4().to_string(), "s": "2" });
+        let response = router(service, interceptor)
+            .oneshot(
+                HttpRequest::post("/v1/verify")
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .header("x-request-id", "req-123")
+                    .header("x-other", "ignored")
+                    .body(Body::from(body.to_string()))?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let entries = entries.lock().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entries[0].metadata,
+            std::collections::BTreeMap::from([("x-request-id".to_string(), "req-123".to_string())])
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returns_conflict_when_already_registered() -> Result<()> {
+        let (service, prover) = service_and_prover();
+
+        let x = prover.compute_registration_x("password".to_string());
+        let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+        let body = json!({ "user": "peggy", "y1": y1.to_string(), "y2": y2.to_string() });
+        post(&service, "/v1/register", &body.to_string()).await?;
+        let (status, error) = post(&service, "/v1/register", &body.to_string()).await?;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(error["code"], Code::AlreadyExists as i32);
+        assert_eq!(error["message"], "User already registered");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returns_bad_request_with_invalid_body() -> Result<()> {
+        let (service, _) = service_and_prover();
+
+        let (status, error) = post(&service, "/v1/challenge", "{\"user\": ").await?;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(error["code"], Code::InvalidArgument as i32);
+        assert!(error["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("Invalid request body: "));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_for_unknown_user() -> Result<()> {
+        let (service, _) = service_and_prover();
+
+        let body = json!({ "user": "nobody", "r1": "2", "r2": "3" });
+        let (status, error) = post(&service, "/v1/challenge", &body.to_string()).await?;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(error["code"], Code::NotFound as i32);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+//! Identities of clients authenticated with a certificate over mutual TLS.
+//!
+//! When the server requires clients to present a certificate signed by its client CA, the
+//! [`ClientIdentityInterceptor`] copies the common name (CN) of the certificate's subject into the
+//! request extensions as a [`ClientIdentity`], so the service can scope registration to it.
+
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+/// The common name of the certificate a client presented, as stashed in the request extensions
+/// by the interceptor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientIdentity(pub String);
+
+/// An interceptor that copies the common name of the client certificate of each request into its
+/// extensions. Requests without a client certificate, or whose certificate has no common name,
+/// are passed through without an identity.
+#[derive(Debug, Clone, Default)]
+pub struct ClientIdentityInterceptor;
+
+/// Implementation of the interceptor for the client identity interceptor.
+impl Interceptor for ClientIdentityInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let identity = request
+            .peer_certs()
+            .and_then(|certs| common_name(certs.first()?.get_ref()));
+        if let Some(identity) = identity {
+            request.extensions_mut().insert(ClientIdentity(identity));
+        }
+        Ok(request)
+    }
+}
+
+/// Returns the first common name of the subject of the given DER-encoded certificate, if it
+/// parses and has one.
+pub fn common_name(der: &[u8]) -> Option<String> {
+    let (_, certificate) = X509Certificate::from_der(der).ok()?;
+    let common_name = certificate.subject().iter_common_name().next()?;
+    common_name.as_str().ok().map(str::to_string)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anyhow::Result;
+
+    const CLIENT_PEM: &str = include_str!("testdata/tls/client.pem");
+
+    /// Decodes the DER of the single certificate in the given PEM.
+    fn decode_pem(pem: &str) -> Vec<u8> {
+        let (_, pem) = x509_parser::pem::parse_x509_pem(pem.as_bytes()).unwrap();
+        pem.contents
+    }
+
+    #[test]
+    fn common_name_of_client_certificate() {
+        assert_eq!(
+            common_name(&decode_pem(CLIENT_PEM)),
+            Some("peggy".to_string())
+        );
+    }
+
+    #[test]
+    fn common_name_of_invalid_certificate() {
+        assert_eq!(common_name(b"not a certificate"), None);
+    }
+
+    #[test]
+    fn interceptor_skips_requests_without_client_certificate() -> Result<()> {
+        let request = ClientIdentityInterceptor.call(Request::new(()))?;
+        assert_eq!(request.extensions().get::<ClientIdentity>(), None);
+        Ok(())
+    }
+}
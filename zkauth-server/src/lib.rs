@@ -47,7 +47,20 @@
 //! zkauth-server --config-generate --config-path=config.json --config-prime=42765216643065397982265462252423826320512529931694366715111734768493812630447
 //! ```
 
+pub mod audit;
 pub mod cli;
+pub mod clock;
+pub mod delay;
+pub mod gateway;
+pub mod identity;
+pub mod metrics;
+pub mod recorder;
+pub mod router;
 pub mod service;
+pub mod session;
+pub mod signing;
+pub mod token;
+pub mod username;
 
 mod store;
+mod sweeper;
@@ -0,0 +1,239 @@
+//! Prometheus metrics for the service, served in the text exposition format at `GET /metrics`:
+//!
+//! * `zkauth_register_total` counts successful registrations.
+//! * `zkauth_challenge_total` counts challenges issued.
+//! * `zkauth_verify_total{result="success|failed"}` counts verifications by outcome.
+//! * `zkauth_verify_duration_seconds` is a histogram of verification latency, excluding any error
+//!   jitter.
+//! * `zkauth_sweeper_swept_total{kind="challenges|sessions"}` counts expired entries removed by
+//!   the sweeper.
+//! * `zkauth_sweeper_errors_total` counts sweeps that failed.
+
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, IntCounter, IntCounterVec, Opts, Registry, TextEncoder,
+};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The metrics of a service, registered in their own registry so that each server exposes only
+/// its own.
+pub struct Metrics {
+    registry: Registry,
+    register_total: IntCounter,
+    challenge_total: IntCounter,
+    verify_total: IntCounterVec,
+    verify_duration_seconds: Histogram,
+    sweeper_swept_total: IntCounterVec,
+    sweeper_errors_total: IntCounter,
+}
+
+/// Implementation of the metrics.
+impl Metrics {
+    /// Creates the metrics, registered in a new registry.
+    pub fn new() -> Self {
+        let register_total = IntCounter::new(
+            "zkauth_register_total",
+            "Number of successful registrations.",
+        )
+        .expect("valid metric");
+        let challenge_total =
+            IntCounter::new("zkauth_challenge_total", "Number of challenges issued.")
+                .expect("valid metric");
+        let verify_total = IntCounterVec::new(
+            Opts::new("zkauth_verify_total", "Number of verifications, by result."),
+            &["result"],
+        )
+        .expect("valid metric");
+        let verify_duration_seconds = Histogram::with_opts(HistogramOpts::new(
+            "zkauth_verify_duration_seconds",
+            "Latency of verifications in seconds.",
+        ))
+        .expect("valid metric");
+        let sweeper_swept_total = IntCounterVec::new(
+            Opts::new(
+                "zkauth_sweeper_swept_total",
+                "Number of expired entries removed by the sweeper, by kind.",
+            ),
+            &["kind"],
+        )
+        .expect("valid metric");
+        let sweeper_errors_total = IntCounter::new(
+            "zkauth_sweeper_errors_total",
+            "Number of sweeps that failed.",
+        )
+        .expect("valid metric");
+
+        let registry = Registry::new();
+        for collector in [
+            Box::new(register_total.clone()) as Box<dyn prometheus::core::Collector>,
+            Box::new(challenge_total.clone()),
+            Box::new(verify_total.clone()),
+            Box::new(verify_duration_seconds.clone()),
+            Box::new(sweeper_swept_total.clone()),
+            Box::new(sweeper_errors_total.clone()),
+        ] {
+            registry.register(collector).expect("unique metric");
+        }
+
+        Self {
+            registry,
+            register_total,
+            challenge_total,
+            verify_total,
+            verify_duration_seconds,
+            sweeper_swept_total,
+            sweeper_errors_total,
+        }
+    }
+
+    /// Counts a successful registration.
+    pub fn record_register(&self) {
+        self.register_total.inc();
+    }
+
+    /// Counts an issued challenge.
+    pub fn record_challenge(&self) {
+        self.challenge_total.inc();
+    }
+
+    /// Counts a verification with the given outcome, and observes how long it took.
+    pub fn record_verify(&self, success: bool, elapsed: Duration) {
+        let result = if success { "success" } else { "failed" };
+        self.verify_total.with_label_values(&[result]).inc();
+        self.verify_duration_seconds.observe(elapsed.as_secs_f64());
+    }
+
+    /// Counts the expired challenges and sessions removed by a sweep.
+    pub fn record_sweep(&self, challenges: u64, sessions: u64) {
+        self.sweeper_swept_total
+            .with_label_values(&["challenges"])
+            .inc_by(challenges);
+        self.sweeper_swept_total
+            .with_label_values(&["sessions"])
+            .inc_by(sessions);
+    }
+
+    /// Counts a sweep that failed.
+    pub fn record_sweep_error(&self) {
+        self.sweeper_errors_total.inc();
+    }
+
+    /// Returns the metrics in the Prometheus text exposition format.
+    pub fn encode(&self) -> String {
+        let mut buffer = Vec::new();
+        // Encoding to a Vec only fails for invalid metric families, which are not registered.
+        let _ = TextEncoder::new().encode(&self.registry.gather(), &mut buffer);
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
+
+/// Implement the Default trait for the metrics.
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the router serving the given metrics at `GET /metrics`.
+pub fn router(metrics: Arc<Metrics>) -> Router {
+    Router::new()
+        .route("/metrics", get(scrape))
+        .with_state(metrics)
+}
+
+/// Serves the metrics on the given listener until the shutdown future completes.
+pub async fn serve(
+    listener: std::net::TcpListener,
+    metrics: Arc<Metrics>,
+    shutdown: impl Future<Output = ()>,
+) -> Result<(), hyper::Error> {
+    axum::Server::from_tcp(listener)?
+        .serve(router(metrics).into_make_service())
+        .with_graceful_shutdown(shutdown)
+        .await
+}
+
+/// Handles a scrape request.
+async fn scrape(State(metrics): State<Arc<Metrics>>) -> Response {
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, TextEncoder::new().format_type())],
+        metrics.encode(),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use anyhow::Result;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    #[test]
+    fn encodes_recorded_metrics() {
+        let metrics = Metrics::new();
+        metrics.record_register();
+        metrics.record_challenge();
+        metrics.record_challenge();
+        metrics.record_verify(true, Duration::from_millis(5));
+        metrics.record_verify(false, Duration::from_millis(5));
+        metrics.record_verify(false, Duration::from_millis(5));
+        metrics.record_sweep(3, 1);
+        metrics.record_sweep_error();
+
+        let text = metrics.encode();
+        assert!(text.contains("zkauth_register_total 1\n"), "{}", text);
+        assert!(text.contains("zkauth_challenge_total 2\n"), "{}", text);
+        assert!(
+            text.contains("zkauth_verify_total{result=\"success\"} 1\n"),
+            "{}",
+            text
+        );
+        assert!(
+            text.contains("zkauth_verify_total{result=\"failed\"} 2\n"),
+            "{}",
+            text
+        );
+        assert!(
+            text.contains("zkauth_verify_duration_seconds_count 3\n"),
+            "{}",
+            text
+        );
+        assert!(
+            text.contains("zkauth_sweeper_swept_total{kind=\"challenges\"} 3\n"),
+            "{}",
+            text
+        );
+        assert!(
+            text.contains("zkauth_sweeper_swept_total{kind=\"sessions\"} 1\n"),
+            "{}",
+            text
+        );
+        assert!(text.contains("zkauth_sweeper_errors_total 1\n"), "{}", text);
+    }
+
+    #[tokio::test]
+    async fn serves_metrics() -> Result<()> {
+        let metrics = Arc::new(Metrics::new());
+        metrics.record_register();
+
+        let response = router(metrics)
+            .oneshot(Request::get("/metrics").body(Body::empty())?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = hyper::body::to_bytes(response.into_body()).await?;
+        assert!(String::from_utf8(body.to_vec())?.contains("zkauth_register_total 1\n"));
+
+        Ok(())
+    }
+}
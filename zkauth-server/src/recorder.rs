@@ -0,0 +1,450 @@
+//! Recording of requests to the service, and replaying them to reproduce verification failures.
+//!
+//! Records are written as JSON lines. Only public protocol values are recorded, since every value
+//! on the wire is public in the zero-knowledge protocol.
+
+use anyhow::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::sync::Mutex;
+use tonic::{Code, Status};
+use zkauth::Scalar;
+use zkauth_protobuf::v1::AuthenticationChallengeResponse;
+
+use crate::service::Service;
+
+/// A request to the service, along with any values the service generated for it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum RecordedRequest {
+    /// A register request.
+    Register {
+        /// The user to register.
+        user: String,
+        /// The registration y1 value.
+        y1: String,
+        /// The registration y2 value.
+        y2: String,
+        /// The user metadata.
+        #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+        metadata: HashMap<String, String>,
+    },
+
+    /// A create_authentication_challenge request, along with the auth_id and challenge c that
+    /// the service generated for it.
+    CreateAuthenticationChallenge {
+        /// The user to authenticate.
+        user: String,
+        /// The challenge commitment r1 value.
+        r1: String,
+        /// The challenge commitment r2 value.
+        r2: String,
+        /// The generated auth_id.
+        auth_id: String,
+        /// The generated challenge c value, or empty if the request failed.
+        c: String,
+    },
+
+    /// A register_and_challenge request, along with the auth_id and challenge c that the service
+    /// generated for it.
+    RegisterAndChallenge {
+        /// The user to register.
+        user: String,
+        /// The registration y1 value.
+        y1: String,
+        /// The registration y2 value.
+        y2: String,
+        /// The user metadata.
+        #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+        metadata: HashMap<String, String>,
+        /// The challenge commitment r1 value.
+        r1: String,
+        /// The challenge commitment r2 value.
+        r2: String,
+        /// The generated auth_id.
+        auth_id: String,
+        /// The generated challenge c value, or empty if the request failed.
+        c: String,
+    },
+
+    /// A verify_authentication request.
+    VerifyAuthentication {
+        /// The auth_id of the challenge.
+        auth_id: String,
+        /// The challenge response s value.
+        s: String,
+        /// The challenge c value echoed back by the client.
+        #[serde(default)]
+        c: String,
+        /// The challenge MAC echoed back by the client.
+        #[serde(default)]
+        mac: String,
+    },
+
+    /// An authenticate request, with a non-interactive proof.
+    Authenticate {
+        /// The user to authenticate.
+        user: String,
+        /// The commitment r1 value.
+        r1: String,
+        /// The commitment r2 value.
+        r2: String,
+        /// The response s value.
+        s: String,
+        /// The time the proof was computed, in seconds since the Unix epoch.
+        #[serde(default)]
+        timestamp: u64,
+    },
+}
+
+/// Implementation of the recorded request.
+impl RecordedRequest {
+    /// Sets the challenge c of a challenge request from the response to it. It is left empty if
+    /// the request failed, since c may not have been generated, and the failure does not depend
+    /// on it.
+    pub fn set_challenge_c(&mut self, result: &Result<AuthenticationChallengeResponse, Status>) {
+        let Ok(response) = result else {
+            return;
+        };
+        match self {
+            RecordedRequest::CreateAuthenticationChallenge { c, .. }
+            | RecordedRequest::RegisterAndChallenge { c, .. } => c.clone_from(&response.c),
+            _ => {}
+        }
+    }
+}
+
+/// Parses the recorded challenge c of a request, which is empty if the request failed, so that
+/// replaying it generates a new one.
+pub(crate) fn parse_recorded_c(c: &str) -> Result<Option<Scalar>> {
+    if c.is_empty() {
+        return Ok(None);
+    }
+    c.parse()
+        .map(Some)
+        .map_err(|_| Error::msg("invalid recorded c"))
+}
+
+/// The outcome of a request, as a status code and message. Successful requests have the ok code
+/// and an empty message, since their responses contain random values such as session ids.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedOutcome {
+    /// The status code.
+    pub code: i32,
+
+    /// The status message.
+    pub message: String,
+}
+
+/// Implementation of the recorded outcome.
+impl RecordedOutcome {
+    /// Returns the outcome of the given result.
+    pub fn from_result<T>(result: &Result<T, Status>) -> Self {
+        match result {
+            Ok(_) => RecordedOutcome {
+                code: Code::Ok as i32,
+                message: "".to_string(),
+            },
+            Err(status) => Self::from_status(status),
+        }
+    }
+
+    /// Returns the outcome of the given error status.
+    pub fn from_status(status: &Status) -> Self {
+        RecordedOutcome {
+            code: status.code() as i32,
+            message: status.message().to_string(),
+        }
+    }
+}
+
+/// A recorded request and its outcome.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    /// The version of the configuration the request was handled with.
+    pub config_version: String,
+
+    /// The request.
+    pub request: RecordedRequest,
+
+    /// The outcome of the request.
+    pub outcome: RecordedOutcome,
+}
+
+/// A recorder that appends records to a JSON lines file.
+#[derive(Debug)]
+pub struct RequestRecorder {
+    file: Mutex<File>,
+}
+
+/// Implementation of the request recorder.
+impl RequestRecorder {
+    /// Creates a recorder that appends to the file at the given path, creating it if necessary.
+    pub fn create(path: &str) -> Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(RequestRecorder {
+            file: Mutex::new(file),
+        })
+    }
+
+    /// Appends a record to the file.
+    pub fn record(&self, record: &Record) -> Result<()> {
+        let mut line = serde_json::to_string(record)?;
+        line.push('\n');
+        let mut file = self
+            .file
+            .lock()
+            .map_err(|_| Error::msg("recorder lock poisoned"))?;
+        file.write_all(line.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// Loads the records from a JSON lines file.
+pub fn load_records(path: &str) -> Result<Vec<Record>> {
+    let mut records = Vec::new();
+    for line in BufReader::new(File::open(path)?).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        records.push(serde_json::from_str(&line)?);
+    }
+    Ok(records)
+}
+
+/// A replayed request whose outcome differs from the recorded outcome.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayMismatch {
+    /// The index of the record.
+    pub index: usize,
+
+    /// The recorded request.
+    pub request: RecordedRequest,
+
+    /// The recorded outcome.
+    pub expected: RecordedOutcome,
+
+    /// The outcome of the replayed request.
+    pub actual: RecordedOutcome,
+}
+
+/// Replays the records in order against the given service, which should be freshly constructed
+/// with the same configuration and options, and returns the requests whose outcomes differ.
+/// # Errors
+/// * Returns an error if a record was made with a different configuration version.
+pub async fn replay(service: &Service, records: Vec<Record>) -> Result<Vec<ReplayMismatch>> {
+    let config_version = service.configuration().config_version();
+
+    let mut mismatches = Vec::new();
+    for (index, record) in records.into_iter().enumerate() {
+        if record.config_version != config_version {
+            return Err(Error::msg(format!(
+                "record {} was made with configuration version {}, but the service has {}",
+                index, record.config_version, config_version
+            )));
+        }
+
+        let actual = service.replay_request(record.request.clone()).await;
+        if actual != record.outcome {
+            mismatches.push(ReplayMismatch {
+                index,
+                request: record.request,
+                expected: record.outcome,
+                actual,
+            });
+        }
+    }
+    Ok(mismatches)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tempdir::TempDir;
+    use tonic::Request;
+    use zkauth::discrete_logarithm::{
+        configuration::DiscreteLogarithmConfiguration, prover::DiscreteLogarithmProver,
+        verifier::DiscreteLogarithmVerifier,
+    };
+    use zkauth::{Prover, Scalar};
+    use zkauth_protobuf::v1::{
+        auth_server::Auth, AuthenticationAnswerRequest, AuthenticationChallengeRequest,
+        RegisterRequest,
+    };
+
+    /// Creates a new service for testing with the given configuration.
+    fn test_service(config: &DiscreteLogarithmConfiguration) -> Service {
+        let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+        Service::new(config.clone().into(), verifier)
+    }
+
+    /// Registers and logs in a user against the service, with the given password for the login.
+    async fn register_and_login(
+        service: &Service,
+        config: &DiscreteLogarithmConfiguration,
+        login_password: &str,
+    ) -> Result<()> {
+        let prover = DiscreteLogarithmProver::new(config.clone());
+        let x = prover.compute_registration_x("password".to_string());
+        let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+        service
+            .register(Request::new(RegisterRequest {
+                user: "peggy".to_string(),
+                y1: y1.to_string(),
+                y2: y2.to_string(),
+                metadata: Default::default(),
+            }))
+            .await?;
+
+        let k = prover.generate_challenge_k();
+        let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+        let challenge = service
+            .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                user: "peggy".to_string(),
+                r1: r1.to_string(),
+                r2: r2.to_string(),
+            }))
+            .await?
+            .into_inner();
+
+        let x = prover.compute_registration_x(login_password.to_string());
+        let c: Scalar = challenge.c.parse().map_err(|_| Error::msg("invalid c"))?;
+        let s = prover.compute_challenge_response_s(&x, &k, c)?;
+        let _ = service
+            .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                auth_id: challenge.auth_id,
+                s: s.to_string(),
+                c: challenge.c,
+                mac: challenge.mac,
+            }))
+            .await;
+
+        Ok(())
+    }
+
+    /// Tests that a failed challenge is recorded without a c, and replaying it reproduces the
+    /// failure.
+    #[tokio::test]
+    async fn replay_reproduces_failed_challenge_without_c() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let path = tmp_dir.path().join("requests.jsonl");
+        let path = path.to_str().unwrap();
+
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let service = test_service(&config).with_recorder(RequestRecorder::create(path)?);
+        let err = service
+            .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                user: "nobody".to_string(),
+                r1: config.g.to_string(),
+                r2: config.h.to_string(),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Code::NotFound);
+
+        let records = load_records(path)?;
+        assert!(matches!(
+            &records[0].request,
+            RecordedRequest::CreateAuthenticationChallenge { c, .. } if c.is_empty()
+        ));
+
+        let mismatches = replay(&test_service(&config), records).await?;
+        assert_eq!(mismatches, vec![]);
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    /// Tests that recording a successful login and replaying it reproduces the same outcomes.
+    #[tokio::test]
+    async fn replay_reproduces_successful_login() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let path = tmp_dir.path().join("requests.jsonl");
+        let path = path.to_str().unwrap();
+
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let service = test_service(&config).with_recorder(RequestRecorder::create(path)?);
+        register_and_login(&service, &config, "password").await?;
+
+        let records = load_records(path)?;
+        assert_eq!(records.len(), 3);
+        assert!(records.iter().all(|r| r.outcome.code == Code::Ok as i32));
+
+        let mismatches = replay(&test_service(&config), records).await?;
+        assert_eq!(mismatches, vec![]);
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    /// Tests that recording a failed login and replaying it reproduces the same failure.
+    #[tokio::test]
+    async fn replay_reproduces_failed_login() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let path = tmp_dir.path().join("requests.jsonl");
+        let path = path.to_str().unwrap();
+
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let service = test_service(&config).with_recorder(RequestRecorder::create(path)?);
+        register_and_login(&service, &config, "wrong").await?;
+
+        let records = load_records(path)?;
+        assert_eq!(
+            records[2].outcome,
+            RecordedOutcome {
+                code: Code::FailedPrecondition as i32,
+                message: "Verification failed".to_string(),
+            }
+        );
+
+        let mismatches = replay(&test_service(&config), records).await?;
+        assert_eq!(mismatches, vec![]);
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    /// Tests that replaying against a service with different state reports the mismatch.
+    #[tokio::test]
+    async fn replay_reports_mismatches() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let path = tmp_dir.path().join("requests.jsonl");
+        let path = path.to_str().unwrap();
+
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let service = test_service(&config).with_recorder(RequestRecorder::create(path)?);
+        register_and_login(&service, &config, "password").await?;
+
+        // Replaying against the same service fails to register the user again.
+        let mismatches = replay(&service, load_records(path)?).await?;
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].index, 0);
+        assert_eq!(mismatches[0].actual.code, Code::AlreadyExists as i32);
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    /// Tests that replaying against a different configuration is an error.
+    #[tokio::test]
+    async fn replay_fails_with_different_configuration() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let path = tmp_dir.path().join("requests.jsonl");
+        let path = path.to_str().unwrap();
+
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let service = test_service(&config).with_recorder(RequestRecorder::create(path)?);
+        register_and_login(&service, &config, "password").await?;
+
+        let other = DiscreteLogarithmConfiguration::generate(16);
+        assert!(replay(&test_service(&other), load_records(path)?)
+            .await
+            .is_err());
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+}
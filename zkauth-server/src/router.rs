@@ -0,0 +1,209 @@
+//! Routing of requests to the service of a flavor, so a single server can serve both flavors.
+//!
+//! Each flavor is served under a path prefix named after it, such as
+//! `/elliptic-curve/zkauth.v1.Auth/Register`, and requests without a flavor prefix go to the
+//! default service. A client selects a flavor by connecting to an address with the prefix, such
+//! as `http://127.0.0.1:50001/elliptic-curve`, since tonic keeps the path of the origin.
+
+use std::marker::PhantomData;
+use std::task::{Context, Poll};
+use tonic::codegen::http::{uri::PathAndQuery, Request, Uri};
+use tonic::codegen::Service as TowerService;
+use tonic::server::NamedService;
+use tonic::service::interceptor::InterceptedService;
+use tonic::transport::{server::Routes, Body};
+use zkauth_protobuf::v1::auth_server::AuthServer;
+
+use crate::audit::MetadataInterceptor;
+use crate::cli::config::ConfigFlavor;
+use crate::identity::ClientIdentityInterceptor;
+use crate::service::Service;
+
+/// A server for a single service, with the request metadata and client identity interceptors
+/// applied.
+type InterceptedServer = InterceptedService<
+    InterceptedService<AuthServer<Service>, ClientIdentityInterceptor>,
+    MetadataInterceptor,
+>;
+
+/// Applies the given request metadata interceptor and the client identity interceptor to the
+/// server.
+fn intercept(server: AuthServer<Service>, interceptor: MetadataInterceptor) -> InterceptedServer {
+    InterceptedService::new(
+        InterceptedService::new(server, ClientIdentityInterceptor),
+        interceptor,
+    )
+}
+
+/// The servers of a default service and of each flavor served under its path prefix.
+#[derive(Clone)]
+pub struct FlavorRouter {
+    default: InterceptedServer,
+    discrete_logarithm: Option<InterceptedServer>,
+    elliptic_curve: Option<InterceptedServer>,
+}
+
+/// Implementation of the flavor router.
+impl FlavorRouter {
+    /// Creates a router sending every request to the given server, with the interceptors applied.
+    pub fn new(server: AuthServer<Service>, interceptor: MetadataInterceptor) -> Self {
+        Self {
+            default: intercept(server, interceptor),
+            discrete_logarithm: None,
+            elliptic_curve: None,
+        }
+    }
+
+    /// Serves the given server under the path prefix of the flavor, with the interceptors applied.
+    pub fn with_flavor(
+        mut self,
+        flavor: ConfigFlavor,
+        server: AuthServer<Service>,
+        interceptor: MetadataInterceptor,
+    ) -> Self {
+        let server = Some(intercept(server, interceptor));
+        match flavor {
+            ConfigFlavor::DiscreteLogarithm => self.discrete_logarithm = server,
+            // The null flavor serves a discrete logarithm configuration.
+            #[cfg(feature = "null-verifier")]
+            ConfigFlavor::Null => self.discrete_logarithm = server,
+            ConfigFlavor::EllipticCurve => self.elliptic_curve = server,
+            ConfigFlavor::Both => {
+                self.discrete_logarithm = server.clone();
+                self.elliptic_curve = server;
+            }
+        }
+        self
+    }
+
+    /// Returns the routes to serve, with a route for the default service and for each flavor.
+    pub fn into_routes(self) -> Routes {
+        let mut routes = Routes::new(self.default);
+        if let Some(server) = self.discrete_logarithm {
+            routes = routes.add_service(FlavorServer::<DiscreteLogarithmPrefix>::new(server));
+        }
+        if let Some(server) = self.elliptic_curve {
+            routes = routes.add_service(FlavorServer::<EllipticCurvePrefix>::new(server));
+        }
+        routes
+    }
+}
+
+/// A path prefix of a flavor, which the service of the flavor is named under.
+trait FlavorPrefix {
+    /// The prefix, as the path segment named after the flavor.
+    const PREFIX: &'static str;
+
+    /// The name of the service under the prefix, which routes requests with the prefix to it.
+    const NAME: &'static str;
+}
+
+/// The path prefix of the discrete logarithm flavor.
+#[derive(Clone)]
+struct DiscreteLogarithmPrefix;
+
+impl FlavorPrefix for DiscreteLogarithmPrefix {
+    const PREFIX: &'static str = "/discrete-logarithm";
+    const NAME: &'static str = "discrete-logarithm/zkauth.v1.Auth";
+}
+
+/// The path prefix of the elliptic curve flavor.
+#[derive(Clone)]
+struct EllipticCurvePrefix;
+
+impl FlavorPrefix for EllipticCurvePrefix {
+    const PREFIX: &'static str = "/elliptic-curve";
+    const NAME: &'static str = "elliptic-curve/zkauth.v1.Auth";
+}
+
+/// A server for the service of a flavor, which strips the path prefix of the flavor from requests.
+#[derive(Clone)]
+struct FlavorServer<P> {
+    server: InterceptedServer,
+    prefix: PhantomData<P>,
+}
+
+impl<P> FlavorServer<P> {
+    fn new(server: InterceptedServer) -> Self {
+        Self {
+            server,
+            prefix: PhantomData,
+        }
+    }
+}
+
+/// Returns the path of the given URI without the prefix, if it has the prefix followed by a path.
+fn strip_path_prefix(uri: &Uri, prefix: &str) -> Option<PathAndQuery> {
+    let rest = uri.path_and_query()?.as_str().strip_prefix(prefix)?;
+    if !rest.starts_with('/') {
+        return None;
+    }
+    rest.parse().ok()
+}
+
+/// Implementation of the tower service for the server of a flavor.
+impl<P: FlavorPrefix> TowerService<Request<Body>> for FlavorServer<P> {
+    type Response = <InterceptedServer as TowerService<Request<Body>>>::Response;
+    type Error = <InterceptedServer as TowerService<Request<Body>>>::Error;
+    type Future = <InterceptedServer as TowerService<Request<Body>>>::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        TowerService::<Request<Body>>::poll_ready(&mut self.server, cx)
+    }
+
+    fn call(&mut self, mut request: Request<Body>) -> Self::Future {
+        // Requests are only routed here when their path starts with the name of the service.
+        if let Some(path) = strip_path_prefix(request.uri(), P::PREFIX) {
+            let mut parts = request.uri().clone().into_parts();
+            parts.path_and_query = Some(path);
+            *request.uri_mut() = Uri::from_parts(parts).expect("path only changed");
+        }
+        self.server.call(request)
+    }
+}
+
+/// Implementation of the named service for the server of a flavor, named under its prefix.
+impl<P: FlavorPrefix> NamedService for FlavorServer<P> {
+    const NAME: &'static str = P::NAME;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn strips_path_prefix() {
+        let uri: Uri = "http://127.0.0.1/elliptic-curve/zkauth.v1.Auth/Register"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            strip_path_prefix(&uri, "/elliptic-curve").unwrap(),
+            "/zkauth.v1.Auth/Register"
+        );
+        assert_eq!(strip_path_prefix(&uri, "/discrete-logarithm"), None);
+        assert_eq!(strip_path_prefix(&uri, "/elliptic"), None);
+
+        let uri: Uri = "http://127.0.0.1/zkauth.v1.Auth/Register".parse().unwrap();
+        assert_eq!(strip_path_prefix(&uri, "/elliptic-curve"), None);
+    }
+
+    #[test]
+    fn flavor_prefixes_match_flavor_names() {
+        let service = <AuthServer<Service> as NamedService>::NAME;
+        for (flavor, prefix, name) in [
+            (
+                ConfigFlavor::DiscreteLogarithm,
+                DiscreteLogarithmPrefix::PREFIX,
+                DiscreteLogarithmPrefix::NAME,
+            ),
+            (
+                ConfigFlavor::EllipticCurve,
+                EllipticCurvePrefix::PREFIX,
+                EllipticCurvePrefix::NAME,
+            ),
+        ] {
+            assert_eq!(prefix, format!("/{}", flavor));
+            assert_eq!(name, format!("{}/{}", flavor, service));
+        }
+    }
+}
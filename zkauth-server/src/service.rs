@@ -1,776 +1,6057 @@
+use arc_swap::ArcSwap;
+use clap::ValueEnum;
+use curve25519_dalek::RistrettoPoint;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use rand::Rng;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+use strum_macros::{Display, EnumString, VariantNames};
+use tokio::sync::watch;
+use tokio_stream::wrappers::{errors::BroadcastStreamRecvError, BroadcastStream, WatchStream};
+use tokio_stream::{Stream, StreamExt};
 use tonic::{Request, Response, Status};
 use uuid::Uuid;
-use zkauth::{Element, Scalar, Verifier};
+use zkauth::{
+    discrete_logarithm::{arithmetic, prover::DiscreteLogarithmProver},
+    elliptic_curve::{
+        curve::{Curve, Ristretto, Secp256k1},
+        prover::EllipticCurveProver,
+    },
+    ChallengeContext, Element, Proof, ProofBinding, Prover, RegistrationBlob, Scalar, Verifier,
+};
+use zkauth_protobuf::bundle::SigningKey;
+use zkauth_protobuf::challenge::ChallengeMacError;
 use zkauth_protobuf::v1::{
-    auth_server::Auth, AuthenticationAnswerRequest, AuthenticationAnswerResponse,
-    AuthenticationChallengeRequest, AuthenticationChallengeResponse, Configuration,
-    GetConfigurationRequest, RegisterRequest, RegisterResponse,
+    auth_server::{Auth, AuthServer},
+    configuration::Flavor,
+    watch_sessions_response, AuthenticateRequest, AuthenticationAnswerRequest,
+    AuthenticationAnswerResponse, AuthenticationChallengeRequest, AuthenticationChallengeResponse,
+    Configuration, GetConfigurationRequest, GetUserRequest, GetUserResponse,
+    RegisterAndChallengeRequest, RegisterRequest, RegisterResponse, SelfTestRequest,
+    SelfTestResponse, SessionCreated, SessionExpired, SessionRevoked, UnregisterRequest,
+    UnregisterResponse, UpdateCredentialsRequest, UpdateCredentialsResponse,
+    WatchConfigurationRequest, WatchSessionsRequest, WatchSessionsResponse,
 };
+use zkauth_protobuf::ConfigurationChange;
 
+use crate::audit::{
+    AuditEntry, AuditLog, FailureCounters, FailureCounts, FailureStage, LogAuditLog,
+    RequestMetadata,
+};
+use crate::clock::{Clock, SystemClock};
+use crate::delay::{Sleeper, TokioSleeper};
+use crate::identity::ClientIdentity;
+use crate::metrics::Metrics;
+use crate::recorder::{
+    parse_recorded_c, Record, RecordedOutcome, RecordedRequest, RequestRecorder,
+};
+use crate::session::{RandomSessionIdGenerator, SessionIdGenerator, SessionKeyStrategy};
+use crate::signing::{LocalSigningBackend, SigningBackend};
 use crate::store::memory::MemoryStore;
-use crate::store::{Challenge, Session, Store, User};
+use crate::store::{Challenge, PurgeStats, Session, SessionEvent, Store, StoreError, User};
+use crate::token::SessionTokenIssuer;
+use crate::username::UsernamePolicy;
+
+/// The default maximum size in bytes of a decoded request message.
+pub const DEFAULT_MAX_DECODING_MESSAGE_SIZE: usize = 64 * 1024;
+
+/// The default maximum length in bytes of a request field, checked before it is parsed.
+pub const DEFAULT_MAX_FIELD_LENGTH: usize = 4096;
+
+/// The default maximum difference between the timestamp of a non-interactive proof and the time
+/// it is verified, in either direction to allow for clock skew.
+pub const DEFAULT_NONINTERACTIVE_PROOF_WINDOW: Duration = Duration::from_secs(60);
+
+/// What to do with a new challenge for a user who already has the maximum number of pending
+/// challenges.
+#[derive(
+    Debug, Default, Clone, Copy, EnumString, Display, VariantNames, ValueEnum, PartialEq, Eq,
+)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ChallengeLimitPolicy {
+    /// Remove the user's oldest pending challenge to make room for the new one.
+    #[default]
+    EvictOldest,
+
+    /// Reject the new challenge with resource_exhausted.
+    Reject,
+}
+
+/// The configuration served by the service along with the verifier built from it, which are
+/// swapped together when the configuration is reloaded.
+struct ActiveConfiguration {
+    configuration: Configuration,
+    verifier: Box<dyn Verifier>,
+}
 
 /// Service for the authentication protocol.
 pub struct Service {
-    verifier: Box<dyn Verifier>,
-    configuration: Configuration,
+    active: ArcSwap<ActiveConfiguration>,
+    configuration_updates: watch::Sender<Configuration>,
     store: Box<dyn Store>,
+    max_field_length: usize,
+    username_policy: UsernamePolicy,
+    session_id_generator: Box<dyn SessionIdGenerator>,
+    session_key_strategy: SessionKeyStrategy,
+    require_full_range_commitments: bool,
+    reject_trivial_commitments: bool,
+    auth_error_jitter: Duration,
+    sleeper: Box<dyn Sleeper>,
+    allow_anonymous_challenge: bool,
+    scope_registration_to_client_identity: bool,
+    recorder: Option<RequestRecorder>,
+    metrics: Option<Arc<Metrics>>,
+    audit_log: Box<dyn AuditLog>,
+    signing_backend: Option<Box<dyn SigningBackend>>,
+    challenge_mac_secret: Option<Vec<u8>>,
+    session_token_issuer: Option<SessionTokenIssuer>,
+    challenge_max_age: Option<Duration>,
+    noninteractive_proof_window: Duration,
+    max_challenges_per_user: Option<(usize, ChallengeLimitPolicy)>,
+    clock: Box<dyn Clock>,
+    duplicate_sessions_avoided: AtomicU64,
+    failure_counters: FailureCounters,
 }
 
 impl Service {
     /// Creates a new service with the given configuration and verifier.
     pub fn new(configuration: Configuration, verifier: Box<dyn Verifier>) -> Self {
+        let (configuration_updates, _) =
+            watch::channel(effective_configuration(&configuration, verifier.as_ref()));
         Self {
-            configuration,
-            verifier,
+            active: ArcSwap::from_pointee(ActiveConfiguration {
+                configuration,
+                verifier,
+            }),
+            configuration_updates,
             store: Box::<MemoryStore>::default(),
+            max_field_length: DEFAULT_MAX_FIELD_LENGTH,
+            username_policy: UsernamePolicy::default(),
+            session_id_generator: Box::new(RandomSessionIdGenerator),
+            session_key_strategy: SessionKeyStrategy::default(),
+            require_full_range_commitments: false,
+            reject_trivial_commitments: false,
+            auth_error_jitter: Duration::ZERO,
+            sleeper: Box::new(TokioSleeper),
+            allow_anonymous_challenge: false,
+            scope_registration_to_client_identity: false,
+            recorder: None,
+            metrics: None,
+            audit_log: Box::new(LogAuditLog),
+            signing_backend: None,
+            challenge_mac_secret: None,
+            session_token_issuer: None,
+            challenge_max_age: None,
+            noninteractive_proof_window: DEFAULT_NONINTERACTIVE_PROOF_WINDOW,
+            max_challenges_per_user: None,
+            clock: Box::new(SystemClock),
+            duplicate_sessions_avoided: AtomicU64::new(0),
+            failure_counters: FailureCounters::default(),
         }
     }
-}
 
-/// Implementation of the authentication protocol.
-#[tonic::async_trait]
-impl Auth for Service {
-    /// Returns the configuration for the authentication protocol.
-    async fn get_configuration(
+    /// Sets the store for users, challenges, and sessions.
+    pub(crate) fn with_store(mut self, store: Box<dyn Store>) -> Self {
+        self.store = store;
+        self
+    }
+
+    /// Sets the generator used for new session ids.
+    pub fn with_session_id_generator(
+        mut self,
+        session_id_generator: Box<dyn SessionIdGenerator>,
+    ) -> Self {
+        self.session_id_generator = session_id_generator;
+        self
+    }
+
+    /// Sets the strategy for keying sessions, which decides when a successful verification
+    /// returns an existing session.
+    pub fn with_session_key_strategy(mut self, strategy: SessionKeyStrategy) -> Self {
+        self.session_key_strategy = strategy;
+        self
+    }
+
+    /// Sets the maximum length in bytes of a request field.
+    pub fn with_max_field_length(mut self, max_field_length: usize) -> Self {
+        self.max_field_length = max_field_length;
+        self
+    }
+
+    /// Sets the policy that usernames are normalized with before they are validated, stored, or
+    /// looked up by register, create_authentication_challenge, register_and_challenge, and
+    /// get_user.
+    pub fn with_username_policy(mut self, username_policy: UsernamePolicy) -> Self {
+        self.username_policy = username_policy;
+        self
+    }
+
+    /// Sets whether y1, y2, r1, and r2 are required to fall within the full range of the group,
+    /// rejecting trivial values.
+    pub fn with_require_full_range_commitments(mut self, require: bool) -> Self {
+        self.require_full_range_commitments = require;
+        self
+    }
+
+    /// Sets whether to reject challenges whose r1 or r2 is the group identity, which signals a
+    /// broken client using k = 0, instead of only logging a warning.
+    pub fn with_reject_trivial_commitments(mut self, reject: bool) -> Self {
+        self.reject_trivial_commitments = reject;
+        self
+    }
+
+    /// Sets the maximum random delay added before returning an error from verify_authentication,
+    /// so response timing does not distinguish an unknown challenge or user from a wrong proof.
+    pub fn with_auth_error_jitter(mut self, auth_error_jitter: Duration) -> Self {
+        self.auth_error_jitter = auth_error_jitter;
+        self
+    }
+
+    /// Sets the sleeper used to apply delays.
+    pub fn with_sleeper(mut self, sleeper: Box<dyn Sleeper>) -> Self {
+        self.sleeper = sleeper;
+        self
+    }
+
+    /// Sets whether to issue a throwaway challenge for unknown users instead of returning
+    /// not_found, so usernames cannot be enumerated. Authentication then fails only at
+    /// verify_authentication, with the same error as a wrong proof.
+    pub fn with_allow_anonymous_challenge(mut self, allow: bool) -> Self {
+        self.allow_anonymous_challenge = allow;
+        self
+    }
+
+    /// Sets whether register and register_and_challenge requests may only register the user named
+    /// by the common name of the client certificate they were sent with over mutual TLS, and are
+    /// otherwise rejected with permission_denied.
+    pub fn with_scope_registration_to_client_identity(mut self, scope: bool) -> Self {
+        self.scope_registration_to_client_identity = scope;
+        self
+    }
+
+    /// Sets the recorder that each register, create_authentication_challenge, and
+    /// verify_authentication request is recorded to, along with its outcome, for later replay.
+    pub fn with_recorder(mut self, recorder: RequestRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// Sets the metrics that registrations, challenges, and verifications are counted in. The
+    /// metrics may be shared between services, such as those serving each flavor.
+    pub fn with_metrics(mut self, metrics: Arc<Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Sets the audit log that an entry is written to for each authentication attempt, such as a
+    /// verify_authentication, update_credentials, or unregister request, including the request
+    /// metadata allowlisted by the metadata interceptor.
+    pub fn with_audit_log(mut self, audit_log: Box<dyn AuditLog>) -> Self {
+        self.audit_log = audit_log;
+        self
+    }
+
+    /// Sets the server's long-term key that each authentication challenge's (auth_id, c) is signed
+    /// with, so clients holding the public key can authenticate the server.
+    pub fn with_signing_key(self, signing_key: SigningKey) -> Self {
+        self.with_signing_backend(Box::new(LocalSigningBackend::new(signing_key)))
+    }
+
+    /// Sets the backend holding the server's long-term key, like `with_signing_key`, for keys that
+    /// are not held in memory, such as in a KMS.
+    pub fn with_signing_backend(mut self, signing_backend: Box<dyn SigningBackend>) -> Self {
+        self.signing_backend = Some(signing_backend);
+        self
+    }
+
+    /// Sets the secret that each authentication challenge's (auth_id, c) is MACed with. Clients
+    /// echo c and the MAC back with their answer, and verify_authentication rejects answers whose
+    /// c was altered in transit.
+    pub fn with_challenge_mac_secret(mut self, secret: impl Into<Vec<u8>>) -> Self {
+        self.challenge_mac_secret = Some(secret.into());
+        self
+    }
+
+    /// Sets the issuer of the signed session tokens returned alongside the session id of each
+    /// successful verification.
+    pub fn with_session_token_issuer(mut self, issuer: SessionTokenIssuer) -> Self {
+        self.session_token_issuer = Some(issuer);
+        self
+    }
+
+    /// Sets the maximum time between issuing a challenge and verifying its answer, after which
+    /// verify_authentication returns deadline_exceeded, independently of when the store evicts
+    /// the challenge.
+    pub fn with_challenge_max_age(mut self, challenge_max_age: Duration) -> Self {
+        self.challenge_max_age = Some(challenge_max_age);
+        self
+    }
+
+    /// Sets the maximum difference between the timestamp of a non-interactive proof and the time
+    /// it is verified, after which authenticate returns deadline_exceeded. Proofs are remembered
+    /// for twice this long, so one cannot be replayed while it is fresh.
+    pub fn with_noninteractive_proof_window(mut self, window: Duration) -> Self {
+        self.noninteractive_proof_window = window;
+        self
+    }
+
+    /// Sets the maximum number of pending challenges per user, and what to do with a new challenge
+    /// beyond it, so a single user cannot fill the store with challenges before they expire.
+    pub fn with_max_challenges_per_user(
+        mut self,
+        max_challenges_per_user: usize,
+        policy: ChallengeLimitPolicy,
+    ) -> Self {
+        self.max_challenges_per_user = Some((max_challenges_per_user, policy));
+        self
+    }
+
+    /// Sets the clock used to timestamp challenges and check their age.
+    pub fn with_clock(mut self, clock: Box<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the configuration of the service.
+    pub fn configuration(&self) -> Configuration {
+        self.active().configuration.clone()
+    }
+
+    /// Replaces the configuration and the verifier built from it, such as after the configuration
+    /// file changed, and notifies configuration watchers. Requests already in flight finish with
+    /// the configuration they started with. Returns how the configuration changed.
+    /// # Errors
+    /// * Returns an error if the public parameters changed, which invalidates every existing
+    ///   registration, unless the reload is forced.
+    pub fn reload(
         &self,
-        _: Request<GetConfigurationRequest>,
-    ) -> Result<Response<Configuration>, Status> {
-        Ok(Response::new(self.configuration.clone()))
+        configuration: Configuration,
+        verifier: Box<dyn Verifier>,
+        force: bool,
+    ) -> anyhow::Result<ConfigurationChange> {
+        let change = self.active().configuration.diff(&configuration);
+        match change {
+            ConfigurationChange::Identical => return Ok(change),
+            ConfigurationChange::Breaking if !force => {
+                return Err(anyhow::Error::msg(
+                    "the public parameters changed, which would invalidate existing registrations",
+                ));
+            }
+            ConfigurationChange::Breaking => {
+                log::warn!("Reloading configuration with changed public parameters; existing registrations will no longer verify.");
+            }
+            ConfigurationChange::Benign => {}
+        }
+
+        self.configuration_updates
+            .send_replace(effective_configuration(&configuration, verifier.as_ref()));
+        self.active.store(Arc::new(ActiveConfiguration {
+            configuration,
+            verifier,
+        }));
+        Ok(change)
     }
 
-    /// Registers a new user with the given y1 and y2, returning an error if the user is already registered.
+    /// Returns the active configuration and verifier.
+    fn active(&self) -> Arc<ActiveConfiguration> {
+        self.active.load_full()
+    }
+
+    /// Returns the number of successful verifications that returned an existing session instead
+    /// of creating a duplicate, such as a client retrying an answer whose response was lost.
+    pub fn duplicate_sessions_avoided(&self) -> u64 {
+        self.duplicate_sessions_avoided.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of failed requests answering a challenge or proving a registration, such
+    /// as verify_authentication, by the stage they failed at. The stage is not revealed to
+    /// clients, whose errors are the same as without it.
+    pub fn verification_failures(&self) -> FailureCounts {
+        self.failure_counters.counts()
+    }
+
+    /// Wraps the service in a server that rejects messages larger than the given size once
+    /// decoded. Compressed requests are not accepted, so the size limit cannot be bypassed with a
+    /// highly compressed payload.
+    pub fn into_server(self, max_decoding_message_size: usize) -> AuthServer<Self> {
+        Arc::new(self).into_shared_server(max_decoding_message_size)
+    }
+
+    /// Wraps a shared service in a server like `into_server`, so the service can still be used
+    /// alongside the server, such as by a background sweeper.
+    pub fn into_shared_server(
+        self: Arc<Self>,
+        max_decoding_message_size: usize,
+    ) -> AuthServer<Self> {
+        AuthServer::from_arc(self).max_decoding_message_size(max_decoding_message_size)
+    }
+
+    /// Removes every expired challenge and session from the store, and returns how many were
+    /// removed.
+    pub(crate) async fn purge_expired(&self) -> Result<PurgeStats, StoreError> {
+        self.store.purge_expired().await
+    }
+
+    /// Registers a user from a registration blob produced offline by a prover, on behalf of the
+    /// client with the given identity, applying the same validation and registration scope as the
+    /// register RPC. Unlike the RPC, the registration is not recorded.
     /// # Errors
-    /// * Returns an error if the user is already registered.
-    /// * Returns an error if the user is empty.
+    /// * Returns an error if the user is empty or already registered.
     /// * Returns an error if y1 or y2 is invalid.
-    async fn register(
+    /// * Returns an error if registration is scoped to client identities and the identity is
+    ///   missing or names another user.
+    #[allow(clippy::result_large_err)]
+    pub async fn register_blob(
         &self,
-        request: Request<RegisterRequest>,
-    ) -> Result<Response<RegisterResponse>, Status> {
-        let request = request.into_inner();
+        blob: RegistrationBlob,
+        identity: Option<&ClientIdentity>,
+    ) -> Result<(), Status> {
+        self.check_registration_scope(&blob.user, identity)?;
+        self.register_user(blob.into()).await?;
+        Ok(())
+    }
 
-        if request.user.is_empty() {
-            return Err(Status::invalid_argument("Invalid user argument"));
+    /// Re-runs a recorded request against the service, reusing the recorded auth_id and c for
+    /// challenges so that later verifications see the same inputs, and returns its outcome.
+    /// Requests are not recorded again and errors are not delayed.
+    pub async fn replay_request(&self, request: RecordedRequest) -> RecordedOutcome {
+        match request {
+            RecordedRequest::Register {
+                user,
+                y1,
+                y2,
+                metadata,
+            } => RecordedOutcome::from_result(
+                &self
+                    .register_user(RegisterRequest {
+                        user,
+                        y1,
+                        y2,
+                        metadata,
+                    })
+                    .await,
+            ),
+            RecordedRequest::CreateAuthenticationChallenge {
+                user,
+                r1,
+                r2,
+                auth_id,
+                c,
+            } => {
+                let (Ok(auth_id), Ok(c)) = (Uuid::parse_str(&auth_id), parse_recorded_c(&c)) else {
+                    return RecordedOutcome::from_status(&Status::invalid_argument(
+                        "Invalid recorded auth_id or c",
+                    ));
+                };
+                let request = AuthenticationChallengeRequest { user, r1, r2 };
+                RecordedOutcome::from_result(&self.create_challenge(request, auth_id, c).await)
+            }
+            RecordedRequest::RegisterAndChallenge {
+                user,
+                y1,
+                y2,
+                metadata,
+                r1,
+                r2,
+                auth_id,
+                c,
+            } => {
+                let (Ok(auth_id), Ok(c)) = (Uuid::parse_str(&auth_id), parse_recorded_c(&c)) else {
+                    return RecordedOutcome::from_status(&Status::invalid_argument(
+                        "Invalid recorded auth_id or c",
+                    ));
+                };
+                let request = RegisterAndChallengeRequest {
+                    user,
+                    y1,
+                    y2,
+                    metadata,
+                    r1,
+                    r2,
+                };
+                RecordedOutcome::from_result(
+                    &self
+                        .register_and_create_challenge(request, auth_id, c)
+                        .await,
+                )
+            }
+            RecordedRequest::VerifyAuthentication { auth_id, s, c, mac } => {
+                let request = AuthenticationAnswerRequest { auth_id, s, c, mac };
+                let result = self.verify_authentication_answer(request).await;
+                RecordedOutcome::from_result(&result.map_err(|(_, status)| status))
+            }
+            RecordedRequest::Authenticate {
+                user,
+                r1,
+                r2,
+                s,
+                timestamp,
+            } => {
+                let request = AuthenticateRequest {
+                    user,
+                    r1,
+                    r2,
+                    s,
+                    timestamp,
+                };
+                // Check freshness as of when the proof was computed, since it is replayed later.
+                let now = SystemTime::UNIX_EPOCH
+                    .checked_add(Duration::from_secs(timestamp))
+                    .unwrap_or(SystemTime::UNIX_EPOCH);
+                let result = self.verify_noninteractive_proof(request, now).await;
+                RecordedOutcome::from_result(&result.map_err(|(_, status)| status))
+            }
         }
+    }
 
-        let y1: Element = request
-            .y1
-            .parse()
-            .map_err(|_| tonic::Status::invalid_argument("Invalid y1 argument"))?;
+    /// Records a request and its outcome, if a recorder is set. Failures to record are logged
+    /// rather than failing the request.
+    fn record<T>(&self, request: RecordedRequest, result: &Result<T, Status>) {
+        let Some(recorder) = &self.recorder else {
+            return;
+        };
+        let record = Record {
+            config_version: self.active().configuration.config_version(),
+            request,
+            outcome: RecordedOutcome::from_result(result),
+        };
+        if let Err(e) = recorder.record(&record) {
+            log::warn!("Failed to record request: {}", e);
+        }
+    }
 
-        let y2: Element = request
-            .y2
-            .parse()
-            .map_err(|_| tonic::Status::invalid_argument("Invalid y2 argument"))?;
+    /// Counts, records, and audits the outcome of an authentication attempt started at the given
+    /// instant, and delays its error by the auth error jitter, if configured. Requests that the
+    /// recorder has no entry for, such as update_credentials, are not recorded.
+    async fn complete_authentication<T>(
+        &self,
+        result: Result<T, (FailureStage, Status)>,
+        started: Instant,
+        recorded: Option<RecordedRequest>,
+        auth_id: String,
+        metadata: RequestMetadata,
+    ) -> Result<Response<T>, Status> {
+        let (result, failure) = match result {
+            Ok(response) => (Ok(response), None),
+            Err((stage, status)) => {
+                self.failure_counters.increment(stage);
+                (Err(status), Some(stage))
+            }
+        };
+        if let Some(metrics) = &self.metrics {
+            metrics.record_verify(result.is_ok(), started.elapsed());
+        }
+        if let Some(recorded) = recorded {
+            self.record(recorded, &result);
+        }
+        self.audit_log.write(&AuditEntry {
+            auth_id,
+            outcome: RecordedOutcome::from_result(&result),
+            failure,
+            metadata: metadata.0,
+        });
+        match result {
+            Ok(response) => Ok(Response::new(response)),
+            Err(status) => {
+                self.apply_auth_error_jitter().await;
+                Err(status)
+            }
+        }
+    }
 
-        if self
-            .store
-            .get_user(&request.user)
-            .map_err(|_| Status::internal("Failed to get user"))?
-            .is_some()
+    /// Sleeps for a random duration up to the auth error jitter, if any.
+    async fn apply_auth_error_jitter(&self) {
+        if self.auth_error_jitter.is_zero() {
+            return;
+        }
+        let max = self.auth_error_jitter.as_millis() as u64;
+        let delay = Duration::from_millis(rand::thread_rng().gen_range(0..=max));
+        self.sleeper.sleep(delay).await;
+    }
+
+    /// Registers a batch of users from registration blobs sent by the client with the given
+    /// identity, applying the same validation and registration scope as the register RPC, and
+    /// returns the result for each blob in order. Blobs carry no metadata, so there is none to
+    /// check.
+    ///
+    /// The per-entry validation runs in parallel on the rayon thread pool, off the async runtime,
+    /// while the users are inserted in order afterwards, so the results are deterministic; a user
+    /// repeated within the batch is registered by its first valid entry.
+    #[allow(clippy::result_large_err)]
+    pub async fn register_batch(
+        self: &Arc<Self>,
+        blobs: Vec<RegistrationBlob>,
+        identity: Option<&ClientIdentity>,
+    ) -> Vec<Result<(), Status>> {
+        let scopes: Vec<Result<(), Status>> = blobs
+            .iter()
+            .map(|blob| self.check_registration_scope(&blob.user, identity))
+            .collect();
+        let blobs: Vec<RegistrationBlob> = blobs
+            .into_iter()
+            .map(|blob| RegistrationBlob {
+                user: self.username_policy.normalize(&blob.user),
+                ..blob
+            })
+            .collect();
+
+        let service = self.clone();
+        let (blobs, users) = match tokio::task::spawn_blocking(move || {
+            let users = service.parse_registrations(&blobs);
+            (blobs, users)
+        })
+        .await
         {
-            return Err(Status::already_exists("User already registered"));
+            Ok(parsed) => parsed,
+            Err(e) => {
+                log::error!("Failed to validate registration batch: {}", e);
+                return scopes
+                    .iter()
+                    .map(|_| Err(Status::internal("Failed to validate registration")))
+                    .collect();
+            }
+        };
+
+        let mut results = Vec::with_capacity(blobs.len());
+        for ((blob, scope), user) in blobs.into_iter().zip(scopes).zip(users) {
+            let result = match scope.and(user) {
+                Ok(user) => self.insert_registered_user(&blob.user, user).await,
+                Err(status) => Err(status),
+            };
+            self.record(
+                RecordedRequest::Register {
+                    user: blob.user,
+                    y1: blob.y1,
+                    y2: blob.y2,
+                    metadata: HashMap::new(),
+                },
+                &result,
+            );
+            if let (Ok(_), Some(metrics)) = (&result, &self.metrics) {
+                metrics.record_register();
+            }
+            results.push(result);
         }
+        results
+    }
 
-        self.store
-            .insert_user(&request.user, User { y1, y2 })
-            .map_err(|_| Status::internal("Failed to insert user into store"))?;
+    /// Validates the registration blobs in parallel on the current rayon thread pool, and
+    /// returns the parsed user for each blob in order.
+    #[allow(clippy::result_large_err)]
+    fn parse_registrations(&self, blobs: &[RegistrationBlob]) -> Vec<Result<User, Status>> {
+        blobs
+            .par_iter()
+            .map(|blob| self.parse_registration(&blob.user, &blob.y1, &blob.y2))
+            .collect()
+    }
 
-        Ok(Response::new(RegisterResponse {}))
+    /// Registers a user with the given y1 and y2, along with any metadata.
+    async fn register_user(
+        &self,
+        mut request: RegisterRequest,
+    ) -> Result<RegisterResponse, Status> {
+        request.user = self.username_policy.normalize(&request.user);
+        let mut user = self.parse_registration(&request.user, &request.y1, &request.y2)?;
+        self.check_metadata(&request.metadata)?;
+        user.metadata = request.metadata;
+        self.insert_registered_user(&request.user, user).await?;
+        Ok(RegisterResponse {})
     }
 
-    /// Creates a new challenge using the given commitment, and returns c in the response along
-    /// with the challenge auth id.
-    /// # Errors
-    /// * Returns an error if the user is not found.
-    /// * Returns an error if the user is empty.
-    /// * Returns an error if r1 or r2 is invalid.
-    async fn create_authentication_challenge(
+    /// Checks that the client may register the given user, which must be the user named by its
+    /// identity if registration is scoped to client identities, after both are normalized.
+    #[allow(clippy::result_large_err)]
+    fn check_registration_scope(
         &self,
-        request: Request<AuthenticationChallengeRequest>,
-    ) -> Result<Response<AuthenticationChallengeResponse>, Status> {
-        let request = request.into_inner();
+        user: &str,
+        identity: Option<&ClientIdentity>,
+    ) -> Result<(), Status> {
+        if !self.scope_registration_to_client_identity {
+            return Ok(());
+        }
+        match identity {
+            Some(ClientIdentity(identity))
+                if self.username_policy.normalize(identity)
+                    == self.username_policy.normalize(user) =>
+            {
+                Ok(())
+            }
+            Some(_) => Err(Status::permission_denied(
+                "User does not match the client certificate",
+            )),
+            None => Err(Status::permission_denied(
+                "Registration requires a client certificate",
+            )),
+        }
+    }
 
-        if request.user.is_empty() {
+    /// Checks that every metadata key is non-empty, and that keys and values do not exceed the
+    /// maximum field length.
+    #[allow(clippy::result_large_err)]
+    fn check_metadata(&self, metadata: &HashMap<String, String>) -> Result<(), Status> {
+        for (key, value) in metadata {
+            if key.is_empty() {
+                return Err(Status::invalid_argument(
+                    "Invalid metadata argument: empty key",
+                ));
+            }
+            self.check_field_length("metadata", key)?;
+            self.check_field_length("metadata", value)?;
+        }
+        Ok(())
+    }
+
+    /// Validates the registration arguments and parses them into a user.
+    #[allow(clippy::result_large_err)]
+    fn parse_registration(&self, user: &str, y1: &str, y2: &str) -> Result<User, Status> {
+        if user.is_empty() {
             return Err(Status::invalid_argument("Invalid user argument"));
         }
+        self.check_field_length("user", user)?;
 
-        let r1: Element = request
-            .r1
-            .parse()
-            .map_err(|_| tonic::Status::invalid_argument("Invalid r1 argument"))?;
-        let r2: Element = request
-            .r2
-            .parse()
-            .map_err(|_| tonic::Status::invalid_argument("Invalid r2 argument"))?;
+        let y1: Element = self.parse_field("y1", y1)?;
+
+        let y2: Element = self.parse_field("y2", y2)?;
+        self.check_flavor("y1", &y1)?;
+        self.check_flavor("y2", &y2)?;
+        self.check_point("y1", &y1)?;
+        self.check_point("y2", &y2)?;
+        self.check_commitment_range("y1", &y1)?;
+        self.check_commitment_range("y2", &y2)?;
+        self.check_element_valid("y1", &y1)?;
+        self.check_element_valid("y2", &y2)?;
+
+        Ok(User {
+            y1,
+            y2,
+            metadata: Default::default(),
+        })
+    }
+
+    /// Inserts a registered user, unless the user is already registered.
+    #[allow(clippy::result_large_err)]
+    async fn insert_registered_user(&self, username: &str, user: User) -> Result<(), Status> {
+        let inserted = self
+            .store
+            .insert_user_if_absent(username, user)
+            .await
+            .map_err(|err| store_error_status("Failed to insert user into store", err))?;
+        if !inserted {
+            return Err(Status::already_exists("User already registered"));
+        }
+        Ok(())
+    }
+
+    /// Creates a challenge with the given auth_id for the commitment, with the given challenge
+    /// number c, or one generated for the commitment if not given.
+    async fn create_challenge(
+        &self,
+        mut request: AuthenticationChallengeRequest,
+        auth_id: Uuid,
+        c: Option<Scalar>,
+    ) -> Result<AuthenticationChallengeResponse, Status> {
+        request.user = self.username_policy.normalize(&request.user);
+        let (r1, r2) = self.parse_commitment(&request.user, &request.r1, &request.r2)?;
 
-        self.store
+        let user = self
+            .store
             .get_user(&request.user)
-            .map_err(|_| Status::internal("Failed to get user"))?
-            .ok_or(Status::not_found("User not found"))?;
+            .await
+            .map_err(|err| store_error_status("Failed to get user", err))?;
+        if user.is_none() && !self.allow_anonymous_challenge {
+            return Err(Status::not_found("User not found"));
+        }
 
-        // Generate random challenge number c.
-        let c = self.verifier.generate_challenge_c();
-        log::info!("c = {:?}", c);
+        // For unknown users this is a throwaway challenge, stored like any other so the response
+        // is indistinguishable. It is bound to a zero registration, so a verifier binding
+        // challenges treats it like any other too.
+        let c = c.unwrap_or_else(|| {
+            let (y1, y2) = match user {
+                Some(user) => (user.y1, user.y2),
+                None => (Element::zero(), Element::zero()),
+            };
+            self.generate_challenge_c(y1, y2, &r1, &r2)
+        });
+        log::info!("c = {}", c);
 
         // Store (auth_id, (user, c)) for use in verify_authentication.
-        let auth_id = Uuid::new_v4();
-        self.store
-            .insert_challenge(
+        let challenge = Challenge {
+            user: request.user,
+            c: c.clone(),
+            r1,
+            r2,
+            issued_at: self.clock.now(),
+        };
+        let inserted = match self.max_challenges_per_user {
+            Some((max, policy)) => {
+                self.store
+                    .insert_challenge_limited(
+                        auth_id,
+                        challenge,
+                        max,
+                        policy == ChallengeLimitPolicy::EvictOldest,
+                    )
+                    .await
+            }
+            None => self
+                .store
+                .insert_challenge(auth_id, challenge)
+                .await
+                .map(|()| true),
+        }
+        .map_err(|err| store_error_status("Failed to insert challenge into store", err))?;
+        if !inserted {
+            return Err(Status::resource_exhausted(
+                "Too many pending challenges for user",
+            ));
+        }
+
+        self.challenge_response(auth_id, c).await
+    }
+
+    /// Registers a user and creates a challenge for it with the given auth_id, and the given
+    /// challenge number c, or one generated for the commitment if not given. Both requests are
+    /// validated before anything is stored, and the user and challenge are inserted together, so
+    /// a failure leaves neither behind.
+    async fn register_and_create_challenge(
+        &self,
+        mut request: RegisterAndChallengeRequest,
+        auth_id: Uuid,
+        c: Option<Scalar>,
+    ) -> Result<AuthenticationChallengeResponse, Status> {
+        request.user = self.username_policy.normalize(&request.user);
+        let mut user = self.parse_registration(&request.user, &request.y1, &request.y2)?;
+        self.check_metadata(&request.metadata)?;
+        user.metadata = request.metadata;
+        let (r1, r2) = self.parse_commitment(&request.user, &request.r1, &request.r2)?;
+
+        let c = c.unwrap_or_else(|| {
+            self.generate_challenge_c(user.y1.clone(), user.y2.clone(), &r1, &r2)
+        });
+        log::info!("c = {}", c);
+
+        let inserted = self
+            .store
+            .insert_user_with_challenge(
+                &request.user,
+                user,
                 auth_id,
                 Challenge {
-                    user: request.user,
+                    user: request.user.clone(),
                     c: c.clone(),
                     r1,
                     r2,
+                    issued_at: self.clock.now(),
                 },
             )
-            .map_err(|_| Status::internal("Failed to insert challenge into store"))?;
+            .await
+            .map_err(|err| store_error_status("Failed to insert user into store", err))?;
+        if !inserted {
+            return Err(Status::already_exists("User already registered"));
+        }
+
+        self.challenge_response(auth_id, c).await
+    }
+
+    /// Generates a challenge number c for the commitment, passing the verifier the commitment and
+    /// the registration so that it can bind c to them.
+    fn generate_challenge_c(&self, y1: Element, y2: Element, r1: &Element, r2: &Element) -> Scalar {
+        self.active()
+            .verifier
+            .generate_challenge_c_for(&ChallengeContext {
+                y1,
+                y2,
+                r1: r1.clone(),
+                r2: r2.clone(),
+            })
+    }
+
+    /// Validates a challenge's user and commitment, and parses r1 and r2.
+    #[allow(clippy::result_large_err)]
+    fn parse_commitment(
+        &self,
+        user: &str,
+        r1: &str,
+        r2: &str,
+    ) -> Result<(Element, Element), Status> {
+        if user.is_empty() {
+            return Err(Status::invalid_argument("Invalid user argument"));
+        }
+        self.check_field_length("user", user)?;
 
-        Ok(Response::new(AuthenticationChallengeResponse {
+        let r1: Element = self.parse_field("r1", r1)?;
+        let r2: Element = self.parse_field("r2", r2)?;
+        self.check_flavor("r1", &r1)?;
+        self.check_flavor("r2", &r2)?;
+        self.check_point("r1", &r1)?;
+        self.check_point("r2", &r2)?;
+        self.check_trivial_commitment(user, "r1", &r1)?;
+        self.check_trivial_commitment(user, "r2", &r2)?;
+        self.check_commitment_range("r1", &r1)?;
+        self.check_commitment_range("r2", &r2)?;
+        self.check_element_valid("r1", &r1)?;
+        self.check_element_valid("r2", &r2)?;
+        Ok((r1, r2))
+    }
+
+    /// Builds the response for a stored challenge, with its MAC and signature if configured.
+    async fn challenge_response(
+        &self,
+        auth_id: Uuid,
+        c: Scalar,
+    ) -> Result<AuthenticationChallengeResponse, Status> {
+        let response = AuthenticationChallengeResponse {
             auth_id: auth_id.to_string(),
             c: c.to_string(),
-        }))
+            signature: "".to_string(),
+            mac: "".to_string(),
+        };
+        let response = match &self.challenge_mac_secret {
+            Some(secret) => response.with_mac(secret),
+            None => response,
+        };
+        Ok(match &self.signing_backend {
+            Some(backend) => {
+                let signature = backend
+                    .sign(&response.signed_message())
+                    .await
+                    .map_err(|err| {
+                        log::error!("Failed to sign challenge: {}", err);
+                        Status::internal("Failed to sign challenge")
+                    })?;
+                response.with_signature(&signature)
+            }
+            None => response,
+        })
     }
 
-    /// Verifies the given s and creates a new session based on it if necessary, returning the
-    /// session id in the response.
-    /// # Errors
-    /// * Returns an error if the challenge is not found.
-    /// * Returns an error if the user is not found.
-    /// * Returns an error if the auth_id argument is empty.
-    /// * Returns an error if s is invalid.
-    /// * Returns an error if the verification fails.
-    async fn verify_authentication(
+    /// Verifies the answer to a challenge, returning the challenge, the user it was for, and s,
+    /// or the error along with the stage it failed at.
+    #[allow(clippy::result_large_err)]
+    async fn verify_answer(
         &self,
-        request: Request<AuthenticationAnswerRequest>,
-    ) -> Result<Response<AuthenticationAnswerResponse>, Status> {
-        let request = request.into_inner();
+        request: &AuthenticationAnswerRequest,
+    ) -> Result<(Challenge, User, Scalar), (FailureStage, Status)> {
+        let at = |stage: FailureStage| move |status: Status| (stage, status);
 
-        let s: Scalar = request
-            .s
-            .parse()
-            .map_err(|_| tonic::Status::invalid_argument("Invalid s argument"))?;
+        let s: Scalar = self
+            .parse_field("s", &request.s)
+            .map_err(at(FailureStage::Parse))?;
+        self.check_scalar_valid("s", &s)
+            .map_err(at(FailureStage::Parse))?;
 
         if request.auth_id.is_empty() {
-            return Err(Status::invalid_argument("Invalid auth_id argument"));
+            return Err((
+                FailureStage::Parse,
+                Status::invalid_argument("Invalid auth_id argument"),
+            ));
         }
 
-        let challenge_id = Uuid::parse_str(&request.auth_id)
-            .map_err(|_| Status::invalid_argument("Invalid auth_id argument"))?;
+        let challenge_id = Uuid::parse_str(&request.auth_id).map_err(|_| {
+            (
+                FailureStage::Parse,
+                Status::invalid_argument("Invalid auth_id argument"),
+            )
+        })?;
+        // Every auth_id the server issues is a random UUID, so any other version is forged.
+        if challenge_id.get_version() != Some(uuid::Version::Random) {
+            return Err((
+                FailureStage::Parse,
+                Status::invalid_argument("Invalid auth_id argument: not a version 4 UUID"),
+            ));
+        }
 
+        // The challenge is taken from the store so that it can only be answered once, whether or
+        // not the answer verifies.
         let challenge = self
             .store
-            .get_challenge(challenge_id)
-            .map_err(|_| Status::internal("Failed to get challenge"))?
-            .ok_or_else(|| Status::not_found("Challenge not found"))?;
+            .take_challenge(challenge_id)
+            .await
+            .map_err(|err| {
+                (
+                    FailureStage::Other,
+                    store_error_status("Failed to get challenge", err),
+                )
+            })?
+            .ok_or_else(|| {
+                (
+                    FailureStage::Other,
+                    Status::not_found("Challenge not found"),
+                )
+            })?;
+        self.check_challenge_age(&challenge)
+            .map_err(at(FailureStage::Other))?;
+        self.check_challenge_mac(request, &challenge)
+            .map_err(at(FailureStage::Other))?;
 
         let user = self
             .store
             .get_user(&challenge.user)
-            .map_err(|_| Status::internal("Failed to get user"))?
-            .ok_or_else(|| Status::not_found("User not found"))?;
+            .await
+            .map_err(|err| {
+                (
+                    FailureStage::Other,
+                    store_error_status("Failed to get user", err),
+                )
+            })?
+            .ok_or_else(|| {
+                // Don't reveal that the user is unknown when challenges are issued anonymously.
+                let status = if self.allow_anonymous_challenge {
+                    Status::failed_precondition("Verification failed")
+                } else {
+                    Status::not_found("User not found")
+                };
+                (FailureStage::UnknownUser, status)
+            })?;
 
         // Verify and return error if not correct.
-        let (r1, r2) = self
+        let verified = self
+            .active()
             .verifier
-            .compute_verification_r1r2(user.y1.clone(), user.y2.clone(), challenge.c, s.clone())
-            .map_err(|_| Status::internal("Failed to compute verification r1r2"))?;
+            .verify(
+                user.y1.clone(),
+                user.y2.clone(),
+                &challenge.r1,
+                &challenge.r2,
+                challenge.c.clone(),
+                s.clone(),
+            )
+            .map_err(|_| {
+                (
+                    FailureStage::Verification,
+                    Status::internal("Failed to compute verification r1r2"),
+                )
+            })?;
 
-        if r1 != challenge.r1 || r2 != challenge.r2 {
-            return Err(Status::failed_precondition("Verification failed"));
+        if !verified {
+            return Err((
+                FailureStage::Verification,
+                Status::failed_precondition("Verification failed"),
+            ));
         }
 
-        let session_key = s.to_string();
-        let session = match self
-            .store
-            .get_session(&session_key)
-            .map_err(|_| Status::internal("Failed to get session"))?
-        {
-            None => {
-                let session = Session { id: Uuid::new_v4() };
-                self.store
-                    .insert_session(&session_key, session)
-                    .map_err(|_| Status::internal("Failed to insert session into store"))?;
-                session
-            }
-            Some(session) => session,
-        };
+        Ok((challenge, user, s))
+    }
 
-        Ok(Response::new(AuthenticationAnswerResponse {
-            session_id: session.id.to_string(),
-        }))
+    /// Verifies the answer to a challenge, returning the session for the authenticated user, or
+    /// the error along with the stage it failed at.
+    async fn verify_authentication_answer(
+        &self,
+        request: AuthenticationAnswerRequest,
+    ) -> Result<AuthenticationAnswerResponse, (FailureStage, Status)> {
+        let (challenge, _, s) = self.verify_answer(&request).await?;
+        self.create_session(challenge.user, &s).await
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use anyhow::Result;
-    use num_traits::One;
-    use tonic::Code;
-    use tonic::Request;
-    use zkauth::discrete_logarithm::{
-        configuration::DiscreteLogarithmConfiguration, verifier::DiscreteLogarithmVerifier,
-    };
+    /// Verifies a non-interactive proof for a user at the given time, re-deriving its challenge
+    /// from the user's registration, the commitment, the normalized user, and the timestamp, and
+    /// returns the session for the user, or the error along with the stage it failed at. A proof
+    /// outside the freshness window, or already used within it, is rejected.
+    async fn verify_noninteractive_proof(
+        &self,
+        mut request: AuthenticateRequest,
+        now: SystemTime,
+    ) -> Result<AuthenticationAnswerResponse, (FailureStage, Status)> {
+        let at = |stage: FailureStage| move |status: Status| (stage, status);
 
-    /// Creates a new service for testing.
-    fn test_service() -> Service {
-        let config = DiscreteLogarithmConfiguration::generate(16);
-        let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
-        Service::new(config.into(), verifier)
-    }
+        request.user = self.username_policy.normalize(&request.user);
+        let (r1, r2) = self
+            .parse_commitment(&request.user, &request.r1, &request.r2)
+            .map_err(at(FailureStage::Parse))?;
+        let s: Scalar = self
+            .parse_field("s", &request.s)
+            .map_err(at(FailureStage::Parse))?;
+        self.check_scalar_valid("s", &s)
+            .map_err(at(FailureStage::Parse))?;
+        self.check_proof_freshness(request.timestamp, now)
+            .map_err(at(FailureStage::Other))?;
 
-    #[cfg(test)]
+        let user = self
+            .store
+            .get_user(&request.user)
+            .await
+            .map_err(|err| {
+                (
+                    FailureStage::Other,
+                    store_error_status("Failed to get user", err),
+                )
+            })?
+            .ok_or_else(|| {
+                // Don't reveal that the user is unknown when challenges are issued anonymously.
+                let status = if self.allow_anonymous_challenge {
+                    Status::failed_precondition("Verification failed")
+                } else {
+                    Status::not_found("User not found")
+                };
+                (FailureStage::UnknownUser, status)
+            })?;
+
+        let proof = Proof {
+            context: ChallengeContext {
+                y1: user.y1,
+                y2: user.y2,
+                r1,
+                r2,
+            },
+            binding: ProofBinding {
+                user: request.user.clone(),
+                timestamp: request.timestamp,
+            },
+            s: s.clone(),
+        };
+        let verified = self
+            .active()
+            .verifier
+            .verify_noninteractive(&proof)
+            .map_err(|_| {
+                (
+                    FailureStage::Verification,
+                    Status::internal("Failed to compute verification r1r2"),
+                )
+            })?;
+        if !verified {
+            return Err((
+                FailureStage::Verification,
+                Status::failed_precondition("Verification failed"),
+            ));
+        }
+
+        // Only record verified proofs, so that a forged request cannot use up the commitment of
+        // a real one before it arrives. The commitment is random, so it identifies the proof.
+        let key = format!("{}:{}:{}", proof.context.r1, proof.context.r2, request.user);
+        let unseen = self
+            .store
+            .insert_proof_if_unseen(&key, 2 * self.noninteractive_proof_window)
+            .await
+            .map_err(|err| {
+                (
+                    FailureStage::Other,
+                    store_error_status("Failed to record proof", err),
+                )
+            })?;
+        if !unseen {
+            return Err((
+                FailureStage::Other,
+                Status::failed_precondition("Proof already used"),
+            ));
+        }
+
+        self.create_session(request.user, &s).await
+    }
+
+    /// Checks that a non-interactive proof with the given timestamp, in seconds since the Unix
+    /// epoch, was computed within the freshness window of the given time.
+    #[allow(clippy::result_large_err)]
+    fn check_proof_freshness(&self, timestamp: u64, now: SystemTime) -> Result<(), Status> {
+        let computed_at = SystemTime::UNIX_EPOCH
+            .checked_add(Duration::from_secs(timestamp))
+            .ok_or_else(|| Status::invalid_argument("Invalid timestamp argument"))?;
+        let skew = match now.duration_since(computed_at) {
+            Ok(age) => age,
+            Err(err) => err.duration(),
+        };
+        if skew > self.noninteractive_proof_window {
+            return Err(Status::deadline_exceeded("Proof expired"));
+        }
+        Ok(())
+    }
+
+    /// Returns the session for a user who answered with s, creating it unless the session key
+    /// strategy maps the login to an existing one.
+    async fn create_session(
+        &self,
+        user: String,
+        s: &Scalar,
+    ) -> Result<AuthenticationAnswerResponse, (FailureStage, Status)> {
+        // Concurrent logins with the same session key race to create the session, so it is created
+        // atomically and every caller gets the one that was inserted.
+        let session_key = self.session_key_strategy.session_key(&user, s);
+        let (session, inserted) = self
+            .store
+            .get_or_insert_session(
+                &session_key,
+                Session {
+                    id: self.session_id_generator.generate(),
+                    user,
+                },
+            )
+            .await
+            .map_err(|err| {
+                (
+                    FailureStage::Other,
+                    store_error_status("Failed to insert session into store", err),
+                )
+            })?;
+        if !inserted {
+            self.duplicate_sessions_avoided
+                .fetch_add(1, Ordering::Relaxed);
+        }
+
+        let session_id = session.id.to_string();
+        let token = match &self.session_token_issuer {
+            Some(issuer) => issuer
+                .issue(&session.user, &session_id, self.clock.now())
+                .map_err(|err| {
+                    log::error!("Failed to issue session token: {}", err);
+                    (
+                        FailureStage::Other,
+                        Status::internal("Failed to issue session token"),
+                    )
+                })?,
+            None => "".to_string(),
+        };
+        Ok(AuthenticationAnswerResponse { session_id, token })
+    }
+
+    /// Replaces the y1 and y2 of the user a verified answer was for, keeping their metadata, or
+    /// returns the error along with the stage it failed at.
+    #[allow(clippy::result_large_err)]
+    async fn update_user_credentials(
+        &self,
+        request: UpdateCredentialsRequest,
+    ) -> Result<UpdateCredentialsResponse, (FailureStage, Status)> {
+        let answer = request.answer.ok_or((
+            FailureStage::Parse,
+            Status::invalid_argument("Invalid answer argument"),
+        ))?;
+        let (challenge, current, _) = self.verify_answer(&answer).await?;
+
+        let mut user = self
+            .parse_registration(&challenge.user, &request.y1, &request.y2)
+            .map_err(|status| (FailureStage::Parse, status))?;
+        user.metadata = current.metadata.clone();
+
+        // The user is only replaced if it is still the one the answer was verified against, so
+        // the answer cannot be replayed once the credentials changed.
+        let updated = self
+            .store
+            .update_user(&challenge.user, &current, user)
+            .await
+            .map_err(|err| {
+                (
+                    FailureStage::Other,
+                    store_error_status("Failed to update user", err),
+                )
+            })?;
+        if !updated {
+            return Err((
+                FailureStage::Other,
+                Status::aborted("Credentials changed concurrently"),
+            ));
+        }
+
+        Ok(UpdateCredentialsResponse {})
+    }
+
+    /// Deletes the user a verified answer was for, or returns the error along with the stage it
+    /// failed at.
+    #[allow(clippy::result_large_err)]
+    async fn unregister_user(
+        &self,
+        request: UnregisterRequest,
+    ) -> Result<UnregisterResponse, (FailureStage, Status)> {
+        let answer = request.answer.ok_or((
+            FailureStage::Parse,
+            Status::invalid_argument("Invalid answer argument"),
+        ))?;
+        let (challenge, _, _) = self.verify_answer(&answer).await?;
+
+        let deleted = self
+            .store
+            .delete_user(&challenge.user)
+            .await
+            .map_err(|err| {
+                (
+                    FailureStage::Other,
+                    store_error_status("Failed to delete user", err),
+                )
+            })?;
+        if !deleted {
+            return Err((FailureStage::Other, Status::not_found("User not found")));
+        }
+        log::info!(
+            "Unregistered user {} and revoked their sessions.",
+            challenge.user
+        );
+
+        Ok(UnregisterResponse {})
+    }
+
+    /// Checks that the challenge was issued no longer ago than the maximum challenge age, if any.
+    #[allow(clippy::result_large_err)]
+    fn check_challenge_age(&self, challenge: &Challenge) -> Result<(), Status> {
+        let Some(max_age) = self.challenge_max_age else {
+            return Ok(());
+        };
+        // A clock that moved backwards gives a zero age rather than an error.
+        let age = self
+            .clock
+            .now()
+            .duration_since(challenge.issued_at)
+            .unwrap_or(Duration::ZERO);
+        if age > max_age {
+            return Err(Status::deadline_exceeded("Challenge expired"));
+        }
+        Ok(())
+    }
+
+    /// Checks that the c and MAC echoed back with an answer match the issued challenge, if a
+    /// challenge MAC secret is set.
+    #[allow(clippy::result_large_err)]
+    fn check_challenge_mac(
+        &self,
+        request: &AuthenticationAnswerRequest,
+        challenge: &Challenge,
+    ) -> Result<(), Status> {
+        let Some(secret) = &self.challenge_mac_secret else {
+            return Ok(());
+        };
+        match request.verify_mac(secret) {
+            Err(ChallengeMacError::MissingMac) => {
+                Err(Status::invalid_argument("Invalid mac argument"))
+            }
+            Err(ChallengeMacError::InvalidMac) => {
+                Err(Status::failed_precondition("Challenge was tampered with"))
+            }
+            Ok(()) if request.c != challenge.c.to_string() => {
+                Err(Status::failed_precondition("Challenge was tampered with"))
+            }
+            Ok(()) => Ok(()),
+        }
+    }
+
+    /// Checks the length of a request field against the maximum field length.
+    #[allow(clippy::result_large_err)]
+    fn check_field_length(&self, name: &str, value: &str) -> Result<(), Status> {
+        if value.len() > self.max_field_length {
+            return Err(Status::invalid_argument(format!(
+                "Invalid {} argument: exceeds maximum length",
+                name
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks that a commitment element falls within the full range of the group if required,
+    /// i.e. in (1, p) for discrete logarithms, or not the identity point for elliptic curves.
+    #[allow(clippy::result_large_err)]
+    fn check_commitment_range(&self, name: &str, value: &Element) -> Result<(), Status> {
+        if !self.require_full_range_commitments {
+            return Ok(());
+        }
+
+        let in_range = match &self.active().configuration.flavor {
+            Some(Flavor::DiscreteLogarithm(config)) => {
+                let p: BigInt = config
+                    .p
+                    .parse()
+                    .map_err(|_| Status::internal("Failed to parse p configuration"))?;
+                value.0 > BigInt::one() && value.0 < p
+            }
+            Some(Flavor::EllipticCurve(_)) => !value.0.is_zero(),
+            None => true,
+        };
+
+        if !in_range {
+            return Err(Status::invalid_argument(format!(
+                "Invalid {} argument: not in full range",
+                name
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the moduli that elements and scalars of the configured group are reduced by: p and
+    /// q for discrete logarithms, and the size of the point encoding and the group order for
+    /// elliptic curves.
+    #[allow(clippy::result_large_err)]
+    fn group_moduli(&self) -> Result<Option<(BigInt, BigInt)>, Status> {
+        let moduli = match &self.active().configuration.flavor {
+            Some(Flavor::DiscreteLogarithm(config)) => {
+                let p: BigInt = config
+                    .p
+                    .parse()
+                    .map_err(|_| Status::internal("Failed to parse p configuration"))?;
+                let q: BigInt = config
+                    .q
+                    .parse()
+                    .map_err(|_| Status::internal("Failed to parse q configuration"))?;
+                Some((p, q))
+            }
+            Some(Flavor::EllipticCurve(config)) if config.curve_name() == Secp256k1::NAME => {
+                Some((
+                    BigInt::one() << (8 * Secp256k1::POINT_BYTES),
+                    Secp256k1::order(),
+                ))
+            }
+            Some(Flavor::EllipticCurve(_)) => Some((
+                BigInt::one() << (8 * Ristretto::POINT_BYTES),
+                Ristretto::order(),
+            )),
+            None => None,
+        };
+        Ok(moduli)
+    }
+
+    /// Checks that an element parsed from a request is in the valid range for the configured
+    /// group, so out-of-range values are rejected before they reach the protocol math.
+    #[allow(clippy::result_large_err)]
+    fn check_element_valid(&self, name: &str, value: &Element) -> Result<(), Status> {
+        match self.group_moduli()? {
+            Some((modulus, _)) if !value.is_valid(&modulus) => Err(Status::invalid_argument(
+                format!("Invalid {} argument: out of range", name),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that a scalar parsed from a request is in the valid range for the configured group.
+    #[allow(clippy::result_large_err)]
+    fn check_scalar_valid(&self, name: &str, value: &Scalar) -> Result<(), Status> {
+        match self.group_moduli()? {
+            Some((_, modulus)) if !value.is_valid(&modulus) => Err(Status::invalid_argument(
+                format!("Invalid {} argument: out of range", name),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns whether an element is the identity of the configured group, which is 1 for discrete
+    /// logarithms and the identity point for elliptic curves.
+    fn is_group_identity(&self, value: &Element) -> bool {
+        match &self.active().configuration.flavor {
+            Some(Flavor::DiscreteLogarithm(_)) => value.0.is_one(),
+            Some(Flavor::EllipticCurve(_)) => value.0.is_zero(),
+            None => false,
+        }
+    }
+
+    /// Checks whether a challenge commitment is the group identity, as produced by a client using
+    /// k = 0, logging a warning and rejecting it if trivial commitments are rejected.
+    #[allow(clippy::result_large_err)]
+    fn check_trivial_commitment(
+        &self,
+        user: &str,
+        name: &str,
+        value: &Element,
+    ) -> Result<(), Status> {
+        if !self.is_group_identity(value) {
+            return Ok(());
+        }
+        log::warn!(
+            "Challenge commitment {} for user {:?} is the group identity; the client may be using k = 0",
+            name,
+            user
+        );
+        if self.reject_trivial_commitments {
+            return Err(Status::invalid_argument(format!(
+                "Invalid {} argument: trivial commitment",
+                name
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks that an element is plausible for the configured flavor, so that a client speaking the
+    /// other flavor is diagnosed clearly rather than failing verification. A discrete logarithm
+    /// element outside the subgroup that decodes as a ristretto point, or an elliptic curve element
+    /// too large to be a compressed point, is rejected as a flavor mismatch.
+    #[allow(clippy::result_large_err)]
+    fn check_flavor(&self, name: &str, value: &Element) -> Result<(), Status> {
+        let mismatch = match self.active().configuration.flavor {
+            Some(Flavor::DiscreteLogarithm(_)) => {
+                // Compressed points are uniformly distributed 32-byte values, so small group
+                // elements that happen to decode as a point are not mistaken for one. Decoding is
+                // cheap, so the subgroup test only runs for the rare element that is a point.
+                let is_point =
+                    value.0.bits() > 128 && RistrettoPoint::try_from(value.clone()).is_ok();
+                if !is_point {
+                    return Ok(());
+                }
+                let Some((p, q)) = self.group_moduli()? else {
+                    return Ok(());
+                };
+                // For a safe prime, the subgroup is the quadratic residues, which the Jacobi
+                // symbol tells apart without exponentiating.
+                let in_subgroup = value.0 > BigInt::zero()
+                    && value.0 < p
+                    && if p == &q * 2u32 + 1u32 {
+                        arithmetic::jacobi(&value.0, &p) == 1
+                    } else {
+                        arithmetic::modpow(&value.0, &q, &p).is_one()
+                    };
+                (!in_subgroup).then_some(("an elliptic curve", "discrete logarithm"))
+            }
+            Some(Flavor::EllipticCurve(ref config)) => {
+                let point_bytes = if config.curve_name() == Secp256k1::NAME {
+                    Secp256k1::POINT_BYTES
+                } else {
+                    Ristretto::POINT_BYTES
+                };
+                (value.0.bits() > 8 * point_bytes as u64)
+                    .then_some(("a discrete logarithm", "elliptic curve"))
+            }
+            None => None,
+        };
+        match mismatch {
+            Some((found, expected)) => Err(Status::invalid_argument(format!(
+                "Invalid {} argument: looks like {} element, but the server uses the {} flavor",
+                name, found, expected
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks that an element decodes to a valid point for elliptic curves, so that malformed
+    /// points are rejected as invalid input rather than failing later during verification.
+    #[allow(clippy::result_large_err)]
+    fn check_point(&self, name: &str, value: &Element) -> Result<(), Status> {
+        if let Some(Flavor::EllipticCurve(ref config)) = self.active().configuration.flavor {
+            let valid = if config.curve_name() == Secp256k1::NAME {
+                Secp256k1::point_from(value.clone()).is_ok()
+            } else {
+                Ristretto::point_from(value.clone()).is_ok()
+            };
+            if !valid {
+                return Err(Status::invalid_argument(format!("Invalid {} point", name)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses a request field after checking its length.
+    #[allow(clippy::result_large_err)]
+    fn parse_field<T: FromStr>(&self, name: &str, value: &str) -> Result<T, Status> {
+        self.check_field_length(name, value)?;
+        value
+            .parse()
+            .map_err(|_| Status::invalid_argument(format!("Invalid {} argument", name)))
+    }
+
+    /// Runs a complete register, challenge, and verify cycle with an internal prover of the
+    /// configured flavor against the service's verifier, without touching the store.
+    pub(crate) fn run_self_test(&self) -> anyhow::Result<()> {
+        let active = self.active();
+        let prover: Box<dyn Prover> = match active.configuration.flavor.clone() {
+            Some(Flavor::DiscreteLogarithm(config)) => {
+                Box::new(DiscreteLogarithmProver::new(config.try_into().map_err(
+                    |_| anyhow::Error::msg("failed to convert discrete logarithm configuration"),
+                )?))
+            }
+            Some(Flavor::EllipticCurve(config)) if config.curve_name() == Secp256k1::NAME => {
+                Box::new(EllipticCurveProver::<Secp256k1>::new(
+                    config.try_into().map_err(|_| {
+                        anyhow::Error::msg("failed to convert elliptic curve configuration")
+                    })?,
+                ))
+            }
+            Some(Flavor::EllipticCurve(config)) => Box::new(EllipticCurveProver::<Ristretto>::new(
+                config.try_into().map_err(|_| {
+                    anyhow::Error::msg("failed to convert elliptic curve configuration")
+                })?,
+            )),
+            None => return Err(anyhow::Error::msg("unknown configuration")),
+        };
+
+        let x = prover.generate_registration_x();
+        let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+        let k = prover.generate_challenge_k();
+        let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+        let c = active.verifier.generate_challenge_c();
+        let s = prover.compute_challenge_response_s(&x, &k, c.clone())?;
+        let (v1, v2) = active.verifier.compute_verification_r1r2(y1, y2, c, s)?;
+
+        if !bool::from(v1.ct_eq(&r1) & v2.ct_eq(&r2)) {
+            return Err(anyhow::Error::msg("verification failed"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts a store session event to a protobuf session event.
+impl From<SessionEvent> for watch_sessions_response::Event {
+    fn from(event: SessionEvent) -> Self {
+        match event {
+            SessionEvent::Created { user, session_id } => {
+                watch_sessions_response::Event::Created(SessionCreated {
+                    user,
+                    session_id: session_id.to_string(),
+                })
+            }
+            SessionEvent::Revoked { session_id } => {
+                watch_sessions_response::Event::Revoked(SessionRevoked {
+                    session_id: session_id.to_string(),
+                })
+            }
+            SessionEvent::Expired { session_id } => {
+                watch_sessions_response::Event::Expired(SessionExpired {
+                    session_id: session_id.to_string(),
+                })
+            }
+        }
+    }
+}
+
+/// Maps a store error to a status, logging the details and keeping them out of the response.
+/// Serialization errors are internal, backend errors are unavailable since they may be
+/// transient, and not found errors are not found.
+fn store_error_status(context: &str, err: StoreError) -> Status {
+    log::error!("{}: {}", context, err);
+    match err {
+        StoreError::Serialization(_) => Status::internal(context),
+        StoreError::Backend(_) => Status::unavailable(context),
+        StoreError::NotFound(_) => Status::not_found(context),
+    }
+}
+
+/// Returns the auth_id of the answer carried by a request, or empty if it has none, for its audit
+/// entry.
+fn answer_auth_id(answer: &Option<AuthenticationAnswerRequest>) -> String {
+    answer
+        .as_ref()
+        .map(|answer| answer.auth_id.clone())
+        .unwrap_or_default()
+}
+
+/// Returns the configuration as served to clients, advertising the verifier's protocol version.
+fn effective_configuration(
+    configuration: &Configuration,
+    verifier: &dyn Verifier,
+) -> Configuration {
+    Configuration {
+        protocol_version: verifier.protocol_version(),
+        ..configuration.clone()
+    }
+}
+
+/// Implementation of the authentication protocol.
+#[tonic::async_trait]
+impl Auth for Service {
+    /// Returns the configuration for the authentication protocol.
+    async fn get_configuration(
+        &self,
+        _: Request<GetConfigurationRequest>,
+    ) -> Result<Response<Configuration>, Status> {
+        let active = self.active();
+        Ok(Response::new(effective_configuration(
+            &active.configuration,
+            active.verifier.as_ref(),
+        )))
+    }
+
+    /// Server streaming response type for the watch_configuration method.
+    type WatchConfigurationStream =
+        Pin<Box<dyn Stream<Item = Result<Configuration, Status>> + Send>>;
+
+    /// Streams the effective configuration, starting with the current one and followed by any
+    /// changes to it. The stream stays open until the client disconnects.
+    async fn watch_configuration(
+        &self,
+        _: Request<WatchConfigurationRequest>,
+    ) -> Result<Response<Self::WatchConfigurationStream>, Status> {
+        let updates = WatchStream::new(self.configuration_updates.subscribe()).map(Ok);
+        Ok(Response::new(Box::pin(updates)))
+    }
+
+    /// Registers a new user with the given y1 and y2, returning an error if the user is already registered.
+    /// # Errors
+    /// * Returns an error if the user is already registered.
+    /// * Returns an error if the user is empty.
+    /// * Returns an error if y1 or y2 is invalid.
+    /// * Returns an error if registration is scoped to client identities, and the user is not the
+    ///   one named by the client certificate.
+    async fn register(
+        &self,
+        request: Request<RegisterRequest>,
+    ) -> Result<Response<RegisterResponse>, Status> {
+        let identity = request.extensions().get::<ClientIdentity>().cloned();
+        let request = request.into_inner();
+        let recorded = RecordedRequest::Register {
+            user: request.user.clone(),
+            y1: request.y1.clone(),
+            y2: request.y2.clone(),
+            metadata: request.metadata.clone(),
+        };
+
+        let result = match self.check_registration_scope(&request.user, identity.as_ref()) {
+            Ok(()) => self.register_user(request).await,
+            Err(status) => Err(status),
+        };
+        self.record(recorded, &result);
+        if let (Ok(_), Some(metrics)) = (&result, &self.metrics) {
+            metrics.record_register();
+        }
+        result.map(Response::new)
+    }
+
+    /// Creates a new challenge using the given commitment, and returns c in the response along
+    /// with the challenge auth id.
+    /// # Errors
+    /// * Returns an error if the user is not found, unless anonymous challenges are allowed.
+    /// * Returns an error if the user is empty.
+    /// * Returns an error if r1 or r2 is invalid.
+    async fn create_authentication_challenge(
+        &self,
+        request: Request<AuthenticationChallengeRequest>,
+    ) -> Result<Response<AuthenticationChallengeResponse>, Status> {
+        let request = request.into_inner();
+
+        // Generate the auth_id to store the challenge under. The challenge number c is generated
+        // once the commitment is validated, so that it can be bound to it.
+        let auth_id = Uuid::new_v4();
+        let mut recorded = RecordedRequest::CreateAuthenticationChallenge {
+            user: request.user.clone(),
+            r1: request.r1.clone(),
+            r2: request.r2.clone(),
+            auth_id: auth_id.to_string(),
+            c: "".to_string(),
+        };
+
+        let result = self.create_challenge(request, auth_id, None).await;
+        recorded.set_challenge_c(&result);
+        self.record(recorded, &result);
+        if let (Ok(_), Some(metrics)) = (&result, &self.metrics) {
+            metrics.record_challenge();
+        }
+        result.map(Response::new)
+    }
+
+    /// Registers a new user and creates a challenge for it in one round trip, returning c in the
+    /// response along with the challenge auth id.
+    /// # Errors
+    /// * Returns an error if the user is already registered, in which case no challenge is
+    ///   created.
+    /// * Returns an error if the user is empty.
+    /// * Returns an error if y1, y2, r1 or r2 is invalid.
+    /// * Returns an error if registration is scoped to client identities, and the user is not the
+    ///   one named by the client certificate.
+    async fn register_and_challenge(
+        &self,
+        request: Request<RegisterAndChallengeRequest>,
+    ) -> Result<Response<AuthenticationChallengeResponse>, Status> {
+        let identity = request.extensions().get::<ClientIdentity>().cloned();
+        let request = request.into_inner();
+
+        let auth_id = Uuid::new_v4();
+        let mut recorded = RecordedRequest::RegisterAndChallenge {
+            user: request.user.clone(),
+            y1: request.y1.clone(),
+            y2: request.y2.clone(),
+            metadata: request.metadata.clone(),
+            r1: request.r1.clone(),
+            r2: request.r2.clone(),
+            auth_id: auth_id.to_string(),
+            c: "".to_string(),
+        };
+
+        let result = match self.check_registration_scope(&request.user, identity.as_ref()) {
+            Ok(()) => {
+                self.register_and_create_challenge(request, auth_id, None)
+                    .await
+            }
+            Err(status) => Err(status),
+        };
+        recorded.set_challenge_c(&result);
+        self.record(recorded, &result);
+        if let (Ok(_), Some(metrics)) = (&result, &self.metrics) {
+            metrics.record_register();
+            metrics.record_challenge();
+        }
+        result.map(Response::new)
+    }
+
+    /// Verifies the given s and creates a new session based on it if necessary, returning the
+    /// session id in the response.
+    /// # Errors
+    /// * Returns an error if the challenge is not found.
+    /// * Returns an error if the user is not found.
+    /// * Returns an error if the auth_id argument is empty.
+    /// * Returns an error if s is invalid.
+    /// * Returns an error if the verification fails.
+    ///
+    /// All errors are delayed by a random jitter, if configured, so that their timing does not
+    /// reveal which check failed.
+    async fn verify_authentication(
+        &self,
+        request: Request<AuthenticationAnswerRequest>,
+    ) -> Result<Response<AuthenticationAnswerResponse>, Status> {
+        let metadata = request
+            .extensions()
+            .get::<RequestMetadata>()
+            .cloned()
+            .unwrap_or_default();
+        let request = request.into_inner();
+        let auth_id = request.auth_id.clone();
+        let recorded = RecordedRequest::VerifyAuthentication {
+            auth_id: request.auth_id.clone(),
+            s: request.s.clone(),
+            c: request.c.clone(),
+            mac: request.mac.clone(),
+        };
+
+        let started = Instant::now();
+        let result = self.verify_authentication_answer(request).await;
+        self.complete_authentication(result, started, Some(recorded), auth_id, metadata)
+            .await
+    }
+
+    /// Verifies a non-interactive proof for a user in a single round trip, and creates a new
+    /// session based on it if necessary, returning the session id in the response.
+    /// # Errors
+    /// * Returns an error if the user is empty or not found.
+    /// * Returns an error if r1, r2, or s is invalid.
+    /// * Returns an error if the verification fails.
+    ///
+    /// All errors are delayed by a random jitter, if configured, like verify_authentication errors.
+    async fn authenticate(
+        &self,
+        request: Request<AuthenticateRequest>,
+    ) -> Result<Response<AuthenticationAnswerResponse>, Status> {
+        let metadata = request
+            .extensions()
+            .get::<RequestMetadata>()
+            .cloned()
+            .unwrap_or_default();
+        let request = request.into_inner();
+        let recorded = RecordedRequest::Authenticate {
+            user: request.user.clone(),
+            r1: request.r1.clone(),
+            r2: request.r2.clone(),
+            s: request.s.clone(),
+            timestamp: request.timestamp,
+        };
+
+        let started = Instant::now();
+        let result = self
+            .verify_noninteractive_proof(request, self.clock.now())
+            .await;
+        // There is no challenge, so the attempt is audited without an auth_id.
+        self.complete_authentication(result, started, Some(recorded), String::new(), metadata)
+            .await
+    }
+
+    /// Server streaming response type for the watch_sessions method.
+    type WatchSessionsStream =
+        Pin<Box<dyn Stream<Item = Result<WatchSessionsResponse, Status>> + Send>>;
+
+    /// Streams session lifecycle events as they happen, starting from the time of the request.
+    async fn watch_sessions(
+        &self,
+        _: Request<WatchSessionsRequest>,
+    ) -> Result<Response<Self::WatchSessionsStream>, Status> {
+        let events =
+            BroadcastStream::new(self.store.subscribe_session_events().await).filter_map(|event| {
+                match event {
+                    Ok(event) => Some(Ok(WatchSessionsResponse {
+                        event: Some(event.into()),
+                    })),
+                    Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                        log::warn!("Session watcher lagged, skipped {} events", skipped);
+                        None
+                    }
+                }
+            });
+
+        Ok(Response::new(Box::pin(events)))
+    }
+
+    /// Returns the metadata of a registered user.
+    /// # Errors
+    /// * Returns an error if the user is empty.
+    /// * Returns an error if the user is not found.
+    async fn get_user(
+        &self,
+        request: Request<GetUserRequest>,
+    ) -> Result<Response<GetUserResponse>, Status> {
+        let mut request = request.into_inner();
+        request.user = self.username_policy.normalize(&request.user);
+        if request.user.is_empty() {
+            return Err(Status::invalid_argument("Invalid user argument"));
+        }
+        self.check_field_length("user", &request.user)?;
+
+        let user = self
+            .store
+            .get_user(&request.user)
+            .await
+            .map_err(|err| store_error_status("Failed to get user", err))?
+            .ok_or_else(|| Status::not_found("User not found"))?;
+
+        Ok(Response::new(GetUserResponse {
+            user: request.user,
+            metadata: user.metadata,
+        }))
+    }
+
+    /// Replaces a user's y1 and y2 after verifying an answer to a challenge created for them with
+    /// their current registration.
+    /// # Errors
+    /// * Returns an error if the answer is missing or does not verify.
+    /// * Returns an error if y1 or y2 is invalid.
+    /// * Returns an error if the user's registration changed since the answer was verified.
+    ///
+    /// All errors are delayed by a random jitter, if configured, and counted and audited like
+    /// verify_authentication errors.
+    async fn update_credentials(
+        &self,
+        request: Request<UpdateCredentialsRequest>,
+    ) -> Result<Response<UpdateCredentialsResponse>, Status> {
+        let metadata = request
+            .extensions()
+            .get::<RequestMetadata>()
+            .cloned()
+            .unwrap_or_default();
+        let request = request.into_inner();
+        let auth_id = answer_auth_id(&request.answer);
+
+        let started = Instant::now();
+        let result = self.update_user_credentials(request).await;
+        self.complete_authentication(result, started, None, auth_id, metadata)
+            .await
+    }
+
+    /// Deletes a user's registration after verifying an answer to a challenge created for them
+    /// with their current registration, so the username can be registered again. The user's
+    /// sessions and pending challenges are revoked along with it.
+    /// # Errors
+    /// * Returns an error if the answer is missing or does not verify.
+    /// * Returns an error if the user was already deleted.
+    ///
+    /// All errors are delayed by a random jitter, if configured, and counted and audited like
+    /// verify_authentication errors.
+    async fn unregister(
+        &self,
+        request: Request<UnregisterRequest>,
+    ) -> Result<Response<UnregisterResponse>, Status> {
+        let metadata = request
+            .extensions()
+            .get::<RequestMetadata>()
+            .cloned()
+            .unwrap_or_default();
+        let request = request.into_inner();
+        let auth_id = answer_auth_id(&request.answer);
+
+        let started = Instant::now();
+        let result = self.unregister_user(request).await;
+        self.complete_authentication(result, started, None, auth_id, metadata)
+            .await
+    }
+
+    /// Runs a full internal proof with the service's configuration and verifier, returning
+    /// whether it passed, along with the error if it did not.
+    async fn self_test(
+        &self,
+        _: Request<SelfTestRequest>,
+    ) -> Result<Response<SelfTestResponse>, Status> {
+        let resp = match self.run_self_test() {
+            Ok(()) => SelfTestResponse {
+                passed: true,
+                error: "".to_string(),
+            },
+            Err(err) => {
+                log::error!("Self-test failed: {}", err);
+                SelfTestResponse {
+                    passed: false,
+                    error: err.to_string(),
+                }
+            }
+        };
+
+        Ok(Response::new(resp))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use anyhow::Result;
+    use num_traits::One;
+    use std::time::SystemTime;
+    use tonic::Code;
+    use tonic::Request;
+    use zkauth::discrete_logarithm::{
+        configuration::DiscreteLogarithmConfiguration, verifier::DiscreteLogarithmVerifier,
+    };
+    use zkauth::elliptic_curve::{
+        configuration::EllipticCurveConfiguration, verifier::EllipticCurveVerifier,
+    };
+
+    /// Creates a new service for testing.
+    fn test_service() -> Service {
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+        Service::new(config.into(), verifier)
+    }
+
+    /// Creates a new elliptic curve service for testing.
+    fn test_elliptic_curve_service() -> Service {
+        let config = EllipticCurveConfiguration::generate();
+        let verifier = Box::new(EllipticCurveVerifier::new(config.clone()));
+        Service::new(config.into(), verifier)
+    }
+
+    /// Returns an element that does not decompress to a valid ristretto point.
+    fn invalid_point() -> String {
+        BigInt::from_bytes_le(num_bigint::Sign::Plus, &[0xff; 32]).to_string()
+    }
+
+    #[cfg(test)]
     mod get_configuration {
         use super::*;
 
-        /// Tests that the get_configuration method returns the correct configuration.
+        /// Tests that the get_configuration method returns the correct configuration.
+        #[tokio::test]
+        async fn succeeds_with_discrete_logarithm_config() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let config_pb: Configuration = config.clone().into();
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config));
+            let service = Service::new(config_pb.clone(), verifier);
+
+            let resp = service
+                .get_configuration(Request::new(GetConfigurationRequest {}))
+                .await?
+                .into_inner();
+
+            assert_eq!(resp.flavor, config_pb.flavor);
+            assert_eq!(resp.config_version(), config_pb.config_version());
+
+            Ok(())
+        }
+
+        /// Tests that a configuration watcher immediately receives the current configuration, and
+        /// that the stream stays open for later changes.
+        #[tokio::test]
+        async fn watch_configuration_emits_current_configuration() -> Result<()> {
+            let service = test_service();
+            let expected = service
+                .get_configuration(Request::new(GetConfigurationRequest {}))
+                .await?
+                .into_inner();
+
+            let mut stream = service
+                .watch_configuration(Request::new(WatchConfigurationRequest {}))
+                .await?
+                .into_inner();
+            assert_eq!(stream.next().await.unwrap()?, expected);
+            assert!(
+                tokio::time::timeout(Duration::from_millis(50), stream.next())
+                    .await
+                    .is_err()
+            );
+
+            Ok(())
+        }
+
+        /// Tests that the served configuration advertises the verifier's protocol version.
+        #[tokio::test]
+        async fn advertises_protocol_version() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.into(), verifier);
+
+            let resp = service
+                .get_configuration(Request::new(GetConfigurationRequest {}))
+                .await?
+                .into_inner();
+
+            assert_eq!(resp.protocol_version, zkauth::PROTOCOL_VERSION);
+
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod reload {
+        use super::*;
+
+        /// Returns the configuration with g written with a leading zero, which changes its
+        /// representation but not its value.
+        fn reformatted(config: &Configuration) -> Configuration {
+            let mut config = config.clone();
+            if let Some(Flavor::DiscreteLogarithm(params)) = &mut config.flavor {
+                params.g = format!("0{}", params.g);
+            }
+            config
+        }
+
+        /// Tests that a benign configuration change is reloaded, served to new requests, and
+        /// emitted to configuration watchers, while registrations keep verifying.
+        #[tokio::test]
+        async fn reloads_benign_change() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier);
+            let mut watcher = service
+                .watch_configuration(Request::new(WatchConfigurationRequest {}))
+                .await?
+                .into_inner();
+            watcher.next().await.unwrap()?;
+
+            let reloaded = reformatted(&config.clone().into());
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config));
+            let change = service.reload(reloaded.clone(), verifier, false)?;
+            assert_eq!(change, ConfigurationChange::Benign);
+
+            let served = service
+                .get_configuration(Request::new(GetConfigurationRequest {}))
+                .await?
+                .into_inner();
+            assert_eq!(served.flavor, reloaded.flavor);
+            assert_eq!(watcher.next().await.unwrap()?, served);
+            service.run_self_test()?;
+
+            Ok(())
+        }
+
+        /// Tests that a change to the public parameters is refused unless forced.
+        #[tokio::test]
+        async fn refuses_breaking_change_unless_forced() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier);
+
+            let other = DiscreteLogarithmConfiguration::generate(32);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(other.clone()));
+            assert!(service
+                .reload(other.clone().into(), verifier, false)
+                .is_err());
+            assert_eq!(service.configuration(), config.into());
+
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(other.clone()));
+            let change = service.reload(other.clone().into(), verifier, true)?;
+            assert_eq!(change, ConfigurationChange::Breaking);
+            assert_eq!(service.configuration(), other.into());
+            service.run_self_test()?;
+
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod register_batch {
+        use super::*;
+
+        /// Tests that a large batch registers every valid entry, with results in order.
+        #[tokio::test]
+        async fn registers_many_entries_in_order() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Arc::new(
+                Service::new(config.clone().into(), verifier)
+                    .with_require_full_range_commitments(true),
+            );
+
+            let mut blobs: Vec<RegistrationBlob> = (0..500)
+                .map(|i| RegistrationBlob {
+                    user: format!("user-{}", i),
+                    y1: config.g.to_string(),
+                    y2: config.h.to_string(),
+                })
+                .collect();
+            // An invalid entry, and a duplicate of the first entry.
+            blobs[100].y1 = "1".to_string();
+            blobs.push(blobs[0].clone());
+
+            let results = service.register_batch(blobs, None).await;
+            assert_eq!(results.len(), 501);
+            for (i, result) in results.iter().enumerate() {
+                match i {
+                    100 => {
+                        let err = result.as_ref().unwrap_err();
+                        assert_eq!(err.code(), Code::InvalidArgument);
+                        assert_eq!(err.message(), "Invalid y1 argument: not in full range");
+                    }
+                    500 => assert_eq!(result.as_ref().unwrap_err().code(), Code::AlreadyExists),
+                    _ => assert!(result.is_ok(), "entry {} failed: {:?}", i, result),
+                }
+            }
+
+            for i in [0, 99, 101, 499] {
+                assert!(service
+                    .store
+                    .get_user(&format!("user-{}", i))
+                    .await?
+                    .is_some());
+            }
+            assert!(service.store.get_user("user-100").await?.is_none());
+
+            Ok(())
+        }
+
+        /// Tests that the results are the same regardless of the number of threads.
+        #[tokio::test]
+        async fn results_are_deterministic_across_thread_counts() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let blobs: Vec<RegistrationBlob> = (0..200)
+                .map(|i| RegistrationBlob {
+                    user: format!("user-{}", i % 150),
+                    y1: if i % 7 == 0 {
+                        "x".to_string()
+                    } else {
+                        config.g.to_string()
+                    },
+                    y2: config.h.to_string(),
+                })
+                .collect();
+
+            // The validation is run on the pool, so it runs on the pool's threads.
+            let mut outcomes = Vec::new();
+            for threads in [1, 4] {
+                let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+                let service = Service::new(config.clone().into(), verifier);
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()?;
+                let results = pool.install(|| service.parse_registrations(&blobs));
+                outcomes.push(
+                    results
+                        .into_iter()
+                        .map(|result| result.map(|user| user.y1).map_err(|err| err.code()))
+                        .collect::<Vec<_>>(),
+                );
+            }
+            assert_eq!(outcomes[0], outcomes[1]);
+
+            // The inserts that follow run in order, so the batch results are deterministic too.
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Arc::new(Service::new(config.clone().into(), verifier));
+            let results = service.register_batch(blobs, None).await;
+            for (i, result) in results.iter().enumerate() {
+                match i {
+                    i if i % 7 == 0 => {
+                        assert_eq!(result.as_ref().unwrap_err().code(), Code::InvalidArgument)
+                    }
+                    i if i >= 150 && (i % 150) % 7 != 0 => {
+                        assert_eq!(result.as_ref().unwrap_err().code(), Code::AlreadyExists)
+                    }
+                    _ => assert!(result.is_ok(), "entry {} failed: {:?}", i, result),
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod register {
+        use super::*;
+        use std::sync::Arc;
+
+        /// Tests that concurrent registrations of the same user result in exactly one success.
+        #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+        async fn succeeds_once_when_registered_concurrently() -> Result<()> {
+            let service = Arc::new(test_service());
+
+            let tasks: Vec<_> = (0..2)
+                .map(|_| {
+                    let service = service.clone();
+                    tokio::spawn(async move {
+                        service
+                            .register(Request::new(RegisterRequest {
+                                user: "peggy".to_string(),
+                                y1: "1".to_string(),
+                                y2: "1".to_string(),
+                                metadata: Default::default(),
+                            }))
+                            .await
+                    })
+                })
+                .collect();
+
+            let mut succeeded = 0;
+            let mut already_exists = 0;
+            for task in tasks {
+                match task.await? {
+                    Ok(_) => succeeded += 1,
+                    Err(err) if err.code() == Code::AlreadyExists => already_exists += 1,
+                    Err(err) => return Err(err.into()),
+                }
+            }
+            assert_eq!(succeeded, 1);
+            assert_eq!(already_exists, 1);
+
+            Ok(())
+        }
+
+        /// Tests that the register method succeeds with valid arguments.
+        #[tokio::test]
+        async fn succeeds() -> Result<()> {
+            let service = test_service();
+            let resp = service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: "1".to_string(),
+                    y2: "1".to_string(),
+                    metadata: Default::default(),
+                }))
+                .await?
+                .into_inner();
+
+            assert_eq!(resp, RegisterResponse {});
+
+            Ok(())
+        }
+
+        /// Tests that the register method returns an error when the user is already registered.
+        #[tokio::test]
+        async fn returns_error_when_user_already_registered() -> Result<()> {
+            let service = test_service();
+            service
+                .store
+                .insert_user(
+                    "peggy",
+                    User {
+                        y1: One::one(),
+                        y2: One::one(),
+                        metadata: Default::default(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert user into store"))?;
+
+            let result = service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: "1".to_string(),
+                    y2: "1".to_string(),
+                    metadata: Default::default(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::AlreadyExists);
+            assert_eq!(err.message(), "User already registered");
+
+            Ok(())
+        }
+
+        /// Tests that the register method returns an error when the user is empty.
+        #[tokio::test]
+        async fn returns_error_when_user_is_empty() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .register(Request::new(RegisterRequest {
+                    user: "".to_string(),
+                    y1: "1".to_string(),
+                    y2: "1".to_string(),
+                    metadata: Default::default(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid user argument");
+
+            Ok(())
+        }
+
+        /// Tests that the register method rejects points that fail to decompress for elliptic
+        /// curves.
+        #[tokio::test]
+        async fn returns_error_with_invalid_elliptic_curve_point() -> Result<()> {
+            let service = test_elliptic_curve_service();
+            let valid = Element::from(RistrettoPoint::default()).to_string();
+
+            for (y1, y2, name) in [
+                (invalid_point(), valid.clone(), "y1"),
+                (valid.clone(), invalid_point(), "y2"),
+            ] {
+                let err = service
+                    .register(Request::new(RegisterRequest {
+                        user: "peggy".to_string(),
+                        y1,
+                        y2,
+                        metadata: Default::default(),
+                    }))
+                    .await
+                    .unwrap_err();
+                assert_eq!(err.code(), Code::InvalidArgument);
+                assert_eq!(err.message(), format!("Invalid {} point", name));
+            }
+
+            Ok(())
+        }
+
+        /// Tests that an elliptic curve element sent to a discrete logarithm service is diagnosed
+        /// as a flavor mismatch rather than failing later during verification.
+        #[tokio::test]
+        async fn returns_error_with_elliptic_curve_element_for_discrete_logarithm() -> Result<()> {
+            let service = test_service();
+            let element =
+                Element::from(curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT).to_string();
+
+            let err = service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: element,
+                    y2: "1".to_string(),
+                    metadata: Default::default(),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(
+                err.message(),
+                "Invalid y1 argument: looks like an elliptic curve element, but the server uses the discrete logarithm flavor"
+            );
+
+            Ok(())
+        }
+
+        /// Tests that a discrete logarithm element sent to an elliptic curve service is diagnosed
+        /// as a flavor mismatch.
+        #[tokio::test]
+        async fn returns_error_with_discrete_logarithm_element_for_elliptic_curve() -> Result<()> {
+            let service = test_elliptic_curve_service();
+            let valid = Element::from(RistrettoPoint::default()).to_string();
+
+            let err = service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: valid,
+                    y2: ((BigInt::one() << 1024u32) - 1u32).to_string(),
+                    metadata: Default::default(),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(
+                err.message(),
+                "Invalid y2 argument: looks like a discrete logarithm element, but the server uses the elliptic curve flavor"
+            );
+
+            Ok(())
+        }
+
+        /// Tests that the register method accepts full-range values when required.
+        #[tokio::test]
+        async fn succeeds_with_full_range_commitments() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier)
+                .with_require_full_range_commitments(true);
+            let resp = service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: config.g.to_string(),
+                    y2: config.h.to_string(),
+                    metadata: Default::default(),
+                }))
+                .await?
+                .into_inner();
+
+            assert_eq!(resp, RegisterResponse {});
+
+            Ok(())
+        }
+
+        /// Tests that the register method rejects trivial values when full-range commitments are
+        /// required.
+        #[tokio::test]
+        async fn returns_error_with_trivial_commitments() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier)
+                .with_require_full_range_commitments(true);
+
+            for (y1, y2, name) in [
+                ("1".to_string(), config.h.to_string(), "y1"),
+                (config.g.to_string(), "0".to_string(), "y2"),
+                (config.p.to_string(), config.h.to_string(), "y1"),
+            ] {
+                let err = service
+                    .register(Request::new(RegisterRequest {
+                        user: "peggy".to_string(),
+                        y1,
+                        y2,
+                        metadata: Default::default(),
+                    }))
+                    .await
+                    .unwrap_err();
+                assert_eq!(err.code(), Code::InvalidArgument);
+                assert_eq!(
+                    err.message(),
+                    format!("Invalid {} argument: not in full range", name)
+                );
+            }
+
+            Ok(())
+        }
+
+        /// Tests that the register method accepts y1 and y2 at the boundaries of [0, p), and
+        /// rejects values at or above p as out of range.
+        #[tokio::test]
+        async fn checks_commitments_are_in_range() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier);
+
+            for (i, (value, valid)) in [
+                (BigInt::zero(), true),
+                (&config.p - 1, true),
+                (config.p.clone(), false),
+                (&config.p + 1, false),
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                for (y1, y2, name) in [
+                    (value.to_string(), config.h.to_string(), "y1"),
+                    (config.g.to_string(), value.to_string(), "y2"),
+                ] {
+                    let result = service
+                        .register(Request::new(RegisterRequest {
+                            user: format!("peggy-{}-{}", i, name),
+                            y1,
+                            y2,
+                            metadata: Default::default(),
+                        }))
+                        .await;
+                    if valid {
+                        assert!(result.is_ok(), "{}: {:?}", value, result);
+                    } else {
+                        let err = result.unwrap_err();
+                        assert_eq!(err.code(), Code::InvalidArgument);
+                        assert_eq!(
+                            err.message(),
+                            format!("Invalid {} argument: out of range", name)
+                        );
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Tests that the register method returns an error when y1 exceeds the maximum length.
+        #[tokio::test]
+        async fn returns_error_when_y1_is_too_long() -> Result<()> {
+            let service = test_service().with_max_field_length(8);
+            let result = service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: "123456789".to_string(),
+                    y2: "1".to_string(),
+                    metadata: Default::default(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid y1 argument: exceeds maximum length");
+
+            Ok(())
+        }
+
+        /// Tests that the register method returns an error when the user exceeds the maximum
+        /// length.
+        #[tokio::test]
+        async fn returns_error_when_user_is_too_long() -> Result<()> {
+            let service = test_service().with_max_field_length(8);
+            let result = service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy-the-prover".to_string(),
+                    y1: "1".to_string(),
+                    y2: "1".to_string(),
+                    metadata: Default::default(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(
+                err.message(),
+                "Invalid user argument: exceeds maximum length"
+            );
+
+            Ok(())
+        }
+
+        /// Tests that the register method returns an error when y1 is empty.
+        #[tokio::test]
+        async fn returns_error_when_y1_is_empty() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: "".to_string(),
+                    y2: "1".to_string(),
+                    metadata: Default::default(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid y1 argument");
+
+            Ok(())
+        }
+
+        /// Tests that the register method returns an error when y2 is empty.
+        #[tokio::test]
+        async fn returns_error_when_y2_is_empty() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: "1".to_string(),
+                    y2: "".to_string(),
+                    metadata: Default::default(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid y2 argument");
+
+            Ok(())
+        }
+
+        /// Tests that the register method returns an error when y1 is not a number.
+        #[tokio::test]
+        async fn returns_error_when_y1_is_not_a_number() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: "not-a-number".to_string(),
+                    y2: "1".to_string(),
+                    metadata: Default::default(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid y1 argument");
+
+            Ok(())
+        }
+
+        /// Tests that the register method returns an error when y2 is not a number.
+        #[tokio::test]
+        async fn returns_error_when_y2_is_not_a_number() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: "1".to_string(),
+                    y2: "not-a-number".to_string(),
+                    metadata: Default::default(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid y2 argument");
+
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod register_blob {
+        use super::*;
+
+        /// Tests that the register_blob method registers a user from a serialized blob.
+        #[tokio::test]
+        async fn succeeds_with_serialized_blob() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let prover = DiscreteLogarithmProver::new(config.clone());
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.into(), verifier);
+
+            let blob = prover.registration("peggy", "password")?;
+            let json = serde_json::to_string(&blob)?;
+            let blob: RegistrationBlob = serde_json::from_str(&json)?;
+            service.register_blob(blob.clone(), None).await?;
+
+            let user = service.store.get_user("peggy").await?.unwrap();
+            assert_eq!(user.y1.to_string(), blob.y1);
+            assert_eq!(user.y2.to_string(), blob.y2);
+
+            Ok(())
+        }
+
+        /// Tests that the register_blob method returns an error when the user is already
+        /// registered.
+        #[tokio::test]
+        async fn returns_error_when_user_already_registered() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let prover = DiscreteLogarithmProver::new(config.clone());
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.into(), verifier);
+
+            let blob = prover.registration("peggy", "password")?;
+            service.register_blob(blob.clone(), None).await?;
+
+            let err = service.register_blob(blob, None).await.unwrap_err();
+            assert_eq!(err.code(), Code::AlreadyExists);
+            assert_eq!(err.message(), "User already registered");
+
+            Ok(())
+        }
+
+        /// Tests that the register_blob method applies the registration scope to the identity it
+        /// is given.
+        #[tokio::test]
+        async fn scopes_registration_to_given_identity() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let prover = DiscreteLogarithmProver::new(config.clone());
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.into(), verifier)
+                .with_scope_registration_to_client_identity(true);
+
+            let blob = prover.registration("peggy", "password")?;
+            let err = service.register_blob(blob.clone(), None).await.unwrap_err();
+            assert_eq!(err.code(), Code::PermissionDenied);
+            assert_eq!(err.message(), "Registration requires a client certificate");
+
+            let victor = ClientIdentity("victor".to_string());
+            let err = service
+                .register_blob(blob.clone(), Some(&victor))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::PermissionDenied);
+            assert!(service.store.get_user("peggy").await?.is_none());
+
+            let peggy = ClientIdentity("peggy".to_string());
+            service.register_blob(blob, Some(&peggy)).await?;
+            assert!(service.store.get_user("peggy").await?.is_some());
+
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod get_user {
+        use super::*;
+
+        /// Tests that the metadata a user was registered with is returned by get_user.
+        #[tokio::test]
+        async fn returns_registered_metadata() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier);
+            let metadata: HashMap<String, String> = [
+                ("display_name".to_string(), "Peggy".to_string()),
+                ("roles".to_string(), "admin".to_string()),
+            ]
+            .into();
+
+            service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: config.g.to_string(),
+                    y2: config.h.to_string(),
+                    metadata: metadata.clone(),
+                }))
+                .await?;
+
+            let resp = service
+                .get_user(Request::new(GetUserRequest {
+                    user: "peggy".to_string(),
+                }))
+                .await?
+                .into_inner();
+            assert_eq!(resp.user, "peggy");
+            assert_eq!(resp.metadata, metadata);
+
+            // The cryptographic fields are unaffected by the metadata.
+            let user = service.store.get_user("peggy").await?.unwrap();
+            assert_eq!(user.y1, Element::from(config.g.clone()));
+            assert_eq!(user.y2, Element::from(config.h.clone()));
+
+            Ok(())
+        }
+
+        /// Tests that a user registered without metadata has empty metadata.
+        #[tokio::test]
+        async fn returns_empty_metadata_when_registered_without() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier);
+            service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: config.g.to_string(),
+                    y2: config.h.to_string(),
+                    metadata: Default::default(),
+                }))
+                .await?;
+
+            let resp = service
+                .get_user(Request::new(GetUserRequest {
+                    user: "peggy".to_string(),
+                }))
+                .await?
+                .into_inner();
+            assert!(resp.metadata.is_empty());
+
+            Ok(())
+        }
+
+        /// Tests that get_user returns not_found for an unknown user, and invalid_argument for an
+        /// empty one.
+        #[tokio::test]
+        async fn returns_error_when_user_is_unknown_or_empty() -> Result<()> {
+            let service = test_service();
+
+            for (user, code) in [("peggy", Code::NotFound), ("", Code::InvalidArgument)] {
+                let err = service
+                    .get_user(Request::new(GetUserRequest {
+                        user: user.to_string(),
+                    }))
+                    .await
+                    .unwrap_err();
+                assert_eq!(err.code(), code);
+            }
+
+            Ok(())
+        }
+
+        /// Tests that register rejects metadata with an empty key or an oversized value.
+        #[tokio::test]
+        async fn register_rejects_invalid_metadata() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier).with_max_field_length(16);
+
+            for (key, value, message) in [
+                ("", "value", "Invalid metadata argument: empty key"),
+                (
+                    "key",
+                    "a value that is too long",
+                    "Invalid metadata argument: exceeds maximum length",
+                ),
+            ] {
+                let err = service
+                    .register(Request::new(RegisterRequest {
+                        user: "peggy".to_string(),
+                        y1: config.g.to_string(),
+                        y2: config.h.to_string(),
+                        metadata: [(key.to_string(), value.to_string())].into(),
+                    }))
+                    .await
+                    .unwrap_err();
+                assert_eq!(err.code(), Code::InvalidArgument);
+                assert_eq!(err.message(), message);
+            }
+            assert!(service.store.get_user("peggy").await?.is_none());
+
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod username_policy {
+        use super::*;
+        use zkauth::discrete_logarithm::prover::DiscreteLogarithmProver;
+
+        /// Returns a service with the given username policy, and a prover for it.
+        fn service_with_policy(policy: UsernamePolicy) -> (Service, DiscreteLogarithmProver) {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service =
+                Service::new(config.clone().into(), verifier).with_username_policy(policy);
+            (service, DiscreteLogarithmProver::new(config))
+        }
+
+        /// Registers the user with the prover's registration for the password.
+        async fn register(
+            service: &Service,
+            prover: &DiscreteLogarithmProver,
+            user: &str,
+        ) -> Result<(), Status> {
+            let x = prover.compute_registration_x("password".to_string());
+            let (y1, y2) = prover.compute_registration_y1y2(&x).unwrap();
+            service
+                .register(Request::new(RegisterRequest {
+                    user: user.to_string(),
+                    y1: y1.to_string(),
+                    y2: y2.to_string(),
+                    metadata: Default::default(),
+                }))
+                .await?;
+            Ok(())
+        }
+
+        /// Authenticates the user with the prover, returning the session id.
+        async fn login(
+            service: &Service,
+            prover: &DiscreteLogarithmProver,
+            user: &str,
+        ) -> Result<String, Status> {
+            let x = prover.compute_registration_x("password".to_string());
+            let k = prover.generate_challenge_k();
+            let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k).unwrap();
+            let challenge = service
+                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                    user: user.to_string(),
+                    r1: r1.to_string(),
+                    r2: r2.to_string(),
+                }))
+                .await?
+                .into_inner();
+            let c: Scalar = challenge.c.parse().unwrap();
+            let s = prover.compute_challenge_response_s(&x, &k, c).unwrap();
+            let answer = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: challenge.auth_id,
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await?
+                .into_inner();
+            Ok(answer.session_id)
+        }
+
+        /// Tests that without a policy, usernames differing in whitespace or case are distinct
+        /// users.
+        #[tokio::test]
+        async fn keeps_variants_distinct_by_default() -> Result<()> {
+            let (service, prover) = service_with_policy(UsernamePolicy::default());
+
+            register(&service, &prover, "peggy").await?;
+            register(&service, &prover, "Peggy").await?;
+            register(&service, &prover, "peggy ").await?;
+
+            let err = login(&service, &prover, " peggy").await.unwrap_err();
+            assert_eq!(err.code(), Code::NotFound);
+
+            Ok(())
+        }
+
+        /// Tests that trimmed and NFC normalized variants map to the same stored user.
+        #[tokio::test]
+        async fn maps_normalized_variants_to_same_user() -> Result<()> {
+            let (service, prover) = service_with_policy(UsernamePolicy::normalized());
+
+            // "pe\u{301}ggy" is the decomposed form of "p\u{e9}ggy".
+            register(&service, &prover, " pe\u{301}ggy\t").await?;
+            assert!(service.store.get_user("p\u{e9}ggy").await?.is_some());
+
+            let err = register(&service, &prover, "p\u{e9}ggy").await.unwrap_err();
+            assert_eq!(err.code(), Code::AlreadyExists);
+            login(&service, &prover, "p\u{e9}ggy ").await?;
+
+            // Case is not folded unless enabled.
+            register(&service, &prover, "P\u{c9}GGY").await?;
+
+            Ok(())
+        }
+
+        /// Tests that usernames differing only in case map to the same stored user when case
+        /// folding is enabled.
+        #[tokio::test]
+        async fn maps_case_variants_to_same_user_with_case_fold() -> Result<()> {
+            let (service, prover) =
+                service_with_policy(UsernamePolicy::normalized().with_case_fold(true));
+
+            register(&service, &prover, "Peggy").await?;
+            assert!(service.store.get_user("peggy").await?.is_some());
+
+            let err = register(&service, &prover, " PEGGY").await.unwrap_err();
+            assert_eq!(err.code(), Code::AlreadyExists);
+            login(&service, &prover, "pEgGy").await?;
+
+            let resp = service
+                .get_user(Request::new(GetUserRequest {
+                    user: "PEGGY ".to_string(),
+                }))
+                .await?
+                .into_inner();
+            assert_eq!(resp.user, "peggy");
+
+            Ok(())
+        }
+
+        /// Tests that a username that is empty once normalized is rejected.
+        #[tokio::test]
+        async fn rejects_username_empty_after_normalization() -> Result<()> {
+            let (service, prover) = service_with_policy(UsernamePolicy::normalized());
+
+            let err = register(&service, &prover, "  ").await.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid user argument");
+
+            Ok(())
+        }
+
+        /// Tests that register_and_challenge and register_batch normalize usernames too.
+        #[tokio::test]
+        async fn normalizes_register_and_challenge_and_batch() -> Result<()> {
+            let (service, prover) =
+                service_with_policy(UsernamePolicy::normalized().with_case_fold(true));
+            let service = Arc::new(service);
+
+            let x = prover.compute_registration_x("password".to_string());
+            let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+            let k = prover.generate_challenge_k();
+            let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+            service
+                .register_and_challenge(Request::new(RegisterAndChallengeRequest {
+                    user: " Victor ".to_string(),
+                    y1: y1.to_string(),
+                    y2: y2.to_string(),
+                    metadata: Default::default(),
+                    r1: r1.to_string(),
+                    r2: r2.to_string(),
+                }))
+                .await?;
+            assert!(service.store.get_user("victor").await?.is_some());
+
+            let results = service
+                .register_batch(
+                    vec![
+                        prover.registration("Walter", "password")?,
+                        prover.registration(" walter", "password")?,
+                    ],
+                    None,
+                )
+                .await;
+            assert!(results[0].is_ok());
+            assert_eq!(results[1].as_ref().unwrap_err().code(), Code::AlreadyExists);
+            assert!(service.store.get_user("walter").await?.is_some());
+
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod client_identity {
+        use super::*;
+        use zkauth::discrete_logarithm::prover::DiscreteLogarithmProver;
+
+        /// Returns a service scoping registration to client identities, and a prover for it.
+        fn scoped_service() -> (Service, DiscreteLogarithmProver) {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier)
+                .with_username_policy(UsernamePolicy::normalized().with_case_fold(true))
+                .with_scope_registration_to_client_identity(true);
+            (service, DiscreteLogarithmProver::new(config))
+        }
+
+        /// Returns a register request for the user, sent with the given client identity.
+        fn register_request(
+            prover: &DiscreteLogarithmProver,
+            user: &str,
+            identity: Option<&str>,
+        ) -> Result<Request<RegisterRequest>> {
+            let x = prover.compute_registration_x("password".to_string());
+            let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+            let mut request = Request::new(RegisterRequest {
+                user: user.to_string(),
+                y1: y1.to_string(),
+                y2: y2.to_string(),
+                metadata: Default::default(),
+            });
+            if let Some(identity) = identity {
+                request
+                    .extensions_mut()
+                    .insert(ClientIdentity(identity.to_string()));
+            }
+            Ok(request)
+        }
+
+        /// Tests that a client may register the user named by its identity, compared after
+        /// normalization.
+        #[tokio::test]
+        async fn registers_user_named_by_identity() -> Result<()> {
+            let (service, prover) = scoped_service();
+
+            service
+                .register(register_request(&prover, " Peggy", Some("peggy"))?)
+                .await?;
+            assert!(service.store.get_user("peggy").await?.is_some());
+
+            Ok(())
+        }
+
+        /// Tests that a client may not register another user, or any user without an identity.
+        #[tokio::test]
+        async fn rejects_other_users_and_missing_identity() -> Result<()> {
+            let (service, prover) = scoped_service();
+
+            let err = service
+                .register(register_request(&prover, "victor", Some("peggy"))?)
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::PermissionDenied);
+            assert_eq!(err.message(), "User does not match the client certificate");
+
+            let err = service
+                .register(register_request(&prover, "victor", None)?)
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::PermissionDenied);
+            assert_eq!(err.message(), "Registration requires a client certificate");
+
+            let k = prover.generate_challenge_k();
+            let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+            let register = register_request(&prover, "victor", Some("peggy"))?.into_inner();
+            let mut request = Request::new(RegisterAndChallengeRequest {
+                user: register.user,
+                y1: register.y1,
+                y2: register.y2,
+                metadata: Default::default(),
+                r1: r1.to_string(),
+                r2: r2.to_string(),
+            });
+            request
+                .extensions_mut()
+                .insert(ClientIdentity("peggy".to_string()));
+            let err = service.register_and_challenge(request).await.unwrap_err();
+            assert_eq!(err.code(), Code::PermissionDenied);
+
+            assert!(service.store.get_user("victor").await?.is_none());
+            Ok(())
+        }
+
+        /// Tests that registration is not scoped to the identity unless enabled.
+        #[tokio::test]
+        async fn ignores_identity_by_default() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier);
+            let prover = DiscreteLogarithmProver::new(config);
+
+            service
+                .register(register_request(&prover, "victor", Some("peggy"))?)
+                .await?;
+            service
+                .register(register_request(&prover, "walter", None)?)
+                .await?;
+
+            Ok(())
+        }
+
+        /// Tests that a batch is scoped to the identity of the client per entry, like the
+        /// register RPC.
+        #[tokio::test]
+        async fn scopes_batch_entries_to_identity() -> Result<()> {
+            let (service, prover) = scoped_service();
+            let service = Arc::new(service);
+            let identity = ClientIdentity("peggy".to_string());
+
+            let results = service
+                .register_batch(
+                    vec![
+                        prover.registration("victor", "password")?,
+                        prover.registration(" Peggy", "password")?,
+                    ],
+                    Some(&identity),
+                )
+                .await;
+            let err = results[0].as_ref().unwrap_err();
+            assert_eq!(err.code(), Code::PermissionDenied);
+            assert_eq!(err.message(), "User does not match the client certificate");
+            assert!(results[1].is_ok());
+            assert!(service.store.get_user("victor").await?.is_none());
+            assert!(service.store.get_user("peggy").await?.is_some());
+
+            let results = service
+                .register_batch(vec![prover.registration("walter", "password")?], None)
+                .await;
+            let err = results[0].as_ref().unwrap_err();
+            assert_eq!(err.code(), Code::PermissionDenied);
+            assert_eq!(err.message(), "Registration requires a client certificate");
+
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod register_and_challenge {
+        use super::*;
+
+        /// Tests that a user registered with register_and_challenge can answer the returned
+        /// challenge without a separate challenge request.
+        #[tokio::test]
+        async fn registers_user_and_returns_answerable_challenge() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let prover = DiscreteLogarithmProver::new(config.clone());
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.into(), verifier);
+
+            let x = prover.generate_registration_x();
+            let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+            let k = prover.generate_challenge_k();
+            let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+
+            let resp = service
+                .register_and_challenge(Request::new(RegisterAndChallengeRequest {
+                    user: "peggy".to_string(),
+                    y1: y1.to_string(),
+                    y2: y2.to_string(),
+                    metadata: [("display_name".to_string(), "Peggy".to_string())].into(),
+                    r1: r1.to_string(),
+                    r2: r2.to_string(),
+                }))
+                .await?
+                .into_inner();
+
+            let user = service.store.get_user("peggy").await?.unwrap();
+            assert_eq!(user.y1, y1);
+            assert_eq!(user.y2, y2);
+            assert_eq!(user.metadata["display_name"], "Peggy");
+
+            let c = resp.c.parse::<Scalar>().unwrap();
+            let s = prover.compute_challenge_response_s(&x, &k, c)?;
+            let resp = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: resp.auth_id,
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await?
+                .into_inner();
+            assert!(!resp.session_id.is_empty());
+
+            Ok(())
+        }
+
+        /// Tests that register_and_challenge returns already_exists for a registered user, and
+        /// leaves the existing registration unchanged.
+        #[tokio::test]
+        async fn returns_error_when_user_already_registered() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier);
+
+            service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: config.g.to_string(),
+                    y2: config.h.to_string(),
+                    metadata: Default::default(),
+                }))
+                .await?;
+
+            let err = service
+                .register_and_challenge(Request::new(RegisterAndChallengeRequest {
+                    user: "peggy".to_string(),
+                    y1: config.h.to_string(),
+                    y2: config.g.to_string(),
+                    metadata: Default::default(),
+                    r1: config.g.to_string(),
+                    r2: config.h.to_string(),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::AlreadyExists);
+            assert_eq!(err.message(), "User already registered");
+
+            let user = service.store.get_user("peggy").await?.unwrap();
+            assert_eq!(user.y1, Element::from(config.g.clone()));
+            assert_eq!(user.y2, Element::from(config.h.clone()));
+
+            Ok(())
+        }
+
+        /// Tests that an invalid commitment is rejected before the user is registered.
+        #[tokio::test]
+        async fn does_not_register_user_when_commitment_is_invalid() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier);
+
+            let err = service
+                .register_and_challenge(Request::new(RegisterAndChallengeRequest {
+                    user: "peggy".to_string(),
+                    y1: config.g.to_string(),
+                    y2: config.h.to_string(),
+                    metadata: Default::default(),
+                    r1: "abc".to_string(),
+                    r2: config.h.to_string(),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert!(service.store.get_user("peggy").await?.is_none());
+
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod create_authentication_challenge {
+        use super::*;
+
+        /// Tests that the create_authentication_challenge method rejects points that fail to
+        /// decompress for elliptic curves.
+        #[tokio::test]
+        async fn returns_error_with_invalid_elliptic_curve_point() -> Result<()> {
+            let service = test_elliptic_curve_service();
+            let valid = Element::from(RistrettoPoint::default()).to_string();
+
+            for (r1, r2, name) in [
+                (invalid_point(), valid.clone(), "r1"),
+                (valid.clone(), invalid_point(), "r2"),
+            ] {
+                let err = service
+                    .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                        user: "peggy".to_string(),
+                        r1,
+                        r2,
+                    }))
+                    .await
+                    .unwrap_err();
+                assert_eq!(err.code(), Code::InvalidArgument);
+                assert_eq!(err.message(), format!("Invalid {} point", name));
+            }
+
+            Ok(())
+        }
+
+        /// Tests that a verifier binding challenges gets the commitment and registration, so the
+        /// same commitment is issued the same c, which the prover can answer.
+        #[tokio::test]
+        async fn passes_commitment_to_bound_verifier() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(64);
+            let verifier = DiscreteLogarithmVerifier::new(config.clone()).with_bound_challenges();
+            let service = Service::new(config.clone().into(), Box::new(verifier.clone()));
+            let prover = DiscreteLogarithmProver::new(config);
+
+            let x = prover.compute_registration_x("password".to_string());
+            let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+            service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: y1.to_string(),
+                    y2: y2.to_string(),
+                    metadata: Default::default(),
+                }))
+                .await?;
+
+            let k = prover.generate_challenge_k();
+            let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+            let request = AuthenticationChallengeRequest {
+                user: "peggy".to_string(),
+                r1: r1.to_string(),
+                r2: r2.to_string(),
+            };
+            let first = service
+                .create_authentication_challenge(Request::new(request.clone()))
+                .await?
+                .into_inner();
+            let second = service
+                .create_authentication_challenge(Request::new(request))
+                .await?
+                .into_inner();
+            assert_eq!(first.c, second.c);
+            assert_ne!(first.auth_id, second.auth_id);
+            let c = verifier.generate_challenge_c_for(&ChallengeContext { y1, y2, r1, r2 });
+            assert_eq!(first.c, c.to_string());
+
+            let s = prover.compute_challenge_response_s(&x, &k, c)?;
+            let resp = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: first.auth_id,
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await?
+                .into_inner();
+            assert!(!resp.session_id.is_empty());
+
+            Ok(())
+        }
+
+        /// Creates a service with a registered user, returning the service and the user's
+        /// commitment values.
+        async fn service_with_registered_user(
+            max: usize,
+            policy: ChallengeLimitPolicy,
+        ) -> Result<(Service, DiscreteLogarithmConfiguration)> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier)
+                .with_max_challenges_per_user(max, policy);
+            service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: config.g.to_string(),
+                    y2: config.h.to_string(),
+                    metadata: Default::default(),
+                }))
+                .await?;
+            Ok((service, config))
+        }
+
+        /// Creates a challenge for peggy, returning its auth_id.
+        async fn challenge(
+            service: &Service,
+            config: &DiscreteLogarithmConfiguration,
+        ) -> std::result::Result<Uuid, Status> {
+            let resp = service
+                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                    user: "peggy".to_string(),
+                    r1: config.g.to_string(),
+                    r2: config.h.to_string(),
+                }))
+                .await?
+                .into_inner();
+            Ok(Uuid::parse_str(&resp.auth_id).unwrap())
+        }
+
+        /// Tests that exceeding the per-user challenge limit with the reject policy returns
+        /// resource_exhausted and keeps the existing challenges.
+        #[tokio::test]
+        async fn rejects_challenges_beyond_limit() -> Result<()> {
+            let (service, config) =
+                service_with_registered_user(3, ChallengeLimitPolicy::Reject).await?;
+
+            let mut auth_ids = Vec::new();
+            for _ in 0..3 {
+                auth_ids.push(challenge(&service, &config).await?);
+            }
+            let err = challenge(&service, &config).await.unwrap_err();
+            assert_eq!(err.code(), Code::ResourceExhausted);
+            assert_eq!(err.message(), "Too many pending challenges for user");
+
+            for auth_id in auth_ids {
+                assert!(service.store.get_challenge(auth_id).await?.is_some());
+            }
+
+            Ok(())
+        }
+
+        /// Tests that exceeding the per-user challenge limit with the evict-oldest policy issues
+        /// the new challenge and removes the oldest one.
+        #[tokio::test]
+        async fn evicts_oldest_challenge_beyond_limit() -> Result<()> {
+            let (service, config) =
+                service_with_registered_user(3, ChallengeLimitPolicy::EvictOldest).await?;
+
+            let mut auth_ids = Vec::new();
+            for _ in 0..4 {
+                auth_ids.push(challenge(&service, &config).await?);
+            }
+
+            assert!(service.store.get_challenge(auth_ids[0]).await?.is_none());
+            for auth_id in &auth_ids[1..] {
+                assert!(service.store.get_challenge(*auth_id).await?.is_some());
+            }
+
+            Ok(())
+        }
+
+        /// Tests that the group identity is detected as a trivial commitment for each flavor.
+        #[test]
+        fn detects_group_identity() {
+            let service = test_service();
+            assert!(service.is_group_identity(&Element::from(BigInt::one())));
+            assert!(!service.is_group_identity(&Element::from(BigInt::from(2))));
+
+            let service = test_elliptic_curve_service();
+            let identity = Element::from(RistrettoPoint::default());
+            assert!(service.is_group_identity(&identity));
+            let generator = Element::from(curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT);
+            assert!(!service.is_group_identity(&generator));
+        }
+
+        /// Tests that the create_authentication_challenge method only warns about trivial
+        /// commitments by default, and rejects them when configured to.
+        #[tokio::test]
+        async fn handles_trivial_commitments() -> Result<()> {
+            for reject in [false, true] {
+                let service = test_service().with_reject_trivial_commitments(reject);
+                service
+                    .store
+                    .insert_user(
+                        "peggy",
+                        User {
+                            y1: Element::from(BigInt::from(2)),
+                            y2: Element::from(BigInt::from(3)),
+                            metadata: Default::default(),
+                        },
+                    )
+                    .await
+                    .map_err(|_| Status::internal("Failed to insert user into store"))?;
+
+                for (r1, r2, name) in [("1", "2", "r1"), ("2", "1", "r2")] {
+                    let result = service
+                        .create_authentication_challenge(Request::new(
+                            AuthenticationChallengeRequest {
+                                user: "peggy".to_string(),
+                                r1: r1.to_string(),
+                                r2: r2.to_string(),
+                            },
+                        ))
+                        .await;
+                    if reject {
+                        let err = result.unwrap_err();
+                        assert_eq!(err.code(), Code::InvalidArgument);
+                        assert_eq!(
+                            err.message(),
+                            format!("Invalid {} argument: trivial commitment", name)
+                        );
+                    } else {
+                        assert!(!result?.into_inner().auth_id.is_empty());
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Tests that the create_authentication_challenge method succeeds with valid arguments.
+        #[tokio::test]
+        async fn succeeds() -> Result<()> {
+            let service = test_service();
+            service
+                .store
+                .insert_user(
+                    "peggy",
+                    User {
+                        y1: One::one(),
+                        y2: One::one(),
+                        metadata: Default::default(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert user into store"))?;
+
+            let resp = service
+                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                    user: "peggy".to_string(),
+                    r1: "1".to_string(),
+                    r2: "1".to_string(),
+                }))
+                .await?
+                .into_inner();
+
+            Uuid::parse_str(&resp.auth_id)?;
+            resp.c.parse::<Scalar>().unwrap();
+            assert_eq!(resp.signature, "");
+
+            Ok(())
+        }
+
+        /// Tests that the create_authentication_challenge method signs the challenge when the
+        /// service has a signing key.
+        #[tokio::test]
+        async fn signs_challenge_with_signing_key() -> Result<()> {
+            let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+            let service = test_service().with_signing_key(signing_key.clone());
+            service
+                .store
+                .insert_user(
+                    "peggy",
+                    User {
+                        y1: One::one(),
+                        y2: One::one(),
+                        metadata: Default::default(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert user into store"))?;
+
+            let resp = service
+                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                    user: "peggy".to_string(),
+                    r1: "1".to_string(),
+                    r2: "1".to_string(),
+                }))
+                .await?
+                .into_inner();
+
+            assert!(resp.verify_signature(&signing_key.verifying_key()).is_ok());
+            let other_key = SigningKey::from_bytes(&[8u8; 32]);
+            assert!(resp.verify_signature(&other_key.verifying_key()).is_err());
+
+            Ok(())
+        }
+
+        /// Tests that the create_authentication_challenge method returns an internal error when
+        /// the signing backend fails to sign the challenge.
+        #[tokio::test]
+        async fn returns_internal_when_signing_backend_fails() -> Result<()> {
+            use crate::signing::SigningError;
+            use zkauth_protobuf::bundle::{Signature, VerifyingKey};
+
+            /// A signing backend whose key is unavailable.
+            struct UnavailableSigningBackend;
+
+            #[tonic::async_trait]
+            impl SigningBackend for UnavailableSigningBackend {
+                fn verifying_key(&self) -> VerifyingKey {
+                    SigningKey::from_bytes(&[7u8; 32]).verifying_key()
+                }
+
+                async fn sign(&self, _: &[u8]) -> Result<Signature, SigningError> {
+                    Err(SigningError::Backend("key is unavailable".to_string()))
+                }
+            }
+
+            let service = test_service().with_signing_backend(Box::new(UnavailableSigningBackend));
+            service
+                .store
+                .insert_user(
+                    "peggy",
+                    User {
+                        y1: One::one(),
+                        y2: One::one(),
+                        metadata: Default::default(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert user into store"))?;
+
+            let err = service
+                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                    user: "peggy".to_string(),
+                    r1: "1".to_string(),
+                    r2: "1".to_string(),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::Internal);
+            assert_eq!(err.message(), "Failed to sign challenge");
+
+            Ok(())
+        }
+
+        /// Tests that the create_authentication_challenge method accepts r1 and r2 at the
+        /// boundaries of [0, p), and rejects values at or above p as out of range.
+        #[tokio::test]
+        async fn checks_commitments_are_in_range() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier);
+            service
+                .store
+                .insert_user(
+                    "peggy",
+                    User {
+                        y1: config.g.clone().into(),
+                        y2: config.h.clone().into(),
+                        metadata: Default::default(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert user into store"))?;
+
+            for (value, valid) in [
+                (BigInt::zero(), true),
+                (&config.p - 1, true),
+                (config.p.clone(), false),
+                (&config.p + 1, false),
+            ] {
+                for (r1, r2, name) in [
+                    (value.to_string(), config.h.to_string(), "r1"),
+                    (config.g.to_string(), value.to_string(), "r2"),
+                ] {
+                    let result = service
+                        .create_authentication_challenge(Request::new(
+                            AuthenticationChallengeRequest {
+                                user: "peggy".to_string(),
+                                r1,
+                                r2,
+                            },
+                        ))
+                        .await;
+                    if valid {
+                        assert!(result.is_ok(), "{}: {:?}", value, result);
+                    } else {
+                        let err = result.unwrap_err();
+                        assert_eq!(err.code(), Code::InvalidArgument);
+                        assert_eq!(
+                            err.message(),
+                            format!("Invalid {} argument: out of range", name)
+                        );
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Tests that the create_authentication_challenge method returns an error when the user is
+        #[tokio::test]
+        async fn returns_not_found_when_unknown_user() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                    user: "unknown".to_string(),
+                    r1: "1".to_string(),
+                    r2: "1".to_string(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::NotFound);
+            assert_eq!(err.message(), "User not found");
+
+            Ok(())
+        }
+
+        /// Tests that the create_authentication_challenge method returns a challenge of the same
+        /// shape for known and unknown users when anonymous challenges are allowed.
+        #[tokio::test]
+        async fn returns_same_shape_for_unknown_user_when_anonymous_allowed() -> Result<()> {
+            let service = test_service().with_allow_anonymous_challenge(true);
+            service
+                .store
+                .insert_user(
+                    "peggy",
+                    User {
+                        y1: One::one(),
+                        y2: One::one(),
+                        metadata: Default::default(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert user into store"))?;
+
+            let mut responses = vec![];
+            for user in ["peggy", "unknown"] {
+                let resp = service
+                    .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                        user: user.to_string(),
+                        r1: "1".to_string(),
+                        r2: "1".to_string(),
+                    }))
+                    .await?
+                    .into_inner();
+                Uuid::parse_str(&resp.auth_id)?;
+                resp.c.parse::<Scalar>().unwrap();
+                responses.push(resp);
+            }
+            assert_eq!(responses[0].auth_id.len(), responses[1].auth_id.len());
+
+            // The throwaway challenge fails at verification like a wrong proof.
+            let err = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: responses[1].auth_id.clone(),
+                    s: "1".to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::FailedPrecondition);
+            assert_eq!(err.message(), "Verification failed");
+
+            Ok(())
+        }
+
+        /// Tests that the create_authentication_challenge method returns an error when the user is empty.
+        #[tokio::test]
+        async fn returns_error_when_user_is_empty() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                    user: "".to_string(),
+                    r1: "1".to_string(),
+                    r2: "1".to_string(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid user argument");
+
+            Ok(())
+        }
+
+        /// Tests that the create_authentication_challenge method returns an error when r1 is empty.
+        #[tokio::test]
+        async fn returns_error_when_r1_is_empty() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                    user: "peggy".to_string(),
+                    r1: "".to_string(),
+                    r2: "1".to_string(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid r1 argument");
+
+            Ok(())
+        }
+
+        /// Tests that the create_authentication_challenge method returns an error when r2 is empty.
+        #[tokio::test]
+        async fn returns_error_when_r2_is_empty() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                    user: "peggy".to_string(),
+                    r1: "1".to_string(),
+                    r2: "".to_string(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid r2 argument");
+
+            Ok(())
+        }
+
+        /// Tests that the create_authentication_challenge method returns an error when r1 is not a number.
+        #[tokio::test]
+        async fn returns_error_when_r1_is_not_a_number() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                    user: "peggy".to_string(),
+                    r1: "not-a-number".to_string(),
+                    r2: "1".to_string(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid r1 argument");
+
+            Ok(())
+        }
+
+        /// Tests that the create_authentication_challenge method returns an error when r2 is not a number.
+        #[tokio::test]
+        async fn returns_error_when_r2_is_not_a_number() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                    user: "peggy".to_string(),
+                    r1: "1".to_string(),
+                    r2: "not-a-number".to_string(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid r2 argument");
+
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) mod verify_authentication {
+        use super::*;
+        use crate::clock::ManualClock;
+        use crate::token::validate_session_token;
+        use jsonwebtoken::errors::ErrorKind;
+        use std::sync::atomic::{AtomicU64, Ordering};
+        use std::sync::{Arc, Mutex};
+        use zkauth::{
+            discrete_logarithm::{arithmetic::reduce, prover::DiscreteLogarithmProver},
+            Prover,
+        };
+
+        /// A generator that returns sequential session ids.
+        struct SequentialSessionIdGenerator(AtomicU64);
+
+        impl SessionIdGenerator for SequentialSessionIdGenerator {
+            fn generate(&self) -> Uuid {
+                Uuid::from_u128(self.0.fetch_add(1, Ordering::SeqCst) as u128)
+            }
+        }
+
+        /// Tests that the verify_authentication method succeeds with valid arguments.
+        #[tokio::test]
+        async fn succeeds() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let config_pb: Configuration = config.clone().into();
+            let verifier = DiscreteLogarithmVerifier::new(config.clone());
+            let service = Service::new(config_pb.clone(), Box::new(verifier.clone()));
+
+            let prover = DiscreteLogarithmProver::new(config);
+
+            let user = "peggy";
+            let auth_id = Uuid::new_v4();
+
+            let x = prover.generate_registration_x();
+            let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+            let k = prover.generate_challenge_k();
+            let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k).unwrap();
+            let c = verifier.generate_challenge_c();
+            let s = prover
+                .compute_challenge_response_s(&x, &k, c.clone())
+                .unwrap();
+
+            service
+                .store
+                .insert_user(
+                    user,
+                    User {
+                        y1,
+                        y2,
+                        metadata: Default::default(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert user into store"))?;
+            service
+                .store
+                .insert_challenge(
+                    auth_id,
+                    Challenge {
+                        user: user.to_string(),
+                        c,
+                        r1,
+                        r2,
+                        issued_at: SystemTime::now(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert challenge into store"))?;
+
+            let resp = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: auth_id.to_string(),
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await?
+                .into_inner();
+
+            Uuid::parse_str(&resp.session_id)?;
+
+            // The challenge is used up, so the same answer cannot be verified again.
+            let err = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: auth_id.to_string(),
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::NotFound);
+
+            Ok(())
+        }
+
+        /// Tests that a response of s = 0, from a prover whose k happened to equal c * x mod q, is
+        /// accepted rather than treated as empty.
+        #[tokio::test]
+        async fn succeeds_with_zero_s() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = DiscreteLogarithmVerifier::new(config.clone());
+            let service = Service::new(config.clone().into(), Box::new(verifier.clone()));
+            let prover = DiscreteLogarithmProver::new(config.clone());
+
+            let user = "peggy";
+            let auth_id = Uuid::new_v4();
+
+            let x = prover.generate_registration_x();
+            let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+            let c = verifier.generate_challenge_c();
+            let k: Scalar = reduce(&(&c.0 * &x.0), &config.q).into();
+            let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+            let s = prover.compute_challenge_response_s(&x, &k, c.clone())?;
+            assert_eq!(s.to_string(), "0");
+
+            service
+                .store
+                .insert_user(
+                    user,
+                    User {
+                        y1,
+                        y2,
+                        metadata: Default::default(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert user into store"))?;
+            service
+                .store
+                .insert_challenge(
+                    auth_id,
+                    Challenge {
+                        user: user.to_string(),
+                        c,
+                        r1,
+                        r2,
+                        issued_at: SystemTime::now(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert challenge into store"))?;
+
+            let resp = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: auth_id.to_string(),
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await?
+                .into_inner();
+
+            Uuid::parse_str(&resp.session_id)?;
+
+            Ok(())
+        }
+
+        /// Tests that failures are counted by the stage they failed at, while the errors returned
+        /// to the client are unchanged.
+        #[tokio::test]
+        async fn counts_failures_by_stage() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = DiscreteLogarithmVerifier::new(config.clone());
+            let service = Service::new(config.clone().into(), Box::new(verifier.clone()));
+            let prover = DiscreteLogarithmProver::new(config);
+
+            let x = prover.generate_registration_x();
+            let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+            service
+                .store
+                .insert_user(
+                    "peggy",
+                    User {
+                        y1,
+                        y2,
+                        metadata: Default::default(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert user into store"))?;
+            let k = prover.generate_challenge_k();
+            let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+            let mut auth_ids = Vec::new();
+            for user in ["peggy", "victor"] {
+                let auth_id = Uuid::new_v4();
+                service
+                    .store
+                    .insert_challenge(
+                        auth_id,
+                        Challenge {
+                            user: user.to_string(),
+                            c: verifier.generate_challenge_c(),
+                            r1: r1.clone(),
+                            r2: r2.clone(),
+                            issued_at: SystemTime::now(),
+                        },
+                    )
+                    .await
+                    .map_err(|_| Status::internal("Failed to insert challenge into store"))?;
+                auth_ids.push(auth_id.to_string());
+            }
+
+            let answer = |auth_id: &str, s: &str| {
+                Request::new(AuthenticationAnswerRequest {
+                    auth_id: auth_id.to_string(),
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                })
+            };
+            for (auth_id, s, code, message) in [
+                (
+                    &auth_ids[0],
+                    "x",
+                    Code::InvalidArgument,
+                    "Invalid s argument",
+                ),
+                (
+                    &auth_ids[0],
+                    "1",
+                    Code::FailedPrecondition,
+                    "Verification failed",
+                ),
+                (&auth_ids[1], "1", Code::NotFound, "User not found"),
+                (
+                    &Uuid::new_v4().to_string(),
+                    "1",
+                    Code::NotFound,
+                    "Challenge not found",
+                ),
+                (
+                    &"".to_string(),
+                    "1",
+                    Code::InvalidArgument,
+                    "Invalid auth_id argument",
+                ),
+            ] {
+                let err = service
+                    .verify_authentication(answer(auth_id, s))
+                    .await
+                    .unwrap_err();
+                assert_eq!(err.code(), code);
+                assert_eq!(err.message(), message);
+            }
+
+            assert_eq!(
+                service.verification_failures(),
+                FailureCounts {
+                    parse: 2,
+                    unknown_user: 1,
+                    verification: 1,
+                    other: 1,
+                }
+            );
+
+            Ok(())
+        }
+
+        /// Tests that a service with the null verifier authenticates with any answer.
+        #[cfg(feature = "null-verifier")]
+        #[tokio::test]
+        async fn accepts_any_answer_with_null_verifier() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = zkauth::null::NullVerifier::new(Box::new(
+                DiscreteLogarithmVerifier::new(config.clone()),
+            ));
+            let service = Service::new(config.clone().into(), Box::new(verifier));
+            let prover = DiscreteLogarithmProver::new(config);
+
+            let (y1, y2) = prover.compute_registration_y1y2(&prover.generate_registration_x())?;
+            service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: y1.to_string(),
+                    y2: y2.to_string(),
+                    metadata: Default::default(),
+                }))
+                .await?;
+            let (r1, r2) =
+                prover.compute_challenge_commitment_r1r2(&prover.generate_challenge_k())?;
+            let challenge = service
+                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                    user: "peggy".to_string(),
+                    r1: r1.to_string(),
+                    r2: r2.to_string(),
+                }))
+                .await?
+                .into_inner();
+
+            let resp = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: challenge.auth_id,
+                    s: "1".to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await?
+                .into_inner();
+            Uuid::parse_str(&resp.session_id)?;
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method uses the configured session id generator.
+        #[tokio::test]
+        async fn uses_session_id_generator() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = DiscreteLogarithmVerifier::new(config.clone());
+            let service = Service::new(config.clone().into(), Box::new(verifier.clone()))
+                .with_session_id_generator(Box::new(SequentialSessionIdGenerator(AtomicU64::new(
+                    1,
+                ))));
+            let prover = DiscreteLogarithmProver::new(config);
+
+            let x = prover.generate_registration_x();
+            let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+            service
+                .store
+                .insert_user(
+                    "peggy",
+                    User {
+                        y1,
+                        y2,
+                        metadata: Default::default(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert user into store"))?;
+
+            for expected in 1..=2u128 {
+                let k = prover.generate_challenge_k();
+                let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+                let c = verifier.generate_challenge_c();
+                let s = prover.compute_challenge_response_s(&x, &k, c.clone())?;
+                let auth_id = Uuid::new_v4();
+                service
+                    .store
+                    .insert_challenge(
+                        auth_id,
+                        Challenge {
+                            user: "peggy".to_string(),
+                            c,
+                            r1,
+                            r2,
+                            issued_at: SystemTime::now(),
+                        },
+                    )
+                    .await
+                    .map_err(|_| Status::internal("Failed to insert challenge into store"))?;
+
+                let resp = service
+                    .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                        auth_id: auth_id.to_string(),
+                        s: s.to_string(),
+                        c: "".to_string(),
+                        mac: "".to_string(),
+                    }))
+                    .await?
+                    .into_inner();
+
+                assert_eq!(resp.session_id, Uuid::from_u128(expected).to_string());
+            }
+
+            Ok(())
+        }
+
+        /// Logs in twice with different proofs, and returns the two session ids.
+        async fn login_repeatedly(strategy: SessionKeyStrategy) -> Result<[String; 2]> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = DiscreteLogarithmVerifier::new(config.clone());
+            let service = Service::new(config.clone().into(), Box::new(verifier))
+                .with_session_key_strategy(strategy);
+            let prover = DiscreteLogarithmProver::new(config);
+
+            let verify = |auth_id: String, s: Scalar| {
+                service.verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id,
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+            };
+
+            let (auth_id, s) = issue_challenge(&service, &prover).await?;
+            let first = verify(auth_id, s).await?.into_inner();
+
+            let (auth_id, s) = issue_challenge(&service, &prover).await?;
+            let second = verify(auth_id, s).await?.into_inner();
+
+            Ok([first.session_id, second.session_id])
+        }
+
+        /// Tests that the user session key strategy returns the same session for every login.
+        #[tokio::test]
+        async fn user_session_key_strategy_reuses_session_across_logins() -> Result<()> {
+            let [first, second] = login_repeatedly(SessionKeyStrategy::User).await?;
+            assert_eq!(first, second);
+            Ok(())
+        }
+
+        /// Tests that the user-proof session key strategy returns a new session for a new proof.
+        #[tokio::test]
+        async fn user_proof_session_key_strategy_returns_new_session_for_new_proof() -> Result<()> {
+            let [first, second] = login_repeatedly(SessionKeyStrategy::UserProof).await?;
+            assert_ne!(first, second);
+            Ok(())
+        }
+
+        /// Tests that the fresh session key strategy returns a new session for every verification.
+        #[tokio::test]
+        async fn fresh_session_key_strategy_never_reuses_session() -> Result<()> {
+            let [first, second] = login_repeatedly(SessionKeyStrategy::Fresh).await?;
+            assert_ne!(first, second);
+            Ok(())
+        }
+
+        /// Tests that a verified answer cannot be replayed, since its challenge is taken from the
+        /// store by the first verification.
+        #[tokio::test]
+        async fn rejects_replayed_answer() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = DiscreteLogarithmVerifier::new(config.clone());
+            let service = Service::new(config.clone().into(), Box::new(verifier));
+            let prover = DiscreteLogarithmProver::new(config);
+
+            let (auth_id, s) = issue_challenge(&service, &prover).await?;
+            let request = AuthenticationAnswerRequest {
+                auth_id,
+                s: s.to_string(),
+                c: "".to_string(),
+                mac: "".to_string(),
+            };
+            service
+                .verify_authentication(Request::new(request.clone()))
+                .await?;
+            let err = service
+                .verify_authentication(Request::new(request))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::NotFound);
+            assert_eq!(err.message(), "Challenge not found");
+
+            Ok(())
+        }
+
+        /// Tests that a failed answer also uses up its challenge, so the correct answer cannot be
+        /// guessed over repeated attempts.
+        #[tokio::test]
+        async fn rejects_answer_after_failed_attempt() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = DiscreteLogarithmVerifier::new(config.clone());
+            let service = Service::new(config.clone().into(), Box::new(verifier));
+            let prover = DiscreteLogarithmProver::new(config);
+
+            let (auth_id, s) = issue_challenge(&service, &prover).await?;
+            let verify = |s: Scalar| {
+                service.verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: auth_id.clone(),
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+            };
+            let wrong = Scalar(&s.0 + 1u32);
+            assert_eq!(
+                verify(wrong).await.unwrap_err().code(),
+                Code::FailedPrecondition
+            );
+            let err = verify(s).await.unwrap_err();
+            assert_eq!(err.code(), Code::NotFound);
+            assert_eq!(err.message(), "Challenge not found");
+
+            Ok(())
+        }
+
+        /// Tests that concurrent identical verify_authentication calls, such as a client retrying
+        /// an answer whose response was lost, create exactly one session, with every other call
+        /// failing to find the challenge the first one took.
+        #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+        async fn concurrent_identical_answers_create_one_session() -> Result<()> {
+            const CALLERS: u64 = 16;
+
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = DiscreteLogarithmVerifier::new(config.clone());
+            let service = Arc::new(
+                Service::new(config.clone().into(), Box::new(verifier))
+                    .with_clock(Box::new(ManualClock::new()))
+                    .with_session_id_generator(Box::new(SequentialSessionIdGenerator(
+                        AtomicU64::new(1),
+                    ))),
+            );
+            let prover = DiscreteLogarithmProver::new(config);
+            let mut events = service.store.subscribe_session_events().await;
+
+            let (auth_id, s) = issue_challenge(&service, &prover).await?;
+            let barrier = Arc::new(tokio::sync::Barrier::new(CALLERS as usize));
+            let mut tasks = tokio::task::JoinSet::new();
+            for _ in 0..CALLERS {
+                let service = service.clone();
+                let barrier = barrier.clone();
+                let request = AuthenticationAnswerRequest {
+                    auth_id: auth_id.clone(),
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                };
+                tasks.spawn(async move {
+                    barrier.wait().await;
+                    service.verify_authentication(Request::new(request)).await
+                });
+            }
+            let mut session_ids = Vec::new();
+            while let Some(result) = tasks.join_next().await {
+                match result? {
+                    Ok(response) => session_ids.push(response.into_inner().session_id),
+                    Err(err) => assert_eq!(err.message(), "Challenge not found"),
+                }
+            }
+
+            // Exactly one caller takes the challenge and receives a session.
+            assert_eq!(session_ids.len(), 1);
+            let session_id = Uuid::parse_str(&session_ids[0])?;
+            assert!((1..=CALLERS as u128).contains(&session_id.as_u128()));
+
+            let mut created = Vec::new();
+            while let Ok(event) = events.try_recv() {
+                if let SessionEvent::Created { session_id, .. } = event {
+                    created.push(session_id);
+                }
+            }
+            assert_eq!(created, vec![session_id]);
+            assert_eq!(service.duplicate_sessions_avoided(), 0);
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method rejects s at or above q as out of range,
+        /// before looking up the challenge, while values in [0, q) proceed to the lookup.
+        #[tokio::test]
+        async fn checks_s_is_in_range() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier);
+
+            for (s, valid) in [
+                (BigInt::zero(), true),
+                (&config.q - 1, true),
+                (config.q.clone(), false),
+                (&config.q + 1, false),
+            ] {
+                let err = service
+                    .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                        auth_id: Uuid::new_v4().to_string(),
+                        s: s.to_string(),
+                        c: "".to_string(),
+                        mac: "".to_string(),
+                    }))
+                    .await
+                    .unwrap_err();
+                if valid {
+                    assert_eq!(err.code(), Code::NotFound, "{}", s);
+                } else {
+                    assert_eq!(err.code(), Code::InvalidArgument);
+                    assert_eq!(err.message(), "Invalid s argument: out of range");
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method returns an error when the challenge is not found.
+        #[tokio::test]
+        async fn returns_not_found_when_unknown_challenge() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: Uuid::new_v4().to_string(),
+                    s: "1".to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::NotFound);
+            assert_eq!(err.message(), "Challenge not found");
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method returns an error when the auth_id is not a UUID.
+        #[tokio::test]
+        async fn returns_invalid_argument_when_auth_id_is_not_a_uuid() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: "not-a-uuid".to_string(),
+                    s: "1".to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid auth_id argument");
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method rejects an auth_id that is a UUID of a
+        /// version other than 4, which the server never issues.
+        #[tokio::test]
+        async fn returns_invalid_argument_when_auth_id_is_not_v4() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: "c232ab00-9414-11ec-b3c8-9f6bdeced846".to_string(),
+                    s: "1".to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(
+                err.message(),
+                "Invalid auth_id argument: not a version 4 UUID"
+            );
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method accepts a v4 auth_id, looking it up in the
+        /// store.
+        #[tokio::test]
+        async fn looks_up_v4_auth_id() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: "5f0c3a1e-8a4b-4d2e-9c7f-1b2a3c4d5e6f".to_string(),
+                    s: "1".to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::NotFound);
+            assert_eq!(err.message(), "Challenge not found");
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method returns an error when the user is not found.
+        #[tokio::test]
+        async fn returns_not_found_when_unknown_user() -> Result<()> {
+            let service = test_service();
+            let id = Uuid::new_v4();
+            service
+                .store
+                .insert_challenge(
+                    id,
+                    Challenge {
+                        user: "unknown".to_string(),
+                        c: One::one(),
+                        r1: One::one(),
+                        r2: One::one(),
+                        issued_at: SystemTime::now(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert challenge into store"))?;
+
+            let result = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: id.to_string(),
+                    s: "1".to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::NotFound);
+            assert_eq!(err.message(), "User not found");
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method returns an error when the auth_id is empty.
+        #[tokio::test]
+        async fn returns_error_when_auth_id_is_empty() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: "".to_string(),
+                    s: "1".to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid auth_id argument");
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method returns an error when r1 is empty.
+        #[tokio::test]
+        async fn returns_error_when_r1_is_empty() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: "".to_string(),
+                    s: "".to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid s argument");
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method returns an error when r1 is not a number.
+        #[tokio::test]
+        async fn returns_error_when_r1_is_not_a_number() -> Result<()> {
+            let service = test_service();
+            let result = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: "".to_string(),
+                    s: "not-a-number".to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid s argument");
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method returns an error when the verification fails.
+        #[tokio::test]
+        async fn returns_verification_failed() -> Result<()> {
+            let service = test_service();
+
+            service
+                .store
+                .insert_user(
+                    "peggy",
+                    User {
+                        y1: One::one(),
+                        y2: One::one(),
+                        metadata: Default::default(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert user into store"))?;
+            let id = Uuid::new_v4();
+            service
+                .store
+                .insert_challenge(
+                    id,
+                    Challenge {
+                        user: "peggy".to_string(),
+                        c: One::one(),
+                        r1: One::one(),
+                        r2: One::one(),
+                        issued_at: SystemTime::now(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert challenge into store"))?;
+
+            let result = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: id.to_string(),
+                    s: "1".to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await;
+
+            let err = result.unwrap_err();
+            assert_eq!(err.code(), Code::FailedPrecondition);
+            assert_eq!(err.message(), "Verification failed");
+
+            Ok(())
+        }
+        /// A sleeper that records the requested delays instead of sleeping.
+        #[derive(Default, Clone)]
+        struct RecordingSleeper(Arc<Mutex<Vec<Duration>>>);
+
+        #[tonic::async_trait]
+        impl Sleeper for RecordingSleeper {
+            async fn sleep(&self, duration: Duration) {
+                self.0.lock().unwrap().push(duration);
+            }
+        }
+
+        /// Tests that the verify_authentication method applies the jitter to error responses.
+        #[tokio::test]
+        async fn applies_auth_error_jitter_to_errors() -> Result<()> {
+            let sleeper = RecordingSleeper::default();
+            let service = test_service()
+                .with_auth_error_jitter(Duration::from_millis(10))
+                .with_sleeper(Box::new(sleeper.clone()));
+
+            let err = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: Uuid::new_v4().to_string(),
+                    s: "1".to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::NotFound);
+
+            let delays = sleeper.0.lock().unwrap().clone();
+            assert_eq!(delays.len(), 1);
+            assert!(delays[0] <= Duration::from_millis(10));
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method does not apply the jitter on success.
+        #[tokio::test]
+        async fn does_not_apply_auth_error_jitter_on_success() -> Result<()> {
+            let sleeper = RecordingSleeper::default();
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = DiscreteLogarithmVerifier::new(config.clone());
+            let service = Service::new(config.clone().into(), Box::new(verifier.clone()))
+                .with_auth_error_jitter(Duration::from_millis(10))
+                .with_sleeper(Box::new(sleeper.clone()));
+            let prover = DiscreteLogarithmProver::new(config);
+
+            let x = prover.generate_registration_x();
+            let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+            let k = prover.generate_challenge_k();
+            let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+            let c = verifier.generate_challenge_c();
+            let s = prover.compute_challenge_response_s(&x, &k, c.clone())?;
+            let auth_id = Uuid::new_v4();
+            service
+                .store
+                .insert_user(
+                    "peggy",
+                    User {
+                        y1,
+                        y2,
+                        metadata: Default::default(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert user into store"))?;
+            service
+                .store
+                .insert_challenge(
+                    auth_id,
+                    Challenge {
+                        user: "peggy".to_string(),
+                        c,
+                        r1,
+                        r2,
+                        issued_at: SystemTime::now(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert challenge into store"))?;
+
+            service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: auth_id.to_string(),
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await?;
+
+            assert!(sleeper.0.lock().unwrap().is_empty());
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method does not sleep when the jitter is disabled.
+        #[tokio::test]
+        async fn does_not_apply_auth_error_jitter_when_disabled() -> Result<()> {
+            let sleeper = RecordingSleeper::default();
+            let service = test_service().with_sleeper(Box::new(sleeper.clone()));
+
+            let err = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: Uuid::new_v4().to_string(),
+                    s: "1".to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::NotFound);
+
+            assert!(sleeper.0.lock().unwrap().is_empty());
+
+            Ok(())
+        }
+
+        /// Issues a challenge for a registered user and returns its auth_id and the answer s.
+        async fn issue_challenge(
+            service: &Service,
+            prover: &DiscreteLogarithmProver,
+        ) -> Result<(String, Scalar)> {
+            let x = prover.compute_registration_x("password".to_string());
+            let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+            let _ = service
+                .store
+                .insert_user_if_absent(
+                    "peggy",
+                    User {
+                        y1,
+                        y2,
+                        metadata: Default::default(),
+                    },
+                )
+                .await;
+
+            let k = prover.generate_challenge_k();
+            let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+            let challenge = service
+                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                    user: "peggy".to_string(),
+                    r1: r1.to_string(),
+                    r2: r2.to_string(),
+                }))
+                .await?
+                .into_inner();
+            let c: Scalar = challenge.c.parse().unwrap();
+            let s = prover.compute_challenge_response_s(&x, &k, c)?;
+            Ok((challenge.auth_id, s))
+        }
+
+        /// Tests that the verify_authentication method accepts an answer at the maximum challenge
+        /// age, and returns deadline_exceeded just after it.
+        #[tokio::test]
+        async fn checks_challenge_max_age() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = DiscreteLogarithmVerifier::new(config.clone());
+            let clock = ManualClock::new();
+            let service = Service::new(config.clone().into(), Box::new(verifier))
+                .with_clock(Box::new(clock.clone()))
+                .with_challenge_max_age(Duration::from_secs(30));
+            let prover = DiscreteLogarithmProver::new(config);
+
+            let (auth_id, s) = issue_challenge(&service, &prover).await?;
+            clock.advance(Duration::from_secs(30));
+            service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id,
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await?;
+
+            let (auth_id, s) = issue_challenge(&service, &prover).await?;
+            clock.advance(Duration::from_secs(30) + Duration::from_millis(1));
+            let err = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id,
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::DeadlineExceeded);
+            assert_eq!(err.message(), "Challenge expired");
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method returns a session token signed with the
+        /// signing key, for the user and session, which expires after its ttl.
+        #[tokio::test]
+        async fn returns_signed_session_token() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = DiscreteLogarithmVerifier::new(config.clone());
+            let clock = ManualClock::new();
+            let service = Service::new(config.clone().into(), Box::new(verifier))
+                .with_clock(Box::new(clock.clone()))
+                .with_session_token_issuer(SessionTokenIssuer::new(
+                    b"secret",
+                    Duration::from_secs(60),
+                ));
+            let prover = DiscreteLogarithmProver::new(config);
+            let verify = |auth_id: String, s: Scalar| {
+                service.verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id,
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+            };
+
+            // A token issued ten minutes ago has expired, past the validation leeway.
+            let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?;
+            clock.advance(now - Duration::from_secs(600));
+            let (auth_id, s) = issue_challenge(&service, &prover).await?;
+            let resp = verify(auth_id, s).await?.into_inner();
+            let err = validate_session_token(b"secret", &resp.token).unwrap_err();
+            assert_eq!(err.kind(), &ErrorKind::ExpiredSignature);
+
+            // A token issued now is valid, and only with the signing key.
+            clock.advance(Duration::from_secs(600));
+            let (auth_id, s) = issue_challenge(&service, &prover).await?;
+            let resp = verify(auth_id, s).await?.into_inner();
+            let claims = validate_session_token(b"secret", &resp.token)?;
+            assert_eq!(claims.sub, "peggy");
+            assert_eq!(claims.sid, resp.session_id);
+            assert_eq!(claims.exp, (now + Duration::from_secs(60)).as_secs());
+            let err = validate_session_token(b"other", &resp.token).unwrap_err();
+            assert_eq!(err.kind(), &ErrorKind::InvalidSignature);
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method returns no session token without a
+        /// signing key.
+        #[tokio::test]
+        async fn returns_no_session_token_by_default() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = DiscreteLogarithmVerifier::new(config.clone());
+            let service = Service::new(config.clone().into(), Box::new(verifier));
+            let prover = DiscreteLogarithmProver::new(config);
+
+            let (auth_id, s) = issue_challenge(&service, &prover).await?;
+            let resp = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id,
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await?
+                .into_inner();
+            assert!(!resp.session_id.is_empty());
+            assert_eq!(resp.token, "");
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method returns not found for a challenge that has
+        /// expired from the store.
+        #[tokio::test]
+        async fn returns_not_found_for_expired_challenge() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = DiscreteLogarithmVerifier::new(config.clone());
+            let clock = ManualClock::new();
+            let store = MemoryStore::new(Duration::from_secs(60), Duration::from_secs(60))
+                .with_clock(Box::new(clock.clone()));
+            let service = Service::new(config.clone().into(), Box::new(verifier))
+                .with_clock(Box::new(clock.clone()))
+                .with_store(Box::new(store));
+            let prover = DiscreteLogarithmProver::new(config);
+
+            let (auth_id, s) = issue_challenge(&service, &prover).await?;
+            clock.advance(Duration::from_secs(60));
+            let err = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id,
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::NotFound);
+            assert_eq!(err.message(), "Challenge not found");
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method does not check the challenge age unless a
+        /// maximum is set.
+        #[tokio::test]
+        async fn ignores_challenge_age_without_max_age() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = DiscreteLogarithmVerifier::new(config.clone());
+            let clock = ManualClock::new();
+            let service = Service::new(config.clone().into(), Box::new(verifier))
+                .with_clock(Box::new(clock.clone()));
+            let prover = DiscreteLogarithmProver::new(config);
+
+            let (auth_id, s) = issue_challenge(&service, &prover).await?;
+            clock.advance(Duration::from_secs(24 * 60 * 60));
+            service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id,
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                }))
+                .await?;
+
+            Ok(())
+        }
+
+        /// Tests that the verify_authentication method accepts an answer echoing the challenge's c
+        /// and MAC unchanged, and rejects one whose c was tampered with or whose MAC is missing.
+        #[tokio::test]
+        async fn checks_challenge_mac() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = DiscreteLogarithmVerifier::new(config.clone());
+            let service = Service::new(config.clone().into(), Box::new(verifier))
+                .with_challenge_mac_secret("secret");
+            let prover = DiscreteLogarithmProver::new(config);
+
+            let x = prover.compute_registration_x("password".to_string());
+            let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+            service
+                .store
+                .insert_user(
+                    "peggy",
+                    User {
+                        y1,
+                        y2,
+                        metadata: Default::default(),
+                    },
+                )
+                .await
+                .map_err(|_| Status::internal("Failed to insert user into store"))?;
+
+            let issue = || async {
+                let k = prover.generate_challenge_k();
+                let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+                let challenge = service
+                    .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                        user: "peggy".to_string(),
+                        r1: r1.to_string(),
+                        r2: r2.to_string(),
+                    }))
+                    .await?
+                    .into_inner();
+                assert!(!challenge.mac.is_empty());
+                anyhow::Ok((challenge, k))
+            };
+
+            // An untampered challenge verifies.
+            let (challenge, k) = issue().await?;
+            let s = prover.compute_challenge_response_s(&x, &k, challenge.c.parse().unwrap())?;
+            service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: challenge.auth_id,
+                    s: s.to_string(),
+                    c: challenge.c,
+                    mac: challenge.mac,
+                }))
+                .await?;
+
+            // A c altered in transit is answered by the client and echoed back, and is detected.
+            let (challenge, k) = issue().await?;
+            let tampered: Scalar =
+                challenge.c.parse::<Scalar>().unwrap() + Scalar::from(BigInt::one());
+            let s = prover.compute_challenge_response_s(&x, &k, tampered.clone())?;
+            let err = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: challenge.auth_id,
+                    s: s.to_string(),
+                    c: tampered.to_string(),
+                    mac: challenge.mac,
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::FailedPrecondition);
+            assert_eq!(err.message(), "Challenge was tampered with");
+
+            // An answer without the MAC is rejected.
+            let (challenge, k) = issue().await?;
+            let s = prover.compute_challenge_response_s(&x, &k, challenge.c.parse().unwrap())?;
+            let err = service
+                .verify_authentication(Request::new(AuthenticationAnswerRequest {
+                    auth_id: challenge.auth_id,
+                    s: s.to_string(),
+                    c: challenge.c,
+                    mac: "".to_string(),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+            assert_eq!(err.message(), "Invalid mac argument");
+
+            Ok(())
+        }
+
+        /// An audit log that keeps the entries written to it.
+        pub(crate) struct MemoryAuditLog(pub(crate) Arc<Mutex<Vec<AuditEntry>>>);
+
+        impl AuditLog for MemoryAuditLog {
+            fn write(&self, entry: &AuditEntry) {
+                self.0.lock().unwrap().push(entry.clone());
+            }
+        }
+
+        /// Tests that the allowlisted metadata of a request passed through the metadata
+        /// interceptor is included in the audit entry.
+        #[tokio::test]
+        async fn writes_audit_entry_with_request_metadata() -> Result<()> {
+            use crate::audit::MetadataInterceptor;
+            use tonic::metadata::MetadataValue;
+            use tonic::service::Interceptor;
+
+            let entries = Arc::new(Mutex::new(Vec::new()));
+            let service = test_service().with_audit_log(Box::new(MemoryAuditLog(entries.clone())));
+            let auth_id = Uuid::new_v4().to_string();
+
+            let mut interceptor = MetadataInterceptor::new(vec!["x-request-id".to_string()]);
+            let mut request = Request::new(());
+            request
+                .metadata_mut()
+                .insert("x-request-id", MetadataValue::from_static("req-1"));
+            request
+                .metadata_mut()
+                .insert("x-tenant", MetadataValue::from_static("acme"));
+            let (metadata, extensions, _) = interceptor.call(request)?.into_parts();
+
+            let err = service
+                .verify_authentication(Request::from_parts(
+                    metadata,
+                    extensions,
+                    AuthenticationAnswerRequest {
+                        auth_id: auth_id.clone(),
+                        s: "1".to_string(),
+                        c: "".to_string(),
+                        mac: "".to_string(),
+                    },
+                ))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::NotFound);
+
+            assert_eq!(
+                *entries.lock().unwrap(),
+                vec![AuditEntry {
+                    auth_id,
+                    outcome: RecordedOutcome::from_status(&err),
+                    failure: Some(FailureStage::Other),
+                    metadata: [("x-request-id".to_string(), "req-1".to_string())].into(),
+                }]
+            );
+
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod authenticate {
+        use super::*;
+        use crate::clock::ManualClock;
+        use zkauth::{
+            discrete_logarithm::prover::DiscreteLogarithmProver,
+            elliptic_curve::prover::EllipticCurveProver, Prover,
+        };
+
+        /// Returns the current system time in seconds since the Unix epoch.
+        fn now() -> u64 {
+            SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+        }
+
+        /// Registers the user with the given x, and returns a request authenticating them with a
+        /// non-interactive proof of it computed at the given time.
+        async fn register_and_prove(
+            service: &Service,
+            prover: &dyn Prover,
+            user: &str,
+            x: Scalar,
+            timestamp: u64,
+        ) -> Result<AuthenticateRequest> {
+            let binding = ProofBinding {
+                user: user.to_string(),
+                timestamp,
+            };
+            let proof = prover.compute_noninteractive_proof(&x, binding)?;
+            service
+                .register(Request::new(RegisterRequest {
+                    user: user.to_string(),
+                    y1: proof.context.y1.to_string(),
+                    y2: proof.context.y2.to_string(),
+                    metadata: Default::default(),
+                }))
+                .await?;
+            Ok(AuthenticateRequest {
+                user: user.to_string(),
+                r1: proof.context.r1.to_string(),
+                r2: proof.context.r2.to_string(),
+                s: proof.s.to_string(),
+                timestamp,
+            })
+        }
+
+        /// Tests that a valid proof creates a session, for both flavors.
+        #[tokio::test]
+        async fn succeeds() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier);
+            let prover = DiscreteLogarithmProver::new(config);
+            let x = prover.generate_registration_x();
+            let request = register_and_prove(&service, &prover, "peggy", x, now()).await?;
+            let resp = service.authenticate(Request::new(request)).await?;
+            Uuid::parse_str(&resp.into_inner().session_id)?;
+
+            let config = EllipticCurveConfiguration::generate();
+            let verifier = Box::new(EllipticCurveVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier);
+            let prover = EllipticCurveProver::new(config);
+            let x = prover.generate_registration_x();
+            let request = register_and_prove(&service, &prover, "peggy", x, now()).await?;
+            let resp = service.authenticate(Request::new(request)).await?;
+            Uuid::parse_str(&resp.into_inner().session_id)?;
+
+            Ok(())
+        }
+
+        /// Tests that a proof with a tampered s fails verification, and is counted as such.
+        #[tokio::test]
+        async fn fails_with_tampered_s() -> Result<()> {
+            let config = EllipticCurveConfiguration::generate();
+            let verifier = Box::new(EllipticCurveVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier);
+            let prover = EllipticCurveProver::new(config);
+            let x = prover.generate_registration_x();
+            let mut request = register_and_prove(&service, &prover, "peggy", x, now()).await?;
+            let s: Scalar = request.s.parse().unwrap();
+            request.s = (s + Scalar::one()).to_string();
+
+            let err = service
+                .authenticate(Request::new(request))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::FailedPrecondition);
+            assert_eq!(err.message(), "Verification failed");
+            assert_eq!(service.verification_failures().verification, 1);
+
+            Ok(())
+        }
+
+        /// Tests that a proof for another user's registration fails verification, since the
+        /// challenge is derived from the registration of the user being authenticated.
+        #[tokio::test]
+        async fn fails_with_proof_for_another_user() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier);
+            let prover = DiscreteLogarithmProver::new(config);
+            let mut request = register_and_prove(
+                &service,
+                &prover,
+                "peggy",
+                prover.generate_registration_x(),
+                now(),
+            )
+            .await?;
+            register_and_prove(
+                &service,
+                &prover,
+                "victor",
+                prover.generate_registration_x(),
+                now(),
+            )
+            .await?;
+            request.user = "victor".to_string();
+
+            let err = service
+                .authenticate(Request::new(request))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::FailedPrecondition);
+
+            Ok(())
+        }
+
+        /// Tests that an unknown user is not found, and that malformed fields are rejected before
+        /// the store is consulted.
+        #[tokio::test]
+        async fn rejects_unknown_user_and_invalid_arguments() -> Result<()> {
+            let service = test_service();
+            let request = AuthenticateRequest {
+                user: "peggy".to_string(),
+                r1: "2".to_string(),
+                r2: "3".to_string(),
+                s: "1".to_string(),
+                timestamp: now(),
+            };
+
+            let err = service
+                .authenticate(Request::new(request.clone()))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::NotFound);
+            assert_eq!(err.message(), "User not found");
+
+            for (field, request) in [
+                (
+                    "user",
+                    AuthenticateRequest {
+                        user: "".to_string(),
+                        ..request.clone()
+                    },
+                ),
+                (
+                    "r1",
+                    AuthenticateRequest {
+                        r1: "x".to_string(),
+                        ..request.clone()
+                    },
+                ),
+                (
+                    "s",
+                    AuthenticateRequest {
+                        s: "".to_string(),
+                        ..request.clone()
+                    },
+                ),
+            ] {
+                let err = service
+                    .authenticate(Request::new(request))
+                    .await
+                    .unwrap_err();
+                assert_eq!(err.code(), Code::InvalidArgument, "{}", field);
+                assert!(err.message().contains(field), "{}", err.message());
+            }
+            assert_eq!(service.verification_failures().parse, 3);
+
+            Ok(())
+        }
+
+        /// Tests that replaying a request that already authenticated fails, even while its proof
+        /// is fresh, and does not create another session.
+        #[tokio::test]
+        async fn rejects_replayed_request() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier);
+            let prover = DiscreteLogarithmProver::new(config);
+            let mut events = service.store.subscribe_session_events().await;
+            let x = prover.generate_registration_x();
+            let request = register_and_prove(&service, &prover, "peggy", x, now()).await?;
+            service.authenticate(Request::new(request.clone())).await?;
+
+            let err = service
+                .authenticate(Request::new(request))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::FailedPrecondition);
+            assert_eq!(err.message(), "Proof already used");
+            assert!(matches!(
+                events.try_recv(),
+                Ok(SessionEvent::Created { .. })
+            ));
+            assert!(events.try_recv().is_err());
+
+            Ok(())
+        }
+
+        /// Tests that a proof is accepted at the edges of the freshness window and rejected just
+        /// outside it, and that its timestamp cannot be moved back into the window, since it is
+        /// bound into the challenge.
         #[tokio::test]
-        async fn succeeds_with_discrete_logarithm_config() -> Result<()> {
+        async fn checks_proof_freshness() -> Result<()> {
             let config = DiscreteLogarithmConfiguration::generate(16);
-            let config_pb: Configuration = config.clone().into();
-            let verifier = Box::new(DiscreteLogarithmVerifier::new(config));
-            let service = Service::new(config_pb.clone(), verifier);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let clock = ManualClock::new();
+            clock.advance(Duration::from_secs(1000));
+            let service = Service::new(config.clone().into(), verifier)
+                .with_clock(Box::new(clock.clone()))
+                .with_noninteractive_proof_window(Duration::from_secs(60));
+            let prover = DiscreteLogarithmProver::new(config);
+            let x = prover.generate_registration_x();
+            let stale = register_and_prove(&service, &prover, "peggy", x.clone(), 939).await?;
+            let err = service
+                .authenticate(Request::new(stale.clone()))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::DeadlineExceeded);
+            assert_eq!(err.message(), "Proof expired");
 
-            let resp = service
-                .get_configuration(Request::new(GetConfigurationRequest {}))
-                .await?
-                .into_inner();
+            // Moving the timestamp into the window changes the derived c.
+            let err = service
+                .authenticate(Request::new(AuthenticateRequest {
+                    timestamp: 1000,
+                    ..stale
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::FailedPrecondition);
+            assert_eq!(err.message(), "Verification failed");
 
-            assert_eq!(resp, config_pb);
+            for (timestamp, fresh) in [(940, true), (1060, true), (1061, false)] {
+                let binding = ProofBinding {
+                    user: "peggy".to_string(),
+                    timestamp,
+                };
+                let proof = prover.compute_noninteractive_proof(&x, binding)?;
+                let result = service
+                    .authenticate(Request::new(AuthenticateRequest {
+                        user: "peggy".to_string(),
+                        r1: proof.context.r1.to_string(),
+                        r2: proof.context.r2.to_string(),
+                        s: proof.s.to_string(),
+                        timestamp,
+                    }))
+                    .await;
+                assert_eq!(result.is_ok(), fresh, "{}", timestamp);
+            }
 
             Ok(())
         }
     }
 
     #[cfg(test)]
-    mod register {
+    mod update_credentials {
+        use super::verify_authentication::MemoryAuditLog;
         use super::*;
+        use std::sync::Mutex;
+        use zkauth::{discrete_logarithm::prover::DiscreteLogarithmProver, Prover};
 
-        /// Tests that the register method succeeds with valid arguments.
-        #[tokio::test]
-        async fn succeeds() -> Result<()> {
-            let service = test_service();
-            let resp = service
-                .register(Request::new(RegisterRequest {
+        /// Creates a challenge for the user and answers it with the given x.
+        pub(super) async fn answer(
+            service: &Service,
+            prover: &DiscreteLogarithmProver,
+            x: Scalar,
+        ) -> Result<AuthenticationAnswerRequest> {
+            let k = prover.generate_challenge_k();
+            let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+            let challenge = service
+                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
                     user: "peggy".to_string(),
-                    y1: "1".to_string(),
-                    y2: "1".to_string(),
+                    r1: r1.to_string(),
+                    r2: r2.to_string(),
                 }))
                 .await?
                 .into_inner();
-
-            assert_eq!(resp, RegisterResponse {});
-
-            Ok(())
+            let c: Scalar = challenge.c.parse().unwrap();
+            let s = prover.compute_challenge_response_s(&x, &k, c)?;
+            Ok(AuthenticationAnswerRequest {
+                auth_id: challenge.auth_id,
+                s: s.to_string(),
+                c: "".to_string(),
+                mac: "".to_string(),
+            })
         }
 
-        /// Tests that the register method returns an error when the user is already registered.
-        #[tokio::test]
-        async fn returns_error_when_user_already_registered() -> Result<()> {
-            let service = test_service();
-            service
-                .store
-                .insert_user(
-                    "peggy",
-                    User {
-                        y1: One::one(),
-                        y2: One::one(),
-                    },
-                )
-                .map_err(|_| Status::internal("Failed to insert user into store"))?;
+        /// Creates a service with a registered user, returning it along with a prover and the
+        /// user's x.
+        pub(super) async fn registered_service(
+        ) -> Result<(Service, DiscreteLogarithmProver, Scalar)> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = DiscreteLogarithmVerifier::new(config.clone());
+            let service = Service::new(config.clone().into(), Box::new(verifier));
+            let prover = DiscreteLogarithmProver::new(config);
 
-            let result = service
+            let x = prover.generate_registration_x();
+            let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+            service
                 .register(Request::new(RegisterRequest {
                     user: "peggy".to_string(),
-                    y1: "1".to_string(),
-                    y2: "1".to_string(),
+                    y1: y1.to_string(),
+                    y2: y2.to_string(),
+                    metadata: [("name".to_string(), "Peggy".to_string())].into(),
                 }))
-                .await;
-
-            let err = result.unwrap_err();
-            assert_eq!(err.code(), Code::AlreadyExists);
-            assert_eq!(err.message(), "User already registered");
-
-            Ok(())
+                .await?;
+            Ok((service, prover, x))
         }
 
-        /// Tests that the register method returns an error when the user is empty.
+        /// Tests that an answer with the current credentials rotates them to the new ones, after
+        /// which only the new credentials authenticate.
         #[tokio::test]
-        async fn returns_error_when_user_is_empty() -> Result<()> {
-            let service = test_service();
-            let result = service
-                .register(Request::new(RegisterRequest {
-                    user: "".to_string(),
-                    y1: "1".to_string(),
-                    y2: "1".to_string(),
+        async fn rotates_credentials_with_valid_answer() -> Result<()> {
+            let (service, prover, old_x) = registered_service().await?;
+            let new_x = prover.generate_registration_x();
+            let (y1, y2) = prover.compute_registration_y1y2(&new_x)?;
+
+            let old_answer = answer(&service, &prover, old_x.clone()).await?;
+            service
+                .update_credentials(Request::new(UpdateCredentialsRequest {
+                    answer: Some(old_answer.clone()),
+                    y1: y1.to_string(),
+                    y2: y2.to_string(),
                 }))
-                .await;
+                .await?;
 
-            let err = result.unwrap_err();
-            assert_eq!(err.code(), Code::InvalidArgument);
-            assert_eq!(err.message(), "Invalid user argument");
+            let user = service.store.get_user("peggy").await?.unwrap();
+            assert_eq!((user.y1, user.y2), (y1.clone(), y2.clone()));
+            assert_eq!(user.metadata["name"], "Peggy");
+
+            let resp = service
+                .verify_authentication(Request::new(answer(&service, &prover, new_x).await?))
+                .await?
+                .into_inner();
+            Uuid::parse_str(&resp.session_id)?;
+
+            let err = service
+                .verify_authentication(Request::new(answer(&service, &prover, old_x).await?))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::FailedPrecondition);
+
+            // The old answer cannot be replayed to change the credentials again.
+            let err = service
+                .update_credentials(Request::new(UpdateCredentialsRequest {
+                    answer: Some(old_answer),
+                    y1: y1.to_string(),
+                    y2: y2.to_string(),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::NotFound);
+            assert_eq!(err.message(), "Challenge not found");
 
             Ok(())
         }
 
-        /// Tests that the register method returns an error when y1 is empty.
+        /// Tests that an answer that does not verify with the current credentials is rejected,
+        /// leaving them unchanged.
         #[tokio::test]
-        async fn returns_error_when_y1_is_empty() -> Result<()> {
-            let service = test_service();
-            let result = service
-                .register(Request::new(RegisterRequest {
-                    user: "peggy".to_string(),
-                    y1: "".to_string(),
-                    y2: "1".to_string(),
-                }))
-                .await;
+        async fn returns_error_when_answer_fails_verification() -> Result<()> {
+            let (service, prover, _) = registered_service().await?;
+            let before = service.store.get_user("peggy").await?.unwrap();
+            let (y1, y2) = prover.compute_registration_y1y2(&prover.generate_registration_x())?;
 
-            let err = result.unwrap_err();
-            assert_eq!(err.code(), Code::InvalidArgument);
-            assert_eq!(err.message(), "Invalid y1 argument");
+            let wrong_x = prover.generate_registration_x();
+            let err = service
+                .update_credentials(Request::new(UpdateCredentialsRequest {
+                    answer: Some(answer(&service, &prover, wrong_x).await?),
+                    y1: y1.to_string(),
+                    y2: y2.to_string(),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::FailedPrecondition);
+            assert_eq!(err.message(), "Verification failed");
+            assert_eq!(service.store.get_user("peggy").await?.unwrap(), before);
 
             Ok(())
         }
 
-        /// Tests that the register method returns an error when y2 is empty.
+        /// Tests that invalid new credentials are rejected, leaving the current ones unchanged.
         #[tokio::test]
-        async fn returns_error_when_y2_is_empty() -> Result<()> {
-            let service = test_service();
-            let result = service
-                .register(Request::new(RegisterRequest {
-                    user: "peggy".to_string(),
-                    y1: "1".to_string(),
-                    y2: "".to_string(),
-                }))
-                .await;
+        async fn returns_error_with_invalid_credentials() -> Result<()> {
+            let (service, prover, x) = registered_service().await?;
+            let before = service.store.get_user("peggy").await?.unwrap();
 
-            let err = result.unwrap_err();
+            let err = service
+                .update_credentials(Request::new(UpdateCredentialsRequest {
+                    answer: Some(answer(&service, &prover, x).await?),
+                    y1: "x".to_string(),
+                    y2: "1".to_string(),
+                }))
+                .await
+                .unwrap_err();
             assert_eq!(err.code(), Code::InvalidArgument);
-            assert_eq!(err.message(), "Invalid y2 argument");
+            assert_eq!(err.message(), "Invalid y1 argument");
+            assert_eq!(service.store.get_user("peggy").await?.unwrap(), before);
 
             Ok(())
         }
 
-        /// Tests that the register method returns an error when y1 is not a number.
+        /// Tests that a request without an answer is rejected.
         #[tokio::test]
-        async fn returns_error_when_y1_is_not_a_number() -> Result<()> {
-            let service = test_service();
-            let result = service
-                .register(Request::new(RegisterRequest {
-                    user: "peggy".to_string(),
-                    y1: "not-a-number".to_string(),
+        async fn returns_error_without_answer() -> Result<()> {
+            let (service, _, _) = registered_service().await?;
+
+            let err = service
+                .update_credentials(Request::new(UpdateCredentialsRequest {
+                    answer: None,
+                    y1: "1".to_string(),
                     y2: "1".to_string(),
                 }))
-                .await;
-
-            let err = result.unwrap_err();
+                .await
+                .unwrap_err();
             assert_eq!(err.code(), Code::InvalidArgument);
-            assert_eq!(err.message(), "Invalid y1 argument");
+            assert_eq!(err.message(), "Invalid answer argument");
 
             Ok(())
         }
 
-        /// Tests that the register method returns an error when y2 is not a number.
+        /// Tests that failed requests are counted by stage and in the metrics, and audited with
+        /// the auth_id of their answer.
         #[tokio::test]
-        async fn returns_error_when_y2_is_not_a_number() -> Result<()> {
-            let service = test_service();
-            let result = service
-                .register(Request::new(RegisterRequest {
-                    user: "peggy".to_string(),
-                    y1: "1".to_string(),
-                    y2: "not-a-number".to_string(),
+        async fn counts_and_audits_failures() -> Result<()> {
+            let (service, prover, x) = registered_service().await?;
+            let entries = Arc::new(Mutex::new(Vec::new()));
+            let metrics = Arc::new(Metrics::new());
+            let service = service
+                .with_audit_log(Box::new(MemoryAuditLog(entries.clone())))
+                .with_metrics(metrics.clone());
+            let (y1, y2) = prover.compute_registration_y1y2(&prover.generate_registration_x())?;
+
+            let wrong_answer = answer(&service, &prover, prover.generate_registration_x()).await?;
+            let wrong_err = service
+                .update_credentials(Request::new(UpdateCredentialsRequest {
+                    answer: Some(wrong_answer.clone()),
+                    y1: y1.to_string(),
+                    y2: y2.to_string(),
                 }))
-                .await;
+                .await
+                .unwrap_err();
+            let invalid_answer = answer(&service, &prover, x).await?;
+            let invalid_err = service
+                .update_credentials(Request::new(UpdateCredentialsRequest {
+                    answer: Some(invalid_answer.clone()),
+                    y1: "x".to_string(),
+                    y2: "1".to_string(),
+                }))
+                .await
+                .unwrap_err();
 
-            let err = result.unwrap_err();
-            assert_eq!(err.code(), Code::InvalidArgument);
-            assert_eq!(err.message(), "Invalid y2 argument");
+            let counts = service.verification_failures();
+            assert_eq!((counts.verification, counts.parse), (1, 1));
+            assert_eq!(
+                *entries.lock().unwrap(),
+                vec![
+                    AuditEntry {
+                        auth_id: wrong_answer.auth_id,
+                        outcome: RecordedOutcome::from_status(&wrong_err),
+                        failure: Some(FailureStage::Verification),
+                        metadata: Default::default(),
+                    },
+                    AuditEntry {
+                        auth_id: invalid_answer.auth_id,
+                        outcome: RecordedOutcome::from_status(&invalid_err),
+                        failure: Some(FailureStage::Parse),
+                        metadata: Default::default(),
+                    },
+                ]
+            );
+            let text = metrics.encode();
+            assert!(
+                text.contains("zkauth_verify_total{result=\"failed\"} 2\n"),
+                "{}",
+                text
+            );
 
             Ok(())
         }
     }
 
     #[cfg(test)]
-    mod create_authentication_challenge {
+    mod unregister {
+        use super::update_credentials::{answer, registered_service};
+        use super::verify_authentication::MemoryAuditLog;
         use super::*;
+        use std::sync::Mutex;
+        use zkauth::Prover;
 
-        /// Tests that the create_authentication_challenge method succeeds with valid arguments.
+        /// Tests that a user can unregister with a valid answer, after which the answer cannot be
+        /// replayed and the username can be registered again.
         #[tokio::test]
-        async fn succeeds() -> Result<()> {
-            let service = test_service();
+        async fn unregisters_with_valid_answer_and_allows_registering_again() -> Result<()> {
+            let (service, prover, x) = registered_service().await?;
+
+            let answer = answer(&service, &prover, x).await?;
             service
-                .store
-                .insert_user(
-                    "peggy",
-                    User {
-                        y1: One::one(),
-                        y2: One::one(),
-                    },
-                )
-                .map_err(|_| Status::internal("Failed to insert user into store"))?;
+                .unregister(Request::new(UnregisterRequest {
+                    answer: Some(answer.clone()),
+                }))
+                .await?;
+            assert!(service.store.get_user("peggy").await?.is_none());
 
-            let resp = service
-                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
-                    user: "peggy".to_string(),
-                    r1: "1".to_string(),
-                    r2: "1".to_string(),
+            let err = service
+                .unregister(Request::new(UnregisterRequest {
+                    answer: Some(answer),
                 }))
-                .await?
-                .into_inner();
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::NotFound);
+            assert_eq!(err.message(), "Challenge not found");
 
-            Uuid::parse_str(&resp.auth_id)?;
-            resp.c.parse::<Scalar>().unwrap();
+            let (y1, y2) = prover.compute_registration_y1y2(&prover.generate_registration_x())?;
+            service
+                .register(Request::new(RegisterRequest {
+                    user: "peggy".to_string(),
+                    y1: y1.to_string(),
+                    y2: y2.to_string(),
+                    metadata: Default::default(),
+                }))
+                .await?;
+            let user = service.store.get_user("peggy").await?.unwrap();
+            assert_eq!((user.y1, user.y2), (y1, y2));
 
             Ok(())
         }
 
-        /// Tests that the create_authentication_challenge method returns an error when the user is
+        /// Tests that unregistering revokes the user's existing sessions and pending challenges.
         #[tokio::test]
-        async fn returns_not_found_when_unknown_user() -> Result<()> {
-            let service = test_service();
-            let result = service
-                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
-                    user: "unknown".to_string(),
-                    r1: "1".to_string(),
-                    r2: "1".to_string(),
+        async fn revokes_sessions_and_challenges() -> Result<()> {
+            let (service, prover, x) = registered_service().await?;
+
+            let session = service
+                .verify_authentication(Request::new(answer(&service, &prover, x.clone()).await?))
+                .await?
+                .into_inner();
+            let session_id = Uuid::parse_str(&session.session_id)?;
+            assert!(service.store.get_session_by_id(session_id).await?.is_some());
+            let pending = answer(&service, &prover, x.clone()).await?;
+
+            service
+                .unregister(Request::new(UnregisterRequest {
+                    answer: Some(answer(&service, &prover, x).await?),
                 }))
-                .await;
+                .await?;
+            assert!(service.store.get_session_by_id(session_id).await?.is_none());
 
-            let err = result.unwrap_err();
+            let err = service
+                .verify_authentication(Request::new(pending))
+                .await
+                .unwrap_err();
             assert_eq!(err.code(), Code::NotFound);
-            assert_eq!(err.message(), "User not found");
+            assert_eq!(err.message(), "Challenge not found");
 
             Ok(())
         }
 
-        /// Tests that the create_authentication_challenge method returns an error when the user is empty.
+        /// Tests that failed requests are counted by stage and in the metrics, and audited with
+        /// the auth_id of their answer, if any.
         #[tokio::test]
-        async fn returns_error_when_user_is_empty() -> Result<()> {
-            let service = test_service();
-            let result = service
-                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
-                    user: "".to_string(),
-                    r1: "1".to_string(),
-                    r2: "1".to_string(),
+        async fn counts_and_audits_failures() -> Result<()> {
+            let (service, prover, _) = registered_service().await?;
+            let entries = Arc::new(Mutex::new(Vec::new()));
+            let metrics = Arc::new(Metrics::new());
+            let service = service
+                .with_audit_log(Box::new(MemoryAuditLog(entries.clone())))
+                .with_metrics(metrics.clone());
+
+            let missing_err = service
+                .unregister(Request::new(UnregisterRequest { answer: None }))
+                .await
+                .unwrap_err();
+            let wrong_answer = answer(&service, &prover, prover.generate_registration_x()).await?;
+            let wrong_err = service
+                .unregister(Request::new(UnregisterRequest {
+                    answer: Some(wrong_answer.clone()),
                 }))
-                .await;
+                .await
+                .unwrap_err();
 
-            let err = result.unwrap_err();
-            assert_eq!(err.code(), Code::InvalidArgument);
-            assert_eq!(err.message(), "Invalid user argument");
+            let counts = service.verification_failures();
+            assert_eq!((counts.parse, counts.verification), (1, 1));
+            assert_eq!(
+                *entries.lock().unwrap(),
+                vec![
+                    AuditEntry {
+                        auth_id: "".to_string(),
+                        outcome: RecordedOutcome::from_status(&missing_err),
+                        failure: Some(FailureStage::Parse),
+                        metadata: Default::default(),
+                    },
+                    AuditEntry {
+                        auth_id: wrong_answer.auth_id,
+                        outcome: RecordedOutcome::from_status(&wrong_err),
+                        failure: Some(FailureStage::Verification),
+                        metadata: Default::default(),
+                    },
+                ]
+            );
+            let text = metrics.encode();
+            assert!(
+                text.contains("zkauth_verify_total{result=\"failed\"} 2\n"),
+                "{}",
+                text
+            );
 
             Ok(())
         }
 
-        /// Tests that the create_authentication_challenge method returns an error when r1 is empty.
+        /// Tests that a missing answer, or one that does not verify with the user's credentials,
+        /// is rejected, leaving the user registered.
         #[tokio::test]
-        async fn returns_error_when_r1_is_empty() -> Result<()> {
-            let service = test_service();
-            let result = service
-                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
-                    user: "peggy".to_string(),
-                    r1: "".to_string(),
-                    r2: "1".to_string(),
-                }))
-                .await;
+        async fn returns_error_without_valid_answer() -> Result<()> {
+            let (service, prover, _) = registered_service().await?;
 
-            let err = result.unwrap_err();
+            let err = service
+                .unregister(Request::new(UnregisterRequest { answer: None }))
+                .await
+                .unwrap_err();
             assert_eq!(err.code(), Code::InvalidArgument);
-            assert_eq!(err.message(), "Invalid r1 argument");
+            assert_eq!(err.message(), "Invalid answer argument");
+
+            let wrong_x = prover.generate_registration_x();
+            let err = service
+                .unregister(Request::new(UnregisterRequest {
+                    answer: Some(answer(&service, &prover, wrong_x).await?),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::FailedPrecondition);
+            assert_eq!(err.message(), "Verification failed");
+            assert!(service.store.get_user("peggy").await?.is_some());
 
             Ok(())
         }
+    }
 
-        /// Tests that the create_authentication_challenge method returns an error when r2 is empty.
-        #[tokio::test]
-        async fn returns_error_when_r2_is_empty() -> Result<()> {
-            let service = test_service();
-            let result = service
-                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+    #[cfg(test)]
+    mod memory_store {
+        use super::*;
+        use zkauth::{
+            discrete_logarithm::prover::DiscreteLogarithmProver,
+            elliptic_curve::prover::EllipticCurveProver, Prover,
+        };
+
+        /// Registers, challenges, and verifies a user through the service's store, then checks
+        /// that the answer cannot be replayed.
+        async fn authenticate(service: &Service, prover: &dyn Prover) -> Result<()> {
+            let x = prover.compute_registration_x("password".to_string());
+            let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+            service
+                .register(Request::new(RegisterRequest {
                     user: "peggy".to_string(),
-                    r1: "1".to_string(),
-                    r2: "".to_string(),
+                    y1: y1.to_string(),
+                    y2: y2.to_string(),
+                    metadata: Default::default(),
                 }))
-                .await;
-
-            let err = result.unwrap_err();
-            assert_eq!(err.code(), Code::InvalidArgument);
-            assert_eq!(err.message(), "Invalid r2 argument");
-
-            Ok(())
-        }
+                .await?;
+            assert!(service.store.get_user("peggy").await?.is_some());
 
-        /// Tests that the create_authentication_challenge method returns an error when r1 is not a number.
-        #[tokio::test]
-        async fn returns_error_when_r1_is_not_a_number() -> Result<()> {
-            let service = test_service();
-            let result = service
+            let k = prover.generate_challenge_k();
+            let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+            let challenge = service
                 .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
                     user: "peggy".to_string(),
-                    r1: "not-a-number".to_string(),
-                    r2: "1".to_string(),
+                    r1: r1.to_string(),
+                    r2: r2.to_string(),
                 }))
-                .await;
+                .await?
+                .into_inner();
+            let auth_id = Uuid::parse_str(&challenge.auth_id)?;
+            assert!(service.store.get_challenge(auth_id).await?.is_some());
 
-            let err = result.unwrap_err();
-            assert_eq!(err.code(), Code::InvalidArgument);
-            assert_eq!(err.message(), "Invalid r1 argument");
+            let s = prover.compute_challenge_response_s(&x, &k, challenge.c.parse().unwrap())?;
+            let request = AuthenticationAnswerRequest {
+                auth_id: challenge.auth_id,
+                s: s.to_string(),
+                c: "".to_string(),
+                mac: "".to_string(),
+            };
+            let session = service
+                .verify_authentication(Request::new(request.clone()))
+                .await?
+                .into_inner();
+            let session_id = Uuid::parse_str(&session.session_id)?;
+            assert!(service.store.get_session_by_id(session_id).await?.is_some());
+            assert!(service.store.get_challenge(auth_id).await?.is_none());
+
+            let err = service
+                .verify_authentication(Request::new(request))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::NotFound);
 
             Ok(())
         }
 
-        /// Tests that the create_authentication_challenge method returns an error when r2 is not a number.
+        /// Tests that the register, challenge, and verify flow passes through the async memory
+        /// store with the discrete logarithm flavor.
         #[tokio::test]
-        async fn returns_error_when_r2_is_not_a_number() -> Result<()> {
-            let service = test_service();
-            let result = service
-                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
-                    user: "peggy".to_string(),
-                    r1: "1".to_string(),
-                    r2: "not-a-number".to_string(),
-                }))
-                .await;
-
-            let err = result.unwrap_err();
-            assert_eq!(err.code(), Code::InvalidArgument);
-            assert_eq!(err.message(), "Invalid r2 argument");
+        async fn authenticates_with_discrete_logarithm() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+            let service = Service::new(config.clone().into(), verifier)
+                .with_store(Box::new(MemoryStore::default()));
+            authenticate(&service, &DiscreteLogarithmProver::new(config)).await
+        }
 
-            Ok(())
+        /// Tests that the register, challenge, and verify flow passes through the async memory
+        /// store with the elliptic curve flavor, from tasks on a multi-threaded runtime.
+        #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+        async fn authenticates_with_elliptic_curve() -> Result<()> {
+            let config = EllipticCurveConfiguration::generate();
+            let verifier = Box::new(EllipticCurveVerifier::new(config.clone()));
+            let service = Arc::new(
+                Service::new(config.clone().into(), verifier)
+                    .with_store(Box::new(MemoryStore::default())),
+            );
+            tokio::spawn(
+                async move { authenticate(&service, &EllipticCurveProver::new(config)).await },
+            )
+            .await?
         }
     }
 
     #[cfg(test)]
-    mod verify_authentication {
+    pub(crate) mod store_error {
         use super::*;
-        use zkauth::{discrete_logarithm::prover::DiscreteLogarithmProver, Prover};
-
-        /// Tests that the verify_authentication method succeeds with valid arguments.
-        #[tokio::test]
-        async fn succeeds() -> Result<()> {
-            let config = DiscreteLogarithmConfiguration::generate(16);
-            let config_pb: Configuration = config.clone().into();
-            let verifier = DiscreteLogarithmVerifier::new(config.clone());
-            let service = Service::new(config_pb.clone(), Box::new(verifier.clone()));
+        use crate::store::Result as StoreResult;
+        use tokio::sync::broadcast;
 
-            let prover = DiscreteLogarithmProver::new(config);
+        /// A store whose reads and writes all fail with a serialization error, as if every row
+        /// were corrupted.
+        pub(crate) struct CorruptedStore;
 
-            let user = "peggy";
-            let auth_id = Uuid::new_v4();
+        #[tonic::async_trait]
+        impl Store for CorruptedStore {
+            async fn insert_user(&self, _: &str, _: User) -> StoreResult<()> {
+                Err(StoreError::Serialization("corrupted user".to_string()))
+            }
 
-            let x = prover.generate_registration_x();
-            let (y1, y2) = prover.compute_registration_y1y2(x.clone())?;
-            let k = prover.generate_challenge_k();
-            let (r1, r2) = prover.compute_challenge_commitment_r1r2(k.clone()).unwrap();
-            let c = verifier.generate_challenge_c();
-            let s = prover
-                .compute_challenge_response_s(x, k, c.clone())
-                .unwrap();
+            async fn insert_user_if_absent(&self, _: &str, _: User) -> StoreResult<bool> {
+                Err(StoreError::Serialization("corrupted user".to_string()))
+            }
 
-            service
-                .store
-                .insert_user(user, User { y1, y2 })
-                .map_err(|_| Status::internal("Failed to insert user into store"))?;
-            service
-                .store
-                .insert_challenge(
-                    auth_id,
-                    Challenge {
-                        user: user.to_string(),
-                        c,
-                        r1,
-                        r2,
-                    },
-                )
-                .map_err(|_| Status::internal("Failed to insert challenge into store"))?;
+            async fn insert_user_with_challenge(
+                &self,
+                _: &str,
+                _: User,
+                _: Uuid,
+                _: Challenge,
+            ) -> StoreResult<bool> {
+                Err(StoreError::Serialization("corrupted user".to_string()))
+            }
 
-            let resp = service
-                .verify_authentication(Request::new(AuthenticationAnswerRequest {
-                    auth_id: auth_id.to_string(),
-                    s: s.to_string(),
-                }))
-                .await?
-                .into_inner();
+            async fn update_user(&self, _: &str, _: &User, _: User) -> StoreResult<bool> {
+                Err(StoreError::Serialization("corrupted user".to_string()))
+            }
 
-            Uuid::parse_str(&resp.session_id)?;
-            let original_session_id = resp.session_id;
+            async fn get_user(&self, _: &str) -> StoreResult<Option<User>> {
+                Err(StoreError::Serialization("y1 is not a number".to_string()))
+            }
 
-            let resp = service
-                .verify_authentication(Request::new(AuthenticationAnswerRequest {
-                    auth_id: auth_id.to_string(),
-                    s: s.to_string(),
-                }))
-                .await?
-                .into_inner();
+            async fn delete_user(&self, _: &str) -> StoreResult<bool> {
+                Err(StoreError::Serialization("corrupted user".to_string()))
+            }
 
-            assert_eq!(resp.session_id, original_session_id);
+            async fn insert_challenge(&self, _: Uuid, _: Challenge) -> StoreResult<()> {
+                Err(StoreError::Serialization("corrupted challenge".to_string()))
+            }
 
-            Ok(())
-        }
+            async fn insert_challenge_limited(
+                &self,
+                _: Uuid,
+                _: Challenge,
+                _: usize,
+                _: bool,
+            ) -> StoreResult<bool> {
+                Err(StoreError::Serialization("corrupted challenge".to_string()))
+            }
 
-        /// Tests that the verify_authentication method returns an error when the challenge is not found.
-        #[tokio::test]
-        async fn returns_not_found_when_unknown_challenge() -> Result<()> {
-            let service = test_service();
-            let result = service
-                .verify_authentication(Request::new(AuthenticationAnswerRequest {
-                    auth_id: Uuid::new_v4().to_string(),
-                    s: "1".to_string(),
-                }))
-                .await;
+            async fn get_challenge(&self, _: Uuid) -> StoreResult<Option<Challenge>> {
+                Err(StoreError::Serialization("c is not a number".to_string()))
+            }
 
-            let err = result.unwrap_err();
-            assert_eq!(err.code(), Code::NotFound);
-            assert_eq!(err.message(), "Challenge not found");
+            async fn take_challenge(&self, _: Uuid) -> StoreResult<Option<Challenge>> {
+                Err(StoreError::Serialization("c is not a number".to_string()))
+            }
 
-            Ok(())
-        }
+            async fn insert_session(&self, _: &str, _: Session) -> StoreResult<()> {
+                Err(StoreError::Serialization("corrupted session".to_string()))
+            }
 
-        /// Tests that the verify_authentication method returns an error when the auth_id is not a UUID.
-        #[tokio::test]
-        async fn returns_invalid_argument_when_auth_id_is_not_a_uuid() -> Result<()> {
-            let service = test_service();
-            let result = service
-                .verify_authentication(Request::new(AuthenticationAnswerRequest {
-                    auth_id: "not-a-uuid".to_string(),
-                    s: "1".to_string(),
-                }))
-                .await;
+            async fn get_or_insert_session(
+                &self,
+                _: &str,
+                _: Session,
+            ) -> StoreResult<(Session, bool)> {
+                Err(StoreError::Serialization("corrupted session".to_string()))
+            }
 
-            let err = result.unwrap_err();
-            assert_eq!(err.code(), Code::InvalidArgument);
-            assert_eq!(err.message(), "Invalid auth_id argument");
+            async fn get_session(&self, _: &str) -> StoreResult<Option<Session>> {
+                Err(StoreError::Serialization("corrupted session".to_string()))
+            }
 
-            Ok(())
-        }
+            async fn get_session_by_id(&self, _: Uuid) -> StoreResult<Option<Session>> {
+                Err(StoreError::Serialization("corrupted session".to_string()))
+            }
 
-        /// Tests that the verify_authentication method returns an error when the user is not found.
-        #[tokio::test]
-        async fn returns_not_found_when_unknown_user() -> Result<()> {
-            let service = test_service();
-            let id = Uuid::new_v4();
-            service
-                .store
-                .insert_challenge(
-                    id,
-                    Challenge {
-                        user: "unknown".to_string(),
-                        c: One::one(),
-                        r1: One::one(),
-                        r2: One::one(),
-                    },
-                )
-                .map_err(|_| Status::internal("Failed to insert challenge into store"))?;
+            async fn delete_session(&self, _: &str) -> StoreResult<bool> {
+                Err(StoreError::Serialization("corrupted session".to_string()))
+            }
 
-            let result = service
-                .verify_authentication(Request::new(AuthenticationAnswerRequest {
-                    auth_id: id.to_string(),
-                    s: "1".to_string(),
-                }))
-                .await;
+            async fn insert_proof_if_unseen(&self, _: &str, _: Duration) -> StoreResult<bool> {
+                Err(StoreError::Serialization("corrupted proof".to_string()))
+            }
 
-            let err = result.unwrap_err();
-            assert_eq!(err.code(), Code::NotFound);
-            assert_eq!(err.message(), "User not found");
+            async fn subscribe_session_events(&self) -> broadcast::Receiver<SessionEvent> {
+                broadcast::channel(1).1
+            }
 
-            Ok(())
+            async fn purge_expired(&self) -> StoreResult<PurgeStats> {
+                Err(StoreError::Backend("connection lost".to_string()))
+            }
         }
 
-        /// Tests that the verify_authentication method returns an error when the auth_id is empty.
+        /// Tests that a serialization error from the store maps to an internal status.
         #[tokio::test]
-        async fn returns_error_when_auth_id_is_empty() -> Result<()> {
-            let service = test_service();
-            let result = service
+        async fn serialization_error_maps_to_internal() -> Result<()> {
+            let mut service = test_service();
+            service.store = Box::new(CorruptedStore);
+
+            let err = service
+                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                    user: "peggy".to_string(),
+                    r1: "1".to_string(),
+                    r2: "1".to_string(),
+                }))
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::Internal);
+            assert_eq!(err.message(), "Failed to get user");
+
+            let err = service
                 .verify_authentication(Request::new(AuthenticationAnswerRequest {
-                    auth_id: "".to_string(),
+                    auth_id: Uuid::new_v4().to_string(),
                     s: "1".to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
                 }))
-                .await;
-
-            let err = result.unwrap_err();
-            assert_eq!(err.code(), Code::InvalidArgument);
-            assert_eq!(err.message(), "Invalid auth_id argument");
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), Code::Internal);
+            assert_eq!(err.message(), "Failed to get challenge");
 
             Ok(())
         }
 
-        /// Tests that the verify_authentication method returns an error when r1 is empty.
+        /// Tests that each store error maps to the expected status code.
+        #[test]
+        fn maps_each_variant() {
+            let cases = [
+                (StoreError::Serialization("x".to_string()), Code::Internal),
+                (StoreError::Backend("x".to_string()), Code::Unavailable),
+                (StoreError::NotFound("x".to_string()), Code::NotFound),
+            ];
+            for (err, code) in cases {
+                let status = store_error_status("context", err);
+                assert_eq!(status.code(), code);
+                assert_eq!(status.message(), "context");
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod self_test {
+        use super::*;
+        use zkauth::elliptic_curve::{
+            configuration::EllipticCurveConfiguration, verifier::EllipticCurveVerifier,
+        };
+
+        /// Tests that the self_test method passes with a discrete logarithm configuration.
         #[tokio::test]
-        async fn returns_error_when_r1_is_empty() -> Result<()> {
+        async fn passes_with_discrete_logarithm_config() -> Result<()> {
             let service = test_service();
-            let result = service
-                .verify_authentication(Request::new(AuthenticationAnswerRequest {
-                    auth_id: "".to_string(),
-                    s: "".to_string(),
-                }))
-                .await;
+            let resp = service
+                .self_test(Request::new(SelfTestRequest {}))
+                .await?
+                .into_inner();
 
-            let err = result.unwrap_err();
-            assert_eq!(err.code(), Code::InvalidArgument);
-            assert_eq!(err.message(), "Invalid s argument");
+            assert!(resp.passed);
+            assert_eq!(resp.error, "");
 
             Ok(())
         }
 
-        /// Tests that the verify_authentication method returns an error when r1 is not a number.
+        /// Tests that the self_test method passes with an elliptic curve configuration.
         #[tokio::test]
-        async fn returns_error_when_r1_is_not_a_number() -> Result<()> {
-            let service = test_service();
-            let result = service
-                .verify_authentication(Request::new(AuthenticationAnswerRequest {
-                    auth_id: "".to_string(),
-                    s: "not-a-number".to_string(),
-                }))
-                .await;
+        async fn passes_with_elliptic_curve_config() -> Result<()> {
+            let config = EllipticCurveConfiguration::generate();
+            let verifier = Box::new(EllipticCurveVerifier::new(config.clone()));
+            let service = Service::new(config.into(), verifier);
+            let resp = service
+                .self_test(Request::new(SelfTestRequest {}))
+                .await?
+                .into_inner();
 
-            let err = result.unwrap_err();
-            assert_eq!(err.code(), Code::InvalidArgument);
-            assert_eq!(err.message(), "Invalid s argument");
+            assert!(resp.passed);
 
             Ok(())
         }
 
-        /// Tests that the verify_authentication method returns an error when the verification fails.
+        /// Tests that the self_test method fails when the verifier does not match the configuration.
         #[tokio::test]
-        async fn returns_verification_failed() -> Result<()> {
-            let service = test_service();
-
-            service
-                .store
-                .insert_user(
-                    "peggy",
-                    User {
-                        y1: One::one(),
-                        y2: One::one(),
-                    },
-                )
-                .map_err(|_| Status::internal("Failed to insert user into store"))?;
-            let id = Uuid::new_v4();
-            service
-                .store
-                .insert_challenge(
-                    id,
-                    Challenge {
-                        user: "peggy".to_string(),
-                        c: One::one(),
-                        r1: One::one(),
-                        r2: One::one(),
-                    },
-                )
-                .map_err(|_| Status::internal("Failed to insert challenge into store"))?;
-
-            let result = service
-                .verify_authentication(Request::new(AuthenticationAnswerRequest {
-                    auth_id: id.to_string(),
-                    s: "1".to_string(),
-                }))
-                .await;
+        async fn fails_with_mismatched_verifier() -> Result<()> {
+            let config = DiscreteLogarithmConfiguration::generate(16);
+            let verifier = Box::new(DiscreteLogarithmVerifier::new(
+                DiscreteLogarithmConfiguration::generate(16),
+            ));
+            let service = Service::new(config.into(), verifier);
+            let resp = service
+                .self_test(Request::new(SelfTestRequest {}))
+                .await?
+                .into_inner();
 
-            let err = result.unwrap_err();
-            assert_eq!(err.code(), Code::FailedPrecondition);
-            assert_eq!(err.message(), "Verification failed");
+            assert!(!resp.passed);
+            assert!(!resp.error.is_empty());
 
             Ok(())
         }
@@ -0,0 +1,54 @@
+//! Session id generation and session keying for the authentication protocol.
+
+use clap::ValueEnum;
+use strum_macros::{Display, EnumString, VariantNames};
+use uuid::Uuid;
+use zkauth::Scalar;
+
+/// A trait for generating session ids.
+pub trait SessionIdGenerator: Sync + Send {
+    /// Generates a new session id.
+    fn generate(&self) -> Uuid;
+}
+
+/// A session id generator that returns random (v4) UUIDs.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RandomSessionIdGenerator;
+
+/// Implementation of the random session id generator.
+impl SessionIdGenerator for RandomSessionIdGenerator {
+    fn generate(&self) -> Uuid {
+        Uuid::new_v4()
+    }
+}
+
+/// A strategy for keying sessions, which decides when a successful verification returns an
+/// existing session instead of creating a new one.
+#[derive(
+    Debug, Default, Clone, Copy, EnumString, Display, VariantNames, ValueEnum, PartialEq, Eq,
+)]
+#[strum(serialize_all = "kebab-case")]
+pub enum SessionKeyStrategy {
+    /// One session per user, returned by every login of the user until it expires.
+    User,
+
+    /// One session per user and answer s, so only verifying the same proof again returns the same
+    /// session.
+    #[default]
+    UserProof,
+
+    /// A new session for every successful verification.
+    Fresh,
+}
+
+/// Implementation of the session key strategy.
+impl SessionKeyStrategy {
+    /// Returns the key to store the session for a verified answer s of the given user under.
+    pub fn session_key(&self, user: &str, s: &Scalar) -> String {
+        match self {
+            SessionKeyStrategy::User => format!("user:{}", user),
+            SessionKeyStrategy::UserProof => format!("proof:{}:{}", user, s),
+            SessionKeyStrategy::Fresh => format!("fresh:{}", Uuid::new_v4()),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Signing with the server's long-term key, such as for authentication challenges.
+//!
+//! The signing features call a [`SigningBackend`] without knowing where the key is held, so the
+//! secret key does not have to live in a plaintext file on the server.
+
+use ed25519_dalek::Signer;
+use std::fmt;
+use zkauth_protobuf::bundle::{Signature, SigningKey, VerifyingKey};
+
+/// An error for when a signing backend fails to sign a message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SigningError {
+    /// The backend could not be reached or refused to sign, such as a KMS request failing.
+    Backend(String),
+}
+
+impl fmt::Display for SigningError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SigningError::Backend(msg) => write!(f, "signing backend error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SigningError {}
+
+/// A trait for signing messages with the server's Ed25519 key, wherever the key is held.
+#[tonic::async_trait]
+pub trait SigningBackend: Sync + Send {
+    /// Returns the public key that signatures can be verified against.
+    fn verifying_key(&self) -> VerifyingKey;
+
+    /// Signs the given message.
+    async fn sign(&self, message: &[u8]) -> Result<Signature, SigningError>;
+}
+
+/// A signing backend holding the secret key in memory, such as one loaded from a local file.
+pub struct LocalSigningBackend {
+    key: SigningKey,
+}
+
+/// Implementation of the local signing backend.
+impl LocalSigningBackend {
+    /// Creates a backend that signs with the given key.
+    pub fn new(key: SigningKey) -> Self {
+        Self { key }
+    }
+}
+
+/// Implementation of the signing backend for the local signing backend.
+#[tonic::async_trait]
+impl SigningBackend for LocalSigningBackend {
+    fn verifying_key(&self) -> VerifyingKey {
+        self.key.verifying_key()
+    }
+
+    async fn sign(&self, message: &[u8]) -> Result<Signature, SigningError> {
+        Ok(self.key.sign(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::Verifier;
+    use std::sync::Mutex;
+
+    /// A signing backend that records the messages it signs, standing in for a remote backend.
+    struct MockSigningBackend {
+        key: SigningKey,
+        messages: Mutex<Vec<Vec<u8>>>,
+        fail: bool,
+    }
+
+    #[tonic::async_trait]
+    impl SigningBackend for MockSigningBackend {
+        fn verifying_key(&self) -> VerifyingKey {
+            self.key.verifying_key()
+        }
+
+        async fn sign(&self, message: &[u8]) -> Result<Signature, SigningError> {
+            if self.fail {
+                return Err(SigningError::Backend("key is disabled".to_string()));
+            }
+            self.messages.lock().unwrap().push(message.to_vec());
+            Ok(self.key.sign(message))
+        }
+    }
+
+    /// Signs a message through the abstraction and verifies it against the backend's public key.
+    async fn sign_and_verify(backend: &dyn SigningBackend) -> Result<(), SigningError> {
+        let signature = backend.sign(b"message").await?;
+        assert!(backend
+            .verifying_key()
+            .verify(b"message", &signature)
+            .is_ok());
+        assert!(backend
+            .verifying_key()
+            .verify(b"other message", &signature)
+            .is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn local_backend_signs_and_verifies() -> Result<(), SigningError> {
+        let backend = LocalSigningBackend::new(SigningKey::from_bytes(&[7; 32]));
+        sign_and_verify(&backend).await
+    }
+
+    #[tokio::test]
+    async fn mock_backend_signs_and_verifies() -> Result<(), SigningError> {
+        let backend = MockSigningBackend {
+            key: SigningKey::from_bytes(&[7; 32]),
+            messages: Mutex::new(Vec::new()),
+            fail: false,
+        };
+        sign_and_verify(&backend).await?;
+        assert_eq!(*backend.messages.lock().unwrap(), vec![b"message".to_vec()]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn mock_backend_returns_error() {
+        let backend = MockSigningBackend {
+            key: SigningKey::from_bytes(&[7; 32]),
+            messages: Mutex::new(Vec::new()),
+            fail: true,
+        };
+        assert_eq!(
+            backend.sign(b"message").await.unwrap_err().to_string(),
+            "signing backend error: key is disabled"
+        );
+    }
+}
@@ -1,110 +1,1002 @@
-use anyhow::Result;
+use dashmap::mapref::entry::Entry;
 use dashmap::DashMap;
+use moka::notification::RemovalCause;
+use moka::ops::compute::{CompResult, Op};
 use moka::sync::Cache;
-use std::time::Duration;
+use moka::Expiry;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+use tokio::sync::broadcast;
 use uuid::Uuid;
 
-use crate::store::{Challenge, Session, Store, User};
+use crate::clock::{Clock, SystemClock};
+use crate::store::{Challenge, PurgeStats, Result, Session, SessionEvent, Store, User};
+
+/// The number of session events buffered for slow subscribers.
+const SESSION_EVENTS_CAPACITY: usize = 1024;
+
+/// The default time to live of a challenge, after which it can no longer be answered.
+pub const DEFAULT_CHALLENGES_TTL: Duration = Duration::from_secs(300);
+
+/// The default time to live of a session.
+pub const DEFAULT_SESSIONS_TTL: Duration = Duration::from_secs(3600);
+
+/// A challenge in the store, along with when it expires by the store's clock.
+#[derive(Clone)]
+struct StoredChallenge {
+    challenge: Challenge,
+    expires_at: SystemTime,
+}
 
 /// MemoryStore is an in-memory implementation of the Store trait.
 pub struct MemoryStore {
     users: DashMap<String, User>,
-    challenges: Cache<String, Challenge>,
+    challenges: Cache<String, StoredChallenge>,
+    challenges_ttl: Duration,
+    challenges_by_user: Arc<DashMap<String, VecDeque<Uuid>>>,
     sessions: Cache<String, Session>,
+    session_keys_by_id: Arc<DashMap<Uuid, String>>,
+    seen_proofs: Cache<String, Duration>,
+    session_events: broadcast::Sender<SessionEvent>,
+    expired_challenges: Arc<AtomicU64>,
+    expired_sessions: Arc<AtomicU64>,
+    clock: Box<dyn Clock>,
 }
 
 /// Implement the MemoryStore.
 impl MemoryStore {
+    /// Creates a store whose challenges and sessions expire the given time after they are
+    /// inserted, so an expired challenge is no longer found.
     pub fn new(challenges_ttl: Duration, sessions_ttl: Duration) -> Self {
+        let (session_events, _) = broadcast::channel(SESSION_EVENTS_CAPACITY);
+
+        // Count challenges as they expire from the cache, and drop them from the index by user.
+        // The listener can run while insert_challenge_limited holds the user's index entry, so it
+        // skips the index rather than wait for the entry; the ids it skips are pruned on the
+        // user's next limited insert or by purge_expired.
+        let expired_challenges = Arc::new(AtomicU64::new(0));
+        let counter = expired_challenges.clone();
+        let challenges_by_user: Arc<DashMap<String, VecDeque<Uuid>>> = Arc::new(DashMap::new());
+        let index = challenges_by_user.clone();
+        let challenges_listener =
+            move |key: Arc<String>, value: StoredChallenge, cause: RemovalCause| {
+                if cause == RemovalCause::Expired {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+                if cause == RemovalCause::Replaced {
+                    return;
+                }
+                if let (Ok(id), Some(entry)) =
+                    (Uuid::parse_str(&key), index.try_entry(value.challenge.user))
+                {
+                    remove_pending_challenge(entry, id);
+                }
+            };
+
+        // Publish revoked and expired events when sessions are removed from the cache, count them
+        // as they expire, and drop them from the index by id.
+        let expired_sessions = Arc::new(AtomicU64::new(0));
+        let counter = expired_sessions.clone();
+        let sender = session_events.clone();
+        let session_keys_by_id = Arc::new(DashMap::new());
+        let index = session_keys_by_id.clone();
+        let listener = move |key: Arc<String>, session: Session, cause: RemovalCause| {
+            index.remove_if(&session.id, |_, indexed: &String| *indexed == *key);
+            if cause == RemovalCause::Expired {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }
+            let event = match cause {
+                RemovalCause::Explicit => SessionEvent::Revoked {
+                    session_id: session.id,
+                },
+                RemovalCause::Expired => SessionEvent::Expired {
+                    session_id: session.id,
+                },
+                _ => return,
+            };
+            // Sending only fails when there are no subscribers.
+            let _ = sender.send(event);
+        };
+
         Self {
             users: DashMap::new(),
-            challenges: Cache::builder().time_to_live(challenges_ttl).build(),
-            sessions: Cache::builder().time_to_live(sessions_ttl).build(),
+            challenges: Cache::builder()
+                .time_to_live(challenges_ttl)
+                .eviction_listener(challenges_listener)
+                .build(),
+            challenges_ttl,
+            challenges_by_user,
+            sessions: Cache::builder()
+                .time_to_live(sessions_ttl)
+                .eviction_listener(listener)
+                .build(),
+            session_keys_by_id,
+            seen_proofs: Cache::builder().expire_after(SeenProofExpiry).build(),
+            session_events,
+            expired_challenges,
+            expired_sessions,
+            clock: Box::new(SystemClock),
         }
     }
+
+    /// Sets the clock challenges expire by. The cache still evicts challenges on its own timer
+    /// once their time to live passes, but a challenge is no longer found once it expires by this
+    /// clock, so expiry can be tested without waiting.
+    #[cfg(test)]
+    pub(crate) fn with_clock(mut self, clock: Box<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+}
+
+/// Expires each seen proof after the time to live it was recorded with.
+struct SeenProofExpiry;
+
+/// Implementation of the seen proof expiry.
+impl Expiry<String, Duration> for SeenProofExpiry {
+    fn expire_after_create(&self, _: &String, ttl: &Duration, _: Instant) -> Option<Duration> {
+        Some(*ttl)
+    }
+}
+
+/// Drops the id of a challenge from the user's pending challenges in the given index entry, and
+/// the entry itself once the user has none left.
+fn remove_pending_challenge(entry: Entry<'_, String, VecDeque<Uuid>>, id: Uuid) {
+    if let Entry::Occupied(mut entry) = entry {
+        entry.get_mut().retain(|pending| *pending != id);
+        if entry.get().is_empty() {
+            entry.remove();
+        }
+    }
+}
+
+/// Helpers for expiring challenges and indexing them by user.
+impl MemoryStore {
+    /// Stores the challenge under the given id, expiring its time to live from now.
+    fn store_challenge(&self, id: Uuid, challenge: Challenge) {
+        let expires_at = self.clock.now() + self.challenges_ttl;
+        let stored = StoredChallenge {
+            challenge,
+            expires_at,
+        };
+        self.challenges.insert(id.to_string(), stored);
+    }
+
+    /// Returns whether the stored challenge has not yet expired by the store's clock.
+    fn is_live(&self, stored: &StoredChallenge) -> bool {
+        self.clock.now() < stored.expires_at
+    }
+
+    /// Drops the ids of challenges that have expired or been removed from the user's pending
+    /// challenges.
+    fn prune_pending_challenges(&self, ids: &mut VecDeque<Uuid>) {
+        ids.retain(|id| {
+            self.challenges
+                .get(&id.to_string())
+                .is_some_and(|stored| self.is_live(&stored))
+        });
+    }
 }
 
 /// Implement the Default trait for the MemoryStore.
 impl Default for MemoryStore {
     fn default() -> Self {
-        Self::new(Duration::from_secs(300), Duration::from_secs(3600))
+        Self::new(DEFAULT_CHALLENGES_TTL, DEFAULT_SESSIONS_TTL)
     }
 }
 
-/// Implement the Store trait for the MemoryStore.
+/// Implement the Store trait for the MemoryStore. None of its methods block, so their futures
+/// are ready as soon as they are polled.
+#[tonic::async_trait]
 impl Store for MemoryStore {
-    fn insert_user(&self, username: &str, user: User) -> Result<()> {
+    async fn insert_user(&self, username: &str, user: User) -> Result<()> {
         self.users.insert(username.to_string(), user);
         Ok(())
     }
 
+    /// The insert_user_if_absent method inserts a user into the store if the username is not
+    /// already taken, holding the entry lock so concurrent inserts cannot both succeed.
+    async fn insert_user_if_absent(&self, username: &str, user: User) -> Result<bool> {
+        match self.users.entry(username.to_string()) {
+            Entry::Occupied(_) => Ok(false),
+            Entry::Vacant(entry) => {
+                entry.insert(user);
+                Ok(true)
+            }
+        }
+    }
+
+    /// The insert_user_with_challenge method inserts a user and its challenge while holding the
+    /// user's entry, so a concurrent registration of the same username sees both or neither.
+    async fn insert_user_with_challenge(
+        &self,
+        username: &str,
+        user: User,
+        id: Uuid,
+        challenge: Challenge,
+    ) -> Result<bool> {
+        match self.users.entry(username.to_string()) {
+            Entry::Occupied(_) => Ok(false),
+            Entry::Vacant(entry) => {
+                self.store_challenge(id, challenge);
+                entry.insert(user);
+                Ok(true)
+            }
+        }
+    }
+
+    /// The update_user method compares and replaces the user while holding its entry, so a
+    /// concurrent update of the same user cannot be lost.
+    async fn update_user(&self, username: &str, current: &User, user: User) -> Result<bool> {
+        match self.users.entry(username.to_string()) {
+            Entry::Occupied(mut entry) if entry.get() == current => {
+                entry.insert(user);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
     /// The get_user method returns an Option<User> for the given username.
-    fn get_user(&self, username: &str) -> Result<Option<User>> {
+    async fn get_user(&self, username: &str) -> Result<Option<User>> {
         Ok(self.users.get(username).map(|u| u.value().clone()))
     }
 
-    /// The insert_challenge method inserts a challenge into the store.
-    fn insert_challenge(&self, id: Uuid, challenge: Challenge) -> Result<()> {
-        self.challenges.insert(id.to_string(), challenge);
+    /// The delete_user method deletes the user for the given username, returning whether it
+    /// existed. It revokes the user's sessions and removes their challenges while holding the
+    /// user's entry, so they are gone by the time the user is.
+    async fn delete_user(&self, username: &str) -> Result<bool> {
+        let Entry::Occupied(entry) = self.users.entry(username.to_string()) else {
+            return Ok(false);
+        };
+        for (key, session) in self.sessions.iter() {
+            if session.user == username {
+                self.sessions.invalidate(key.as_str());
+            }
+        }
+        for (key, stored) in self.challenges.iter() {
+            if stored.challenge.user == username {
+                self.challenges.invalidate(key.as_str());
+            }
+        }
+        self.challenges_by_user.remove(username);
+        entry.remove();
+        Ok(true)
+    }
+
+    /// The insert_challenge method inserts a challenge into the store. Only limited challenges are
+    /// indexed by user, so the index does not grow with challenges nothing counts.
+    async fn insert_challenge(&self, id: Uuid, challenge: Challenge) -> Result<()> {
+        self.store_challenge(id, challenge);
         Ok(())
     }
 
+    /// The insert_challenge_limited method checks and updates the user's pending challenges while
+    /// holding their entry in the index, so concurrent challenges for a user cannot exceed the
+    /// limit.
+    async fn insert_challenge_limited(
+        &self,
+        id: Uuid,
+        challenge: Challenge,
+        max_per_user: usize,
+        evict_oldest: bool,
+    ) -> Result<bool> {
+        let mut ids = self
+            .challenges_by_user
+            .entry(challenge.user.clone())
+            .or_default();
+        self.prune_pending_challenges(&mut ids);
+        while ids.len() >= max_per_user {
+            if !evict_oldest {
+                return Ok(false);
+            }
+            match ids.pop_front() {
+                Some(oldest) => self.challenges.invalidate(&oldest.to_string()),
+                None => break,
+            }
+        }
+        self.store_challenge(id, challenge);
+        ids.push_back(id);
+        Ok(true)
+    }
+
     /// The get_challenge method returns an Option<Challenge> for the given id.
-    fn get_challenge(&self, id: Uuid) -> Result<Option<Challenge>> {
-        Ok(self.challenges.get(&id.to_string()))
+    async fn get_challenge(&self, id: Uuid) -> Result<Option<Challenge>> {
+        Ok(self
+            .challenges
+            .get(&id.to_string())
+            .filter(|stored| self.is_live(stored))
+            .map(|stored| stored.challenge))
+    }
+
+    /// The take_challenge method removes and returns the challenge for the given id while holding
+    /// its entry, so an expired challenge is not returned, and drops it from the user's pending
+    /// challenges.
+    async fn take_challenge(&self, id: Uuid) -> Result<Option<Challenge>> {
+        let result = self
+            .challenges
+            .entry(id.to_string())
+            .and_compute_with(|entry| match entry {
+                Some(_) => Op::Remove,
+                None => Op::Nop,
+            });
+        match result {
+            CompResult::Removed(entry) => {
+                let stored = entry.into_value();
+                let user = stored.challenge.user.clone();
+                remove_pending_challenge(self.challenges_by_user.entry(user), id);
+                if !self.is_live(&stored) {
+                    self.expired_challenges.fetch_add(1, Ordering::Relaxed);
+                    return Ok(None);
+                }
+                Ok(Some(stored.challenge))
+            }
+            _ => Ok(None),
+        }
     }
 
     /// The insert_session method inserts a session into the store.
-    fn insert_session(&self, id: &str, session: Session) -> Result<()> {
+    async fn insert_session(&self, id: &str, session: Session) -> Result<()> {
+        let event = SessionEvent::Created {
+            user: session.user.clone(),
+            session_id: session.id,
+        };
+        let session_id = session.id;
         self.sessions.insert(id.to_string(), session);
+        self.session_keys_by_id.insert(session_id, id.to_string());
+        // Sending only fails when there are no subscribers.
+        let _ = self.session_events.send(event);
         Ok(())
     }
 
+    /// The get_or_insert_session method returns the session for the given id, inserting the given
+    /// session if there is none. The cache runs a single insertion for concurrent calls with the
+    /// same id, and the others wait for it and return the inserted session.
+    async fn get_or_insert_session(&self, id: &str, session: Session) -> Result<(Session, bool)> {
+        let event = SessionEvent::Created {
+            user: session.user.clone(),
+            session_id: session.id,
+        };
+        let entry = self.sessions.entry(id.to_string()).or_insert(session);
+        if entry.is_fresh() {
+            self.session_keys_by_id
+                .insert(entry.value().id, id.to_string());
+            // Sending only fails when there are no subscribers.
+            let _ = self.session_events.send(event);
+        }
+        Ok((entry.value().clone(), entry.is_fresh()))
+    }
+
     /// The get_session method returns an Option<Session> for the given id.
-    fn get_session(&self, id: &str) -> Result<Option<Session>> {
+    async fn get_session(&self, id: &str) -> Result<Option<Session>> {
         Ok(self.sessions.get(&id.to_string()))
     }
+
+    /// The get_session_by_id method looks up the key of the session in the index by id, and
+    /// returns the session stored under it if it is still the same session.
+    async fn get_session_by_id(&self, id: Uuid) -> Result<Option<Session>> {
+        let key = match self.session_keys_by_id.get(&id) {
+            Some(key) => key.value().clone(),
+            None => return Ok(None),
+        };
+        Ok(self.sessions.get(&key).filter(|session| session.id == id))
+    }
+
+    /// The delete_session method deletes the session for the given id.
+    async fn delete_session(&self, id: &str) -> Result<bool> {
+        Ok(self.sessions.remove(id).is_some())
+    }
+
+    /// The insert_proof_if_unseen method records the proof while holding its entry, so of
+    /// concurrent calls with the same key, only one records it.
+    async fn insert_proof_if_unseen(&self, key: &str, ttl: Duration) -> Result<bool> {
+        let entry = self.seen_proofs.entry(key.to_string()).or_insert(ttl);
+        Ok(entry.is_fresh())
+    }
+
+    /// The subscribe_session_events method subscribes to session lifecycle events.
+    async fn subscribe_session_events(&self) -> broadcast::Receiver<SessionEvent> {
+        self.session_events.subscribe()
+    }
+
+    /// The purge_expired method runs the caches' pending maintenance, which evicts expired
+    /// entries, and returns how many expired while it ran.
+    async fn purge_expired(&self) -> Result<PurgeStats> {
+        let challenges = self.expired_challenges.load(Ordering::Relaxed);
+        let sessions = self.expired_sessions.load(Ordering::Relaxed);
+        self.challenges.run_pending_tasks();
+        self.sessions.run_pending_tasks();
+        self.seen_proofs.run_pending_tasks();
+        self.challenges_by_user.retain(|_, ids| {
+            self.prune_pending_challenges(ids);
+            !ids.is_empty()
+        });
+        Ok(PurgeStats {
+            challenges: self.expired_challenges.load(Ordering::Relaxed) - challenges,
+            sessions: self.expired_sessions.load(Ordering::Relaxed) - sessions,
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use num_traits::One;
+    use crate::clock::ManualClock;
+    use num_traits::{One, Zero};
+    use std::time::SystemTime;
     use zkauth::{Element, Scalar};
 
-    #[test]
-    fn test_insert_get_user() {
+    #[tokio::test]
+    async fn test_insert_get_user() {
+        let store = MemoryStore::default();
+        let user = User {
+            y1: Element::one(),
+            y2: Element::one(),
+            metadata: Default::default(),
+        };
+        store.insert_user("test", user.clone()).await.unwrap();
+        assert_eq!(store.get_user("test").await.unwrap().unwrap(), user);
+        assert!(store.get_user("test2").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_insert_get_user_with_metadata() {
+        let store = MemoryStore::default();
+        let user = User {
+            y1: Element::one(),
+            y2: Element::one(),
+            metadata: [
+                ("display_name".to_string(), "Peggy".to_string()),
+                ("roles".to_string(), "admin,operator".to_string()),
+            ]
+            .into(),
+        };
+        store.insert_user("test", user.clone()).await.unwrap();
+        assert_eq!(
+            store.get_user("test").await.unwrap().unwrap().metadata,
+            user.metadata
+        );
+    }
+
+    #[tokio::test]
+    async fn test_insert_user_if_absent() {
+        let store = MemoryStore::default();
+        let user = User {
+            y1: Element::one(),
+            y2: Element::one(),
+            metadata: Default::default(),
+        };
+        let other = User {
+            y1: Element::zero(),
+            y2: Element::zero(),
+            metadata: Default::default(),
+        };
+        assert!(store
+            .insert_user_if_absent("test", user.clone())
+            .await
+            .unwrap());
+        assert!(!store.insert_user_if_absent("test", other).await.unwrap());
+        assert_eq!(store.get_user("test").await.unwrap().unwrap(), user);
+    }
+
+    #[tokio::test]
+    async fn test_delete_user() {
+        let store = MemoryStore::default();
+        let user = User {
+            y1: Element::one(),
+            y2: Element::one(),
+            metadata: Default::default(),
+        };
+        store.insert_user("test", user.clone()).await.unwrap();
+        assert!(store.delete_user("test").await.unwrap());
+        assert!(store.get_user("test").await.unwrap().is_none());
+        assert!(!store.delete_user("test").await.unwrap());
+        assert!(store.insert_user_if_absent("test", user).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_delete_user_revokes_sessions_and_challenges() {
+        let store = MemoryStore::default();
+        let user = User {
+            y1: Element::one(),
+            y2: Element::one(),
+            metadata: Default::default(),
+        };
+        store.insert_user("test", user.clone()).await.unwrap();
+        store.insert_user("other", user).await.unwrap();
+        let challenge = |user: &str| Challenge {
+            user: user.to_string(),
+            c: Scalar::one(),
+            r1: Element::one(),
+            r2: Element::one(),
+            issued_at: SystemTime::now(),
+        };
+        let (id, limited_id, other_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        store.insert_challenge(id, challenge("test")).await.unwrap();
+        store
+            .insert_challenge_limited(limited_id, challenge("test"), 1, false)
+            .await
+            .unwrap();
+        store
+            .insert_challenge(other_id, challenge("other"))
+            .await
+            .unwrap();
+        let session = |user: &str| Session {
+            id: Uuid::new_v4(),
+            user: user.to_string(),
+        };
+        let (peggy, other) = (session("test"), session("other"));
+        store.insert_session("a", peggy.clone()).await.unwrap();
+        store.insert_session("b", other.clone()).await.unwrap();
+        let mut events = store.subscribe_session_events().await;
+
+        assert!(store.delete_user("test").await.unwrap());
+        assert!(store.get_session_by_id(peggy.id).await.unwrap().is_none());
+        assert!(store.get_challenge(id).await.unwrap().is_none());
+        assert!(store.get_challenge(limited_id).await.unwrap().is_none());
+        assert!(!store.challenges_by_user.contains_key("test"));
+        assert_eq!(
+            events.recv().await.unwrap(),
+            SessionEvent::Revoked {
+                session_id: peggy.id
+            }
+        );
+
+        // The other user's session and challenge are untouched.
+        assert_eq!(
+            store.get_session_by_id(other.id).await.unwrap(),
+            Some(other)
+        );
+        assert!(store.get_challenge(other_id).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_insert_user_with_challenge() {
         let store = MemoryStore::default();
         let user = User {
             y1: Element::one(),
             y2: Element::one(),
+            metadata: Default::default(),
+        };
+        let challenge = Challenge {
+            user: "test".to_string(),
+            c: Scalar::one(),
+            r1: Element::one(),
+            r2: Element::one(),
+            issued_at: SystemTime::now(),
         };
-        store.insert_user("test", user.clone()).unwrap();
-        assert_eq!(store.get_user("test").unwrap().unwrap(), user);
-        assert!(store.get_user("test2").unwrap().is_none());
+        let id = Uuid::new_v4();
+        assert!(store
+            .insert_user_with_challenge("test", user.clone(), id, challenge.clone())
+            .await
+            .unwrap());
+        assert_eq!(store.get_user("test").await.unwrap().unwrap(), user);
+        assert_eq!(store.get_challenge(id).await.unwrap().unwrap(), challenge);
+
+        let other_id = Uuid::new_v4();
+        assert!(!store
+            .insert_user_with_challenge("test", user, other_id, challenge)
+            .await
+            .unwrap());
+        assert!(store.get_challenge(other_id).await.unwrap().is_none());
     }
 
-    #[test]
-    fn test_insert_get_challenge() {
+    #[tokio::test]
+    async fn test_insert_get_challenge() {
         let store = MemoryStore::default();
         let challenge = Challenge {
             user: "test".to_string(),
             c: Scalar::one(),
             r1: Element::one(),
             r2: Element::one(),
+            issued_at: SystemTime::now(),
         };
         let id = Uuid::new_v4();
-        store.insert_challenge(id, challenge.clone()).unwrap();
-        assert_eq!(store.get_challenge(id).unwrap().unwrap(), challenge);
-        assert!(store.get_challenge(Uuid::new_v4()).unwrap().is_none());
+        store.insert_challenge(id, challenge.clone()).await.unwrap();
+        assert_eq!(store.get_challenge(id).await.unwrap().unwrap(), challenge);
+        assert!(store.get_challenge(Uuid::new_v4()).await.unwrap().is_none());
     }
 
-    #[test]
-    fn test_insert_get_session() {
+    #[tokio::test]
+    async fn test_take_challenge() {
         let store = MemoryStore::default();
-        let session = Session { id: Uuid::new_v4() };
-        store.insert_session("test", session.clone()).unwrap();
-        assert_eq!(store.get_session("test").unwrap().unwrap(), session);
-        assert!(store.get_session("test2").unwrap().is_none());
+        let challenge = Challenge {
+            user: "test".to_string(),
+            c: Scalar::one(),
+            r1: Element::one(),
+            r2: Element::one(),
+            issued_at: SystemTime::now(),
+        };
+        let id = Uuid::new_v4();
+        store.insert_challenge(id, challenge.clone()).await.unwrap();
+        assert_eq!(store.take_challenge(id).await.unwrap().unwrap(), challenge);
+        assert!(store.take_challenge(id).await.unwrap().is_none());
+        assert!(store.get_challenge(id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_take_challenge_ignores_expired() {
+        let clock = ManualClock::new();
+        let store = MemoryStore::new(Duration::from_secs(60), Duration::from_secs(60))
+            .with_clock(Box::new(clock.clone()));
+        let challenge = Challenge {
+            user: "test".to_string(),
+            c: Scalar::one(),
+            r1: Element::one(),
+            r2: Element::one(),
+            issued_at: SystemTime::now(),
+        };
+        let id = Uuid::new_v4();
+        store.insert_challenge(id, challenge).await.unwrap();
+        clock.advance(Duration::from_secs(59));
+        assert!(store.get_challenge(id).await.unwrap().is_some());
+        clock.advance(Duration::from_secs(1));
+        assert!(store.get_challenge(id).await.unwrap().is_none());
+        assert!(store.take_challenge(id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_insert_challenge_limited() {
+        let challenge = |user: &str| Challenge {
+            user: user.to_string(),
+            c: Scalar::one(),
+            r1: Element::one(),
+            r2: Element::one(),
+            issued_at: SystemTime::now(),
+        };
+
+        // Rejecting keeps the existing challenges, and other users are unaffected.
+        let store = MemoryStore::default();
+        let ids: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+        assert!(store
+            .insert_challenge_limited(ids[0], challenge("test"), 2, false)
+            .await
+            .unwrap());
+        assert!(store
+            .insert_challenge_limited(ids[1], challenge("test"), 2, false)
+            .await
+            .unwrap());
+        assert!(!store
+            .insert_challenge_limited(ids[2], challenge("test"), 2, false)
+            .await
+            .unwrap());
+        assert!(store.get_challenge(ids[0]).await.unwrap().is_some());
+        assert!(store.get_challenge(ids[2]).await.unwrap().is_none());
+        assert!(store
+            .insert_challenge_limited(Uuid::new_v4(), challenge("other"), 2, false)
+            .await
+            .unwrap());
+
+        // Evicting removes the oldest challenge to make room.
+        let store = MemoryStore::default();
+        for id in &ids {
+            assert!(store
+                .insert_challenge_limited(*id, challenge("test"), 2, true)
+                .await
+                .unwrap());
+        }
+        assert!(store.get_challenge(ids[0]).await.unwrap().is_none());
+        assert!(store.get_challenge(ids[1]).await.unwrap().is_some());
+        assert!(store.get_challenge(ids[2]).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_insert_challenge_limited_ignores_expired() {
+        let clock = ManualClock::new();
+        let store = MemoryStore::new(Duration::from_secs(60), Duration::from_secs(60))
+            .with_clock(Box::new(clock.clone()));
+        let challenge = Challenge {
+            user: "test".to_string(),
+            c: Scalar::one(),
+            r1: Element::one(),
+            r2: Element::one(),
+            issued_at: SystemTime::now(),
+        };
+        assert!(store
+            .insert_challenge_limited(Uuid::new_v4(), challenge.clone(), 1, false)
+            .await
+            .unwrap());
+        clock.advance(Duration::from_secs(60));
+        assert!(store
+            .insert_challenge_limited(Uuid::new_v4(), challenge, 1, false)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_challenges_by_user_index() {
+        let store = MemoryStore::new(Duration::from_millis(10), Duration::from_secs(60));
+        let challenge = |user: &str| Challenge {
+            user: user.to_string(),
+            c: Scalar::one(),
+            r1: Element::one(),
+            r2: Element::one(),
+            issued_at: SystemTime::now(),
+        };
+
+        // Challenges inserted without a limit are not indexed.
+        for user in ["a", "b", "c"] {
+            store
+                .insert_challenge(Uuid::new_v4(), challenge(user))
+                .await
+                .unwrap();
+        }
+        assert!(store.challenges_by_user.is_empty());
+
+        // Taking a challenge drops it from the index, and the user with it once none are left.
+        let id = Uuid::new_v4();
+        assert!(store
+            .insert_challenge_limited(id, challenge("test"), 2, false)
+            .await
+            .unwrap());
+        assert!(store.challenges_by_user.contains_key("test"));
+        assert!(store.take_challenge(id).await.unwrap().is_some());
+        assert!(!store.challenges_by_user.contains_key("test"));
+
+        // So does expiring from the cache.
+        assert!(store
+            .insert_challenge_limited(Uuid::new_v4(), challenge("test"), 2, false)
+            .await
+            .unwrap());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        store.challenges.run_pending_tasks();
+        assert!(store.challenges_by_user.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_insert_proof_if_unseen() {
+        let store = MemoryStore::default();
+        let ttl = Duration::from_secs(60);
+        assert!(store.insert_proof_if_unseen("a", ttl).await.unwrap());
+        assert!(!store.insert_proof_if_unseen("a", ttl).await.unwrap());
+        assert!(store.insert_proof_if_unseen("b", ttl).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_update_user() {
+        let store = MemoryStore::default();
+        let user = User {
+            y1: Element::one(),
+            y2: Element::one(),
+            metadata: Default::default(),
+        };
+        let updated = User {
+            y1: Element::zero(),
+            y2: Element::zero(),
+            metadata: Default::default(),
+        };
+        assert!(!store
+            .update_user("test", &user, updated.clone())
+            .await
+            .unwrap());
+        assert!(store.get_user("test").await.unwrap().is_none());
+
+        store.insert_user("test", user.clone()).await.unwrap();
+        assert!(store
+            .update_user("test", &user, updated.clone())
+            .await
+            .unwrap());
+        assert_eq!(store.get_user("test").await.unwrap(), Some(updated.clone()));
+
+        // The registration it was read from is no longer current.
+        assert!(!store
+            .update_user("test", &user, user.clone())
+            .await
+            .unwrap());
+        assert_eq!(store.get_user("test").await.unwrap(), Some(updated));
+    }
+
+    #[tokio::test]
+    async fn test_insert_get_session() {
+        let store = MemoryStore::default();
+        let session = Session {
+            id: Uuid::new_v4(),
+            user: "test".to_string(),
+        };
+        store.insert_session("test", session.clone()).await.unwrap();
+        assert_eq!(store.get_session("test").await.unwrap().unwrap(), session);
+        assert!(store.get_session("test2").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_session_by_id() {
+        let store = MemoryStore::default();
+        let session = Session {
+            id: Uuid::new_v4(),
+            user: "test".to_string(),
+        };
+        store.insert_session("key", session.clone()).await.unwrap();
+        assert_eq!(
+            store.get_session("key").await.unwrap(),
+            Some(session.clone())
+        );
+        assert_eq!(
+            store.get_session_by_id(session.id).await.unwrap(),
+            Some(session.clone())
+        );
+        assert_eq!(store.get_session_by_id(Uuid::new_v4()).await.unwrap(), None);
+
+        // A session inserted only if absent is indexed too.
+        let other = Session {
+            id: Uuid::new_v4(),
+            user: "other".to_string(),
+        };
+        store
+            .get_or_insert_session("other-key", other.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            store.get_session_by_id(other.id).await.unwrap(),
+            Some(other)
+        );
+
+        // Deleting or replacing a session removes it from the index.
+        assert!(store.delete_session("key").await.unwrap());
+        assert_eq!(store.get_session_by_id(session.id).await.unwrap(), None);
+        assert!(store.session_keys_by_id.get(&session.id).is_none());
+
+        let replacement = Session {
+            id: Uuid::new_v4(),
+            user: "other".to_string(),
+        };
+        store
+            .insert_session("other-key", replacement.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            store.get_session_by_id(replacement.id).await.unwrap(),
+            Some(replacement)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_session_by_id_after_expiry() {
+        let store = MemoryStore::new(Duration::from_secs(300), Duration::from_millis(10));
+        let session = Session {
+            id: Uuid::new_v4(),
+            user: "test".to_string(),
+        };
+        store.insert_session("key", session.clone()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(store.get_session_by_id(session.id).await.unwrap(), None);
+
+        store.purge_expired().await.unwrap();
+        assert!(store.session_keys_by_id.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_or_insert_session() {
+        let store = MemoryStore::default();
+        let mut events = store.subscribe_session_events().await;
+        let session = Session {
+            id: Uuid::new_v4(),
+            user: "test".to_string(),
+        };
+        let other = Session {
+            id: Uuid::new_v4(),
+            user: "test".to_string(),
+        };
+        assert_eq!(
+            store
+                .get_or_insert_session("test", session.clone())
+                .await
+                .unwrap(),
+            (session.clone(), true)
+        );
+        assert_eq!(
+            store.get_or_insert_session("test", other).await.unwrap(),
+            (session.clone(), false)
+        );
+        assert_eq!(store.get_session("test").await.unwrap().unwrap(), session);
+
+        // Only the inserted session is announced.
+        assert_eq!(
+            events.try_recv().unwrap(),
+            SessionEvent::Created {
+                user: "test".to_string(),
+                session_id: session.id
+            }
+        );
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_session_events() {
+        let store = MemoryStore::default();
+        let mut events = store.subscribe_session_events().await;
+        let session = Session {
+            id: Uuid::new_v4(),
+            user: "test".to_string(),
+        };
+        store.insert_session("test", session.clone()).await.unwrap();
+        assert!(store.delete_session("test").await.unwrap());
+        assert!(!store.delete_session("test").await.unwrap());
+
+        assert_eq!(
+            events.try_recv().unwrap(),
+            SessionEvent::Created {
+                user: "test".to_string(),
+                session_id: session.id,
+            }
+        );
+        assert_eq!(
+            events.try_recv().unwrap(),
+            SessionEvent::Revoked {
+                session_id: session.id,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_purge_expired() {
+        let store = MemoryStore::new(Duration::from_millis(10), Duration::from_millis(10));
+        for _ in 0..3 {
+            let challenge = Challenge {
+                user: "test".to_string(),
+                c: Scalar::one(),
+                r1: Element::one(),
+                r2: Element::one(),
+                issued_at: SystemTime::now(),
+            };
+            store
+                .insert_challenge(Uuid::new_v4(), challenge)
+                .await
+                .unwrap();
+        }
+        let session = Session {
+            id: Uuid::new_v4(),
+            user: "test".to_string(),
+        };
+        store.insert_session("test", session).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert_eq!(
+            store.purge_expired().await.unwrap(),
+            PurgeStats {
+                challenges: 3,
+                sessions: 1,
+            }
+        );
+        assert_eq!(store.challenges.entry_count(), 0);
+        assert_eq!(store.sessions.entry_count(), 0);
+
+        // Nothing is left to purge.
+        assert_eq!(store.purge_expired().await.unwrap(), PurgeStats::default());
+    }
+
+    #[tokio::test]
+    async fn test_purge_expired_keeps_live_entries() {
+        let store = MemoryStore::default();
+        let session = Session {
+            id: Uuid::new_v4(),
+            user: "test".to_string(),
+        };
+        store.insert_session("test", session.clone()).await.unwrap();
+
+        assert_eq!(store.purge_expired().await.unwrap(), PurgeStats::default());
+        assert_eq!(store.get_session("test").await.unwrap(), Some(session));
+    }
+
+    #[tokio::test]
+    async fn test_session_expired_event() {
+        let store = MemoryStore::new(Duration::from_secs(300), Duration::from_millis(10));
+        let mut events = store.subscribe_session_events().await;
+        let session = Session {
+            id: Uuid::new_v4(),
+            user: "test".to_string(),
+        };
+        store.insert_session("test", session.clone()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        store.sessions.run_pending_tasks();
+
+        events.try_recv().unwrap();
+        assert_eq!(
+            events.try_recv().unwrap(),
+            SessionEvent::Expired {
+                session_id: session.id,
+            }
+        );
     }
 }
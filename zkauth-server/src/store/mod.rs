@@ -1,14 +1,21 @@
 pub mod memory;
 
-use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::time::{Duration, SystemTime};
+use tokio::sync::broadcast;
 use uuid::Uuid;
 use zkauth::{Element, Scalar};
 
-/// User data for the authentication protocol.
-#[derive(Debug, Clone, PartialEq)]
+/// User data for the authentication protocol. It serializes y1 and y2 as decimal strings, so a
+/// store can persist users as JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct User {
     pub y1: Element,
     pub y2: Element,
+    /// Non-secret metadata about the user, such as a display name or roles.
+    pub metadata: HashMap<String, String>,
 }
 
 /// Challenge data for the authentication protocol.
@@ -18,31 +25,185 @@ pub struct Challenge {
     pub c: Scalar,
     pub r1: Element,
     pub r2: Element,
+    pub issued_at: SystemTime,
 }
 
 /// Session data for the authentication protocol.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Session {
     pub id: Uuid,
+    pub user: String,
+}
+
+/// Session lifecycle events published by the store.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionEvent {
+    /// A session was created for the user.
+    Created { user: String, session_id: Uuid },
+
+    /// A session was explicitly deleted.
+    Revoked { session_id: Uuid },
+
+    /// A session expired.
+    Expired { session_id: Uuid },
 }
 
-/// Store trait for the authentication protocol.
+/// The number of expired entries removed from a store by a purge.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PurgeStats {
+    /// The number of expired challenges removed.
+    pub challenges: u64,
+
+    /// The number of expired sessions removed.
+    pub sessions: u64,
+}
+
+/// Errors returned by a store, which the service maps to statuses. The in-memory store cannot
+/// fail, so until a fallible backend is added, the variants are only constructed by the failing
+/// stores of the tests.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StoreError {
+    /// A stored value could not be serialized or deserialized, such as a corrupted row.
+    #[cfg_attr(not(test), allow(dead_code))]
+    Serialization(String),
+
+    /// The storage backend failed, such as a lost database connection.
+    #[cfg_attr(not(test), allow(dead_code))]
+    Backend(String),
+
+    /// The requested item was not found.
+    #[cfg_attr(not(test), allow(dead_code))]
+    NotFound(String),
+}
+
+/// Displays the store error.
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoreError::Serialization(msg) => write!(f, "serialization error: {}", msg),
+            StoreError::Backend(msg) => write!(f, "backend error: {}", msg),
+            StoreError::NotFound(msg) => write!(f, "not found: {}", msg),
+        }
+    }
+}
+
+/// Implements the Error trait for the store error.
+impl std::error::Error for StoreError {}
+
+/// Result type for store operations.
+pub type Result<T> = std::result::Result<T, StoreError>;
+
+/// Store trait for the authentication protocol. Its methods are async so that networked backends
+/// do not block the gRPC handlers.
+#[tonic::async_trait]
 pub trait Store: Sync + Send {
     /// Inserts a user into the store.
-    fn insert_user(&self, username: &str, user: User) -> Result<()>;
+    #[allow(dead_code)]
+    async fn insert_user(&self, username: &str, user: User) -> Result<()>;
+
+    /// Atomically inserts a user into the store if the username is not already taken, returning
+    /// whether it was inserted.
+    async fn insert_user_if_absent(&self, username: &str, user: User) -> Result<bool>;
+
+    /// Atomically inserts a user along with a challenge for it, unless the username is already
+    /// taken, returning whether they were inserted. Neither is inserted if the user exists.
+    async fn insert_user_with_challenge(
+        &self,
+        username: &str,
+        user: User,
+        id: Uuid,
+        challenge: Challenge,
+    ) -> Result<bool>;
+
+    /// Atomically replaces the user's registration with the given one if it is still the current
+    /// one, returning whether it was replaced. Nothing is replaced if the user does not exist or
+    /// was changed since the current registration was read.
+    async fn update_user(&self, username: &str, current: &User, user: User) -> Result<bool>;
 
     /// Returns an Option<User> for the given username.
-    fn get_user(&self, username: &str) -> Result<Option<User>>;
+    async fn get_user(&self, username: &str) -> Result<Option<User>>;
+
+    /// Deletes the user for the given username along with their sessions and pending challenges,
+    /// so that nothing issued to them before survives the deletion, returning whether the user
+    /// existed.
+    async fn delete_user(&self, username: &str) -> Result<bool>;
 
     /// Inserts a challenge into the store.
-    fn insert_challenge(&self, id: Uuid, challenge: Challenge) -> Result<()>;
+    async fn insert_challenge(&self, id: Uuid, challenge: Challenge) -> Result<()>;
+
+    /// Atomically inserts a challenge unless its user already has max_per_user pending
+    /// challenges, returning whether it was inserted. If evict_oldest is set, the user's oldest
+    /// pending challenges are removed to make room instead of rejecting the new one. Only
+    /// challenges inserted with this method count toward the limit.
+    async fn insert_challenge_limited(
+        &self,
+        id: Uuid,
+        challenge: Challenge,
+        max_per_user: usize,
+        evict_oldest: bool,
+    ) -> Result<bool>;
 
     /// Returns an Option<Challenge> for the given id.
-    fn get_challenge(&self, id: Uuid) -> Result<Option<Challenge>>;
+    #[allow(dead_code)]
+    async fn get_challenge(&self, id: Uuid) -> Result<Option<Challenge>>;
+
+    /// Removes and returns the challenge for the given id, if any, atomically so that each
+    /// challenge is taken at most once.
+    async fn take_challenge(&self, id: Uuid) -> Result<Option<Challenge>>;
 
     /// Inserts a session into the store.
-    fn insert_session(&self, id: &str, session: Session) -> Result<()>;
+    #[allow(dead_code)]
+    async fn insert_session(&self, id: &str, session: Session) -> Result<()>;
+
+    /// Atomically returns the session for the given id, inserting the given session if there is
+    /// none, along with whether it was inserted. Concurrent calls for the same id all return the
+    /// one session that was inserted.
+    async fn get_or_insert_session(&self, id: &str, session: Session) -> Result<(Session, bool)>;
 
     /// Returns an Option<Session> for the given id.
-    fn get_session(&self, id: &str) -> Result<Option<Session>>;
+    #[allow(dead_code)]
+    async fn get_session(&self, id: &str) -> Result<Option<Session>>;
+
+    /// Returns an Option<Session> for the given session id, rather than the key it is stored
+    /// under.
+    #[allow(dead_code)]
+    async fn get_session_by_id(&self, id: Uuid) -> Result<Option<Session>>;
+
+    /// Deletes the session for the given id, returning whether it existed.
+    #[allow(dead_code)]
+    async fn delete_session(&self, id: &str) -> Result<bool>;
+
+    /// Atomically records that a non-interactive proof with the given key was used, unless it
+    /// already was within the given time to live, returning whether it was recorded.
+    async fn insert_proof_if_unseen(&self, key: &str, ttl: Duration) -> Result<bool>;
+
+    /// Subscribes to session lifecycle events.
+    async fn subscribe_session_events(&self) -> broadcast::Receiver<SessionEvent>;
+
+    /// Removes every expired challenge and session, including those that were never accessed
+    /// again, and returns how many were removed.
+    async fn purge_expired(&self) -> Result<PurgeStats>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn user_round_trips_through_json() -> anyhow::Result<()> {
+        let user = User {
+            y1: Element(BigInt::from(2).pow(200) + 1),
+            y2: Element(BigInt::from(9)),
+            metadata: [("display_name".to_string(), "Peggy".to_string())].into(),
+        };
+
+        let json = serde_json::to_value(&user)?;
+        assert_eq!(json["y1"], user.y1.to_string());
+        assert_eq!(json["y2"], "9");
+
+        let deserialized: User = serde_json::from_value(json)?;
+        assert_eq!(deserialized, user);
+        Ok(())
+    }
 }
@@ -0,0 +1,227 @@
+//! Periodic purging of expired challenges and sessions from the store, so that entries which are
+//! never accessed again do not hold memory until their next access.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+use crate::metrics::Metrics;
+use crate::service::Service;
+use crate::store::{PurgeStats, Result};
+
+/// A sweeper that purges expired entries from a service's store, keeping totals of how many were
+/// swept and counting them in the metrics, if any.
+pub(crate) struct Sweeper {
+    service: Arc<Service>,
+    metrics: Option<Arc<Metrics>>,
+    swept_challenges: AtomicU64,
+    swept_sessions: AtomicU64,
+}
+
+/// Implementation of the sweeper.
+impl Sweeper {
+    /// Creates a new sweeper for the given service.
+    pub(crate) fn new(service: Arc<Service>) -> Self {
+        Sweeper {
+            service,
+            metrics: None,
+            swept_challenges: AtomicU64::new(0),
+            swept_sessions: AtomicU64::new(0),
+        }
+    }
+
+    /// Sets the metrics that swept entries and failed sweeps are counted in.
+    pub(crate) fn with_metrics(mut self, metrics: Arc<Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Returns the total number of expired challenges swept.
+    pub(crate) fn swept_challenges(&self) -> u64 {
+        self.swept_challenges.load(Ordering::Relaxed)
+    }
+
+    /// Returns the total number of expired sessions swept.
+    pub(crate) fn swept_sessions(&self) -> u64 {
+        self.swept_sessions.load(Ordering::Relaxed)
+    }
+
+    /// Purges expired entries once, adding them to the totals and the metrics, and returns how
+    /// many were swept. A failed sweep is counted in the metrics.
+    pub(crate) async fn sweep(&self) -> Result<PurgeStats> {
+        let stats = match self.service.purge_expired().await {
+            Ok(stats) => stats,
+            Err(e) => {
+                if let Some(metrics) = &self.metrics {
+                    metrics.record_sweep_error();
+                }
+                return Err(e);
+            }
+        };
+        self.swept_challenges
+            .fetch_add(stats.challenges, Ordering::Relaxed);
+        self.swept_sessions
+            .fetch_add(stats.sessions, Ordering::Relaxed);
+        if let Some(metrics) = &self.metrics {
+            metrics.record_sweep(stats.challenges, stats.sessions);
+        }
+        Ok(stats)
+    }
+
+    /// Sweeps every interval until shutdown is signalled, logging how many entries were swept.
+    pub(crate) async fn run(self, interval: Duration, mut shutdown: watch::Receiver<bool>) {
+        let mut ticker = tokio::time::interval(interval);
+        // The first tick completes immediately, and there is nothing to sweep at startup.
+        ticker.tick().await;
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {},
+                _ = shutdown.changed() => return,
+            }
+            match self.sweep().await {
+                Ok(stats) => log::debug!(
+                    "Swept {} expired challenges and {} expired sessions ({} and {} in total).",
+                    stats.challenges,
+                    stats.sessions,
+                    self.swept_challenges(),
+                    self.swept_sessions()
+                ),
+                Err(e) => log::warn!("Failed to sweep expired entries: {}", e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::service::test::store_error::CorruptedStore;
+    use crate::store::memory::MemoryStore;
+    use anyhow::{Error, Result};
+    use tonic::{Request, Status};
+    use zkauth::discrete_logarithm::{
+        configuration::DiscreteLogarithmConfiguration, prover::DiscreteLogarithmProver,
+        verifier::DiscreteLogarithmVerifier,
+    };
+    use zkauth::Prover;
+    use zkauth_protobuf::v1::{auth_server::Auth, AuthenticationChallengeRequest, RegisterRequest};
+
+    /// Creates a service whose challenges and sessions expire after the given TTL, with a
+    /// registered user and the given number of outstanding challenges.
+    async fn test_service(ttl: Duration, challenges: usize) -> Result<Arc<Service>> {
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+        let service = Service::new(config.clone().into(), verifier)
+            .with_store(Box::new(MemoryStore::new(ttl, ttl)));
+
+        let prover = DiscreteLogarithmProver::new(config);
+        let x = prover.compute_registration_x("password".to_string());
+        let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+        service
+            .register(Request::new(RegisterRequest {
+                user: "peggy".to_string(),
+                y1: y1.to_string(),
+                y2: y2.to_string(),
+                metadata: Default::default(),
+            }))
+            .await?;
+
+        for _ in 0..challenges {
+            let k = prover.generate_challenge_k();
+            let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+            service
+                .create_authentication_challenge(Request::new(AuthenticationChallengeRequest {
+                    user: "peggy".to_string(),
+                    r1: r1.to_string(),
+                    r2: r2.to_string(),
+                }))
+                .await
+                .map_err(|e: Status| Error::msg(e.to_string()))?;
+        }
+
+        Ok(Arc::new(service))
+    }
+
+    /// Tests that a sweep removes expired challenges and adds them to the totals and metrics.
+    #[tokio::test]
+    async fn sweep_removes_expired_entries() -> Result<()> {
+        let service = test_service(Duration::from_millis(10), 3).await?;
+        let metrics = Arc::new(Metrics::new());
+        let sweeper = Sweeper::new(service).with_metrics(metrics.clone());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let stats = sweeper.sweep().await?;
+        assert_eq!(stats.challenges, 3);
+        assert_eq!(sweeper.swept_challenges(), 3);
+
+        // A second sweep has nothing left to remove.
+        assert_eq!(sweeper.sweep().await?, PurgeStats::default());
+        assert_eq!(sweeper.swept_challenges(), 3);
+
+        let text = metrics.encode();
+        assert!(
+            text.contains("zkauth_sweeper_swept_total{kind=\"challenges\"} 3\n"),
+            "{}",
+            text
+        );
+        assert!(
+            text.contains("zkauth_sweeper_swept_total{kind=\"sessions\"} 0\n"),
+            "{}",
+            text
+        );
+        assert!(text.contains("zkauth_sweeper_errors_total 0\n"), "{}", text);
+
+        Ok(())
+    }
+
+    /// Tests that a failed sweep is counted in the metrics, and nothing is counted as swept.
+    #[tokio::test]
+    async fn sweep_counts_errors() -> Result<()> {
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+        let service = Service::new(config.into(), verifier).with_store(Box::new(CorruptedStore));
+        let metrics = Arc::new(Metrics::new());
+        let sweeper = Sweeper::new(Arc::new(service)).with_metrics(metrics.clone());
+
+        assert!(sweeper.sweep().await.is_err());
+        assert!(sweeper.sweep().await.is_err());
+        assert_eq!(sweeper.swept_challenges(), 0);
+
+        let text = metrics.encode();
+        assert!(text.contains("zkauth_sweeper_errors_total 2\n"), "{}", text);
+        assert!(!text.contains("zkauth_sweeper_swept_total{"), "{}", text);
+
+        Ok(())
+    }
+
+    /// Tests that a sweep keeps entries that have not expired.
+    #[tokio::test]
+    async fn sweep_keeps_live_entries() -> Result<()> {
+        let service = test_service(Duration::from_secs(300), 2).await?;
+        let sweeper = Sweeper::new(service);
+
+        assert_eq!(sweeper.sweep().await?, PurgeStats::default());
+
+        Ok(())
+    }
+
+    /// Tests that the sweeper runs periodically and stops on shutdown.
+    #[tokio::test]
+    async fn run_sweeps_until_shutdown() -> Result<()> {
+        let service = test_service(Duration::from_millis(10), 2).await?;
+        let (shutdown_sender, shutdown_receiver) = watch::channel(false);
+        let handle = tokio::spawn(
+            Sweeper::new(service.clone()).run(Duration::from_millis(20), shutdown_receiver),
+        );
+
+        // The challenges expire and are swept, so a direct purge finds nothing left.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(service.purge_expired().await?, PurgeStats::default());
+
+        shutdown_sender.send(true)?;
+        handle.await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+//! Username normalization, so that variants of a username map to the same stored user.
+
+use unicode_normalization::UnicodeNormalization;
+
+/// A policy for normalizing usernames at the service boundary, before they are validated, stored,
+/// or looked up. The default policy leaves usernames as given.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UsernamePolicy {
+    /// Whether to trim leading and trailing whitespace.
+    pub trim: bool,
+
+    /// Whether to apply Unicode NFC normalization, so that precomposed and decomposed forms of the
+    /// same characters are equal.
+    pub nfc: bool,
+
+    /// Whether to fold case, so that usernames differing only in case are equal.
+    pub case_fold: bool,
+}
+
+/// Implementation of the username policy.
+impl UsernamePolicy {
+    /// Returns a policy that trims and NFC normalizes usernames, without folding case.
+    pub fn normalized() -> Self {
+        Self {
+            trim: true,
+            nfc: true,
+            case_fold: false,
+        }
+    }
+
+    /// Sets whether to fold case.
+    pub fn with_case_fold(mut self, case_fold: bool) -> Self {
+        self.case_fold = case_fold;
+        self
+    }
+
+    /// Returns the normalized form of the username.
+    pub fn normalize(&self, user: &str) -> String {
+        let user = if self.trim { user.trim() } else { user };
+        let user = if self.nfc {
+            user.nfc().collect()
+        } else {
+            user.to_string()
+        };
+        if self.case_fold {
+            user.to_lowercase()
+        } else {
+            user
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn default_leaves_usernames_as_given() {
+        let policy = UsernamePolicy::default();
+        assert_eq!(policy.normalize(" Peggy "), " Peggy ");
+        assert_eq!(policy.normalize("Pe\u{301}ggy"), "Pe\u{301}ggy");
+    }
+
+    #[test]
+    fn normalized_trims_and_composes() {
+        let policy = UsernamePolicy::normalized();
+        assert_eq!(policy.normalize("  peggy\t"), "peggy");
+        assert_eq!(policy.normalize("pe\u{301}ggy"), "p\u{e9}ggy");
+        assert_eq!(policy.normalize("Peggy"), "Peggy");
+    }
+
+    #[test]
+    fn case_fold_lowercases() {
+        let policy = UsernamePolicy::normalized().with_case_fold(true);
+        assert_eq!(policy.normalize(" PEGGY "), "peggy");
+        assert_eq!(policy.normalize("P\u{c9}GGY"), "p\u{e9}ggy");
+    }
+
+    #[test]
+    fn case_fold_without_trim_keeps_whitespace() {
+        let policy = UsernamePolicy::default().with_case_fold(true);
+        assert_eq!(policy.normalize(" Peggy"), " peggy");
+    }
+}
@@ -2,13 +2,37 @@
 mod run {
     use anyhow::Result;
     use clap::Parser;
+    use std::fs::File;
     use std::time::Duration;
     use tempdir::TempDir;
     use tokio::time::sleep;
+    use tonic::transport::{Certificate, ClientTlsConfig, Endpoint, Identity};
+    use tonic::Request;
+    use zkauth::discrete_logarithm::{
+        configuration::DiscreteLogarithmConfiguration, prover::DiscreteLogarithmProver,
+        verifier::DiscreteLogarithmVerifier,
+    };
+    use zkauth::elliptic_curve::{
+        curve::{Ristretto, Secp256k1},
+        prover::EllipticCurveProver,
+    };
+    use zkauth::{Prover, Scalar};
+    use zkauth_protobuf::bundle::ParameterBundle;
+    use zkauth_protobuf::v1::{
+        auth_client::AuthClient, auth_server::Auth, configuration::Flavor,
+        AuthenticationAnswerRequest, AuthenticationChallengeRequest, Configuration,
+        GetConfigurationRequest, RegisterRequest,
+    };
     use zkauth_server::cli::{
-        config::{generate_config, load_config_from_file, write_config_to_file, ConfigFlavor},
-        run, Options,
+        config::{
+            config_meta_path, generate_config, generate_config_with_options, load_config_from_file,
+            parameter_report, write_config_to_file, ConfigCurve, ConfigFlavor, GenerateOptions,
+        },
+        listen::ListenAddress,
+        run, spawn, Options, ServerHandle,
     };
+    use zkauth_server::recorder::{load_records, RequestRecorder};
+    use zkauth_server::service::Service;
 
     #[tokio::test]
     async fn succeeds_with_generate_config() -> Result<()> {
@@ -52,6 +76,89 @@ mod run {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn generate_writes_meta_with_option() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_path = config_path.to_str().unwrap();
+
+        let opts = Options::parse_from(vec![
+            "bin",
+            "--config-prime-bits=32",
+            "--config-generate",
+            "--config-meta",
+            format!("--config-path={}", config_path).as_str(),
+        ]);
+        run(opts).await?;
+
+        let config = load_config_from_file(config_path)?;
+        let meta: serde_json::Value =
+            serde_json::from_reader(File::open(config_meta_path(config_path))?)?;
+        assert_eq!(meta["flavor"], "discrete-logarithm");
+        assert_eq!(meta["config_version"], config.config_version());
+        assert_eq!(meta["p_bits"], 32);
+        assert_eq!(meta["q_bits"], 31);
+        assert_eq!(meta["safe_prime"], true);
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn generate_does_not_write_meta_by_default() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_path = config_path.to_str().unwrap();
+
+        let opts = Options::parse_from(vec![
+            "bin",
+            "--config-prime-bits=8",
+            "--config-generate",
+            format!("--config-path={}", config_path).as_str(),
+        ]);
+        run(opts).await?;
+
+        assert!(!std::path::Path::new(&config_meta_path(config_path)).exists());
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn concurrent_generate_produces_single_config() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_path = config_path.to_str().unwrap().to_string();
+
+        let (done_tx, mut done_rx) = tokio::sync::mpsc::unbounded_channel();
+        for _ in 0..2 {
+            let opts = Options::parse_from(vec![
+                "bin".to_string(),
+                "--config-prime-bits=64".to_string(),
+                "--config-generate".to_string(),
+                format!("--config-path={}", config_path),
+            ]);
+            let done_tx = done_tx.clone();
+            tokio::spawn(async move {
+                let _ = done_tx.send(run(opts).await);
+            });
+        }
+
+        // The configuration written by whichever run finishes first is not replaced by the other,
+        // which either waits on the generation lock and reuses it or finds it already there.
+        done_rx.recv().await.unwrap()?;
+        let first = std::fs::read(&config_path)?;
+        done_rx.recv().await.unwrap()?;
+        assert_eq!(std::fs::read(&config_path)?, first);
+
+        let config = load_config_from_file(&config_path)?;
+        assert!(matches!(config.flavor, Some(Flavor::DiscreteLogarithm(_))));
+        assert!(std::path::Path::new(&format!("{}.lock", config_path)).exists());
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
     #[tokio::test]
     async fn generate_overwrites_existing_with_option() -> Result<()> {
         let tmp_dir = TempDir::new("zkauth-test")?;
@@ -89,6 +196,59 @@ mod run {
         Ok(())
     }
 
+    #[cfg(feature = "console-subscriber")]
+    #[tokio::test]
+    async fn succeeds_with_tokio_console() -> Result<()> {
+        tokio::spawn(async move {
+            let opts = Options::parse_from(vec![
+                "bin",
+                "--config-prime-bits=8",
+                "--enable-tokio-console",
+            ]);
+            run(opts).await
+        });
+
+        sleep(Duration::from_millis(200)).await;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_below_min_security_level() -> Result<()> {
+        let opts = Options::parse_from(vec![
+            "bin",
+            "--config-prime-bits=16",
+            "--min-security-level=112",
+        ]);
+        let err = run(opts).await.unwrap_err();
+        assert!(
+            err.to_string().contains("below the minimum of 112"),
+            "{}",
+            err
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn succeeds_below_min_security_level_with_allow_weak_parameters() -> Result<()> {
+        let server = tokio::spawn(async move {
+            let opts = Options::parse_from(vec![
+                "bin",
+                "--config-prime-bits=16",
+                "--min-security-level=112",
+                "--allow-weak-parameters",
+            ]);
+            run(opts).await
+        });
+
+        sleep(Duration::from_millis(200)).await;
+        assert!(!server.is_finished());
+        server.abort();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn succeeds_with_loaded_config() -> Result<()> {
         let tmp_dir = TempDir::new("zkauth-test")?;
@@ -115,4 +275,725 @@ mod run {
         tmp_dir.close()?;
         Ok(())
     }
+
+    #[tokio::test]
+    async fn fails_with_loaded_config_without_flavor() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        std::fs::write(&config_path, "{\"protocol_version\": 1}")?;
+
+        let opts = Options::parse_from(vec![
+            "bin",
+            format!("--config-path={}", config_path.to_str().unwrap()).as_str(),
+        ]);
+        let err = run(opts).await.unwrap_err();
+        assert!(
+            err.to_string().ends_with("configuration has no flavor"),
+            "{}",
+            err
+        );
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_with_loaded_config_with_unsound_parameters() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_path = config_path.to_str().unwrap().to_owned();
+
+        // 5 is a primitive root mod 23, so it has order 22 rather than q = 11.
+        let config = DiscreteLogarithmConfiguration {
+            p: 23.into(),
+            q: 11.into(),
+            g: 5.into(),
+            h: 9.into(),
+        };
+        write_config_to_file(config.into(), &config_path)?;
+
+        let opts = Options::parse_from(vec![
+            "bin".to_string(),
+            format!("--config-path={}", config_path),
+        ]);
+        let err = run(opts).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "configuration at '{}' failed validation: g is not a generator of order q",
+                config_path
+            )
+        );
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    /// Connects to the server at the listen address it reported at the given index.
+    async fn connect(
+        server: &ServerHandle,
+        index: usize,
+    ) -> Result<AuthClient<tonic::transport::Channel>> {
+        let ListenAddress::Tcp(address) = &server.report().listen_addresses[index] else {
+            panic!("expected a TCP listen address");
+        };
+        Ok(AuthClient::connect(format!("http://{}", address)).await?)
+    }
+
+    #[tokio::test]
+    async fn spawn_reports_resolved_addresses_and_shuts_down() -> Result<()> {
+        let server = spawn(Options::parse_from(vec![
+            "bin",
+            "--config-prime-bits=16",
+            "--port=0",
+            "--enable-http-json",
+            "--http-json-listen=127.0.0.1:0",
+        ]))
+        .await?;
+
+        let report = server.report().clone();
+        assert_eq!(report.listen_addresses.len(), 1);
+        let ListenAddress::Tcp(address) = &report.listen_addresses[0] else {
+            panic!("expected a TCP listen address");
+        };
+        let address: std::net::SocketAddr = address.parse()?;
+        assert_ne!(address.port(), 0);
+        assert_ne!(report.http_json_address.unwrap().port(), 0);
+        assert_eq!(report.config.flavor, ConfigFlavor::DiscreteLogarithm);
+
+        // The server is serving on the reported address until it is shut down.
+        let mut client = connect(&server, 0).await?;
+        client.get_configuration(GetConfigurationRequest {}).await?;
+        server.shutdown().await?;
+        assert!(AuthClient::connect(format!("http://{}", address))
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn spawn_fails_when_not_serving() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+
+        let err = spawn(Options::parse_from(vec![
+            "bin".to_string(),
+            "--config-generate".to_string(),
+            "--config-prime-bits=16".to_string(),
+            format!("--config-path={}", config_path.to_str().unwrap()),
+        ]))
+        .await
+        .unwrap_err();
+        assert_eq!(err.to_string(), "server exited without serving");
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn authenticates_with_generated_elliptic_curve_config() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_path = config_path.to_str().unwrap().to_owned();
+
+        let config = generate_config(ConfigFlavor::EllipticCurve, 0)?;
+        write_config_to_file(config.clone(), &config_path)?;
+
+        let server = spawn(Options::parse_from(vec![
+            "bin".to_string(),
+            "--port=0".to_string(),
+            format!("--config-path={}", config_path),
+        ]))
+        .await?;
+
+        let mut client = connect(&server, 0).await?;
+
+        // The server serves the elliptic curve configuration that was written.
+        let served = client
+            .get_configuration(GetConfigurationRequest {})
+            .await?
+            .into_inner();
+        assert_eq!(served.flavor, config.flavor);
+        let Some(Flavor::EllipticCurve(params)) = served.flavor else {
+            panic!("expected an elliptic curve configuration");
+        };
+        let prover = EllipticCurveProver::<Ristretto>::new(
+            params
+                .try_into()
+                .map_err(|_| anyhow::Error::msg("invalid elliptic curve configuration"))?,
+        );
+
+        // Register and authenticate against the running server.
+        let x = prover.compute_registration_x("password".to_string());
+        let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+        client
+            .register(RegisterRequest {
+                user: "peggy".to_string(),
+                y1: y1.to_string(),
+                y2: y2.to_string(),
+                metadata: Default::default(),
+            })
+            .await?;
+
+        let k = prover.generate_challenge_k();
+        let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+        let challenge = client
+            .create_authentication_challenge(AuthenticationChallengeRequest {
+                user: "peggy".to_string(),
+                r1: r1.to_string(),
+                r2: r2.to_string(),
+            })
+            .await?
+            .into_inner();
+
+        let c: Scalar = challenge
+            .c
+            .parse()
+            .map_err(|_| anyhow::Error::msg("invalid c"))?;
+        let s = prover.compute_challenge_response_s(&x, &k, c)?;
+        let answer = client
+            .verify_authentication(AuthenticationAnswerRequest {
+                auth_id: challenge.auth_id,
+                s: s.to_string(),
+                c: "".to_string(),
+                mac: "".to_string(),
+            })
+            .await?
+            .into_inner();
+        assert!(!answer.session_id.is_empty());
+
+        server.shutdown().await?;
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn authenticates_with_generated_secp256k1_config() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_path = config_path.to_str().unwrap().to_owned();
+
+        let options = GenerateOptions {
+            curve: ConfigCurve::Secp256k1,
+            ..Default::default()
+        };
+        let config = generate_config_with_options(ConfigFlavor::EllipticCurve, 0, options)?;
+        write_config_to_file(config.clone(), &config_path)?;
+
+        let server = spawn(Options::parse_from(vec![
+            "bin".to_string(),
+            "--port=0".to_string(),
+            format!("--config-path={}", config_path),
+        ]))
+        .await?;
+
+        let mut client = connect(&server, 0).await?;
+
+        // The server serves the secp256k1 configuration that was written.
+        let served = client
+            .get_configuration(GetConfigurationRequest {})
+            .await?
+            .into_inner();
+        assert_eq!(served.flavor, config.flavor);
+        let Some(Flavor::EllipticCurve(params)) = served.flavor else {
+            panic!("expected an elliptic curve configuration");
+        };
+        assert_eq!(params.curve, "secp256k1");
+        let prover = EllipticCurveProver::<Secp256k1>::new(
+            params
+                .try_into()
+                .map_err(|_| anyhow::Error::msg("invalid secp256k1 configuration"))?,
+        );
+
+        // Register and authenticate against the running server.
+        let x = prover.compute_registration_x("password".to_string());
+        let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+        client
+            .register(RegisterRequest {
+                user: "peggy".to_string(),
+                y1: y1.to_string(),
+                y2: y2.to_string(),
+                metadata: Default::default(),
+            })
+            .await?;
+
+        let k = prover.generate_challenge_k();
+        let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+        let challenge = client
+            .create_authentication_challenge(AuthenticationChallengeRequest {
+                user: "peggy".to_string(),
+                r1: r1.to_string(),
+                r2: r2.to_string(),
+            })
+            .await?
+            .into_inner();
+
+        let c: Scalar = challenge
+            .c
+            .parse()
+            .map_err(|_| anyhow::Error::msg("invalid c"))?;
+        let s = prover.compute_challenge_response_s(&x, &k, c)?;
+        let answer = client
+            .verify_authentication(AuthenticationAnswerRequest {
+                auth_id: challenge.auth_id,
+                s: s.to_string(),
+                c: "".to_string(),
+                mac: "".to_string(),
+            })
+            .await?
+            .into_inner();
+        assert!(!answer.session_id.is_empty());
+
+        server.shutdown().await?;
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    /// Returns the path of a TLS certificate or key for testing, all signed by the same test CA.
+    fn tls_testdata(file: &str) -> String {
+        format!("{}/src/testdata/tls/{}", env!("CARGO_MANIFEST_DIR"), file)
+    }
+
+    /// Connects to the server over TLS, presenting the client certificate if given, and fetches
+    /// its configuration.
+    async fn get_configuration_over_tls(
+        server: &ServerHandle,
+        client_identity: Option<Identity>,
+    ) -> Result<(AuthClient<tonic::transport::Channel>, Configuration)> {
+        let ListenAddress::Tcp(address) = &server.report().listen_addresses[0] else {
+            panic!("expected a TCP listen address");
+        };
+        let mut tls_config = ClientTlsConfig::new()
+            .ca_certificate(Certificate::from_pem(std::fs::read(tls_testdata(
+                "ca.pem",
+            ))?))
+            .domain_name("localhost");
+        if let Some(identity) = client_identity {
+            tls_config = tls_config.identity(identity);
+        }
+        let channel = Endpoint::from_shared(format!("https://{}", address))?
+            .tls_config(tls_config)?
+            .connect()
+            .await?;
+        let mut client = AuthClient::new(channel);
+        let config = client
+            .get_configuration(GetConfigurationRequest {})
+            .await?
+            .into_inner();
+        Ok((client, config))
+    }
+
+    #[tokio::test]
+    async fn requires_client_certificate_with_tls_client_ca() -> Result<()> {
+        let server = spawn(Options::parse_from(vec![
+            "bin".to_string(),
+            "--config-prime-bits=16".to_string(),
+            "--port=0".to_string(),
+            format!("--tls-cert={}", tls_testdata("server.pem")),
+            format!("--tls-key={}", tls_testdata("server.key")),
+            format!("--tls-client-ca={}", tls_testdata("ca.pem")),
+            "--tls-client-scoped-registration".to_string(),
+        ]))
+        .await?;
+
+        // A client without a certificate is rejected.
+        assert!(get_configuration_over_tls(&server, None).await.is_err());
+
+        // A client with a certificate signed by the client CA is served, and may register the
+        // user named by its common name, but no other.
+        let identity = Identity::from_pem(
+            std::fs::read(tls_testdata("client.pem"))?,
+            std::fs::read(tls_testdata("client.key"))?,
+        );
+        let (mut client, config) = get_configuration_over_tls(&server, Some(identity)).await?;
+        let Some(Flavor::DiscreteLogarithm(params)) = config.flavor else {
+            panic!("expected a discrete logarithm configuration");
+        };
+        let prover = DiscreteLogarithmProver::new(
+            params
+                .try_into()
+                .map_err(|_| anyhow::Error::msg("invalid discrete logarithm configuration"))?,
+        );
+        let x = prover.compute_registration_x("password".to_string());
+        let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+        let register = |user: &str| RegisterRequest {
+            user: user.to_string(),
+            y1: y1.to_string(),
+            y2: y2.to_string(),
+            metadata: Default::default(),
+        };
+        client.register(register("peggy")).await?;
+        let err = client.register(register("victor")).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::PermissionDenied);
+
+        server.shutdown().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn serves_http_json_gateway_sharing_the_store() -> Result<()> {
+        let server = spawn(Options::parse_from(vec![
+            "bin",
+            "--config-prime-bits=16",
+            "--port=0",
+            "--enable-http-json",
+            "--http-json-listen=127.0.0.1:0",
+        ]))
+        .await?;
+        let http_json_address = server.report().http_json_address.unwrap();
+
+        let mut client = connect(&server, 0).await?;
+        let config = client
+            .get_configuration(GetConfigurationRequest {})
+            .await?
+            .into_inner();
+        let Some(Flavor::DiscreteLogarithm(params)) = config.flavor else {
+            panic!("expected a discrete logarithm configuration");
+        };
+        let prover = DiscreteLogarithmProver::new(
+            params
+                .try_into()
+                .map_err(|_| anyhow::Error::msg("invalid discrete logarithm configuration"))?,
+        );
+
+        // Register over the gateway.
+        let x = prover.compute_registration_x("password".to_string());
+        let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+        let body =
+            serde_json::json!({ "user": "peggy", "y1": y1.to_string(), "y2": y2.to_string() });
+        let response = hyper::Client::new()
+            .request(
+                hyper::Request::post(format!("http://{}/v1/register", http_json_address))
+                    .header("content-type", "application/json")
+                    .body(hyper::Body::from(body.to_string()))?,
+            )
+            .await?;
+        assert_eq!(response.status(), hyper::StatusCode::OK);
+
+        // The user is registered in the store shared with the gRPC server.
+        let err = client
+            .register(RegisterRequest {
+                user: "peggy".to_string(),
+                y1: y1.to_string(),
+                y2: y2.to_string(),
+                metadata: Default::default(),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::AlreadyExists);
+
+        server.shutdown().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_with_http_json_when_serving_both_flavors() -> Result<()> {
+        let opts = Options::parse_from(vec![
+            "bin",
+            "--config-prime-bits=16",
+            "--config-flavor=both",
+            "--enable-http-json",
+        ]);
+        let err = run(opts).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "--enable-http-json cannot be used with --config-flavor=both, since the gateway serves a single flavor"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_with_record_requests_when_serving_both_flavors() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let record_path = tmp_dir.path().join("requests.jsonl");
+
+        let opts = Options::parse_from(vec![
+            "bin".to_string(),
+            "--config-prime-bits=16".to_string(),
+            "--config-flavor=both".to_string(),
+            format!("--record-requests={}", record_path.to_str().unwrap()),
+        ]);
+        let err = run(opts).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "--record-requests cannot be used with --config-flavor=both, since recordings are replayed against a single flavor"
+        );
+        assert!(!record_path.exists());
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn serves_metrics_counting_registrations() -> Result<()> {
+        let server = spawn(Options::parse_from(vec![
+            "bin",
+            "--config-prime-bits=16",
+            "--port=0",
+            "--metrics-port=0",
+        ]))
+        .await?;
+        let metrics_address = server.report().metrics_address.unwrap();
+
+        let mut client = connect(&server, 0).await?;
+        let config = client
+            .get_configuration(GetConfigurationRequest {})
+            .await?
+            .into_inner();
+        let Some(Flavor::DiscreteLogarithm(params)) = config.flavor else {
+            panic!("expected a discrete logarithm configuration");
+        };
+        let prover = DiscreteLogarithmProver::new(
+            params
+                .try_into()
+                .map_err(|_| anyhow::Error::msg("invalid discrete logarithm configuration"))?,
+        );
+        let x = prover.compute_registration_x("password".to_string());
+        let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+        client
+            .register(RegisterRequest {
+                user: "peggy".to_string(),
+                y1: y1.to_string(),
+                y2: y2.to_string(),
+                metadata: Default::default(),
+            })
+            .await?;
+
+        let response = hyper::Client::new()
+            .get(format!("http://{}/metrics", metrics_address).parse()?)
+            .await?;
+        assert_eq!(response.status(), hyper::StatusCode::OK);
+        let body = hyper::body::to_bytes(response.into_body()).await?;
+        let body = String::from_utf8(body.to_vec())?;
+        assert!(body.contains("zkauth_register_total 1\n"), "{}", body);
+
+        server.shutdown().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn authenticates_over_multiple_listeners_with_shared_state() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_path = config_path.to_str().unwrap().to_owned();
+
+        let config = generate_config(ConfigFlavor::EllipticCurve, 0)?;
+        write_config_to_file(config.clone(), &config_path)?;
+
+        let server = spawn(Options::parse_from(vec![
+            "bin".to_string(),
+            "--listen=127.0.0.1:0".to_string(),
+            "--listen=127.0.0.1:0".to_string(),
+            format!("--config-path={}", config_path),
+        ]))
+        .await?;
+
+        let mut client1 = connect(&server, 0).await?;
+        let mut client2 = connect(&server, 1).await?;
+
+        let Some(Flavor::EllipticCurve(params)) = config.flavor else {
+            panic!("expected an elliptic curve configuration");
+        };
+        let prover = EllipticCurveProver::<Ristretto>::new(
+            params
+                .try_into()
+                .map_err(|_| anyhow::Error::msg("invalid elliptic curve configuration"))?,
+        );
+
+        // Register over the first listener.
+        let x = prover.compute_registration_x("password".to_string());
+        let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+        client1
+            .register(RegisterRequest {
+                user: "peggy".to_string(),
+                y1: y1.to_string(),
+                y2: y2.to_string(),
+                metadata: Default::default(),
+            })
+            .await?;
+
+        // The registration is visible over the second listener.
+        let err = client2
+            .register(RegisterRequest {
+                user: "peggy".to_string(),
+                y1: y1.to_string(),
+                y2: y2.to_string(),
+                metadata: Default::default(),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::AlreadyExists);
+
+        // Authenticate over each listener, answering a challenge issued by the other.
+        for (mut challenger, mut answerer) in [
+            (client2.clone(), client1.clone()),
+            (client1.clone(), client2.clone()),
+        ] {
+            let k = prover.generate_challenge_k();
+            let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+            let challenge = challenger
+                .create_authentication_challenge(AuthenticationChallengeRequest {
+                    user: "peggy".to_string(),
+                    r1: r1.to_string(),
+                    r2: r2.to_string(),
+                })
+                .await?
+                .into_inner();
+
+            let c: Scalar = challenge
+                .c
+                .parse()
+                .map_err(|_| anyhow::Error::msg("invalid c"))?;
+            let s = prover.compute_challenge_response_s(&x, &k, c)?;
+            let answer = answerer
+                .verify_authentication(AuthenticationAnswerRequest {
+                    auth_id: challenge.auth_id,
+                    s: s.to_string(),
+                    c: "".to_string(),
+                    mac: "".to_string(),
+                })
+                .await?
+                .into_inner();
+            assert!(!answer.session_id.is_empty());
+        }
+
+        server.shutdown().await?;
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn dumps_parameters_from_loaded_config_without_serving() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_path = config_path.to_str().unwrap();
+
+        let config = generate_config(ConfigFlavor::DiscreteLogarithm, 8)?;
+        write_config_to_file(config.clone(), config_path)?;
+
+        // Returns rather than serving, since no socket is bound.
+        let opts = Options::parse_from(vec![
+            "bin",
+            format!("--config-path={}", config_path).as_str(),
+            "--dump-parameters",
+        ]);
+        run(opts).await?;
+
+        let report = serde_json::to_value(parameter_report(&load_config_from_file(config_path)?)?)?;
+        assert_eq!(report["flavor"], "discrete-logarithm");
+        assert_eq!(report["config_version"], config.config_version());
+        for field in ["p", "p_bits", "q", "q_bits", "g", "h"] {
+            assert!(report.get(field).is_some(), "missing {}", field);
+        }
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exports_bundle_from_loaded_config() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_path = config_path.to_str().unwrap();
+        let bundle_path = tmp_dir.path().join("bundle.json");
+        let bundle_path = bundle_path.to_str().unwrap();
+
+        let config = generate_config(ConfigFlavor::DiscreteLogarithm, 8)?;
+        write_config_to_file(config.clone(), config_path)?;
+
+        let opts = Options::parse_from(vec![
+            "bin",
+            format!("--config-path={}", config_path).as_str(),
+            format!("--config-export-bundle={}", bundle_path).as_str(),
+        ]);
+        run(opts).await?;
+
+        let bundle: ParameterBundle = serde_json::from_reader(File::open(bundle_path)?)?;
+        assert_eq!(
+            bundle.configuration,
+            Configuration {
+                protocol_version: zkauth::PROTOCOL_VERSION,
+                ..config
+            }
+        );
+        assert!(bundle.verify().is_ok());
+        assert!(bundle.signature.is_none());
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    /// Records a registration with a service for the given configuration to the given path.
+    async fn record_registration(
+        config: DiscreteLogarithmConfiguration,
+        record_path: &str,
+    ) -> Result<()> {
+        let verifier = Box::new(DiscreteLogarithmVerifier::new(config.clone()));
+        let service = Service::new(config.clone().into(), verifier)
+            .with_recorder(RequestRecorder::create(record_path)?);
+        service
+            .register(Request::new(RegisterRequest {
+                user: "peggy".to_string(),
+                y1: config.g.to_string(),
+                y2: config.h.to_string(),
+                metadata: Default::default(),
+            }))
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn replays_recorded_requests() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_path = config_path.to_str().unwrap();
+        let record_path = tmp_dir.path().join("requests.jsonl");
+        let record_path = record_path.to_str().unwrap();
+
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        write_config_to_file(config.clone().into(), config_path)?;
+        record_registration(config, record_path).await?;
+        assert_eq!(load_records(record_path)?.len(), 1);
+
+        let opts = Options::parse_from(vec![
+            "bin",
+            format!("--config-path={}", config_path).as_str(),
+            "replay",
+            record_path,
+        ]);
+        run(opts).await?;
+
+        tmp_dir.close()?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn replay_fails_when_outcomes_differ() -> Result<()> {
+        let tmp_dir = TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_path = config_path.to_str().unwrap();
+        let record_path = tmp_dir.path().join("requests.jsonl");
+        let record_path = record_path.to_str().unwrap();
+
+        // Record the same successful registration twice, which cannot both succeed on replay.
+        let config = DiscreteLogarithmConfiguration::generate(16);
+        write_config_to_file(config.clone().into(), config_path)?;
+        record_registration(config.clone(), record_path).await?;
+        record_registration(config, record_path).await?;
+
+        let opts = Options::parse_from(vec![
+            "bin",
+            format!("--config-path={}", config_path).as_str(),
+            "replay",
+            record_path,
+        ]);
+        assert!(run(opts).await.is_err());
+
+        tmp_dir.close()?;
+        Ok(())
+    }
 }
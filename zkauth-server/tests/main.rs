@@ -34,4 +34,155 @@ mod main {
 
         Ok(())
     }
+
+    #[cfg(feature = "null-verifier")]
+    #[test]
+    fn starts_with_null_flavor() -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("zkauth-server")?
+            .arg("--config-prime-bits=16")
+            .arg("--config-flavor=null")
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        if cmd.wait_timeout(Duration::from_millis(500))?.is_none() {
+            cmd.kill()?;
+            cmd.wait()?;
+        }
+
+        let mut output = String::new();
+        cmd.stderr.unwrap().read_to_string(&mut output)?;
+        assert!(output.contains("null verifier"), "{}", output);
+        assert!(
+            output.contains("Server listening on 127.0.0.1:"),
+            "{}",
+            output
+        );
+
+        Ok(())
+    }
+
+    /// Runs the server with the given arguments for a short while, and returns its stdout.
+    fn run_stdout(args: &[&str]) -> Result<String, Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("zkauth-server")?
+            .arg("--config-prime-bits=16")
+            .arg("--port=0")
+            .args(args)
+            .stdout(Stdio::piped())
+            .spawn()?;
+
+        if cmd.wait_timeout(Duration::from_millis(500))?.is_none() {
+            cmd.kill()?;
+            cmd.wait()?;
+        }
+
+        let mut output = String::new();
+        cmd.stdout.unwrap().read_to_string(&mut output)?;
+        Ok(output)
+    }
+
+    #[test]
+    fn prints_config_summary_by_default() -> Result<(), Box<dyn std::error::Error>> {
+        let output = run_stdout(&[])?;
+
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 1, "{}", output);
+        let summary: serde_json::Value = serde_json::from_str(lines[0])?;
+        let fields: Vec<&String> = summary.as_object().unwrap().keys().collect();
+        assert_eq!(fields, vec!["config_version", "flavor"]);
+        assert_eq!(summary["flavor"], "discrete-logarithm");
+
+        Ok(())
+    }
+
+    #[test]
+    fn prints_full_config_when_specified() -> Result<(), Box<dyn std::error::Error>> {
+        let output = run_stdout(&["--print-full-config"])?;
+
+        let config: serde_json::Value = serde_json::from_str(&output)?;
+        assert!(config["discreteLogarithm"]["p"].is_string(), "{}", output);
+
+        Ok(())
+    }
+
+    #[test]
+    fn profile_prints_timing_and_exits_without_serving() -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("zkauth-server")?
+            .arg("--config-prime-bits=16")
+            .arg("--profile")
+            .arg("--profile-iterations=5")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let Some(status) = cmd.wait_timeout(Duration::from_secs(10))? else {
+            cmd.kill()?;
+            panic!("profiling did not exit");
+        };
+        assert!(status.success());
+
+        let mut output = String::new();
+        cmd.stdout.unwrap().read_to_string(&mut output)?;
+        let mut logs = String::new();
+        cmd.stderr.unwrap().read_to_string(&mut logs)?;
+        assert!(!logs.contains("Server listening"), "{}", logs);
+
+        let report: serde_json::Value = serde_json::from_str(&output)?;
+        assert_eq!(report["iterations"], 5, "{}", output);
+        let p50 = report["p50_us"].as_u64().unwrap();
+        let p90 = report["p90_us"].as_u64().unwrap();
+        let p99 = report["p99_us"].as_u64().unwrap();
+        let max = report["max_us"].as_u64().unwrap();
+        assert!(p50 <= p90 && p90 <= p99 && p99 <= max, "{}", output);
+
+        Ok(())
+    }
+
+    /// Tests that the server reloads its configuration file on SIGHUP when the change is benign,
+    /// keeping the same public parameters.
+    #[cfg(unix)]
+    #[test]
+    fn reloads_benign_config_change_on_hangup() -> Result<(), Box<dyn std::error::Error>> {
+        let tmp_dir = tempdir::TempDir::new("zkauth-test")?;
+        let config_path = tmp_dir.path().join("config.json");
+        let config_arg = format!("--config-path={}", config_path.to_str().unwrap());
+
+        let status = Command::cargo_bin("zkauth-server")?
+            .args(["--config-prime-bits=16", "--config-generate", &config_arg])
+            .stderr(Stdio::null())
+            .status()?;
+        assert!(status.success());
+
+        let mut cmd = Command::cargo_bin("zkauth-server")?
+            .args(["--port=0", &config_arg])
+            .stderr(Stdio::piped())
+            .spawn()?;
+        std::thread::sleep(Duration::from_millis(500));
+
+        // Write g with a leading zero, which changes the file but not the parameters.
+        let mut config: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&config_path)?)?;
+        let g = config["discrete_logarithm"]["g"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        config["discrete_logarithm"]["g"] = format!("0{}", g).into();
+        std::fs::write(&config_path, serde_json::to_string_pretty(&config)?)?;
+
+        let status = Command::new("kill")
+            .args(["-HUP", &cmd.id().to_string()])
+            .status()?;
+        assert!(status.success());
+        std::thread::sleep(Duration::from_millis(500));
+
+        // The server is still running after the reload.
+        assert!(cmd.try_wait()?.is_none());
+        cmd.kill()?;
+        cmd.wait()?;
+
+        let mut output = String::new();
+        cmd.stderr.unwrap().read_to_string(&mut output)?;
+        assert!(output.contains("Configuration reloaded from"), "{}", output);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,65 @@
+//! Compares the modular exponentiation backends for the discrete logarithm protocol, and the
+//! simultaneous exponentiation used by the verifier with two independent ones.
+//!
+//! Run with `cargo bench -p zkauth --features rug` to include the GMP backend.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use num_bigint::{BigInt, BigUint, RandBigInt};
+#[cfg(feature = "rug")]
+use zkauth::discrete_logarithm::arithmetic::RugBackend;
+use zkauth::discrete_logarithm::arithmetic::{
+    modpow, multi_modpow, ModPowBackend, NumBigintBackend,
+};
+
+/// The modulus sizes in bits to benchmark.
+const BITS: [u64; 3] = [256, 1024, 3072];
+
+/// Generates a random odd modulus of the given size, with a base and exponent below it.
+fn inputs(bits: u64) -> (BigInt, BigInt, BigInt) {
+    let mut rng = rand::thread_rng();
+    let modulus: BigInt = (rng.gen_biguint(bits) | BigUint::from(1u32)).into();
+    let base = rng.gen_bigint_range(&BigInt::from(2), &modulus);
+    let exponent = rng.gen_bigint_range(&BigInt::from(2), &modulus);
+    (base, exponent, modulus)
+}
+
+fn bench_modpow(c: &mut Criterion) {
+    let mut group = c.benchmark_group("modpow");
+    for bits in BITS {
+        let (base, exponent, modulus) = inputs(bits);
+
+        group.bench_with_input(BenchmarkId::new("num-bigint", bits), &bits, |b, _| {
+            b.iter(|| NumBigintBackend::modpow(&base, &exponent, &modulus))
+        });
+
+        #[cfg(feature = "rug")]
+        group.bench_with_input(BenchmarkId::new("rug", bits), &bits, |b, _| {
+            b.iter(|| RugBackend::modpow(&base, &exponent, &modulus))
+        });
+    }
+    group.finish();
+}
+
+fn bench_multi_modpow(c: &mut Criterion) {
+    let mut group = c.benchmark_group("multi_modpow");
+    for bits in BITS {
+        let (base1, exponent1, modulus) = inputs(bits);
+        let (base2, exponent2, _) = inputs(bits);
+        let (base2, exponent2) = (base2 % &modulus, exponent2 % &modulus);
+
+        group.bench_with_input(BenchmarkId::new("two-modpows", bits), &bits, |b, _| {
+            b.iter(|| {
+                (modpow(&base1, &exponent1, &modulus) * modpow(&base2, &exponent2, &modulus))
+                    % &modulus
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("shamir", bits), &bits, |b, _| {
+            b.iter(|| multi_modpow(&base1, &exponent1, &base2, &exponent2, &modulus))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_modpow, bench_multi_modpow);
+criterion_main!(benches);
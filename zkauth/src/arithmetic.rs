@@ -0,0 +1,387 @@
+//! Modular arithmetic for `Scalar` and the discrete logarithm protocol, behind a swappable
+//! big-integer backend.
+//!
+//! The default backend is the pure Rust `num-bigint`. Enabling the `rug` feature switches to GMP
+//! via the `rug` crate, which is considerably faster for large (e.g. 3072-bit) moduli.
+
+use num_bigint::{BigInt, RandBigInt, Sign};
+use num_traits::{One, Zero};
+
+/// A big-integer backend for modular exponentiation.
+pub trait ModPowBackend {
+    /// Computes base^exponent mod modulus, for a non-negative exponent and positive modulus.
+    fn modpow(base: &BigInt, exponent: &BigInt, modulus: &BigInt) -> BigInt;
+}
+
+/// The pure Rust `num-bigint` backend.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NumBigintBackend;
+
+/// Implementation of the `num-bigint` backend.
+impl ModPowBackend for NumBigintBackend {
+    fn modpow(base: &BigInt, exponent: &BigInt, modulus: &BigInt) -> BigInt {
+        base.modpow(exponent, modulus)
+    }
+}
+
+/// The GMP backend, via the `rug` crate.
+#[cfg(feature = "rug")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RugBackend;
+
+#[cfg(feature = "rug")]
+mod rug_backend {
+    use super::{ModPowBackend, RugBackend};
+    use num_bigint::{BigInt, Sign};
+    use rug::{integer::Order, Integer};
+
+    /// Converts a BigInt to a rug integer.
+    fn to_rug(value: &BigInt) -> Integer {
+        let (sign, digits) = value.to_bytes_le();
+        let integer = Integer::from_digits(&digits, Order::Lsf);
+        if sign == Sign::Minus {
+            -integer
+        } else {
+            integer
+        }
+    }
+
+    /// Converts a rug integer to a BigInt.
+    fn from_rug(value: &Integer) -> BigInt {
+        let sign = if value.is_negative() {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+        let digits: Vec<u8> = value.to_digits(Order::Lsf);
+        BigInt::from_bytes_le(sign, &digits)
+    }
+
+    /// Implementation of the GMP backend.
+    impl ModPowBackend for RugBackend {
+        fn modpow(base: &BigInt, exponent: &BigInt, modulus: &BigInt) -> BigInt {
+            assert!(
+                exponent.sign() != Sign::Minus,
+                "exponent must be non-negative"
+            );
+            let result = to_rug(base)
+                .pow_mod(&to_rug(exponent), &to_rug(modulus))
+                .expect("non-negative exponent always has a result");
+            from_rug(&result)
+        }
+    }
+}
+
+/// The backend selected by the enabled features.
+#[cfg(not(feature = "rug"))]
+pub type DefaultBackend = NumBigintBackend;
+
+/// The backend selected by the enabled features.
+#[cfg(feature = "rug")]
+pub type DefaultBackend = RugBackend;
+
+/// Computes base^exponent mod modulus using the default backend.
+pub fn modpow(base: &BigInt, exponent: &BigInt, modulus: &BigInt) -> BigInt {
+    DefaultBackend::modpow(base, exponent, modulus)
+}
+
+/// Computes base1^exponent1 * base2^exponent2 mod modulus with Shamir's trick, for non-negative
+/// exponents and a positive modulus. Both exponents are scanned together two bits at a time
+/// against a table of the 16 products base1^i * base2^j, so the squarings are shared.
+pub fn multi_modpow(
+    base1: &BigInt,
+    exponent1: &BigInt,
+    base2: &BigInt,
+    exponent2: &BigInt,
+    modulus: &BigInt,
+) -> BigInt {
+    assert!(
+        exponent1.sign() != Sign::Minus && exponent2.sign() != Sign::Minus,
+        "exponents must be non-negative"
+    );
+    let base1 = reduce(base1, modulus);
+    let base2 = reduce(base2, modulus);
+
+    // table[i * 4 + j] = base1^i * base2^j mod modulus.
+    let mut table = vec![BigInt::one() % modulus; 16];
+    for index in 1..16 {
+        table[index] = if index % 4 == 0 {
+            (&table[index - 4] * &base1) % modulus
+        } else {
+            (&table[index - 1] * &base2) % modulus
+        };
+    }
+
+    let window = |exponent: &BigInt, bit: u64| {
+        (exponent.bit(bit + 1) as usize) << 1 | exponent.bit(bit) as usize
+    };
+    let mut result = BigInt::one() % modulus;
+    let mut bit = exponent1.bits().max(exponent2.bits()).next_multiple_of(2);
+    while bit > 0 {
+        bit -= 2;
+        result = (&result * &result) % modulus;
+        result = (&result * &result) % modulus;
+        let index = window(exponent1, bit) * 4 + window(exponent2, bit);
+        if index != 0 {
+            result = (result * &table[index]) % modulus;
+        }
+    }
+    result
+}
+
+/// Returns the least non-negative residue of value mod modulus, for a positive modulus. Unlike
+/// `%`, the result is never negative, so it is canonical for negative values such as `k - c * x`.
+pub fn reduce(value: &BigInt, modulus: &BigInt) -> BigInt {
+    let remainder = value % modulus;
+    if remainder < BigInt::zero() {
+        remainder + modulus
+    } else {
+        remainder
+    }
+}
+
+/// Returns the Jacobi symbol (a/n), which is 1, -1, or 0, for a positive odd n. For a prime n it
+/// is the Legendre symbol, so for a safe prime p = 2q + 1 an element of [1, p) is in the subgroup
+/// of order q exactly when its symbol is 1. This costs about as much as a gcd, far less than the
+/// equivalent a^q mod p.
+pub fn jacobi(a: &BigInt, n: &BigInt) -> i32 {
+    assert!(
+        n.sign() == Sign::Plus && n.bit(0),
+        "n must be a positive odd integer"
+    );
+    let low_bits =
+        |value: &BigInt| (value.bit(2) as u8) << 2 | (value.bit(1) as u8) << 1 | value.bit(0) as u8;
+
+    let mut a = reduce(a, n);
+    let mut n = n.clone();
+    let mut result = 1;
+    while !a.is_zero() {
+        // (2/n) is -1 exactly when n is 3 or 5 mod 8.
+        let twos = a.trailing_zeros().unwrap_or(0);
+        a >>= twos;
+        if twos % 2 == 1 && matches!(low_bits(&n), 3 | 5) {
+            result = -result;
+        }
+
+        // Quadratic reciprocity flips the sign when both are 3 mod 4.
+        if low_bits(&a) & 3 == 3 && low_bits(&n) & 3 == 3 {
+            result = -result;
+        }
+        std::mem::swap(&mut a, &mut n);
+        a %= &n;
+    }
+    if n.is_one() {
+        result
+    } else {
+        0
+    }
+}
+
+/// Returns whether n is probably prime, using the given number of Miller-Rabin rounds. A composite
+/// passes with probability at most 4^-rounds.
+pub fn is_probable_prime(n: &BigInt, rounds: usize) -> bool {
+    let one = BigInt::one();
+    let two = BigInt::from(2);
+    if *n < two {
+        return false;
+    }
+    for small in [2u32, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        let small = BigInt::from(small);
+        if *n == small {
+            return true;
+        }
+        if (n % &small).is_zero() {
+            return false;
+        }
+    }
+
+    // Write n - 1 as d * 2^r with d odd.
+    let n_minus_one = n - &one;
+    let r = n_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &n_minus_one >> r;
+
+    let mut rng = rand::thread_rng();
+    'witness: for _ in 0..rounds {
+        let a = rng.gen_bigint_range(&two, &n_minus_one);
+        let mut x = modpow(&a, &d, n);
+        if x == one || x == n_minus_one {
+            continue;
+        }
+        for _ in 1..r {
+            x = modpow(&x, &two, n);
+            if x == n_minus_one {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Known (base, exponent, modulus, result) inputs.
+    fn known_inputs() -> Vec<(BigInt, BigInt, BigInt, BigInt)> {
+        let p: BigInt =
+            "42765216643065397982265462252423826320512529931694366715111734768493812630447"
+                .parse()
+                .unwrap();
+        vec![
+            (4.into(), 13.into(), 497.into(), 445.into()),
+            (2.into(), 0.into(), 23.into(), 1.into()),
+            (0.into(), 5.into(), 23.into(), 0.into()),
+            ((-4).into(), 3.into(), 23.into(), 5.into()),
+            (
+                3.into(),
+                &p - 1u32,
+                p.clone(),
+                // Fermat's little theorem.
+                1.into(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn num_bigint_backend_matches_known_results() {
+        for (base, exponent, modulus, expected) in known_inputs() {
+            assert_eq!(
+                NumBigintBackend::modpow(&base, &exponent, &modulus),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn modpow_uses_default_backend() {
+        for (base, exponent, modulus, expected) in known_inputs() {
+            assert_eq!(modpow(&base, &exponent, &modulus), expected);
+        }
+    }
+
+    #[test]
+    fn multi_modpow_matches_two_modpows() {
+        let mut rng = rand::thread_rng();
+        let p: BigInt =
+            "42765216643065397982265462252423826320512529931694366715111734768493812630447"
+                .parse()
+                .unwrap();
+        let mut inputs: Vec<[BigInt; 5]> = vec![
+            [4.into(), 13.into(), 5.into(), 0.into(), 497.into()],
+            [2.into(), 0.into(), 3.into(), 0.into(), 23.into()],
+            [(-4).into(), 3.into(), 0.into(), 7.into(), 23.into()],
+            [3.into(), 5.into(), 7.into(), 11.into(), 1.into()],
+        ];
+        for _ in 0..20 {
+            inputs.push([
+                rng.gen_bigint_range(&BigInt::zero(), &p),
+                rng.gen_bigint_range(&BigInt::zero(), &p),
+                rng.gen_bigint_range(&BigInt::zero(), &p),
+                rng.gen_bigint_range(&BigInt::zero(), &p),
+                p.clone(),
+            ]);
+        }
+        for [base1, exponent1, base2, exponent2, modulus] in inputs {
+            let expected = (modpow(&base1, &exponent1, &modulus)
+                * modpow(&base2, &exponent2, &modulus))
+                % &modulus;
+            assert_eq!(
+                multi_modpow(&base1, &exponent1, &base2, &exponent2, &modulus),
+                reduce(&expected, &modulus),
+                "{} {} {} {} {}",
+                base1,
+                exponent1,
+                base2,
+                exponent2,
+                modulus
+            );
+        }
+    }
+
+    #[test]
+    fn reduce_returns_least_non_negative_residue() {
+        let q = BigInt::from(11);
+        for (value, expected) in [
+            (0, 0),
+            (1, 1),
+            (11, 0),
+            (12, 1),
+            (-1, 10),
+            (-32, 1),
+            (-33, 0),
+        ] {
+            assert_eq!(reduce(&BigInt::from(value), &q), BigInt::from(expected));
+        }
+    }
+
+    #[test]
+    fn jacobi_matches_known_symbols() {
+        for (a, n, expected) in [
+            (1001, 9907, -1),
+            (19, 45, 1),
+            (8, 21, -1),
+            (5, 21, 1),
+            (6, 15, 0),
+            (0, 1, 1),
+            (-1, 7, -1),
+        ] {
+            assert_eq!(
+                jacobi(&BigInt::from(a), &BigInt::from(n)),
+                expected,
+                "({}/{})",
+                a,
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn jacobi_matches_euler_criterion_for_primes() {
+        let mut rng = rand::thread_rng();
+        let p: BigInt =
+            "42765216643065397982265462252423826320512529931694366715111734768493812630447"
+                .parse()
+                .unwrap();
+        let half = (&p - 1u32) >> 1;
+        for _ in 0..20 {
+            let a = rng.gen_bigint_range(&BigInt::zero(), &p);
+            let expected = match modpow(&a, &half, &p) {
+                residue if residue.is_zero() => 0,
+                residue if residue.is_one() => 1,
+                _ => -1,
+            };
+            assert_eq!(jacobi(&a, &p), expected, "{}", a);
+        }
+    }
+
+    #[test]
+    fn is_probable_prime_classifies_known_values() {
+        let p: BigInt =
+            "42765216643065397982265462252423826320512529931694366715111734768493812630447"
+                .parse()
+                .unwrap();
+        for prime in [2, 3, 5, 37, 41, 7919] {
+            assert!(is_probable_prime(&BigInt::from(prime), 20), "{}", prime);
+        }
+        for composite in [-7, 0, 1, 4, 1681, 7917, 561] {
+            assert!(
+                !is_probable_prime(&BigInt::from(composite), 20),
+                "{}",
+                composite
+            );
+        }
+        assert!(is_probable_prime(&p, 20));
+        assert!(!is_probable_prime(&(&p * 3u32), 20));
+    }
+
+    #[cfg(feature = "rug")]
+    #[test]
+    fn rug_backend_matches_num_bigint_backend() {
+        for (base, exponent, modulus, expected) in known_inputs() {
+            let rug = RugBackend::modpow(&base, &exponent, &modulus);
+            assert_eq!(rug, NumBigintBackend::modpow(&base, &exponent, &modulus));
+            assert_eq!(rug, expected);
+        }
+    }
+}
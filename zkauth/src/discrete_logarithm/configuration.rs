@@ -1,7 +1,173 @@
-use num_bigint::{BigInt, RandBigInt, Sign};
+use anyhow::{Error, Result};
+use num_bigint::{BigInt, BigUint, RandBigInt, Sign};
 use num_primes::Generator;
-use num_traits::One;
+use num_traits::{One, Zero};
+use rayon::prelude::*;
+use sha2::{Digest, Sha512};
+use std::fmt;
 use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+
+use super::arithmetic::{is_probable_prime, modpow, reduce};
+use crate::{ChallengeContext, ProofBinding};
+
+/// The number of Miller-Rabin rounds used to test candidates during a safe prime search.
+const SEARCH_PRIMALITY_ROUNDS: usize = 20;
+
+/// The number of Miller-Rabin rounds used to validate the primes of a configuration, so that a
+/// composite passes with probability at most 2^-80.
+const VALIDATION_PRIMALITY_ROUNDS: usize = 40;
+
+/// The prime of the RFC 3526 2048-bit MODP group 14, in hexadecimal.
+const MODP_2048_P: &str = concat!(
+    "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74",
+    "020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437",
+    "4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED",
+    "EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05",
+    "98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB",
+    "9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B",
+    "E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF695581718",
+    "3995497CEA956AE515D2261898FA051015728E5A8AACAA68FFFFFFFFFFFFFFFF",
+);
+
+/// The prime of the RFC 3526 3072-bit MODP group 15, in hexadecimal.
+const MODP_3072_P: &str = concat!(
+    "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74",
+    "020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437",
+    "4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED",
+    "EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05",
+    "98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB",
+    "9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B",
+    "E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF695581718",
+    "3995497CEA956AE515D2261898FA051015728E5A8AAAC42DAD33170D04507A33",
+    "A85521ABDF1CBA64ECFB850458DBEF0A8AEA71575D060C7DB3970F85A6E1E4C7",
+    "ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6BF12FFA06D98A0864",
+    "D87602733EC86A64521F2B18177B200CBBE117577A615D6C770988C0BAD946E2",
+    "08E24FA074E5AB3143DB5BFCE0FD108E4B82D120A93AD2CAFFFFFFFFFFFFFFFF",
+);
+
+/// The prime of the RFC 3526 4096-bit MODP group 16, in hexadecimal.
+const MODP_4096_P: &str = concat!(
+    "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74",
+    "020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437",
+    "4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED",
+    "EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05",
+    "98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB",
+    "9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B",
+    "E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF695581718",
+    "3995497CEA956AE515D2261898FA051015728E5A8AAAC42DAD33170D04507A33",
+    "A85521ABDF1CBA64ECFB850458DBEF0A8AEA71575D060C7DB3970F85A6E1E4C7",
+    "ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6BF12FFA06D98A0864",
+    "D87602733EC86A64521F2B18177B200CBBE117577A615D6C770988C0BAD946E2",
+    "08E24FA074E5AB3143DB5BFCE0FD108E4B82D120A92108011A723C12A787E6D7",
+    "88719A10BDBA5B2699C327186AF4E23C1A946834B6150BDA2583E9CA2AD44CE8",
+    "DBBBC2DB04DE8EF92E8EFC141FBECAA6287C59474E6BC05D99B2964FA090C3A2",
+    "233BA186515BE7ED1F612970CEE2D7AFB81BDD762170481CD0069127D5B05AA9",
+    "93B4EA988D8FDDC186FFB7DC90A6C08F4DF435C934063199FFFFFFFFFFFFFFFF",
+);
+
+/// The domain separation tag for deriving h of a MODP group.
+const MODP_H_TAG: &[u8] = b"zkauth-modp-h-v1";
+
+/// An error for when a configuration's parameters are not cryptographically sound.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// p is not prime.
+    PNotPrime,
+
+    /// q is not prime, or does not divide p - 1.
+    QNotPrimeFactor,
+
+    /// The named generator is out of range, is 1, or does not have order q.
+    InvalidGenerator(&'static str),
+
+    /// g and h are equal.
+    GeneratorsNotDistinct,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::PNotPrime => write!(f, "p is not prime"),
+            ConfigError::QNotPrimeFactor => write!(f, "q is not a prime factor of p - 1"),
+            ConfigError::InvalidGenerator(name) => {
+                write!(f, "{} is not a generator of order q", name)
+            }
+            ConfigError::GeneratorsNotDistinct => write!(f, "g and h must be distinct"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// A well-known MODP group from RFC 3526, whose safe prime p was generated from the digits of pi
+/// so that anyone can audit it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModpGroup {
+    /// The 2048-bit MODP group, group 14.
+    Modp2048,
+
+    /// The 3072-bit MODP group, group 15.
+    Modp3072,
+
+    /// The 4096-bit MODP group, group 16.
+    Modp4096,
+}
+
+/// Implementation of the MODP group.
+impl ModpGroup {
+    /// All supported MODP groups, from the smallest.
+    pub const ALL: [ModpGroup; 3] = [
+        ModpGroup::Modp2048,
+        ModpGroup::Modp3072,
+        ModpGroup::Modp4096,
+    ];
+
+    /// Returns the name of the group, such as `modp-2048`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ModpGroup::Modp2048 => "modp-2048",
+            ModpGroup::Modp3072 => "modp-3072",
+            ModpGroup::Modp4096 => "modp-4096",
+        }
+    }
+
+    /// Returns the prime p of the group.
+    pub fn prime(&self) -> BigInt {
+        let hex = match self {
+            ModpGroup::Modp2048 => MODP_2048_P,
+            ModpGroup::Modp3072 => MODP_3072_P,
+            ModpGroup::Modp4096 => MODP_4096_P,
+        };
+        BigInt::parse_bytes(hex.as_bytes(), 16).expect("MODP primes are valid hexadecimal")
+    }
+}
+
+/// Converts a MODP group to its name.
+impl fmt::Display for ModpGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Converts a name such as `modp-2048` to a MODP group.
+impl FromStr for ModpGroup {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        ModpGroup::ALL
+            .into_iter()
+            .find(|group| group.name() == s)
+            .ok_or_else(|| {
+                let names: Vec<&str> = ModpGroup::ALL.iter().map(|group| group.name()).collect();
+                Error::msg(format!(
+                    "unknown MODP group '{}', expected one of {}",
+                    s,
+                    names.join(", ")
+                ))
+            })
+    }
+}
 
 #[derive(Debug, Clone)]
 /// Configuration for the discrete logarithm protocol.
@@ -27,6 +193,85 @@ impl DiscreteLogarithmConfiguration {
         Self::generate_from_prime(p)
     }
 
+    /// Generates a configuration with the specified number of bits for the prime number, searching
+    /// for a safe prime with the given options. The search reports its progress periodically, and
+    /// gives up once the maximum number of candidate trials is reached.
+    /// # Errors
+    /// * Returns an error if the number of bits is less than 3.
+    /// * Returns an error if no safe prime is found within the maximum number of trials.
+    pub fn generate_with_options(
+        prime_bits: usize,
+        options: PrimeSearchOptions,
+        progress: impl FnMut(PrimeSearchProgress),
+    ) -> Result<DiscreteLogarithmConfiguration> {
+        let p = search_safe_prime(prime_bits, options, progress)?;
+        Ok(Self::generate_from_prime(p))
+    }
+
+    /// Generates a DSA-style configuration, where q is a `q_bits`-bit prime factor of p - 1 and p
+    /// is a much larger `p_bits`-bit prime, searching with the given options. Each candidate p
+    /// counts as one trial towards the maximum and the progress reports.
+    /// # Errors
+    /// * Returns an error if q has less than 2 bits, or p does not have more bits than q.
+    /// * Returns an error if no prime p is found within the maximum number of trials.
+    pub fn generate_dsa_with_options(
+        p_bits: usize,
+        q_bits: usize,
+        options: PrimeSearchOptions,
+        progress: impl FnMut(PrimeSearchProgress),
+    ) -> Result<DiscreteLogarithmConfiguration> {
+        let (p, q) = search_dsa_primes(p_bits, q_bits, options, progress)?;
+        Ok(Self::generate_from_primes(p, q))
+    }
+
+    /// Validates that the parameters are cryptographically sound: that p is probably prime, that q
+    /// is probably prime and divides p - 1, and that g and h are distinct elements of order q,
+    /// i.e. in (1, p) with g^q = 1 mod p. This accepts both safe primes, where q = (p - 1) / 2,
+    /// and DSA-style primes. The primality and generator checks run in parallel on the rayon
+    /// thread pool.
+    /// # Errors
+    /// * Returns an error for the first check that fails, in the order above.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let one = BigInt::one();
+        let (p_is_prime, q_is_prime) = rayon::join(
+            || is_probable_prime(&self.p, VALIDATION_PRIMALITY_ROUNDS),
+            || is_probable_prime(&self.q, VALIDATION_PRIMALITY_ROUNDS),
+        );
+        if !p_is_prime {
+            return Err(ConfigError::PNotPrime);
+        }
+        if !q_is_prime || !((&self.p - &one) % &self.q).is_zero() {
+            return Err(ConfigError::QNotPrimeFactor);
+        }
+        let invalid_generator =
+            [("g", &self.g), ("h", &self.h)]
+                .into_par_iter()
+                .find_first(|(_, value)| {
+                    **value <= one || **value >= self.p || modpow(value, &self.q, &self.p) != one
+                });
+        if let Some((name, _)) = invalid_generator {
+            return Err(ConfigError::InvalidGenerator(name));
+        }
+        if self.g == self.h {
+            return Err(ConfigError::GeneratorsNotDistinct);
+        }
+        Ok(())
+    }
+
+    /// Returns the configuration for a well-known RFC 3526 MODP group, with its fixed prime p,
+    /// q = (p - 1) / 2, and g = 2, which generates the subgroup of order q. Since RFC 3526 defines
+    /// no second generator, h is derived deterministically so that nobody knows its discrete
+    /// logarithm to the base g: SHA-512 of the tag `zkauth-modp-h-v1`, the group name, and a
+    /// counter byte is expanded to 128 bits more than p, reduced mod p, and squared mod p.
+    pub fn from_modp_group(group: ModpGroup) -> DiscreteLogarithmConfiguration {
+        let one: BigInt = One::one();
+        let p = group.prime();
+        let q = (&p - &one) >> 1;
+        let g = BigInt::from(2);
+        let h = derive_modp_h(group, &p);
+        DiscreteLogarithmConfiguration { p, q, g, h }
+    }
+
     /// Generates a configuration from a prime number.
     pub fn generate_from_prime(p: BigInt) -> DiscreteLogarithmConfiguration {
         // Based on https://github.com/neongazer/zkp-auth-py/blob/main/zkp_auth/sigma_protocols/utils.py
@@ -43,6 +288,175 @@ impl DiscreteLogarithmConfiguration {
         let (g, h) = if g1 < g2 { (g1, g2) } else { (g2, g1) };
         DiscreteLogarithmConfiguration { p, q, g, h }
     }
+
+    /// Derives the challenge c of a non-interactive proof from the hash of g, h, the given
+    /// context, and the binding, reduced mod q.
+    pub fn derive_noninteractive_c(
+        &self,
+        context: &ChallengeContext,
+        binding: &ProofBinding,
+    ) -> BigInt {
+        let digest =
+            context.noninteractive_digest(&self.g.clone().into(), &self.h.clone().into(), binding);
+        reduce(&BigInt::from_bytes_be(Sign::Plus, &digest), &self.q)
+    }
+
+    /// Generates a configuration from a prime p and a prime factor q of p - 1, deriving the
+    /// generators from the cofactor (p - 1) / q, since for DSA-style primes the subgroup of order
+    /// q is too small for random elements to land in it.
+    fn generate_from_primes(p: BigInt, q: BigInt) -> DiscreteLogarithmConfiguration {
+        let g1 = generate_subgroup_generator(&p, &q);
+        let mut g2 = generate_subgroup_generator(&p, &q);
+        while g1 == g2 {
+            g2 = generate_subgroup_generator(&p, &q);
+        }
+        let (g, h) = if g1 < g2 { (g1, g2) } else { (g2, g1) };
+        DiscreteLogarithmConfiguration { p, q, g, h }
+    }
+}
+
+/// Options for a safe prime search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrimeSearchOptions {
+    /// The maximum number of candidates to try before giving up, or `None` for no limit.
+    pub max_trials: Option<usize>,
+
+    /// The number of candidates tried between progress reports.
+    pub progress_interval: usize,
+}
+
+/// Default options, with no trial limit and progress reported every 1000 candidates.
+impl Default for PrimeSearchOptions {
+    fn default() -> Self {
+        PrimeSearchOptions {
+            max_trials: None,
+            progress_interval: 1000,
+        }
+    }
+}
+
+/// The progress of a safe prime search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrimeSearchProgress {
+    /// The number of bits of the prime being searched for.
+    pub bits: usize,
+
+    /// The number of candidates tried so far.
+    pub trials: usize,
+}
+
+/// Searches for a safe prime p = 2q + 1 with the specified number of bits, trying random
+/// candidates for q until both q and p are prime.
+fn search_safe_prime(
+    bits: usize,
+    options: PrimeSearchOptions,
+    mut progress: impl FnMut(PrimeSearchProgress),
+) -> Result<BigInt> {
+    if bits < 3 {
+        return Err(Error::msg("safe primes require at least 3 bits"));
+    }
+
+    let mut rng = rand::thread_rng();
+    let top_bit = BigUint::one() << (bits - 2);
+    let mut trials = 0;
+    loop {
+        if options.max_trials.is_some_and(|max| trials >= max) {
+            return Err(Error::msg(format!(
+                "no {}-bit safe prime found after {} candidate trials; use a smaller number of \
+                 bits, or a known prime or standard named group instead",
+                bits, trials
+            )));
+        }
+        trials += 1;
+
+        // A (bits - 1)-bit odd candidate q, so that p = 2q + 1 has exactly the given bits.
+        let q: BigInt = (rng.gen_biguint((bits - 1) as u64) | &top_bit | BigUint::one()).into();
+        if is_probable_prime(&q, SEARCH_PRIMALITY_ROUNDS) {
+            let p: BigInt = (&q << 1) + 1;
+            if is_probable_prime(&p, SEARCH_PRIMALITY_ROUNDS) {
+                return Ok(p);
+            }
+        }
+
+        if options.progress_interval > 0 && trials % options.progress_interval == 0 {
+            progress(PrimeSearchProgress { bits, trials });
+        }
+    }
+}
+
+/// Searches for DSA-style primes, picking a random `q_bits`-bit prime q and then trying random
+/// `p_bits`-bit candidates p = 1 (mod 2q) until one is prime. As in FIPS 186, q is replaced after
+/// 4 * `p_bits` candidates without a prime p.
+fn search_dsa_primes(
+    p_bits: usize,
+    q_bits: usize,
+    options: PrimeSearchOptions,
+    mut progress: impl FnMut(PrimeSearchProgress),
+) -> Result<(BigInt, BigInt)> {
+    if q_bits < 2 {
+        return Err(Error::msg("q requires at least 2 bits"));
+    }
+    if p_bits <= q_bits {
+        return Err(Error::msg("p requires more bits than q"));
+    }
+
+    let mut rng = rand::thread_rng();
+    let q_top_bit = BigUint::one() << (q_bits - 1);
+    let p_top_bit = BigUint::one() << (p_bits - 1);
+    let mut trials = 0;
+    loop {
+        let q: BigInt = loop {
+            let q = (rng.gen_biguint(q_bits as u64) | &q_top_bit | BigUint::one()).into();
+            if is_probable_prime(&q, SEARCH_PRIMALITY_ROUNDS) {
+                break q;
+            }
+        };
+        let two_q: BigInt = &q << 1;
+
+        for _ in 0..4 * p_bits {
+            if options.max_trials.is_some_and(|max| trials >= max) {
+                return Err(Error::msg(format!(
+                    "no {}-bit DSA prime found after {} candidate trials; use a smaller number of \
+                     bits, or a known prime or standard named group instead",
+                    p_bits, trials
+                )));
+            }
+            trials += 1;
+
+            // A p_bits-bit candidate rounded down to 1 (mod 2q), so that q divides p - 1.
+            let x: BigInt = (rng.gen_biguint(p_bits as u64) | &p_top_bit).into();
+            let p: BigInt = &x - (&x % &two_q) + 1;
+            if p.bits() == p_bits as u64 && is_probable_prime(&p, SEARCH_PRIMALITY_ROUNDS) {
+                return Ok((p, q));
+            }
+
+            if options.progress_interval > 0 && trials % options.progress_interval == 0 {
+                progress(PrimeSearchProgress {
+                    bits: p_bits,
+                    trials,
+                });
+            }
+        }
+    }
+}
+
+/// Derives the h of a MODP group by hashing to an element and squaring it, which lands in the
+/// subgroup of quadratic residues of order q for a safe prime p.
+fn derive_modp_h(group: ModpGroup, p: &BigInt) -> BigInt {
+    let bytes = (p.bits() as usize + 128) / 8;
+    let mut expanded = Vec::with_capacity(bytes + 64);
+    let mut counter: u8 = 0;
+    while expanded.len() < bytes {
+        let mut hasher = Sha512::new();
+        hasher.update(MODP_H_TAG);
+        hasher.update(group.name().as_bytes());
+        hasher.update([counter]);
+        expanded.extend_from_slice(&hasher.finalize());
+        counter += 1;
+    }
+    expanded.truncate(bytes);
+    let a = BigInt::from_bytes_be(Sign::Plus, &expanded) % p;
+    modpow(&a, &BigInt::from(2), p)
 }
 
 /// Generates a prime number with the specified number of bits.
@@ -67,15 +481,33 @@ fn generate_prime_p(bits: usize) -> BigInt {
 
 /// Generates a generator for the specified prime number and its corresponding q.
 fn generate_generator_g(p: BigInt, q: BigInt) -> BigInt {
-    let one = One::one();
+    // The identity 1 trivially has order dividing q, so it is excluded from the range.
+    let one: BigInt = One::one();
+    let two = &one + &one;
     let mut rng = rand::thread_rng();
-    let mut g = rng.gen_bigint_range(&one, &p);
-    while g.modpow(&q, &p) != one {
-        g = rng.gen_bigint_range(&one, &p);
+    let mut g = rng.gen_bigint_range(&two, &p);
+    while modpow(&g, &q, &p) != one {
+        g = rng.gen_bigint_range(&two, &p);
     }
     g
 }
 
+/// Generates an element of order q by raising a random element to the cofactor (p - 1) / q,
+/// retrying when the result is the identity.
+fn generate_subgroup_generator(p: &BigInt, q: &BigInt) -> BigInt {
+    let one: BigInt = One::one();
+    let two = &one + &one;
+    let cofactor = (p - &one) / q;
+    let mut rng = rand::thread_rng();
+    loop {
+        let a = rng.gen_bigint_range(&two, &(p - &one));
+        let g = modpow(&a, &cofactor, p);
+        if g != one {
+            return g;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,4 +529,246 @@ mod tests {
         assert_eq!(config.g.modpow(&config.q, &config.p), One::one());
         assert_eq!(config.h.modpow(&config.q, &config.p), One::one());
     }
+
+    #[test]
+    fn generate_with_options() {
+        let config = DiscreteLogarithmConfiguration::generate_with_options(
+            16,
+            PrimeSearchOptions::default(),
+            |_| {},
+        )
+        .unwrap();
+        assert_eq!(config.p.bits(), 16);
+        assert_eq!(config.q, (&config.p - 1) / 2);
+        assert!(is_probable_prime(&config.p, 20));
+        assert!(is_probable_prime(&config.q, 20));
+        assert_eq!(modpow(&config.g, &config.q, &config.p), One::one());
+        assert_eq!(modpow(&config.h, &config.q, &config.p), One::one());
+    }
+
+    #[test]
+    fn generate_with_options_reports_progress() {
+        let mut reports = Vec::new();
+        let options = PrimeSearchOptions {
+            max_trials: None,
+            progress_interval: 1,
+        };
+        DiscreteLogarithmConfiguration::generate_with_options(32, options, |progress| {
+            reports.push(progress)
+        })
+        .unwrap();
+        for (i, report) in reports.iter().enumerate() {
+            assert_eq!(
+                *report,
+                PrimeSearchProgress {
+                    bits: 32,
+                    trials: i + 1
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn generate_with_options_fails_after_max_trials() {
+        let mut reports = Vec::new();
+        let options = PrimeSearchOptions {
+            max_trials: Some(1),
+            progress_interval: 1,
+        };
+        let err =
+            DiscreteLogarithmConfiguration::generate_with_options(2048, options, |progress| {
+                reports.push(progress)
+            })
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("no 2048-bit safe prime found after 1 candidate trials"));
+        assert_eq!(
+            reports,
+            vec![PrimeSearchProgress {
+                bits: 2048,
+                trials: 1
+            }]
+        );
+    }
+
+    #[test]
+    fn generate_with_options_rejects_too_few_bits() {
+        assert!(DiscreteLogarithmConfiguration::generate_with_options(
+            2,
+            PrimeSearchOptions::default(),
+            |_| {}
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn generate_dsa_with_options() {
+        let config = DiscreteLogarithmConfiguration::generate_dsa_with_options(
+            128,
+            32,
+            PrimeSearchOptions::default(),
+            |_| {},
+        )
+        .unwrap();
+        assert_eq!(config.p.bits(), 128);
+        assert_eq!(config.q.bits(), 32);
+        assert!(is_probable_prime(&config.p, 20));
+        assert!(is_probable_prime(&config.q, 20));
+        assert_eq!((&config.p - 1) % &config.q, BigInt::from(0));
+        assert_ne!(config.g, config.h);
+        for generator in [&config.g, &config.h] {
+            // Since q is prime, an element other than 1 with g^q = 1 has order exactly q.
+            assert_ne!(*generator, One::one());
+            assert_eq!(modpow(generator, &config.q, &config.p), One::one());
+        }
+    }
+
+    #[test]
+    fn generate_dsa_with_options_fails_after_max_trials() {
+        let options = PrimeSearchOptions {
+            max_trials: Some(1),
+            progress_interval: 1,
+        };
+        let err =
+            DiscreteLogarithmConfiguration::generate_dsa_with_options(2048, 256, options, |_| {})
+                .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("no 2048-bit DSA prime found after 1 candidate trials"));
+    }
+
+    /// Returns a configuration with the given parameters.
+    fn config(p: i64, q: i64, g: i64, h: i64) -> DiscreteLogarithmConfiguration {
+        DiscreteLogarithmConfiguration {
+            p: BigInt::from(p),
+            q: BigInt::from(q),
+            g: BigInt::from(g),
+            h: BigInt::from(h),
+        }
+    }
+
+    #[test]
+    fn validate_accepts_sound_configs() {
+        assert_eq!(config(23, 11, 4, 9).validate(), Ok(()));
+        // A DSA-style prime, where q = 11 is a smaller prime factor of p - 1 = 66.
+        assert_eq!(config(67, 11, 9, 14).validate(), Ok(()));
+        assert_eq!(
+            DiscreteLogarithmConfiguration::generate(32).validate(),
+            Ok(())
+        );
+        assert_eq!(
+            DiscreteLogarithmConfiguration::from_modp_group(ModpGroup::Modp2048).validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_rejects_unsound_configs() {
+        for (config, expected) in [
+            // 25 is composite.
+            (config(25, 11, 4, 9), ConfigError::PNotPrime),
+            // 22 = p - 1 is not prime.
+            (config(23, 22, 4, 9), ConfigError::QNotPrimeFactor),
+            // 7 is prime, but does not divide p - 1 = 22.
+            (config(23, 7, 4, 9), ConfigError::QNotPrimeFactor),
+            // 1 trivially has order dividing q.
+            (config(23, 11, 1, 9), ConfigError::InvalidGenerator("g")),
+            // 5 is a primitive root mod 23, of order 22 rather than 11.
+            (config(23, 11, 5, 9), ConfigError::InvalidGenerator("g")),
+            // 27 = 4 mod 23 has order q, but is out of range.
+            (config(23, 11, 4, 27), ConfigError::InvalidGenerator("h")),
+            (config(23, 11, 4, 0), ConfigError::InvalidGenerator("h")),
+            (config(23, 11, 4, 4), ConfigError::GeneratorsNotDistinct),
+        ] {
+            assert_eq!(config.validate(), Err(expected.clone()), "{:?}", config);
+        }
+    }
+
+    #[test]
+    fn config_error_messages() {
+        assert_eq!(ConfigError::PNotPrime.to_string(), "p is not prime");
+        assert_eq!(
+            ConfigError::QNotPrimeFactor.to_string(),
+            "q is not a prime factor of p - 1"
+        );
+        assert_eq!(
+            ConfigError::InvalidGenerator("h").to_string(),
+            "h is not a generator of order q"
+        );
+        assert_eq!(
+            ConfigError::GeneratorsNotDistinct.to_string(),
+            "g and h must be distinct"
+        );
+    }
+
+    #[test]
+    fn from_modp_group() {
+        for (group, bits) in [
+            (ModpGroup::Modp2048, 2048),
+            (ModpGroup::Modp3072, 3072),
+            (ModpGroup::Modp4096, 4096),
+        ] {
+            let config = DiscreteLogarithmConfiguration::from_modp_group(group);
+            assert_eq!(config.p.bits(), bits, "{}", group);
+            assert_eq!(config.g, BigInt::from(2));
+
+            // q = (p - 1) / 2 divides p - 1, and is prime since p is a safe prime.
+            assert_eq!((&config.p - 1) % &config.q, BigInt::from(0));
+            assert_eq!(&config.q * 2 + 1, config.p);
+            assert!(is_probable_prime(&config.q, 2), "{}", group);
+
+            // g and h are distinct elements of order q.
+            assert_ne!(config.g, config.h);
+            for generator in [&config.g, &config.h] {
+                assert_ne!(*generator, One::one());
+                assert_eq!(modpow(generator, &config.q, &config.p), One::one());
+            }
+        }
+    }
+
+    #[test]
+    fn from_modp_group_is_deterministic() {
+        let a = DiscreteLogarithmConfiguration::from_modp_group(ModpGroup::Modp2048);
+        let b = DiscreteLogarithmConfiguration::from_modp_group(ModpGroup::Modp2048);
+        assert_eq!((a.p, a.q, a.g, a.h), (b.p, b.q, b.g, b.h));
+    }
+
+    #[test]
+    fn modp_group_matches_rfc_3526_prime() {
+        // The RFC 3526 primes start with the 64 one bits and the digits of pi, and end with 64 one
+        // bits.
+        for group in ModpGroup::ALL {
+            let hex = format!("{:X}", group.prime());
+            assert!(hex.starts_with("FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD1"));
+            assert!(hex.ends_with("FFFFFFFFFFFFFFFF"));
+        }
+        assert!(format!("{:X}", ModpGroup::Modp2048.prime())
+            .ends_with("15728E5A8AACAA68FFFFFFFFFFFFFFFF"));
+    }
+
+    #[test]
+    fn modp_group_names_round_trip() {
+        for group in ModpGroup::ALL {
+            assert_eq!(group.to_string().parse::<ModpGroup>().unwrap(), group);
+        }
+        let err = "modp-1024".parse::<ModpGroup>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown MODP group 'modp-1024', expected one of modp-2048, modp-3072, modp-4096"
+        );
+    }
+
+    #[test]
+    fn generate_dsa_with_options_rejects_invalid_bits() {
+        for (p_bits, q_bits) in [(64, 1), (64, 64), (32, 64)] {
+            assert!(DiscreteLogarithmConfiguration::generate_dsa_with_options(
+                p_bits,
+                q_bits,
+                PrimeSearchOptions::default(),
+                |_| {}
+            )
+            .is_err());
+        }
+    }
 }
@@ -30,6 +30,9 @@
 use num_bigint::{BigInt, BigUint, RandomBits};
 use rand::Rng;
 
+/// The modular arithmetic module, re-exported from the crate root where `Scalar` also uses it.
+pub use crate::arithmetic;
+
 /// The configuration module.
 pub mod configuration;
 
@@ -40,7 +43,7 @@ pub mod prover;
 pub mod verifier;
 
 #[cfg(test)]
-mod test;
+pub(crate) mod test;
 
 /// Generates a random non-negative scalar.
 fn generate_random_scalar() -> BigInt {
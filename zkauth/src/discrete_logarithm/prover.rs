@@ -2,9 +2,10 @@ use anyhow::Result;
 use num_bigint::{BigInt, BigUint};
 use num_traits::Zero;
 use sha2::{Digest, Sha512};
+use zeroize::{Zeroize, Zeroizing};
 
 use super::{configuration::DiscreteLogarithmConfiguration, generate_random_scalar};
-use crate::{Element, Prover, Scalar};
+use crate::{ChallengeContext, Element, ProofBinding, Prover, Scalar};
 
 /// The discrete logarithm prover.
 #[derive(Debug)]
@@ -19,10 +20,18 @@ impl DiscreteLogarithmProver {
         DiscreteLogarithmProver { config }
     }
 
-    /// Computes x from the given password.
-    fn compute_x(&self, password: String) -> BigInt {
-        let x = BigUint::from_bytes_be(&Sha512::digest(password.as_bytes()));
-        x.into()
+    /// Computes x from the given password, reducing the full 64-byte hash mod q so that it is a
+    /// canonical exponent. The password, the hash and the unreduced value are wiped.
+    fn compute_x(&self, password: String) -> Scalar {
+        let password = Zeroizing::new(password);
+        let mut digest: Zeroizing<[u8; 64]> =
+            Zeroizing::new(Sha512::digest(password.as_bytes()).into());
+        // Reversed in place, since decoding big-endian bytes would copy them.
+        digest.reverse();
+        let mut unreduced = Scalar(BigUint::from_bytes_le(digest.as_slice()).into());
+        let x = unreduced.rem_euclid(&Scalar(self.config.q.clone()));
+        unreduced.zeroize();
+        x
     }
 
     /// Generates a random x value.
@@ -31,13 +40,13 @@ impl DiscreteLogarithmProver {
     }
 
     /// Computes y1 from the given x using the g and p configuration values.
-    fn compute_y1(&self, x: BigInt) -> BigInt {
-        self.config.g.modpow(&x, &self.config.p)
+    fn compute_y1(&self, x: &Scalar) -> Element {
+        self.pow(&self.config.g, x)
     }
 
     /// Computes y2 from the given x using the h and p configuration values.
-    fn compute_y2(&self, x: BigInt) -> BigInt {
-        self.config.h.modpow(&x, &self.config.p)
+    fn compute_y2(&self, x: &Scalar) -> Element {
+        self.pow(&self.config.h, x)
     }
 
     /// Generates a random k value.
@@ -46,23 +55,29 @@ impl DiscreteLogarithmProver {
     }
 
     /// Computes r1 from the given k using the g and p configuration values.
-    fn compute_r1(&self, k: BigInt) -> BigInt {
-        self.config.g.modpow(&k, &self.config.p)
+    fn compute_r1(&self, k: &Scalar) -> Element {
+        self.pow(&self.config.g, k)
     }
 
     /// Computes r2 from the given k using the h and p configuration values.
-    fn compute_r2(&self, k: BigInt) -> BigInt {
-        self.config.h.modpow(&k, &self.config.p)
+    fn compute_r2(&self, k: &Scalar) -> Element {
+        self.pow(&self.config.h, k)
     }
 
-    /// Computes s from the given x, k, and c using the q configuration value.
-    fn compute_s(&self, x: BigInt, k: BigInt, c: BigInt) -> BigInt {
-        let mut s = (k - c * x) % &self.config.q;
-        if s < Zero::zero() {
-            s += &self.config.q;
-        }
+    /// Computes s = k - c * x mod q from the given x, k, and c, reduced into [0, q). The
+    /// intermediate values, from which x could be recovered, are wiped.
+    fn compute_s(&self, x: &Scalar, k: &Scalar, c: Scalar) -> Scalar {
+        let mut unreduced = Scalar(&k.0 - c.0 * &x.0);
+        let s = unreduced.rem_euclid(&Scalar(self.config.q.clone()));
+        unreduced.zeroize();
         s
     }
+
+    /// Computes base^exponent mod p for a generator of the group.
+    fn pow(&self, base: &BigInt, exponent: &Scalar) -> Element {
+        let power = Scalar(base.clone()).modpow(exponent, &Scalar(self.config.p.clone()));
+        power.into_inner().into()
+    }
 }
 
 /// Prover implementation for the discrete logarithm protocol.
@@ -74,15 +89,17 @@ impl Prover for DiscreteLogarithmProver {
 
     /// Computes a registration x value from the given password.
     fn compute_registration_x(&self, password: String) -> Scalar {
-        self.compute_x(password).into()
+        self.compute_x(password)
+    }
+
+    /// Returns whether the given x is in the range (0, q).
+    fn is_valid_registration_x(&self, x: &Scalar) -> bool {
+        x.0 > BigInt::zero() && x.0 < self.config.q
     }
 
     /// Computes a registration y1 and y2 value from the given x.
-    fn compute_registration_y1y2(&self, x: Scalar) -> Result<(Element, Element)> {
-        let x: BigInt = x.into();
-        let y1 = self.compute_y1(x.clone());
-        let y2 = self.compute_y2(x);
-        Ok((y1.into(), y2.into()))
+    fn compute_registration_y1y2(&self, x: &Scalar) -> Result<(Element, Element)> {
+        Ok((self.compute_y1(x), self.compute_y2(x)))
     }
 
     /// Generates a challenge k value.
@@ -91,22 +108,29 @@ impl Prover for DiscreteLogarithmProver {
     }
 
     /// Computes a challenge commitment r1 and r2 value from the given k.
-    fn compute_challenge_commitment_r1r2(&self, k: Scalar) -> Result<(Element, Element)> {
-        let k: BigInt = k.into();
-        let r1 = self.compute_r1(k.clone());
-        let r2 = self.compute_r2(k);
-        Ok((r1.into(), r2.into()))
+    fn compute_challenge_commitment_r1r2(&self, k: &Scalar) -> Result<(Element, Element)> {
+        Ok((self.compute_r1(k), self.compute_r2(k)))
     }
 
     /// Computes a challenge response s value from the given x, k, and c.
-    fn compute_challenge_response_s(&self, x: Scalar, k: Scalar, c: Scalar) -> Result<Scalar> {
-        Ok(self.compute_s(x.into(), k.into(), c.into()).into())
+    fn compute_challenge_response_s(&self, x: &Scalar, k: &Scalar, c: Scalar) -> Result<Scalar> {
+        Ok(self.compute_s(x, k, c))
+    }
+
+    /// Derives a non-interactive challenge c value from the hash of g, h, and the context.
+    fn derive_noninteractive_challenge_c(
+        &self,
+        context: &ChallengeContext,
+        binding: &ProofBinding,
+    ) -> Scalar {
+        self.config.derive_noninteractive_c(context, binding).into()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::arithmetic::reduce;
     use crate::discrete_logarithm::test::test_prover;
 
     #[test]
@@ -132,6 +156,18 @@ mod tests {
         assert_ne!(x1, x3);
     }
 
+    #[test]
+    fn compute_registration_x_is_reduced_mod_q() {
+        let prover = test_prover();
+        for password in ["", "password", "password2", "correct horse battery staple"] {
+            let x = prover.compute_registration_x(password.to_string());
+            assert!(x >= Scalar::zero() && x < prover.config.q.clone().into());
+
+            let digest = BigUint::from_bytes_be(&Sha512::digest(password.as_bytes()));
+            assert_eq!(x, reduce(&digest.into(), &prover.config.q).into());
+        }
+    }
+
     #[test]
     fn compute_registration_x_with_empty_password() {
         let prover = test_prover();
@@ -139,11 +175,21 @@ mod tests {
         assert!(x > Scalar::zero());
     }
 
+    #[test]
+    fn is_valid_registration_x() {
+        let prover = test_prover();
+        assert!(prover.is_valid_registration_x(&BigInt::from(1).into()));
+        assert!(prover.is_valid_registration_x(&(&prover.config.q - 1u32).into()));
+        assert!(!prover.is_valid_registration_x(&Scalar::zero()));
+        assert!(!prover.is_valid_registration_x(&prover.config.q.clone().into()));
+        assert!(!prover.is_valid_registration_x(&BigInt::from(-1).into()));
+    }
+
     #[test]
     fn compute_registration_y1y2() {
         let prover = test_prover();
         let x = prover.generate_x();
-        let (y1, y2) = prover.compute_registration_y1y2(x.into()).unwrap();
+        let (y1, y2) = prover.compute_registration_y1y2(&x.into()).unwrap();
         assert!(y1 > Element::zero());
         assert!(y2 > Element::zero());
         assert_ne!(y1, y2);
@@ -163,7 +209,7 @@ mod tests {
     fn compute_challenge_commitment_r1r2() {
         let prover = test_prover();
         let k = prover.generate_k();
-        let (r1, r2) = prover.compute_challenge_commitment_r1r2(k.into()).unwrap();
+        let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k.into()).unwrap();
         assert!(r1 > Element::zero());
         assert!(r2 > Element::zero());
         assert_ne!(r1, r2);
@@ -176,7 +222,7 @@ mod tests {
         let k = prover.generate_k();
         let c = generate_random_scalar();
         let s = prover
-            .compute_challenge_response_s(x.into(), k.into(), c.into())
+            .compute_challenge_response_s(&x.into(), &k.into(), c.into())
             .unwrap();
         assert!(s >= Scalar::zero() && s < prover.config.q.into());
     }
@@ -1,21 +1,54 @@
 use anyhow::Result;
-use num_bigint::BigInt;
-use num_traits::One;
+use num_bigint::{BigInt, Sign};
 
-use super::{configuration::DiscreteLogarithmConfiguration, generate_random_scalar};
-use crate::{Element, Scalar, Verifier};
+use super::{
+    arithmetic::multi_modpow, configuration::DiscreteLogarithmConfiguration, generate_random_scalar,
+};
+use crate::{ChallengeContext, Element, ProofBinding, Scalar, Verifier};
+
+/// Approximate security levels in bits of finite field groups by modulus size in bits, from NIST
+/// SP 800-57 Part 1, largest first.
+const FINITE_FIELD_SECURITY_LEVELS: [(u64, u32); 5] = [
+    (15360, 256),
+    (7680, 192),
+    (3072, 128),
+    (2048, 112),
+    (1024, 80),
+];
+
+/// Returns the approximate security level in bits of a finite field group with a modulus of the
+/// given size. Moduli smaller than the NIST table are scaled down linearly from 80 bits at 1024,
+/// which is conservative but sufficient to flag them as weak.
+fn finite_field_security_level(modulus_bits: u64) -> u32 {
+    FINITE_FIELD_SECURITY_LEVELS
+        .iter()
+        .find(|(bits, _)| modulus_bits >= *bits)
+        .map(|(_, level)| *level)
+        .unwrap_or((modulus_bits * 80 / 1024) as u32)
+}
 
 /// The discrete logarithm verifier.
 #[derive(Clone)]
 pub struct DiscreteLogarithmVerifier {
     config: DiscreteLogarithmConfiguration,
+    bind_challenges: bool,
 }
 
 /// Implementation of the discrete logarithm verifier.
 impl DiscreteLogarithmVerifier {
     /// Creates a new discrete logarithm verifier.
     pub fn new(config: DiscreteLogarithmConfiguration) -> Self {
-        DiscreteLogarithmVerifier { config }
+        DiscreteLogarithmVerifier {
+            config,
+            bind_challenges: false,
+        }
+    }
+
+    /// Binds each challenge generated for a context to it, deriving c from the hash of the
+    /// context reduced mod q instead of generating it randomly.
+    pub fn with_bound_challenges(mut self) -> Self {
+        self.bind_challenges = true;
+        self
     }
 
     /// Generates a random c value.
@@ -23,18 +56,45 @@ impl DiscreteLogarithmVerifier {
         generate_random_scalar()
     }
 
+    /// Derives a c value from the hash of the given context, reduced mod q.
+    fn derive_c(&self, context: &ChallengeContext) -> Scalar {
+        let digest = BigInt::from_bytes_be(Sign::Plus, &context.digest());
+        Scalar(digest).rem_euclid(&Scalar(self.config.q.clone()))
+    }
+
     /// Computes r1' from the given y1, c, and s using the g and p configuration values.
-    fn compute_r1_prime(&self, y1: BigInt, c: BigInt, s: BigInt) -> BigInt {
-        let one: BigInt = One::one();
-        (self.config.g.modpow(&s, &self.config.p) * y1.modpow(&c, &self.config.p))
-            .modpow(&one, &self.config.p)
+    fn compute_r1_prime(&self, y1: Element, c: &Scalar, s: &Scalar) -> Element {
+        self.product_of_powers(Scalar(self.config.g.clone()), s, Scalar(y1.0), c)
     }
 
     /// Computes r2' from the given y2, c, and s using the h and p configuration values.
-    fn compute_r2_prime(&self, y2: BigInt, c: BigInt, s: BigInt) -> BigInt {
-        let one: BigInt = One::one();
-        (self.config.h.modpow(&s, &self.config.p) * y2.modpow(&c, &self.config.p))
-            .modpow(&one, &self.config.p)
+    fn compute_r2_prime(&self, y2: Element, c: &Scalar, s: &Scalar) -> Element {
+        self.product_of_powers(Scalar(self.config.h.clone()), s, Scalar(y2.0), c)
+    }
+
+    /// Computes base1^exponent1 * base2^exponent2 mod p, with a single simultaneous
+    /// exponentiation if the `multi_exponentiation` feature is enabled, or two independent ones
+    /// otherwise.
+    fn product_of_powers(
+        &self,
+        base1: Scalar,
+        exponent1: &Scalar,
+        base2: Scalar,
+        exponent2: &Scalar,
+    ) -> Element {
+        let p = Scalar(self.config.p.clone());
+        let product = if cfg!(feature = "multi_exponentiation") {
+            Scalar(multi_modpow(
+                &base1.0,
+                &exponent1.0,
+                &base2.0,
+                &exponent2.0,
+                &p.0,
+            ))
+        } else {
+            (base1.modpow(exponent1, &p) * base2.modpow(exponent2, &p)).rem_euclid(&p)
+        };
+        product.into_inner().into()
     }
 }
 
@@ -45,6 +105,16 @@ impl Verifier for DiscreteLogarithmVerifier {
         self.generate_c().into()
     }
 
+    /// Generates a challenge c value bound to the context if configured, or a random one
+    /// otherwise.
+    fn generate_challenge_c_for(&self, context: &ChallengeContext) -> Scalar {
+        if self.bind_challenges {
+            self.derive_c(context)
+        } else {
+            self.generate_c().into()
+        }
+    }
+
     /// Computes verification r1' and r2' values from the given y1, y2, c, and s.
     fn compute_verification_r1r2(
         &self,
@@ -53,11 +123,29 @@ impl Verifier for DiscreteLogarithmVerifier {
         c: Scalar,
         s: Scalar,
     ) -> Result<(Element, Element)> {
-        let c: BigInt = c.into();
-        let s: BigInt = s.into();
-        let r1 = self.compute_r1_prime(y1.into(), c.clone(), s.clone());
-        let r2 = self.compute_r2_prime(y2.into(), c, s);
-        Ok((r1.into(), r2.into()))
+        // g and h have order q, so s only matters mod q. Reducing it also keeps a negative s from
+        // a misbehaving prover out of the exponentiation, which requires a non-negative exponent.
+        let s = s.rem_euclid(&Scalar(self.config.q.clone()));
+        let r1 = self.compute_r1_prime(y1, &c, &s);
+        let r2 = self.compute_r2_prime(y2, &c, &s);
+        Ok((r1, r2))
+    }
+
+    /// Derives a non-interactive challenge c value from the hash of g, h, and the context.
+    fn derive_noninteractive_challenge_c(
+        &self,
+        context: &ChallengeContext,
+        binding: &ProofBinding,
+    ) -> Scalar {
+        self.config.derive_noninteractive_c(context, binding).into()
+    }
+
+    /// Returns the security level from the size of p, capped at half the size of q since generic
+    /// attacks on the subgroup take about sqrt(q) steps.
+    fn security_level(&self) -> u32 {
+        let field_level = finite_field_security_level(self.config.p.bits());
+        let subgroup_level = (self.config.q.bits() / 2) as u32;
+        field_level.min(subgroup_level)
     }
 }
 
@@ -73,4 +161,35 @@ mod tests {
         let c = verifier.generate_challenge_c();
         assert!(c > Scalar::zero());
     }
+
+    /// Returns a verifier whose p and q have the given sizes in bits. Only the sizes matter for the
+    /// security level, so the values are not actually prime.
+    fn verifier_with_bits(p_bits: u64, q_bits: u64) -> DiscreteLogarithmVerifier {
+        let one = BigInt::from(1);
+        DiscreteLogarithmVerifier::new(DiscreteLogarithmConfiguration {
+            p: &one << (p_bits - 1),
+            q: &one << (q_bits - 1),
+            g: BigInt::from(2),
+            h: BigInt::from(3),
+        })
+    }
+
+    #[test]
+    fn test_security_level_small() {
+        let verifier = DiscreteLogarithmVerifier::new(DiscreteLogarithmConfiguration::generate(64));
+        let level = verifier.security_level();
+        assert!(level > 0 && level < 80, "{}", level);
+    }
+
+    #[test]
+    fn test_security_level_large() {
+        assert_eq!(verifier_with_bits(2048, 2047).security_level(), 112);
+        assert_eq!(verifier_with_bits(3072, 3071).security_level(), 128);
+        assert_eq!(verifier_with_bits(1024, 1023).security_level(), 80);
+    }
+
+    #[test]
+    fn test_security_level_capped_by_subgroup() {
+        assert_eq!(verifier_with_bits(3072, 160).security_level(), 80);
+    }
 }
@@ -1,31 +1,33 @@
-use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT, RistrettoPoint, Scalar};
-use sha2::{Digest, Sha512};
+use super::curve::{Curve, Ristretto};
+use crate::{ChallengeContext, ProofBinding};
 
 #[derive(Debug, Clone)]
-/// Configuration for the elliptic curve protocol using ristretto points.
-pub struct EllipticCurveConfiguration {
-    /// The generator ristretto point g.
-    pub g: RistrettoPoint,
+/// Configuration for the elliptic curve protocol, on ristretto255 by default.
+pub struct EllipticCurveConfiguration<C: Curve = Ristretto> {
+    /// The generator point g.
+    pub g: C::Point,
 
-    /// The generator ristretto point h.
-    pub h: RistrettoPoint,
+    /// The generator point h.
+    pub h: C::Point,
 }
 
-/// Configuration for the elliptic curve protocol.
+/// Configuration for the elliptic curve protocol on ristretto255.
 impl EllipticCurveConfiguration {
     /// Generates a configuration from the ristretto base point.
     pub fn generate() -> EllipticCurveConfiguration {
-        let g = RISTRETTO_BASEPOINT_POINT;
+        Self::generate_on_curve()
+    }
+}
 
-        let h_value = "Unique value for H";
-        let mut hasher = Sha512::new();
-        hasher.update(h_value.as_bytes());
-        let h_result = hasher.finalize();
-        let h_bigint: [u8; 64] = *h_result.as_ref();
-        let h = RistrettoPoint::from_uniform_bytes(&h_bigint);
+/// Configuration for the elliptic curve protocol.
+impl<C: Curve> EllipticCurveConfiguration<C> {
+    /// Generates a configuration from the base point of the curve, with h hashed to a point whose
+    /// discrete logarithm with respect to g is unknown.
+    pub fn generate_on_curve() -> EllipticCurveConfiguration<C> {
+        let g = C::base_point();
+        let h = C::hash_to_point("Unique value for H".as_bytes());
 
-        let mut rng = rand::thread_rng();
-        let secret = Scalar::random(&mut rng);
+        let secret = C::random_scalar();
         let point_g = g * secret;
         let point_h = h * secret;
 
@@ -34,15 +36,34 @@ impl EllipticCurveConfiguration {
             h: point_h,
         }
     }
+
+    /// Derives the challenge c of a non-interactive proof from the hash of g, h, the given
+    /// context, and the binding, reduced mod the group order.
+    pub fn derive_noninteractive_c(
+        &self,
+        context: &ChallengeContext,
+        binding: &ProofBinding,
+    ) -> C::Scalar {
+        let digest =
+            context.noninteractive_digest(&C::point_into(self.g), &C::point_into(self.h), binding);
+        C::reduce_wide_scalar(&digest)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::elliptic_curve::curve::Secp256k1;
 
     #[test]
     fn generate() {
         let config = EllipticCurveConfiguration::generate();
         assert_ne!(config.g, config.h);
     }
+
+    #[test]
+    fn generate_on_secp256k1() {
+        let config = EllipticCurveConfiguration::<Secp256k1>::generate_on_curve();
+        assert_ne!(config.g, config.h);
+    }
 }
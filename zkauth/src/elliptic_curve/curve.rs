@@ -0,0 +1,318 @@
+use curve25519_dalek::{
+    constants::RISTRETTO_BASEPOINT_POINT, RistrettoPoint, Scalar as DalekScalar,
+};
+use k256::elliptic_curve::{
+    bigint::U512,
+    group::Group,
+    hash2curve::{ExpandMsgXmd, GroupDigest},
+    ops::Reduce,
+    sec1::{FromEncodedPoint, ToEncodedPoint},
+    Field, PrimeField,
+};
+use k256::{AffinePoint, EncodedPoint, FieldBytes, ProjectivePoint, WideBytes};
+use num_bigint::{BigInt, Sign};
+use sha2::{Digest, Sha256, Sha512};
+use std::fmt::Debug;
+use std::ops::{Add, Mul, Sub};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{ConversionError, Element, Scalar};
+
+/// The curve-specific operations of the elliptic curve protocol, so that the prover and verifier
+/// are generic over the group they run in.
+pub trait Curve: Debug + Clone + Copy + Send + Sync + 'static {
+    /// A point on the curve.
+    type Point: Debug
+        + Clone
+        + Copy
+        + PartialEq
+        + Send
+        + Sync
+        + Mul<Self::Scalar, Output = Self::Point>
+        + Sub<Output = Self::Point>;
+
+    /// A scalar mod the order of the group.
+    type Scalar: Debug
+        + Clone
+        + Copy
+        + PartialEq
+        + Send
+        + Sync
+        + Zeroize
+        + Add<Output = Self::Scalar>
+        + Mul<Output = Self::Scalar>;
+
+    /// The name of the curve, as used in configurations.
+    const NAME: &'static str;
+
+    /// The approximate security level of the curve in bits.
+    const SECURITY_LEVEL: u32;
+
+    /// The length in bytes of the encoding of a compressed point.
+    const POINT_BYTES: usize;
+
+    /// Returns the order of the group, which scalars are reduced by.
+    fn order() -> BigInt;
+
+    /// Returns the standard base point of the curve.
+    fn base_point() -> Self::Point;
+
+    /// Hashes a message to a point whose discrete logarithm is unknown.
+    fn hash_to_point(message: &[u8]) -> Self::Point;
+
+    /// Generates a random scalar.
+    fn random_scalar() -> Self::Scalar;
+
+    /// Reduces 64 bytes, in the byte order of the curve's scalar encoding, mod the group order.
+    fn reduce_wide_scalar(bytes: &[u8; 64]) -> Self::Scalar;
+
+    /// Converts a BigInt scalar to a curve scalar. The scalar is borrowed and the byte encodings
+    /// in between are wiped, so converting a secret leaves no copies of it behind.
+    /// # Errors
+    /// * Returns an error if the value is not a canonical scalar.
+    fn scalar_from(value: &Scalar) -> Result<Self::Scalar, ConversionError>;
+
+    /// Converts a curve scalar to a BigInt scalar.
+    fn scalar_into(value: Self::Scalar) -> Scalar;
+
+    /// Converts a BigInt element, the encoding of a compressed point, to a point.
+    /// # Errors
+    /// * Returns an error if the value does not decode to a point on the curve.
+    fn point_from(value: Element) -> Result<Self::Point, ConversionError>;
+
+    /// Converts a point to a BigInt element, the encoding of the compressed point.
+    fn point_into(value: Self::Point) -> Element;
+}
+
+/// The ristretto255 group over curve25519, with scalars and compressed points encoded as 32
+/// little-endian bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ristretto;
+
+/// Implementation of the curve trait for ristretto255.
+impl Curve for Ristretto {
+    type Point = RistrettoPoint;
+    type Scalar = DalekScalar;
+
+    const NAME: &'static str = "ristretto255";
+    const SECURITY_LEVEL: u32 = 128;
+    const POINT_BYTES: usize = 32;
+
+    fn order() -> BigInt {
+        Self::scalar_into(-DalekScalar::ONE).0 + 1
+    }
+
+    fn base_point() -> Self::Point {
+        RISTRETTO_BASEPOINT_POINT
+    }
+
+    fn hash_to_point(message: &[u8]) -> Self::Point {
+        let bytes: [u8; 64] = Sha512::digest(message).into();
+        RistrettoPoint::from_uniform_bytes(&bytes)
+    }
+
+    fn random_scalar() -> Self::Scalar {
+        super::generate_random_scalar()
+    }
+
+    fn reduce_wide_scalar(bytes: &[u8; 64]) -> Self::Scalar {
+        DalekScalar::from_bytes_mod_order_wide(bytes)
+    }
+
+    fn scalar_from(value: &Scalar) -> Result<Self::Scalar, ConversionError> {
+        value.try_into()
+    }
+
+    fn scalar_into(value: Self::Scalar) -> Scalar {
+        value.into()
+    }
+
+    fn point_from(value: Element) -> Result<Self::Point, ConversionError> {
+        value.try_into()
+    }
+
+    fn point_into(value: Self::Point) -> Element {
+        value.into()
+    }
+}
+
+/// The secp256k1 curve, with scalars encoded as 32 big-endian bytes and points in the 33-byte
+/// SEC1 compressed encoding, as used by Bitcoin tooling. The identity is encoded as zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Secp256k1;
+
+/// The domain separation tag for hashing to secp256k1 points.
+const SECP256K1_HASH_TO_POINT_DST: &[u8] = b"zkauth-secp256k1_XMD:SHA-256_SSWU_RO_";
+
+/// Implementation of the curve trait for secp256k1.
+impl Curve for Secp256k1 {
+    type Point = ProjectivePoint;
+    type Scalar = k256::Scalar;
+
+    const NAME: &'static str = "secp256k1";
+    const SECURITY_LEVEL: u32 = 128;
+    const POINT_BYTES: usize = 33;
+
+    fn order() -> BigInt {
+        Self::scalar_into(-k256::Scalar::ONE).0 + 1
+    }
+
+    fn base_point() -> Self::Point {
+        ProjectivePoint::GENERATOR
+    }
+
+    fn hash_to_point(message: &[u8]) -> Self::Point {
+        k256::Secp256k1::hash_from_bytes::<ExpandMsgXmd<Sha256>>(
+            &[message],
+            &[SECP256K1_HASH_TO_POINT_DST],
+        )
+        .expect("valid domain separation tag")
+    }
+
+    fn random_scalar() -> Self::Scalar {
+        let mut rng = rand::thread_rng();
+        k256::Scalar::random(&mut rng)
+    }
+
+    fn reduce_wide_scalar(bytes: &[u8; 64]) -> Self::Scalar {
+        <k256::Scalar as Reduce<U512>>::reduce_bytes(&WideBytes::clone_from_slice(bytes))
+    }
+
+    fn scalar_from(value: &Scalar) -> Result<Self::Scalar, ConversionError> {
+        let bytes = to_bytes_be(&value.0, 32)?;
+        Option::from(k256::Scalar::from_repr(FieldBytes::clone_from_slice(
+            &bytes,
+        )))
+        .ok_or(ConversionError)
+    }
+
+    fn scalar_into(value: Self::Scalar) -> Scalar {
+        Scalar(BigInt::from_bytes_be(Sign::Plus, &value.to_bytes()))
+    }
+
+    fn point_from(value: Element) -> Result<Self::Point, ConversionError> {
+        if value.0 == BigInt::from(0) {
+            return Ok(ProjectivePoint::IDENTITY);
+        }
+        let bytes = to_bytes_be(&value.0, Self::POINT_BYTES)?;
+        let encoded = EncodedPoint::from_bytes(bytes.as_slice()).map_err(|_| ConversionError)?;
+        Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded))
+            .map(ProjectivePoint::from)
+            .ok_or(ConversionError)
+    }
+
+    fn point_into(value: Self::Point) -> Element {
+        if bool::from(value.is_identity()) {
+            return Element(BigInt::from(0));
+        }
+        let encoded = value.to_affine().to_encoded_point(true);
+        Element(BigInt::from_bytes_be(Sign::Plus, encoded.as_bytes()))
+    }
+}
+
+/// Encodes a non-negative BigInt as big-endian bytes of the given length. Shorter values are
+/// zero-padded on the most significant end. The bytes are wiped when dropped, since the value may
+/// be a secret scalar.
+/// # Errors
+/// * Returns an error if the value is negative or does not fit, rather than truncating it.
+fn to_bytes_be(value: &BigInt, len: usize) -> Result<Zeroizing<Vec<u8>>, ConversionError> {
+    let (sign, bytes) = value.to_bytes_be();
+    let bytes = Zeroizing::new(bytes);
+    if sign == Sign::Minus || bytes.len() > len {
+        return Err(ConversionError);
+    }
+    let mut padded = Zeroizing::new(vec![0u8; len]);
+    padded[len - bytes.len()..].copy_from_slice(&bytes);
+    Ok(padded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_traits::{One, Zero};
+
+    #[test]
+    fn secp256k1_scalar_round_trip() {
+        let scalar = Secp256k1::random_scalar();
+        let value = Secp256k1::scalar_into(scalar);
+        assert_eq!(Secp256k1::scalar_from(&value).unwrap(), scalar);
+        assert_eq!(
+            Secp256k1::scalar_into(k256::Scalar::ONE),
+            Scalar(BigInt::one())
+        );
+    }
+
+    #[test]
+    fn secp256k1_scalar_rejects_non_canonical() {
+        // The group order itself is not a canonical scalar.
+        let order = BigInt::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            16,
+        )
+        .unwrap();
+        assert_eq!(Secp256k1::order(), order);
+        assert!(Secp256k1::scalar_from(&Scalar(order.clone() - 1)).is_ok());
+        assert!(Secp256k1::scalar_from(&Scalar(order)).is_err());
+        assert!(Secp256k1::scalar_from(&Scalar(BigInt::one() << 256usize)).is_err());
+        assert!(Secp256k1::scalar_from(&Scalar(-BigInt::one())).is_err());
+    }
+
+    #[test]
+    fn secp256k1_point_is_sec1_compressed() {
+        // The well-known compressed encoding of the secp256k1 generator.
+        let generator = BigInt::parse_bytes(
+            b"0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+            16,
+        )
+        .unwrap();
+        assert_eq!(
+            Secp256k1::point_into(Secp256k1::base_point()),
+            Element(generator.clone())
+        );
+        assert_eq!(
+            Secp256k1::point_from(Element(generator)).unwrap(),
+            Secp256k1::base_point()
+        );
+    }
+
+    #[test]
+    fn secp256k1_point_round_trip() {
+        for point in [
+            Secp256k1::base_point() * Secp256k1::random_scalar(),
+            ProjectivePoint::IDENTITY,
+        ] {
+            let element = Secp256k1::point_into(point);
+            assert_eq!(Secp256k1::point_from(element).unwrap(), point);
+        }
+        assert_eq!(
+            Secp256k1::point_into(ProjectivePoint::IDENTITY),
+            Element::zero()
+        );
+    }
+
+    #[test]
+    fn secp256k1_point_rejects_invalid_encodings() {
+        let generator = Secp256k1::point_into(Secp256k1::base_point());
+        for value in [
+            BigInt::one(),
+            // An x-coordinate without a compression prefix.
+            generator.0.clone() - (BigInt::from(2) << 256usize),
+            // A 34-byte value.
+            generator.0 + (BigInt::one() << 264usize),
+        ] {
+            assert!(Secp256k1::point_from(Element(value)).is_err());
+        }
+    }
+
+    #[test]
+    fn hash_to_point_is_deterministic() {
+        let h = Secp256k1::hash_to_point(b"H");
+        assert_eq!(Secp256k1::hash_to_point(b"H"), h);
+        assert_ne!(Secp256k1::hash_to_point(b"G"), h);
+        assert_ne!(h, Secp256k1::base_point());
+        assert_eq!(
+            Ristretto::hash_to_point(b"H"),
+            Ristretto::hash_to_point(b"H")
+        );
+    }
+}
@@ -31,14 +31,19 @@
 //! group operations and from working with integers modulo a prime to working with points on an
 //! elliptic curve.
 
+use base64::{engine::general_purpose, Engine};
 use curve25519_dalek::{ristretto::CompressedRistretto, RistrettoPoint, Scalar as DalekScalar};
 use num_bigint::{BigInt, Sign};
+use zeroize::Zeroizing;
 
 use crate::{ConversionError, Element, Scalar};
 
 /// The configuration module.
 pub mod configuration;
 
+/// The curve module.
+pub mod curve;
+
 /// The prover module.
 pub mod prover;
 
@@ -46,7 +51,7 @@ pub mod prover;
 pub mod verifier;
 
 #[cfg(test)]
-mod test;
+pub(crate) mod test;
 
 /// Generates a random scalar.
 fn generate_random_scalar() -> DalekScalar {
@@ -54,15 +59,38 @@ fn generate_random_scalar() -> DalekScalar {
     DalekScalar::random(&mut rng)
 }
 
-/// Converts a dalek scalar to a BigInt scalar.
+/// Encodes a BigInt as 32 little-endian bytes, the encoding used by dalek scalars and compressed
+/// ristretto points. Shorter values are zero-padded on the most significant end, and the unpadded
+/// bytes are wiped, since the value may be a secret scalar.
+/// # Errors
+/// * Returns an error if the value does not fit in 32 bytes, rather than truncating it.
+fn to_bytes_le_32(value: &BigInt) -> Result<[u8; 32], ConversionError> {
+    let (_, bytes) = value.to_bytes_le();
+    let bytes = Zeroizing::new(bytes);
+    if bytes.len() > 32 {
+        return Err(ConversionError);
+    }
+    let mut padded = [0u8; 32];
+    padded[..bytes.len()].copy_from_slice(&bytes);
+    Ok(padded)
+}
+
+/// Converts a BigInt scalar to a dalek scalar, interpreting it as little-endian bytes.
 impl TryFrom<Scalar> for DalekScalar {
     type Error = ConversionError;
 
     fn try_from(value: Scalar) -> Result<Self, Self::Error> {
-        let (_, mut bytes) = value.0.to_bytes_le();
-        bytes.resize(32, 0);
-        let bytes: [u8; 32] = bytes.try_into().map_err(|_| ConversionError)?;
-        let scalar = DalekScalar::from_canonical_bytes(bytes);
+        DalekScalar::try_from(&value)
+    }
+}
+
+/// Converts a borrowed BigInt scalar to a dalek scalar, wiping the bytes in between.
+impl TryFrom<&Scalar> for DalekScalar {
+    type Error = ConversionError;
+
+    fn try_from(value: &Scalar) -> Result<Self, Self::Error> {
+        let bytes = Zeroizing::new(to_bytes_le_32(&value.0)?);
+        let scalar = DalekScalar::from_canonical_bytes(*bytes);
         if scalar.is_some().into() {
             Ok(scalar.unwrap())
         } else {
@@ -79,13 +107,13 @@ impl From<DalekScalar> for Scalar {
     }
 }
 
-/// Converts a ristretto point to a BigInt element.
+/// Converts a BigInt element to a ristretto point, interpreting it as the little-endian bytes of
+/// a compressed point.
 impl TryFrom<Element> for RistrettoPoint {
     type Error = ConversionError;
 
     fn try_from(value: Element) -> Result<Self, Self::Error> {
-        let (_, mut bytes) = value.0.to_bytes_le();
-        bytes.resize(32, 0);
+        let bytes = to_bytes_le_32(&value.0)?;
         let compressed = CompressedRistretto::from_slice(&bytes).map_err(|_| ConversionError)?;
         compressed.decompress().ok_or(ConversionError)
     }
@@ -99,6 +127,84 @@ impl From<RistrettoPoint> for Element {
     }
 }
 
+/// Converts 32 little-endian bytes, the encoding of a dalek scalar, to a BigInt scalar.
+impl From<[u8; 32]> for Scalar {
+    fn from(value: [u8; 32]) -> Self {
+        Scalar(BigInt::from_bytes_le(Sign::Plus, &value))
+    }
+}
+
+/// Converts 32 little-endian bytes, the encoding of a compressed ristretto point, to a BigInt
+/// element.
+impl From<[u8; 32]> for Element {
+    fn from(value: [u8; 32]) -> Self {
+        Element(BigInt::from_bytes_le(Sign::Plus, &value))
+    }
+}
+
+/// Returns whether a PEM label is valid, which is non-empty printable ASCII without hyphens at
+/// either end.
+fn is_valid_pem_label(label: &str) -> bool {
+    !label.is_empty()
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label.chars().all(|c| c.is_ascii_graphic() || c == ' ')
+}
+
+/// PEM encoding of elliptic curve elements, for interop with PKI tooling.
+impl Element {
+    /// Encodes the element, a compressed ristretto point, as a PEM block with the given label,
+    /// containing the base64 of its 32-byte encoding.
+    /// # Errors
+    /// * Returns an error if the label is empty or not printable ASCII.
+    /// * Returns an error if the element does not fit in 32 bytes.
+    pub fn to_pem(&self, label: &str) -> Result<String, ConversionError> {
+        if !is_valid_pem_label(label) {
+            return Err(ConversionError);
+        }
+        let encoded = general_purpose::STANDARD.encode(to_bytes_le_32(&self.0)?);
+        Ok(format!(
+            "-----BEGIN {}-----\n{}\n-----END {}-----\n",
+            label, encoded, label
+        ))
+    }
+
+    /// Decodes an element from a PEM block with the given label, containing the base64 of the
+    /// 32-byte encoding of a compressed ristretto point.
+    /// # Errors
+    /// * Returns an error if the block is malformed or has a different label.
+    /// * Returns an error if the contents are not 32 bytes of valid base64.
+    /// * Returns an error if the contents do not decode to a valid point.
+    pub fn from_pem(pem: &str, label: &str) -> Result<Self, ConversionError> {
+        let mut lines = pem.trim().lines().map(str::trim);
+        if lines.next() != Some(format!("-----BEGIN {}-----", label).as_str()) {
+            return Err(ConversionError);
+        }
+        let mut body = String::new();
+        let mut ended = false;
+        for line in lines.by_ref() {
+            if line == format!("-----END {}-----", label) {
+                ended = true;
+                break;
+            }
+            body.push_str(line);
+        }
+        if !ended || lines.next().is_some() {
+            return Err(ConversionError);
+        }
+
+        let bytes: [u8; 32] = general_purpose::STANDARD
+            .decode(body)
+            .map_err(|_| ConversionError)?
+            .try_into()
+            .map_err(|_| ConversionError)?;
+        let point = CompressedRistretto(bytes)
+            .decompress()
+            .ok_or(ConversionError)?;
+        Ok(point.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,4 +249,125 @@ mod tests {
         let element: Result<RistrettoPoint, ConversionError> = value.clone().try_into();
         assert!(element.is_err());
     }
+
+    #[test]
+    fn from_bytes_to_scalar_round_trip() {
+        let dalek = generate_random_scalar();
+        let scalar = Scalar::from(dalek.to_bytes());
+        assert_eq!(scalar, Scalar::from(dalek));
+        let scalar: DalekScalar = scalar.try_into().unwrap();
+        assert_eq!(scalar, dalek);
+    }
+
+    #[test]
+    fn from_bytes_to_element_round_trip() {
+        let point = RISTRETTO_BASEPOINT_POINT * generate_random_scalar();
+        let element = Element::from(point.compress().to_bytes());
+        assert_eq!(element, Element::from(point));
+        let element: RistrettoPoint = element.try_into().unwrap();
+        assert_eq!(element, point);
+    }
+
+    #[test]
+    fn from_bytes_is_little_endian() {
+        let mut bytes = [0u8; 32];
+        bytes[0] = 1;
+        assert_eq!(Scalar::from(bytes), Scalar::one());
+        assert_eq!(Element::from(bytes), Element::one());
+    }
+
+    #[test]
+    fn try_from_scalar_to_dalek_scalar_rejects_33_bytes() {
+        // The low 32 bytes are a valid scalar, so truncating would silently yield one.
+        let value: Scalar = (BigInt::one() + (BigInt::one() << 256usize)).into();
+        assert_eq!(value.0.to_bytes_le().1.len(), 33);
+        let scalar: Result<DalekScalar, ConversionError> = value.try_into();
+        assert!(scalar.is_err());
+    }
+
+    #[test]
+    fn try_from_element_to_ristretto_point_rejects_33_bytes() {
+        // The low 32 bytes are a valid point, so truncating would silently yield the basepoint.
+        let basepoint: Element = RISTRETTO_BASEPOINT_POINT.into();
+        let value: Element = (basepoint.0 + (BigInt::one() << 256usize)).into();
+        assert_eq!(value.0.to_bytes_le().1.len(), 33);
+        let element: Result<RistrettoPoint, ConversionError> = value.try_into();
+        assert!(element.is_err());
+    }
+
+    #[test]
+    fn pem_round_trip() {
+        for point in [RISTRETTO_BASEPOINT_POINT, RistrettoPoint::default()] {
+            let element: Element = point.into();
+            let pem = element.to_pem("ZKAUTH PUBLIC POINT").unwrap();
+            assert!(pem.starts_with("-----BEGIN ZKAUTH PUBLIC POINT-----\n"));
+            assert!(pem.ends_with("-----END ZKAUTH PUBLIC POINT-----\n"));
+            assert_eq!(
+                Element::from_pem(&pem, "ZKAUTH PUBLIC POINT").unwrap(),
+                element
+            );
+
+            // Surrounding whitespace, CRLF line endings, and wrapped lines are accepted.
+            let body = pem.lines().nth(1).unwrap();
+            let wrapped = format!(
+                "\n  -----BEGIN ZKAUTH PUBLIC POINT-----\r\n{}\r\n{}\r\n-----END ZKAUTH PUBLIC POINT-----  \n",
+                &body[..20],
+                &body[20..]
+            );
+            assert_eq!(
+                Element::from_pem(&wrapped, "ZKAUTH PUBLIC POINT").unwrap(),
+                element
+            );
+        }
+    }
+
+    #[test]
+    fn pem_encodes_compressed_point() {
+        let element: Element = RISTRETTO_BASEPOINT_POINT.into();
+        let pem = element.to_pem("G").unwrap();
+        let body = pem.lines().nth(1).unwrap();
+        assert_eq!(
+            general_purpose::STANDARD.decode(body).unwrap(),
+            RISTRETTO_BASEPOINT_POINT.compress().to_bytes()
+        );
+    }
+
+    #[test]
+    fn from_pem_rejects_wrong_label() {
+        let element: Element = RISTRETTO_BASEPOINT_POINT.into();
+        let pem = element.to_pem("G").unwrap();
+        assert!(Element::from_pem(&pem, "H").is_err());
+
+        // A mismatched end label is also rejected.
+        let pem = pem.replace("-----END G-----", "-----END H-----");
+        assert!(Element::from_pem(&pem, "G").is_err());
+    }
+
+    #[test]
+    fn from_pem_rejects_invalid_contents() {
+        for pem in [
+            "",
+            "-----BEGIN G-----\n-----END G-----\n",
+            "-----BEGIN G-----\nnot base64!\n-----END G-----\n",
+            // 31 bytes.
+            "-----BEGIN G-----\nAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA==\n-----END G-----\n",
+            // 32 bytes that do not decode to a point.
+            "-----BEGIN G-----\n//////////////////////////////////////////8=\n-----END G-----\n",
+            // Trailing data after the block.
+            "-----BEGIN G-----\nAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=\n-----END G-----\nextra\n",
+        ] {
+            assert!(Element::from_pem(pem, "G").is_err(), "{}", pem);
+        }
+    }
+
+    #[test]
+    fn to_pem_rejects_invalid_label_and_oversized_element() {
+        let element: Element = RISTRETTO_BASEPOINT_POINT.into();
+        assert!(element.to_pem("").is_err());
+        assert!(element.to_pem("-G").is_err());
+        assert!(element.to_pem("G\n").is_err());
+
+        let oversized: Element = (BigInt::one() << 256usize).into();
+        assert!(oversized.to_pem("G").is_err());
+    }
 }
@@ -1,128 +1,165 @@
 use anyhow::{Error, Result};
-use curve25519_dalek::{RistrettoPoint, Scalar as DalekScalar};
-use sha2::{Digest, Sha512};
+use num_traits::Zero;
+use zeroize::Zeroizing;
 
-use super::{configuration::EllipticCurveConfiguration, generate_random_scalar};
-use crate::{Element, Prover, Scalar};
+use super::configuration::EllipticCurveConfiguration;
+use super::curve::{Curve, Ristretto};
+use crate::{
+    ChallengeContext, Element, PasswordHasher, ProofBinding, Prover, Scalar, Sha512PasswordHasher,
+};
 
-/// The elliptic curve prover.
+/// The elliptic curve prover, on ristretto255 by default.
 #[derive(Debug)]
-pub struct EllipticCurveProver {
-    config: EllipticCurveConfiguration,
+pub struct EllipticCurveProver<C: Curve = Ristretto> {
+    config: EllipticCurveConfiguration<C>,
+    hasher: Box<dyn PasswordHasher>,
 }
 
 /// Implementation of the elliptic curve prover.
-impl EllipticCurveProver {
-    /// Creates a new elliptic curve prover.
-    pub fn new(config: EllipticCurveConfiguration) -> Self {
-        EllipticCurveProver { config }
+impl<C: Curve> EllipticCurveProver<C> {
+    /// Creates a new elliptic curve prover, hashing passwords with SHA-512.
+    pub fn new(config: EllipticCurveConfiguration<C>) -> Self {
+        EllipticCurveProver {
+            config,
+            hasher: Box::new(Sha512PasswordHasher),
+        }
     }
 
-    /// Computes x from the given password.
-    fn compute_x(&self, password: String) -> DalekScalar {
-        let password_hash = Sha512::digest(password.as_bytes());
-        let mut x_bytes = [0u8; 32];
-        x_bytes.copy_from_slice(&password_hash[..32]);
-        DalekScalar::from_bytes_mod_order(x_bytes)
+    /// Hashes passwords with the given hasher instead of SHA-512, such as one based on Argon2id
+    /// to stretch them.
+    pub fn with_password_hasher(mut self, hasher: impl PasswordHasher + 'static) -> Self {
+        self.hasher = Box::new(hasher);
+        self
+    }
+
+    /// Computes x from the given password, reducing the full 64-byte hash mod the group order. The
+    /// password and the hash are wiped.
+    fn compute_x(&self, password: String) -> C::Scalar {
+        let password = Zeroizing::new(password);
+        let digest = Zeroizing::new(self.hasher.hash(password.as_bytes()));
+        C::reduce_wide_scalar(&digest)
     }
 
     /// Generates a random x value.
-    fn generate_x(&self) -> DalekScalar {
-        generate_random_scalar()
+    fn generate_x(&self) -> C::Scalar {
+        C::random_scalar()
     }
 
     /// Computes y1 from the given x using the g configuration value.
-    fn compute_y1(&self, x: DalekScalar) -> RistrettoPoint {
+    fn compute_y1(&self, x: C::Scalar) -> C::Point {
         self.config.g * x
     }
 
     /// Computes y2 from the given x using the h configuration value.
-    fn compute_y2(&self, x: DalekScalar) -> RistrettoPoint {
+    fn compute_y2(&self, x: C::Scalar) -> C::Point {
         self.config.h * x
     }
 
     /// Generates a random k value.
-    fn generate_k(&self) -> DalekScalar {
-        generate_random_scalar()
+    fn generate_k(&self) -> C::Scalar {
+        C::random_scalar()
     }
 
     /// Computes r1 from the given k using the g configuration value.
-    fn compute_r1(&self, k: DalekScalar) -> RistrettoPoint {
+    fn compute_r1(&self, k: C::Scalar) -> C::Point {
         self.config.g * k
     }
 
     /// Computes r2 from the given k using the h configuration value.
-    fn compute_r2(&self, k: DalekScalar) -> RistrettoPoint {
+    fn compute_r2(&self, k: C::Scalar) -> C::Point {
         self.config.h * k
     }
 
     /// Computes s from the given x, k, and c.
-    fn compute_s(&self, x: DalekScalar, k: DalekScalar, c: DalekScalar) -> DalekScalar {
+    fn compute_s(&self, x: C::Scalar, k: C::Scalar, c: C::Scalar) -> C::Scalar {
         k + c * x
     }
 }
 
 /// Implementation of the prover trait for the elliptic curve prover.
-impl Prover for EllipticCurveProver {
+impl<C: Curve> Prover for EllipticCurveProver<C> {
     /// Generates a random x value for registration.
     fn generate_registration_x(&self) -> Scalar {
-        let x = self.generate_x();
-        x.into()
+        let x = Zeroizing::new(self.generate_x());
+        C::scalar_into(*x)
     }
 
     /// Computes x from the given password for registration.
     fn compute_registration_x(&self, password: String) -> Scalar {
-        let x = self.compute_x(password);
-        x.into()
+        let x = Zeroizing::new(self.compute_x(password));
+        C::scalar_into(*x)
+    }
+
+    /// Returns whether the given x is a positive canonical scalar.
+    fn is_valid_registration_x(&self, x: &Scalar) -> bool {
+        *x > Scalar::zero() && C::scalar_from(x).map(Zeroizing::new).is_ok()
     }
 
     /// Computes y1 and y2 from the given x for registration.
-    fn compute_registration_y1y2(&self, x: Scalar) -> Result<(Element, Element)> {
-        let x: DalekScalar = x
-            .try_into()
-            .map_err(|_| Error::msg("Failed to convert scalar x"))?;
-        let y1 = self.compute_y1(x);
-        let y2 = self.compute_y2(x);
-        Ok((y1.into(), y2.into()))
+    fn compute_registration_y1y2(&self, x: &Scalar) -> Result<(Element, Element)> {
+        let x = C::scalar_from(x).map_err(|_| Error::msg("Failed to convert scalar x"))?;
+        let x = Zeroizing::new(x);
+        let y1 = self.compute_y1(*x);
+        let y2 = self.compute_y2(*x);
+        Ok((C::point_into(y1), C::point_into(y2)))
     }
 
     /// Generates a random k value for challenge.
     fn generate_challenge_k(&self) -> Scalar {
-        let c = self.generate_k();
-        c.into()
+        let k = Zeroizing::new(self.generate_k());
+        C::scalar_into(*k)
     }
 
     /// Computes r1 and r2 from the given k for challenge.
-    fn compute_challenge_commitment_r1r2(&self, k: Scalar) -> Result<(Element, Element)> {
-        let k: DalekScalar = k
-            .try_into()
-            .map_err(|_| Error::msg("Failed to convert scalar k"))?;
-        let r1 = self.compute_r1(k);
-        let r2 = self.compute_r2(k);
-        Ok((r1.into(), r2.into()))
+    fn compute_challenge_commitment_r1r2(&self, k: &Scalar) -> Result<(Element, Element)> {
+        let k = C::scalar_from(k).map_err(|_| Error::msg("Failed to convert scalar k"))?;
+        let k = Zeroizing::new(k);
+        let r1 = self.compute_r1(*k);
+        let r2 = self.compute_r2(*k);
+        Ok((C::point_into(r1), C::point_into(r2)))
     }
 
     /// Computes s from the given x, k, and c for challenge response.
-    fn compute_challenge_response_s(&self, x: Scalar, k: Scalar, c: Scalar) -> Result<Scalar> {
-        let x = x
-            .try_into()
-            .map_err(|_| Error::msg("Failed to convert scalar x"))?;
-        let k = k
-            .try_into()
-            .map_err(|_| Error::msg("Failed to convert scalar k"))?;
-        let c = c
-            .try_into()
-            .map_err(|_| Error::msg("Failed to convert scalar c"))?;
-        let s = self.compute_s(x, k, c);
-        Ok(s.into())
+    fn compute_challenge_response_s(&self, x: &Scalar, k: &Scalar, c: Scalar) -> Result<Scalar> {
+        let x = C::scalar_from(x).map_err(|_| Error::msg("Failed to convert scalar x"))?;
+        let x = Zeroizing::new(x);
+        let k = C::scalar_from(k).map_err(|_| Error::msg("Failed to convert scalar k"))?;
+        let k = Zeroizing::new(k);
+        let c = C::scalar_from(&c).map_err(|_| Error::msg("Failed to convert scalar c"))?;
+        let s = self.compute_s(*x, *k, c);
+        Ok(C::scalar_into(s))
+    }
+
+    /// Derives a non-interactive challenge c value from the hash of g, h, and the context.
+    fn derive_noninteractive_challenge_c(
+        &self,
+        context: &ChallengeContext,
+        binding: &ProofBinding,
+    ) -> Scalar {
+        C::scalar_into(self.config.derive_noninteractive_c(context, binding))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::elliptic_curve::test::test_prover;
-    use num_traits::Zero;
+    use crate::elliptic_curve::{generate_random_scalar, test::test_prover};
+    use num_bigint::BigInt;
+    use sha2::{Digest, Sha512};
+
+    /// A password hasher that salts passwords, standing in for a stretching hasher.
+    #[derive(Debug)]
+    struct SaltedHasher(&'static str);
+
+    impl PasswordHasher for SaltedHasher {
+        fn hash(&self, password: &[u8]) -> [u8; 64] {
+            Sha512::new()
+                .chain_update(self.0)
+                .chain_update(password)
+                .finalize()
+                .into()
+        }
+    }
 
     #[test]
     fn generate_registration_x() {
@@ -147,6 +184,39 @@ mod tests {
         assert_ne!(x1, x3);
     }
 
+    #[test]
+    fn compute_registration_x_with_password_hasher() {
+        let config = test_prover().config;
+        let default = EllipticCurveProver::new(config.clone());
+        let salted =
+            EllipticCurveProver::new(config.clone()).with_password_hasher(SaltedHasher("a"));
+        let other = EllipticCurveProver::new(config).with_password_hasher(SaltedHasher("b"));
+
+        let x = salted.compute_registration_x("password".to_string());
+        assert!(salted.is_valid_registration_x(&x));
+        assert_eq!(salted.compute_registration_x("password".to_string()), x);
+        assert_ne!(default.compute_registration_x("password".to_string()), x);
+        assert_ne!(other.compute_registration_x("password".to_string()), x);
+    }
+
+    #[test]
+    fn compute_registration_x_reduces_full_digest() {
+        // The full SHA-512 digest is reduced, rather than only its first 32 bytes.
+        let prover = test_prover();
+        let digest: [u8; 64] = Sha512::digest(b"password").into();
+        let mut truncated = [0u8; 32];
+        truncated.copy_from_slice(&digest[..32]);
+        let x = prover.compute_registration_x("password".to_string());
+        assert_eq!(
+            x,
+            Scalar::from(curve25519_dalek::Scalar::from_bytes_mod_order_wide(&digest))
+        );
+        assert_ne!(
+            x,
+            Scalar::from(curve25519_dalek::Scalar::from_bytes_mod_order(truncated))
+        );
+    }
+
     #[test]
     fn compute_registration_x_with_empty_password() {
         let prover = test_prover();
@@ -154,11 +224,21 @@ mod tests {
         assert!(x > Scalar::zero());
     }
 
+    #[test]
+    fn is_valid_registration_x() {
+        let prover = test_prover();
+        assert!(prover.is_valid_registration_x(&prover.generate_registration_x()));
+        assert!(prover.is_valid_registration_x(&BigInt::from(1).into()));
+        assert!(!prover.is_valid_registration_x(&Scalar::zero()));
+        assert!(!prover.is_valid_registration_x(&BigInt::from(-1).into()));
+        assert!(!prover.is_valid_registration_x(&(BigInt::from(1) << 255usize).into()));
+    }
+
     #[test]
     fn compute_registration_y1y2() {
         let prover = test_prover();
         let x = prover.generate_x();
-        let (y1, y2) = prover.compute_registration_y1y2(x.into()).unwrap();
+        let (y1, y2) = prover.compute_registration_y1y2(&x.into()).unwrap();
         assert!(y1 > Element::zero());
         assert!(y2 > Element::zero());
         assert_ne!(y1, y2);
@@ -178,7 +258,7 @@ mod tests {
     fn compute_challenge_commitment_r1r2() {
         let prover = test_prover();
         let k = prover.generate_k();
-        let (r1, r2) = prover.compute_challenge_commitment_r1r2(k.into()).unwrap();
+        let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k.into()).unwrap();
         assert!(r1 > Element::zero());
         assert!(r2 > Element::zero());
         assert_ne!(r1, r2);
@@ -191,7 +271,7 @@ mod tests {
         let k = prover.generate_k();
         let c = generate_random_scalar();
         let s = prover
-            .compute_challenge_response_s(x.into(), k.into(), c.into())
+            .compute_challenge_response_s(&x.into(), &k.into(), c.into())
             .unwrap();
         assert!(s > Scalar::zero());
     }
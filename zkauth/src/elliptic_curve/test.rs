@@ -1,14 +1,21 @@
-use crate::{Prover, Verifier};
+use crate::{ChallengeContext, Prover, RegistrationBlob, Verifier};
 use anyhow::Result;
 use num_traits::One;
 
 use super::{
-    configuration::EllipticCurveConfiguration, prover::EllipticCurveProver,
+    configuration::EllipticCurveConfiguration,
+    curve::{Curve, Ristretto, Secp256k1},
+    prover::EllipticCurveProver,
     verifier::EllipticCurveVerifier,
 };
 
 pub fn test_prover_and_verifier() -> (EllipticCurveProver, EllipticCurveVerifier) {
-    let config = EllipticCurveConfiguration::generate();
+    test_prover_and_verifier_on::<Ristretto>()
+}
+
+pub fn test_prover_and_verifier_on<C: Curve>() -> (EllipticCurveProver<C>, EllipticCurveVerifier<C>)
+{
+    let config = EllipticCurveConfiguration::<C>::generate_on_curve();
     let prover = EllipticCurveProver::new(config.clone());
     let verifier = EllipticCurveVerifier::new(config);
     (prover, verifier)
@@ -29,12 +36,12 @@ fn verify_succeeds_with_given_password() -> Result<()> {
     let (prover, verifier) = test_prover_and_verifier();
 
     let x = prover.compute_registration_x("password".to_string());
-    let (y1, y2) = prover.compute_registration_y1y2(x.clone()).unwrap();
+    let (y1, y2) = prover.compute_registration_y1y2(&x).unwrap();
     let c = verifier.generate_challenge_c();
     let k = prover.generate_challenge_k();
-    let (r1, r2) = prover.compute_challenge_commitment_r1r2(k.clone()).unwrap();
+    let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k).unwrap();
     let s = prover
-        .compute_challenge_response_s(x, k, c.clone())
+        .compute_challenge_response_s(&x, &k, c.clone())
         .unwrap();
     let (r1_prime, r2_prime) = verifier.compute_verification_r1r2(y1, y2, c, s).unwrap();
     assert_eq!(r1_prime, r1);
@@ -48,12 +55,12 @@ fn verify_succeeds_with_generated_x() -> Result<()> {
     let (prover, verifier) = test_prover_and_verifier();
 
     let x = prover.generate_registration_x();
-    let (y1, y2) = prover.compute_registration_y1y2(x.clone()).unwrap();
+    let (y1, y2) = prover.compute_registration_y1y2(&x).unwrap();
     let c = verifier.generate_challenge_c();
     let k = prover.generate_challenge_k();
-    let (r1, r2) = prover.compute_challenge_commitment_r1r2(k.clone()).unwrap();
+    let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k).unwrap();
     let s = prover
-        .compute_challenge_response_s(x, k, c.clone())
+        .compute_challenge_response_s(&x, &k, c.clone())
         .unwrap();
     let (r1_prime, r2_prime) = verifier.compute_verification_r1r2(y1, y2, c, s).unwrap();
     assert_eq!(r1_prime, r1);
@@ -67,12 +74,12 @@ fn verify_fails_with_incorrect_y1() -> Result<()> {
     let (prover, verifier) = test_prover_and_verifier();
 
     let x = prover.compute_registration_x("password".to_string());
-    let (mut y1, y2) = prover.compute_registration_y1y2(x.clone()).unwrap();
+    let (mut y1, y2) = prover.compute_registration_y1y2(&x).unwrap();
     y1 = y1 + One::one();
     let c = verifier.generate_challenge_c();
     let k = prover.generate_challenge_k();
     let s = prover
-        .compute_challenge_response_s(x, k, c.clone())
+        .compute_challenge_response_s(&x, &k, c.clone())
         .unwrap();
     let err = verifier
         .compute_verification_r1r2(y1, y2, c, s)
@@ -87,12 +94,12 @@ fn verify_fails_with_incorrect_y2() -> Result<()> {
     let (prover, verifier) = test_prover_and_verifier();
 
     let x = prover.compute_registration_x("password".to_string());
-    let (y1, mut y2) = prover.compute_registration_y1y2(x.clone()).unwrap();
+    let (y1, mut y2) = prover.compute_registration_y1y2(&x).unwrap();
     y2 = y2 + One::one();
     let c = verifier.generate_challenge_c();
     let k = prover.generate_challenge_k();
     let s = prover
-        .compute_challenge_response_s(x, k, c.clone())
+        .compute_challenge_response_s(&x, &k, c.clone())
         .unwrap();
     let err = verifier
         .compute_verification_r1r2(y1, y2, c, s)
@@ -101,3 +108,161 @@ fn verify_fails_with_incorrect_y2() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn registration_blob_matches_computed_y1y2() -> Result<()> {
+    let prover = test_prover();
+
+    let blob = prover.registration("user", "password")?;
+    let x = prover.compute_registration_x("password".to_string());
+    let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+    assert_eq!(blob.user, "user");
+    assert_eq!(blob.y1, y1.to_string());
+    assert_eq!(blob.y2, y2.to_string());
+
+    let json = serde_json::to_string(&blob)?;
+    let decoded: RegistrationBlob = serde_json::from_str(&json)?;
+    assert_eq!(decoded, blob);
+
+    Ok(())
+}
+
+#[test]
+fn generate_challenge_c_for_is_random_by_default() -> Result<()> {
+    let (prover, verifier) = test_prover_and_verifier();
+
+    let x = prover.generate_registration_x();
+    let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+    let (r1, r2) = prover.compute_challenge_commitment_r1r2(&prover.generate_challenge_k())?;
+    let context = ChallengeContext { y1, y2, r1, r2 };
+    assert_ne!(
+        verifier.generate_challenge_c_for(&context),
+        verifier.generate_challenge_c_for(&context)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn bound_challenge_is_deterministic_and_verifies() -> Result<()> {
+    let (prover, verifier) = test_prover_and_verifier();
+    let verifier = verifier.with_bound_challenges();
+
+    let x = prover.compute_registration_x("password".to_string());
+    let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+    let k = prover.generate_challenge_k();
+    let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+    let context = ChallengeContext {
+        y1: y1.clone(),
+        y2: y2.clone(),
+        r1: r1.clone(),
+        r2: r2.clone(),
+    };
+
+    let c = verifier.generate_challenge_c_for(&context);
+    assert_eq!(verifier.generate_challenge_c_for(&context), c);
+
+    // A different commitment is bound to a different challenge.
+    let (other_r1, other_r2) =
+        prover.compute_challenge_commitment_r1r2(&prover.generate_challenge_k())?;
+    let other = ChallengeContext {
+        r1: other_r1,
+        r2: other_r2,
+        ..context.clone()
+    };
+    assert_ne!(verifier.generate_challenge_c_for(&other), c);
+
+    let s = prover.compute_challenge_response_s(&x, &k, c.clone())?;
+    assert!(verifier.verify(y1, y2, &r1, &r2, c, s)?);
+
+    Ok(())
+}
+
+#[test]
+fn secp256k1_verify_succeeds_with_given_password() -> Result<()> {
+    let (prover, verifier) = test_prover_and_verifier_on::<Secp256k1>();
+
+    let x = prover.compute_registration_x("password".to_string());
+    assert!(prover.is_valid_registration_x(&x));
+    let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+    let c = verifier.generate_challenge_c();
+    let k = prover.generate_challenge_k();
+    let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+    let s = prover.compute_challenge_response_s(&x, &k, c.clone())?;
+    let (r1_prime, r2_prime) = verifier.compute_verification_r1r2(y1, y2, c, s)?;
+    assert_eq!(r1_prime, r1);
+    assert_eq!(r2_prime, r2);
+
+    Ok(())
+}
+
+#[test]
+fn secp256k1_verify_succeeds_with_generated_x() -> Result<()> {
+    let (prover, verifier) = test_prover_and_verifier_on::<Secp256k1>();
+
+    let x = prover.generate_registration_x();
+    let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+    let c = verifier.generate_challenge_c();
+    let k = prover.generate_challenge_k();
+    let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+    let s = prover.compute_challenge_response_s(&x, &k, c.clone())?;
+    assert!(verifier.verify(y1, y2, &r1, &r2, c, s)?);
+
+    Ok(())
+}
+
+#[test]
+fn secp256k1_verify_fails_with_wrong_password() -> Result<()> {
+    let (prover, verifier) = test_prover_and_verifier_on::<Secp256k1>();
+
+    let x = prover.compute_registration_x("password".to_string());
+    let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+    let c = verifier.generate_challenge_c();
+    let k = prover.generate_challenge_k();
+    let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+    let wrong_x = prover.compute_registration_x("wrong password".to_string());
+    let s = prover.compute_challenge_response_s(&wrong_x, &k, c.clone())?;
+    assert!(!verifier.verify(y1, y2, &r1, &r2, c, s)?);
+
+    Ok(())
+}
+
+#[test]
+fn secp256k1_bound_challenge_verifies() -> Result<()> {
+    let (prover, verifier) = test_prover_and_verifier_on::<Secp256k1>();
+    let verifier = verifier.with_bound_challenges();
+
+    let x = prover.compute_registration_x("password".to_string());
+    let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+    let k = prover.generate_challenge_k();
+    let (r1, r2) = prover.compute_challenge_commitment_r1r2(&k)?;
+    let context = ChallengeContext {
+        y1: y1.clone(),
+        y2: y2.clone(),
+        r1: r1.clone(),
+        r2: r2.clone(),
+    };
+
+    let c = verifier.generate_challenge_c_for(&context);
+    assert_eq!(verifier.generate_challenge_c_for(&context), c);
+    let s = prover.compute_challenge_response_s(&x, &k, c.clone())?;
+    assert!(verifier.verify(y1, y2, &r1, &r2, c, s)?);
+
+    Ok(())
+}
+
+#[test]
+fn secp256k1_elements_are_not_ristretto_elements() -> Result<()> {
+    let (prover, _) = test_prover_and_verifier_on::<Secp256k1>();
+    let (_, verifier) = test_prover_and_verifier();
+
+    let x = prover.generate_registration_x();
+    let (y1, y2) = prover.compute_registration_y1y2(&x)?;
+    let c = verifier.generate_challenge_c();
+    let err = verifier
+        .compute_verification_r1r2(y1, y2, c.clone(), c)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Failed to convert element y1");
+
+    Ok(())
+}
@@ -1,55 +1,70 @@
 use anyhow::{Error, Result};
-use curve25519_dalek::{RistrettoPoint, Scalar as DalekScalar};
 
 use super::configuration::EllipticCurveConfiguration;
-use super::generate_random_scalar;
+use super::curve::{Curve, Ristretto};
 use crate::Verifier;
-use crate::{Element, Scalar};
+use crate::{ChallengeContext, Element, ProofBinding, Scalar};
 
-/// The elliptic curve verifier.
-pub struct EllipticCurveVerifier {
-    config: EllipticCurveConfiguration,
+/// The elliptic curve verifier, on ristretto255 by default.
+pub struct EllipticCurveVerifier<C: Curve = Ristretto> {
+    config: EllipticCurveConfiguration<C>,
+    bind_challenges: bool,
 }
 
 /// Implementation of the elliptic curve verifier.
-impl EllipticCurveVerifier {
+impl<C: Curve> EllipticCurveVerifier<C> {
     /// Creates a new elliptic curve verifier.
-    pub fn new(config: EllipticCurveConfiguration) -> Self {
-        EllipticCurveVerifier { config }
+    pub fn new(config: EllipticCurveConfiguration<C>) -> Self {
+        EllipticCurveVerifier {
+            config,
+            bind_challenges: false,
+        }
+    }
+
+    /// Binds each challenge generated for a context to it, deriving c from the hash of the
+    /// context reduced mod the group order instead of generating it randomly.
+    pub fn with_bound_challenges(mut self) -> Self {
+        self.bind_challenges = true;
+        self
     }
 
     /// Generates a random c value.
-    fn generate_c(&self) -> DalekScalar {
-        generate_random_scalar()
+    fn generate_c(&self) -> C::Scalar {
+        C::random_scalar()
+    }
+
+    /// Derives a c value from the hash of the given context, reduced mod the group order.
+    fn derive_c(&self, context: &ChallengeContext) -> C::Scalar {
+        C::reduce_wide_scalar(&context.digest())
     }
 
     /// Computes r1' from the given y1, c, and s.
-    fn compute_r1_prime(
-        &self,
-        y1: RistrettoPoint,
-        c: DalekScalar,
-        s: DalekScalar,
-    ) -> RistrettoPoint {
+    fn compute_r1_prime(&self, y1: C::Point, c: C::Scalar, s: C::Scalar) -> C::Point {
         (self.config.g * s) - (y1 * c)
     }
 
     /// Computes r2' from the given y2, c, and s.
-    fn compute_r2_prime(
-        &self,
-        y2: RistrettoPoint,
-        c: DalekScalar,
-        s: DalekScalar,
-    ) -> RistrettoPoint {
+    fn compute_r2_prime(&self, y2: C::Point, c: C::Scalar, s: C::Scalar) -> C::Point {
         (self.config.h * s) - (y2 * c)
     }
 }
 
 /// Implementation of the verifier trait for the elliptic curve verifier.
-impl Verifier for EllipticCurveVerifier {
+impl<C: Curve> Verifier for EllipticCurveVerifier<C> {
     /// Generates a challenge c value.
     fn generate_challenge_c(&self) -> Scalar {
-        let c = self.generate_c();
-        c.into()
+        C::scalar_into(self.generate_c())
+    }
+
+    /// Generates a challenge c value bound to the context if configured, or a random one
+    /// otherwise.
+    fn generate_challenge_c_for(&self, context: &ChallengeContext) -> Scalar {
+        let c = if self.bind_challenges {
+            self.derive_c(context)
+        } else {
+            self.generate_c()
+        };
+        C::scalar_into(c)
     }
 
     /// Computes verification r1' and r2' values from the given y1, y2, c, and s.
@@ -60,21 +75,28 @@ impl Verifier for EllipticCurveVerifier {
         c: Scalar,
         s: Scalar,
     ) -> Result<(Element, Element)> {
-        let y1: RistrettoPoint = y1
-            .try_into()
-            .map_err(|_| Error::msg("Failed to convert element y1"))?;
-        let y2: RistrettoPoint = y2
-            .try_into()
-            .map_err(|_| Error::msg("Failed to convert element y2"))?;
-        let c: DalekScalar = c
-            .try_into()
-            .map_err(|_| Error::msg("Failed to convert scalar c"))?;
-        let s: DalekScalar = s
-            .try_into()
-            .map_err(|_| Error::msg("Failed to convert scalar s"))?;
+        let y1 = C::point_from(y1).map_err(|_| Error::msg("Failed to convert element y1"))?;
+        let y2 = C::point_from(y2).map_err(|_| Error::msg("Failed to convert element y2"))?;
+        let c = C::scalar_from(&c).map_err(|_| Error::msg("Failed to convert scalar c"))?;
+        let s = C::scalar_from(&s).map_err(|_| Error::msg("Failed to convert scalar s"))?;
         let r1 = self.compute_r1_prime(y1, c, s);
         let r2 = self.compute_r2_prime(y2, c, s);
-        Ok((r1.into(), r2.into()))
+        Ok((C::point_into(r1), C::point_into(r2)))
+    }
+
+    /// Derives a non-interactive challenge c value from the hash of g, h, and the context.
+    fn derive_noninteractive_challenge_c(
+        &self,
+        context: &ChallengeContext,
+        binding: &ProofBinding,
+    ) -> Scalar {
+        C::scalar_into(self.config.derive_noninteractive_c(context, binding))
+    }
+
+    /// Returns the security level of the curve, which is about 128 bits for both ristretto255 and
+    /// secp256k1.
+    fn security_level(&self) -> u32 {
+        C::SECURITY_LEVEL
     }
 }
 
@@ -90,4 +112,9 @@ mod tests {
         let c = verifier.generate_challenge_c();
         assert!(c > Scalar::zero());
     }
+
+    #[test]
+    fn test_security_level() {
+        assert_eq!(test_verifier().security_level(), 128);
+    }
 }
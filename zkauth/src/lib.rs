@@ -7,57 +7,304 @@
 //! of a secret corresponding to a public value without revealing the secret itself.
 
 use anyhow::Result;
-use num_bigint::BigInt;
+use base64::{
+    alphabet,
+    engine::{general_purpose, DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+    Engine,
+};
+use num_bigint::{BigInt, Sign};
 use num_traits::{One, Zero};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha512};
 use std::fmt;
 use std::fmt::Debug;
 use std::ops::{Add, Mul};
 use std::str::FromStr;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroize;
+
+/// The modular arithmetic module.
+pub mod arithmetic;
 
 /// The discrete logarithm module.
+#[cfg(feature = "discrete_logarithm")]
 pub mod discrete_logarithm;
 
 /// The elliptic curve module.
+#[cfg(feature = "elliptic_curve")]
 pub mod elliptic_curve;
 
+/// The null verifier module, for load testing without the cost of verification.
+#[cfg(feature = "null_verifier")]
+pub mod null;
+
+#[cfg(all(test, any(feature = "discrete_logarithm", feature = "elliptic_curve")))]
+mod noninteractive;
+
+#[cfg(all(test, any(feature = "discrete_logarithm", feature = "elliptic_curve")))]
+mod reduction;
+
 /// A scalar value.
-#[derive(Debug, PartialEq, PartialOrd, Clone)]
-pub struct Scalar(pub BigInt);
+///
+/// The inner field is public for backwards compatibility, but will become private; prefer
+/// `Scalar::new_checked` or `From<BigInt>` to construct, and `Scalar::value` or
+/// `Scalar::into_inner` to access it.
+///
+/// Equality and hashing compare the integer value as is, so they assume the value is canonical,
+/// i.e. already reduced into [0, modulus). A value and the same value plus the modulus are
+/// different keys.
+///
+/// `Add` and `Mul` are plain integer arithmetic and never reduce, since a scalar does not know its
+/// modulus. Protocol code that needs a result mod q or mod the curve order reduces it explicitly.
+///
+/// With the `serde` feature, it serializes as a decimal string, like the protobuf string fields.
+///
+/// Its `Debug` output is redacted, since scalars such as x and k are secrets; use `Display` to
+/// print a public scalar such as c or s.
+#[derive(PartialEq, Eq, Hash, PartialOrd, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Scalar(#[cfg_attr(feature = "serde", serde(with = "decimal"))] pub BigInt);
 
 /// An element value.
-#[derive(Debug, PartialEq, PartialOrd, Clone)]
-pub struct Element(pub BigInt);
+///
+/// The inner field is public for backwards compatibility, but will become private; prefer
+/// `Element::new_checked` or `From<BigInt>` to construct, and `Element::value` or
+/// `Element::into_inner` to access it.
+///
+/// Equality and hashing compare the integer value as is, so they assume the value is canonical,
+/// i.e. already reduced into [0, modulus) or a compressed point encoding. A value and the same
+/// value plus the modulus are different keys.
+///
+/// `Add` and `Mul` are plain integer arithmetic and never reduce, like those of `Scalar`.
+///
+/// With the `serde` feature, it serializes as a decimal string, like `Scalar`.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Element(#[cfg_attr(feature = "serde", serde(with = "decimal"))] pub BigInt);
 
 /// An error for when a conversion fails.
 #[derive(Debug)]
 pub struct ConversionError;
 
+/// Everything a server needs to register a user, for offline or asynchronous provisioning.
+///
+/// The y1 and y2 values are encoded as decimal strings, the same as on the wire.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct RegistrationBlob {
+    /// The user to register.
+    pub user: String,
+    /// The registration y1 value.
+    pub y1: String,
+    /// The registration y2 value.
+    pub y2: String,
+}
+
+/// The version of the authentication protocol implemented by the provers and verifiers of this
+/// crate. It is incremented when a change to the protocol is incompatible with earlier versions.
+///
+/// Version 2 derives the elliptic curve x by wide-reducing the full 64-byte password hash, where
+/// version 1 reduced only its first 32 bytes, so the same password gives a different x. Users
+/// registered with the elliptic curve flavor under version 1 must register again.
+pub const PROTOCOL_VERSION: u32 = 2;
+
 /// A trait for implementing a prover for a zero-knowledge proof.
 pub trait Prover: Sync + Send + Debug {
+    /// Returns the version of the authentication protocol the prover speaks.
+    fn protocol_version(&self) -> u32 {
+        PROTOCOL_VERSION
+    }
+
     /// Generates a registration x value.
     fn generate_registration_x(&self) -> Scalar;
 
     /// Computes a registration x value from the given password.
     fn compute_registration_x(&self, password: String) -> Scalar;
 
-    /// Computes a registration y1 and y2 value from the given x.
-    fn compute_registration_y1y2(&self, x: Scalar) -> Result<(Element, Element)>;
+    /// Returns whether the given x is a valid secret for registration, such as one supplied
+    /// directly instead of being computed from a password.
+    fn is_valid_registration_x(&self, x: &Scalar) -> bool;
+
+    /// Computes a registration y1 and y2 value from the given x. The secret x is borrowed, so that
+    /// callers holding it do not hand out copies that outlive their own wiping of it.
+    fn compute_registration_y1y2(&self, x: &Scalar) -> Result<(Element, Element)>;
 
     /// Generates a challenge k value.
     fn generate_challenge_k(&self) -> Scalar;
 
     /// Computes a challenge commitment r1 and r2 value from the given k.
-    fn compute_challenge_commitment_r1r2(&self, k: Scalar) -> Result<(Element, Element)>;
+    fn compute_challenge_commitment_r1r2(&self, k: &Scalar) -> Result<(Element, Element)>;
 
     /// Computes a challenge response s value from the given x, k, and c.
-    fn compute_challenge_response_s(&self, x: Scalar, k: Scalar, c: Scalar) -> Result<Scalar>;
+    fn compute_challenge_response_s(&self, x: &Scalar, k: &Scalar, c: Scalar) -> Result<Scalar>;
+
+    /// Derives the challenge c value of a non-interactive proof from the hash of the public
+    /// parameters, the given context, and what the proof is bound to, reduced mod the group order.
+    fn derive_noninteractive_challenge_c(
+        &self,
+        context: &ChallengeContext,
+        binding: &ProofBinding,
+    ) -> Scalar;
+
+    /// Computes a non-interactive proof of knowledge of x, deriving the challenge c from the
+    /// commitment with the Fiat-Shamir transform instead of requesting it from a verifier. The
+    /// proof is bound to the given user and time, so it only verifies for them.
+    fn compute_noninteractive_proof(&self, x: &Scalar, binding: ProofBinding) -> Result<Proof> {
+        let (y1, y2) = self.compute_registration_y1y2(x)?;
+        let mut k = self.generate_challenge_k();
+        let proof = self
+            .compute_challenge_commitment_r1r2(&k)
+            .and_then(|(r1, r2)| {
+                let context = ChallengeContext { y1, y2, r1, r2 };
+                let c = self.derive_noninteractive_challenge_c(&context, &binding);
+                let s = self.compute_challenge_response_s(x, &k, c)?;
+                Ok(Proof {
+                    context,
+                    binding,
+                    s,
+                })
+            });
+        k.zeroize();
+        proof
+    }
+
+    /// Computes a registration blob for the given user from the given password, without a
+    /// live connection to a server.
+    fn registration(&self, user: &str, password: &str) -> Result<RegistrationBlob> {
+        let mut x = self.compute_registration_x(password.to_string());
+        let registration = self.compute_registration_y1y2(&x);
+        x.zeroize();
+        let (y1, y2) = registration?;
+        Ok(RegistrationBlob {
+            user: user.to_string(),
+            y1: y1.to_string(),
+            y2: y2.to_string(),
+        })
+    }
+}
+
+/// A trait for hashing a password to the 64 bytes a prover derives x from, so that a slow,
+/// memory-hard function such as Argon2id can be plugged in to stretch passwords.
+pub trait PasswordHasher: Sync + Send + Debug {
+    /// Hashes the given password to 64 bytes.
+    fn hash(&self, password: &[u8]) -> [u8; 64];
+}
+
+/// The default password hasher, a single SHA-512 digest of the password.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sha512PasswordHasher;
+
+/// Implementation of the password hasher trait for SHA-512.
+impl PasswordHasher for Sha512PasswordHasher {
+    /// Returns the SHA-512 digest of the password.
+    fn hash(&self, password: &[u8]) -> [u8; 64] {
+        Sha512::digest(password).into()
+    }
+}
+
+/// The public values a challenge can be bound to: the registration y1 and y2 of the user, and the
+/// commitment r1 and r2 the challenge is issued for.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ChallengeContext {
+    /// The registration y1 value.
+    pub y1: Element,
+    /// The registration y2 value.
+    pub y2: Element,
+    /// The challenge commitment r1 value.
+    pub r1: Element,
+    /// The challenge commitment r2 value.
+    pub r2: Element,
+}
+
+/// The domain separation tag hashed into the digest of a challenge context.
+const CHALLENGE_CONTEXT_TAG: &[u8] = b"zkauth-challenge-v1";
+
+/// The domain separation tag hashed into the digest of a non-interactive proof, so that its
+/// challenge can never equal one a verifier binds to the same context.
+const NONINTERACTIVE_CONTEXT_TAG: &[u8] = b"zkauth-noninteractive-v2";
+
+/// Implementation of the challenge context.
+impl ChallengeContext {
+    /// Returns the SHA-512 digest of the context, hashing each value as its length-prefixed
+    /// big-endian bytes after a domain separation tag, so that different contexts cannot produce
+    /// the same input.
+    pub fn digest(&self) -> [u8; 64] {
+        hash_elements(
+            CHALLENGE_CONTEXT_TAG,
+            [&self.y1, &self.y2, &self.r1, &self.r2],
+        )
+    }
+
+    /// Returns the SHA-512 digest of the generators g and h, the context, and the user and
+    /// timestamp of the binding, under its own domain separation tag, from which the challenge of
+    /// a non-interactive proof is derived. The user is hashed as its length-prefixed bytes and the
+    /// timestamp as its big-endian bytes, after the tag.
+    pub fn noninteractive_digest(
+        &self,
+        g: &Element,
+        h: &Element,
+        binding: &ProofBinding,
+    ) -> [u8; 64] {
+        let mut prefix = NONINTERACTIVE_CONTEXT_TAG.to_vec();
+        prefix.extend((binding.user.len() as u64).to_be_bytes());
+        prefix.extend(binding.user.as_bytes());
+        prefix.extend(binding.timestamp.to_be_bytes());
+        hash_elements(&prefix, [g, h, &self.y1, &self.y2, &self.r1, &self.r2])
+    }
+}
+
+/// Hashes the given elements with SHA-512 as their length-prefixed big-endian bytes, after the
+/// given prefix, such as a domain separation tag.
+fn hash_elements<'a>(prefix: &[u8], values: impl IntoIterator<Item = &'a Element>) -> [u8; 64] {
+    let mut hasher = Sha512::new();
+    hasher.update(prefix);
+    for value in values {
+        let (_, bytes) = value.0.to_bytes_be();
+        hasher.update((bytes.len() as u64).to_be_bytes());
+        hasher.update(bytes);
+    }
+    hasher.finalize().into()
+}
+
+/// What a non-interactive proof is bound to besides its context, hashed into its challenge so
+/// that it cannot be presented for another user, and a verifier can reject it once it is stale.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ProofBinding {
+    /// The user the proof authenticates, as the verifier normalizes it.
+    pub user: String,
+    /// The time the proof was computed, in seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+/// A non-interactive proof of knowledge of the x behind a registration, which a verifier can
+/// check without issuing a challenge.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Proof {
+    /// The registration y1 and y2 and the commitment r1 and r2 the challenge is derived from.
+    pub context: ChallengeContext,
+    /// The user and time the challenge is bound to.
+    pub binding: ProofBinding,
+    /// The response s to the derived challenge.
+    pub s: Scalar,
 }
 
 /// A trait for implementing a verifier for a zero-knowledge proof.
 pub trait Verifier: Sync + Send {
+    /// Returns the version of the authentication protocol the verifier speaks.
+    fn protocol_version(&self) -> u32 {
+        PROTOCOL_VERSION
+    }
+
     /// Generates a challenge c value.
     fn generate_challenge_c(&self) -> Scalar;
 
+    /// Generates a challenge c value for the given commitment and registration, so that a
+    /// verifier can bind c to them. By default the context is ignored, and c is random like that
+    /// of `generate_challenge_c`.
+    fn generate_challenge_c_for(&self, context: &ChallengeContext) -> Scalar {
+        let _ = context;
+        self.generate_challenge_c()
+    }
+
     /// Computes a verification r1 and r2 value from the given y1, y2, c, and s.
     fn compute_verification_r1r2(
         &self,
@@ -66,6 +313,285 @@ pub trait Verifier: Sync + Send {
         c: Scalar,
         s: Scalar,
     ) -> Result<(Element, Element)>;
+
+    /// Returns whether s answers the challenge c for the registration y1 and y2 and the
+    /// commitments r1 and r2, i.e. whether the verification r1 and r2 match the commitments.
+    fn verify(
+        &self,
+        y1: Element,
+        y2: Element,
+        r1: &Element,
+        r2: &Element,
+        c: Scalar,
+        s: Scalar,
+    ) -> Result<bool> {
+        let (r1_prime, r2_prime) = self.compute_verification_r1r2(y1, y2, c, s)?;
+        Ok((r1_prime.ct_eq(r1) & r2_prime.ct_eq(r2)).into())
+    }
+
+    /// Derives the challenge c value of a non-interactive proof from the hash of the public
+    /// parameters, the given context, and what the proof is bound to, the same as the prover does.
+    fn derive_noninteractive_challenge_c(
+        &self,
+        context: &ChallengeContext,
+        binding: &ProofBinding,
+    ) -> Scalar;
+
+    /// Returns whether the given non-interactive proof verifies, re-deriving its challenge c
+    /// from its commitment rather than trusting one supplied by the prover.
+    fn verify_noninteractive(&self, proof: &Proof) -> Result<bool> {
+        let context = &proof.context;
+        let c = self.derive_noninteractive_challenge_c(context, &proof.binding);
+        self.verify(
+            context.y1.clone(),
+            context.y2.clone(),
+            &context.r1,
+            &context.r2,
+            c,
+            proof.s.clone(),
+        )
+    }
+
+    /// Returns the approximate security level of the public parameters in bits, i.e. the base-2
+    /// logarithm of the estimated work to break them.
+    fn security_level(&self) -> u32;
+}
+
+/// The base64url engine, which encodes without padding as web APIs such as WebAuthn do, and
+/// decodes with or without padding.
+const BASE64URL: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new()
+        .with_encode_padding(false)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Encodes a non-negative value as the base64 of its minimal big-endian bytes, with the given
+/// engine. Zero is encoded as a single zero byte.
+fn encode_base64(value: &BigInt, engine: &impl Engine) -> Result<String, ConversionError> {
+    if value.sign() == Sign::Minus {
+        return Err(ConversionError);
+    }
+    Ok(engine.encode(value.to_bytes_be().1))
+}
+
+/// Decodes a non-negative value from the base64 of its big-endian bytes, with the given engine.
+fn decode_base64(encoded: &str, engine: &impl Engine) -> Result<BigInt, ConversionError> {
+    let bytes = engine.decode(encoded).map_err(|_| ConversionError)?;
+    if bytes.is_empty() {
+        return Err(ConversionError);
+    }
+    Ok(BigInt::from_bytes_be(Sign::Plus, &bytes))
+}
+
+/// Serde support for serializing a BigInt as a decimal string.
+#[cfg(feature = "serde")]
+mod decimal {
+    use num_bigint::BigInt;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use std::str::FromStr;
+
+    /// Serializes the value as a decimal string.
+    pub fn serialize<S: Serializer>(value: &BigInt, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    /// Deserializes the value from a decimal string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BigInt, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        BigInt::from_str(&value)
+            .map_err(|_| de::Error::custom(format!("invalid decimal integer '{}'", value)))
+    }
+}
+
+/// Compares two values in constant time for a given width, by comparing their signs and their
+/// little-endian magnitudes zero-padded to the width of the wider one. Only that width, which
+/// for group elements is bounded by the modulus, can leak through timing.
+fn ct_eq_bigint(a: &BigInt, b: &BigInt) -> Choice {
+    let (sign_a, mut bytes_a) = a.to_bytes_le();
+    let (sign_b, mut bytes_b) = b.to_bytes_le();
+    let width = bytes_a.len().max(bytes_b.len());
+    bytes_a.resize(width, 0);
+    bytes_b.resize(width, 0);
+    (sign_a as u8).ct_eq(&(sign_b as u8)) & bytes_a.ct_eq(&bytes_b)
+}
+
+/// Returns whether the value is in the range [0, modulus).
+fn in_range(value: &BigInt, modulus: &BigInt) -> bool {
+    *modulus > BigInt::zero() && *value >= BigInt::zero() && value < modulus
+}
+
+impl Scalar {
+    /// Creates a scalar from the given value, checking that it is in the range [0, modulus).
+    /// # Errors
+    /// * Returns an error if the modulus is not positive.
+    /// * Returns an error if the value is negative or not less than the modulus.
+    pub fn new_checked(value: BigInt, modulus: &BigInt) -> Result<Self, ConversionError> {
+        if !in_range(&value, modulus) {
+            return Err(ConversionError);
+        }
+        Ok(Scalar(value))
+    }
+
+    /// Returns whether the scalar is in the range [0, modulus), such as a value reduced modulo
+    /// the group order. Always false if the modulus is not positive.
+    pub fn is_valid(&self, modulus: &BigInt) -> bool {
+        in_range(&self.0, modulus)
+    }
+
+    /// Returns a reference to the inner value.
+    pub fn value(&self) -> &BigInt {
+        &self.0
+    }
+
+    /// Returns whether the scalar equals the other in constant time, unlike `==`, which exits
+    /// early at the first differing limb.
+    pub fn ct_eq(&self, other: &Scalar) -> Choice {
+        ct_eq_bigint(&self.0, &other.0)
+    }
+
+    /// Returns the inner value.
+    pub fn into_inner(self) -> BigInt {
+        self.0
+    }
+
+    /// Encodes the scalar as standard, padded base64 of its big-endian bytes.
+    /// # Errors
+    /// * Returns an error if the scalar is negative.
+    pub fn to_base64(&self) -> Result<String, ConversionError> {
+        encode_base64(&self.0, &general_purpose::STANDARD)
+    }
+
+    /// Decodes a scalar from standard, padded base64 of its big-endian bytes.
+    /// # Errors
+    /// * Returns an error if the value is empty or not valid base64.
+    pub fn from_base64(encoded: &str) -> Result<Self, ConversionError> {
+        decode_base64(encoded, &general_purpose::STANDARD).map(Scalar)
+    }
+
+    /// Encodes the scalar as base64url without padding of its big-endian bytes, as used by web
+    /// APIs such as WebAuthn.
+    /// # Errors
+    /// * Returns an error if the scalar is negative.
+    pub fn to_base64url(&self) -> Result<String, ConversionError> {
+        encode_base64(&self.0, &BASE64URL)
+    }
+
+    /// Decodes a scalar from base64url of its big-endian bytes, with or without padding.
+    /// # Errors
+    /// * Returns an error if the value is empty or not valid base64url.
+    pub fn from_base64url(encoded: &str) -> Result<Self, ConversionError> {
+        decode_base64(encoded, &BASE64URL).map(Scalar)
+    }
+
+    /// Returns the least non-negative residue of the scalar modulo the given positive modulus, in
+    /// the range [0, modulus). Unlike `%`, the result is never negative, since the modulus is added
+    /// back to a negative remainder, so it is canonical for negative values such as `k - c * x`.
+    /// # Panics
+    /// * Panics if the modulus is zero.
+    pub fn rem_euclid(&self, modulus: &Scalar) -> Scalar {
+        Scalar(arithmetic::reduce(&self.0, &modulus.0))
+    }
+
+    /// Returns the scalar raised to the given exponent modulo the given positive modulus, in the
+    /// range [0, modulus), with the backend selected by the enabled features. A negative scalar is
+    /// reduced into the range first.
+    /// # Panics
+    /// * Panics if the exponent is negative or the modulus is zero.
+    pub fn modpow(&self, exponent: &Scalar, modulus: &Scalar) -> Scalar {
+        let base = self.rem_euclid(modulus);
+        Scalar(arithmetic::modpow(&base.0, &exponent.0, &modulus.0))
+    }
+
+    /// Returns the multiplicative inverse of the scalar modulo the given modulus, in the range
+    /// [0, modulus), using the extended Euclidean algorithm. Returns `None` if the modulus is not
+    /// positive, or if the scalar and modulus are not coprime so no inverse exists.
+    pub fn mod_inverse(&self, modulus: &Scalar) -> Option<Scalar> {
+        let m = &modulus.0;
+        if *m <= BigInt::zero() {
+            return None;
+        }
+
+        // Invariants: old_r = old_s * a (mod m), r = s * a (mod m).
+        let (mut old_r, mut r) = (self.rem_euclid(modulus).0, m.clone());
+        let (mut old_s, mut s) = (BigInt::one(), BigInt::zero());
+        while !r.is_zero() {
+            let quotient = &old_r / &r;
+            let next_r = &old_r - &quotient * &r;
+            old_r = std::mem::replace(&mut r, next_r);
+            let next_s = &old_s - &quotient * &s;
+            old_s = std::mem::replace(&mut s, next_s);
+        }
+
+        if !old_r.is_one() {
+            return None;
+        }
+        Some(Scalar(old_s).rem_euclid(modulus))
+    }
+}
+
+impl Element {
+    /// Creates an element from the given value, checking that it is in the range [0, modulus).
+    /// # Errors
+    /// * Returns an error if the modulus is not positive.
+    /// * Returns an error if the value is negative or not less than the modulus.
+    pub fn new_checked(value: BigInt, modulus: &BigInt) -> Result<Self, ConversionError> {
+        if !in_range(&value, modulus) {
+            return Err(ConversionError);
+        }
+        Ok(Element(value))
+    }
+
+    /// Returns whether the element is in the range [0, modulus), such as a value reduced modulo
+    /// the group's prime. Always false if the modulus is not positive.
+    pub fn is_valid(&self, modulus: &BigInt) -> bool {
+        in_range(&self.0, modulus)
+    }
+
+    /// Returns a reference to the inner value.
+    pub fn value(&self) -> &BigInt {
+        &self.0
+    }
+
+    /// Returns whether the element equals the other in constant time, unlike `==`, which exits
+    /// early at the first differing limb. Verifiers compare the recomputed commitments with it.
+    pub fn ct_eq(&self, other: &Element) -> Choice {
+        ct_eq_bigint(&self.0, &other.0)
+    }
+
+    /// Returns the inner value.
+    pub fn into_inner(self) -> BigInt {
+        self.0
+    }
+
+    /// Encodes the element as standard, padded base64 of its big-endian bytes.
+    /// # Errors
+    /// * Returns an error if the element is negative.
+    pub fn to_base64(&self) -> Result<String, ConversionError> {
+        encode_base64(&self.0, &general_purpose::STANDARD)
+    }
+
+    /// Decodes an element from standard, padded base64 of its big-endian bytes.
+    /// # Errors
+    /// * Returns an error if the value is empty or not valid base64.
+    pub fn from_base64(encoded: &str) -> Result<Self, ConversionError> {
+        decode_base64(encoded, &general_purpose::STANDARD).map(Element)
+    }
+
+    /// Encodes the element as base64url without padding of its big-endian bytes, as used by web
+    /// APIs such as WebAuthn.
+    /// # Errors
+    /// * Returns an error if the element is negative.
+    pub fn to_base64url(&self) -> Result<String, ConversionError> {
+        encode_base64(&self.0, &BASE64URL)
+    }
+
+    /// Decodes an element from base64url of its big-endian bytes, with or without padding.
+    /// # Errors
+    /// * Returns an error if the value is empty or not valid base64url.
+    pub fn from_base64url(encoded: &str) -> Result<Self, ConversionError> {
+        decode_base64(encoded, &BASE64URL).map(Element)
+    }
 }
 
 /// Converts a BigInt to a Scalar.
@@ -112,6 +638,13 @@ impl fmt::Display for Scalar {
     }
 }
 
+/// Debugs a Scalar without its value, which may be a secret.
+impl fmt::Debug for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Scalar(<redacted>)")
+    }
+}
+
 /// Converts a string to an Element.
 impl FromStr for Element {
     type Err = ConversionError;
@@ -140,7 +673,7 @@ impl Add for Scalar {
 }
 
 /// Implements the Add trait for &Scalar.
-impl<'a> Add for &'a Scalar {
+impl Add for &Scalar {
     type Output = Scalar;
 
     fn add(self, other: Self) -> Self::Output {
@@ -158,7 +691,7 @@ impl Mul for Scalar {
 }
 
 /// Implements the Mul trait for &Scalar.
-impl<'a> Mul for &'a Scalar {
+impl Mul for &Scalar {
     type Output = Scalar;
 
     fn mul(self, other: Self) -> Self::Output {
@@ -176,7 +709,7 @@ impl Add for Element {
 }
 
 /// Implements the Add trait for &Element.
-impl<'a> Add for &'a Element {
+impl Add for &Element {
     type Output = Element;
 
     fn add(self, other: Self) -> Self::Output {
@@ -194,7 +727,7 @@ impl Mul for Element {
 }
 
 /// Implements the Mul trait for &Element.
-impl<'a> Mul for &'a Element {
+impl Mul for &Element {
     type Output = Element;
 
     fn mul(self, other: Self) -> Self::Output {
@@ -237,3 +770,372 @@ impl One for Element {
         Element(BigInt::one())
     }
 }
+
+/// Wipes a scalar, such as a secret x or k, by overwriting its digits with zeros before setting it
+/// to zero. num-bigint does not expose its digits mutably, so the zeros are written through
+/// `assign_from_slice`, which reuses the digit buffer. Copies made earlier, such as with `clone`,
+/// are not wiped.
+impl Zeroize for Scalar {
+    fn zeroize(&mut self) {
+        let digits = self.0.iter_u32_digits().len();
+        self.0.assign_from_slice(Sign::Plus, &vec![0; digits]);
+        self.0.set_zero();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::RandBigInt;
+
+    #[test]
+    fn scalar_debug_is_redacted() {
+        let scalar = Scalar::from(BigInt::from(123456789));
+        assert_eq!(format!("{:?}", scalar), "Scalar(<redacted>)");
+        assert_eq!(scalar.to_string(), "123456789");
+    }
+
+    #[test]
+    fn scalar_new_checked_succeeds_in_range() {
+        let modulus = BigInt::from(11);
+        let scalar = Scalar::new_checked(BigInt::from(10), &modulus).unwrap();
+        assert_eq!(scalar.value(), &BigInt::from(10));
+        assert_eq!(scalar.into_inner(), BigInt::from(10));
+        assert!(Scalar::new_checked(BigInt::zero(), &modulus).is_ok());
+    }
+
+    #[test]
+    fn scalar_new_checked_rejects_out_of_range() {
+        let modulus = BigInt::from(11);
+        assert!(Scalar::new_checked(BigInt::from(11), &modulus).is_err());
+        assert!(Scalar::new_checked(BigInt::from(12), &modulus).is_err());
+        assert!(Scalar::new_checked(BigInt::from(-1), &modulus).is_err());
+        assert!(Scalar::new_checked(BigInt::one(), &BigInt::zero()).is_err());
+    }
+
+    #[test]
+    fn element_new_checked_succeeds_in_range() {
+        let modulus = BigInt::from(23);
+        let element = Element::new_checked(BigInt::from(4), &modulus).unwrap();
+        assert_eq!(element.value(), &BigInt::from(4));
+        assert_eq!(element.into_inner(), BigInt::from(4));
+    }
+
+    #[test]
+    fn element_new_checked_rejects_out_of_range() {
+        let modulus = BigInt::from(23);
+        assert!(Element::new_checked(BigInt::from(23), &modulus).is_err());
+        assert!(Element::new_checked(BigInt::from(-4), &modulus).is_err());
+        assert!(Element::new_checked(BigInt::one(), &BigInt::from(-23)).is_err());
+    }
+
+    #[test]
+    fn is_valid_boundary_values() {
+        let p = BigInt::from(23);
+        for (value, valid) in [(0, true), (22, true), (23, false), (24, false), (-1, false)] {
+            let value = BigInt::from(value);
+            assert_eq!(Scalar(value.clone()).is_valid(&p), valid, "{}", value);
+            assert_eq!(Element(value.clone()).is_valid(&p), valid, "{}", value);
+        }
+    }
+
+    #[test]
+    fn is_valid_rejects_non_positive_modulus() {
+        for modulus in [BigInt::zero(), BigInt::from(-23)] {
+            assert!(!Scalar::zero().is_valid(&modulus));
+            assert!(!Element::zero().is_valid(&modulus));
+        }
+    }
+
+    #[test]
+    fn from_bigint_is_unchecked() {
+        let scalar: Scalar = BigInt::from(-1).into();
+        assert_eq!(scalar.value(), &BigInt::from(-1));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_uses_decimal_strings() -> Result<()> {
+        let scalar = Scalar(BigInt::from(-42));
+        let element = Element((BigInt::one() << 130) + 7);
+        assert_eq!(serde_json::to_string(&scalar)?, "\"-42\"");
+        assert_eq!(serde_json::to_string(&element)?, format!("\"{}\"", element));
+        assert_eq!(serde_json::from_str::<Scalar>("\"-42\"")?, scalar);
+        assert_eq!(
+            serde_json::from_str::<Element>(&serde_json::to_string(&element)?)?,
+            element
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_invalid_decimal_strings() {
+        for invalid in ["\"\"", "\"0x2a\"", "\"4 2\"", "42"] {
+            assert!(
+                serde_json::from_str::<Scalar>(invalid).is_err(),
+                "{}",
+                invalid
+            );
+            assert!(
+                serde_json::from_str::<Element>(invalid).is_err(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn ct_eq_agrees_with_eq() {
+        let mut rng = rand::thread_rng();
+        let mut values: Vec<BigInt> = (0..32).map(|i| rng.gen_bigint(1 + (i % 4) * 64)).collect();
+        values.extend([
+            BigInt::zero(),
+            BigInt::one(),
+            -BigInt::one(),
+            BigInt::from(256),
+            BigInt::one() << 64,
+        ]);
+
+        for a in &values {
+            // Equal values, including separately constructed copies.
+            let copy = BigInt::from_signed_bytes_le(&a.to_signed_bytes_le());
+            assert!(bool::from(Element(a.clone()).ct_eq(&Element(copy.clone()))));
+            assert!(bool::from(Scalar(a.clone()).ct_eq(&Scalar(copy))));
+
+            for b in &values {
+                assert_eq!(
+                    bool::from(Element(a.clone()).ct_eq(&Element(b.clone()))),
+                    a == b,
+                    "{} == {}",
+                    a,
+                    b
+                );
+                assert_eq!(
+                    bool::from(Scalar(a.clone()).ct_eq(&Scalar(b.clone()))),
+                    a == b,
+                    "{} == {}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ct_eq_distinguishes_sign_and_padding() {
+        let one = Element(BigInt::one());
+        assert!(!bool::from(one.ct_eq(&Element(-BigInt::one()))));
+        assert!(!bool::from(one.ct_eq(&Element(BigInt::from(257)))));
+        assert!(!bool::from(
+            Element::zero().ct_eq(&Element(BigInt::one() << 64))
+        ));
+    }
+
+    #[test]
+    fn scalar_zeroize_clears_bytes() {
+        for value in [
+            BigInt::from(42),
+            -BigInt::from(42),
+            (BigInt::one() << 255) - 19,
+            BigInt::zero(),
+        ] {
+            let mut scalar = Scalar(value);
+            scalar.zeroize();
+            assert!(scalar.is_zero());
+            assert_eq!(scalar.0.to_bytes_le(), (Sign::NoSign, vec![0]));
+            assert_eq!(scalar.0.iter_u32_digits().len(), 0);
+        }
+    }
+
+    #[test]
+    fn scalar_rem_euclid_known_values() {
+        for (value, modulus, residue) in [
+            (7, 5, 2),
+            (-7, 5, 3),
+            (-5, 5, 0),
+            (-1, 11, 10),
+            (0, 11, 0),
+            (4, 11, 4),
+            (-23, 1, 0),
+        ] {
+            assert_eq!(
+                Scalar(BigInt::from(value)).rem_euclid(&Scalar(BigInt::from(modulus))),
+                Scalar(BigInt::from(residue)),
+                "{} mod {}",
+                value,
+                modulus
+            );
+        }
+    }
+
+    #[test]
+    fn scalar_rem_euclid_matches_compute_s_fix_up() {
+        // s = k - c * x mod q is negative before the fix-up whenever c * x > k.
+        let q = Scalar(BigInt::from(11));
+        let (k, c, x) = (BigInt::from(3), BigInt::from(5), BigInt::from(7));
+        let s = Scalar(k - c * x).rem_euclid(&q);
+        assert_eq!(s, Scalar(BigInt::from(1)));
+        assert!(s.is_valid(&q.0));
+    }
+
+    #[test]
+    fn scalar_modpow_known_values() {
+        for (base, exponent, modulus, result) in [
+            (4, 3, 23, 18),
+            (9, 5, 23, 8),
+            (2, 10, 1000, 24),
+            (-4, 3, 23, 5),
+            (-1, 2, 7, 1),
+            (27, 1, 23, 4),
+        ] {
+            assert_eq!(
+                Scalar(BigInt::from(base)).modpow(
+                    &Scalar(BigInt::from(exponent)),
+                    &Scalar(BigInt::from(modulus))
+                ),
+                Scalar(BigInt::from(result)),
+                "{}^{} mod {}",
+                base,
+                exponent,
+                modulus
+            );
+        }
+    }
+
+    #[test]
+    fn scalar_modpow_zero_exponent() {
+        let zero = Scalar::zero();
+        for base in [0, 1, 4, -4, 22] {
+            assert_eq!(
+                Scalar(BigInt::from(base)).modpow(&zero, &Scalar(BigInt::from(23))),
+                Scalar::one(),
+                "{}^0 mod 23",
+                base
+            );
+        }
+        // Everything is 0 mod 1, including x^0.
+        assert_eq!(
+            Scalar(BigInt::from(4)).modpow(&zero, &Scalar::one()),
+            Scalar::zero()
+        );
+    }
+
+    #[test]
+    fn scalar_mod_inverse_known_values() {
+        for (value, modulus, inverse) in [
+            (3, 11, 4),
+            (10, 17, 12),
+            (7, 26, 15),
+            (1, 13, 1),
+            (-3, 11, 7),
+            (14, 11, 4),
+            (5, 1, 0),
+        ] {
+            let scalar = Scalar(BigInt::from(value));
+            assert_eq!(
+                scalar.mod_inverse(&Scalar(BigInt::from(modulus))),
+                Some(Scalar(BigInt::from(inverse))),
+                "inverse of {} mod {}",
+                value,
+                modulus
+            );
+        }
+    }
+
+    #[test]
+    fn scalar_mod_inverse_large_prime() {
+        // 2^127 - 1 is prime, so every non-zero value has an inverse.
+        let modulus = Scalar((BigInt::one() << 127) - 1);
+        let scalar = Scalar(BigInt::from(123456789u64));
+        let inverse = scalar.mod_inverse(&modulus).unwrap();
+        assert_eq!((&scalar * &inverse).0 % &modulus.0, BigInt::one());
+    }
+
+    #[test]
+    fn scalar_mod_inverse_non_invertible() {
+        let modulus = Scalar(BigInt::from(12));
+        assert_eq!(Scalar(BigInt::from(4)).mod_inverse(&modulus), None);
+        assert_eq!(Scalar(BigInt::from(0)).mod_inverse(&modulus), None);
+        assert_eq!(Scalar(BigInt::from(24)).mod_inverse(&modulus), None);
+        assert_eq!(
+            Scalar(BigInt::from(3)).mod_inverse(&Scalar(BigInt::zero())),
+            None
+        );
+        assert_eq!(
+            Scalar(BigInt::from(3)).mod_inverse(&Scalar(BigInt::from(-7))),
+            None
+        );
+    }
+
+    #[test]
+    fn scalars_and_elements_as_hash_set_keys() {
+        use std::collections::HashSet;
+
+        let mut scalars = HashSet::new();
+        assert!(scalars.insert(Scalar(BigInt::from(7))));
+        assert!(scalars.insert(Scalar(BigInt::from(8))));
+        assert!(!scalars.insert("7".parse::<Scalar>().unwrap()));
+        assert_eq!(scalars.len(), 2);
+
+        let mut elements = HashSet::new();
+        assert!(elements.insert(Element(BigInt::from(7))));
+        assert!(elements.insert(Element(BigInt::from(-7))));
+        assert!(!elements.insert(Element::from(BigInt::from(7))));
+        assert_eq!(elements.len(), 2);
+        assert!(elements.contains(&Element(BigInt::from(-7))));
+
+        // Values are not reduced, so non-canonical representations are distinct keys.
+        assert!(scalars.insert(Scalar(BigInt::from(7 + 11))));
+    }
+
+    #[test]
+    fn base64url_round_trip() {
+        // Values of 1 to 5 bytes, so every padding length is exercised.
+        for value in [0u64, 0xff, 0x1234, 0x123456, 0x12345678, 0x123456789a] {
+            let scalar = Scalar(BigInt::from(value));
+            let encoded = scalar.to_base64url().unwrap();
+            assert!(!encoded.contains('='));
+            assert_eq!(Scalar::from_base64url(&encoded).unwrap(), scalar);
+
+            let element = Element(BigInt::from(value));
+            let encoded = element.to_base64url().unwrap();
+            assert_eq!(Element::from_base64url(&encoded).unwrap(), element);
+        }
+    }
+
+    #[test]
+    fn base64_round_trip() {
+        for value in [0u64, 0xff, 0x1234, 0x123456, 0x12345678, 0x123456789a] {
+            let scalar = Scalar(BigInt::from(value));
+            let encoded = scalar.to_base64().unwrap();
+            assert_eq!(encoded.len() % 4, 0);
+            assert_eq!(Scalar::from_base64(&encoded).unwrap(), scalar);
+
+            let element = Element(BigInt::from(value));
+            let encoded = element.to_base64().unwrap();
+            assert_eq!(Element::from_base64(&encoded).unwrap(), element);
+        }
+    }
+
+    #[test]
+    fn base64url_uses_big_endian_bytes_and_url_alphabet() {
+        let scalar = Scalar(BigInt::from(0xfbffu32));
+        assert_eq!(scalar.to_base64url().unwrap(), "-_8");
+        assert_eq!(scalar.to_base64().unwrap(), "+/8=");
+
+        let element = Element(BigInt::from(0x1234u32));
+        assert_eq!(element.to_base64url().unwrap(), "EjQ");
+        assert_eq!(Element::from_base64url("EjQ=").unwrap(), element);
+    }
+
+    #[test]
+    fn base64_rejects_invalid_values() {
+        assert!(Scalar(BigInt::from(-1)).to_base64url().is_err());
+        assert!(Element(BigInt::from(-1)).to_base64().is_err());
+        assert!(Scalar::from_base64url("").is_err());
+        assert!(Scalar::from_base64url("+/8").is_err());
+        assert!(Element::from_base64("-_8=").is_err());
+        assert!(Element::from_base64("EjQ").is_err());
+    }
+}
@@ -0,0 +1,155 @@
+//! Tests of non-interactive proofs across flavors.
+//!
+//! The prover derives c from the hash of g, h, y1, y2, r1, r2, the user, and the timestamp, and
+//! the verifier re-derives it from the same values, so a proof only verifies if none of them, nor
+//! s, was changed.
+
+use anyhow::Result;
+use num_traits::One;
+
+use crate::{ProofBinding, Prover, Scalar, Verifier};
+
+/// Returns a binding to the user peggy at the given time.
+fn binding(timestamp: u64) -> ProofBinding {
+    ProofBinding {
+        user: "peggy".to_string(),
+        timestamp,
+    }
+}
+
+/// Asserts that a proof computed by the prover verifies, and that it no longer does once its s,
+/// its commitment, its registration, its user, or its timestamp is tampered with.
+fn assert_noninteractive_proof_verifies(
+    prover: &dyn Prover,
+    verifier: &dyn Verifier,
+) -> Result<()> {
+    let x = prover.compute_registration_x("password".to_string());
+    let proof = prover.compute_noninteractive_proof(&x, binding(1000))?;
+    assert!(verifier.verify_noninteractive(&proof)?);
+
+    // Presenting the proof for another user, or as computed at another time, changes the derived
+    // c, so the response no longer answers it.
+    let mut tampered = proof.clone();
+    tampered.binding.user = "victor".to_string();
+    assert!(!verifier.verify_noninteractive(&tampered)?);
+
+    let mut tampered = proof.clone();
+    tampered.binding.timestamp += 1;
+    assert!(!verifier.verify_noninteractive(&tampered)?);
+
+    let mut tampered = proof.clone();
+    tampered.s = tampered.s + Scalar::one();
+    assert!(!verifier.verify_noninteractive(&tampered)?);
+
+    // Swapping in the commitment of another proof changes the derived c, so the response no
+    // longer answers it.
+    let other =
+        prover.compute_noninteractive_proof(&prover.generate_registration_x(), binding(1000))?;
+    let mut tampered = proof.clone();
+    tampered.context.r1 = other.context.r1.clone();
+    tampered.context.r2 = other.context.r2.clone();
+    assert!(!verifier.verify_noninteractive(&tampered)?);
+
+    let mut tampered = proof;
+    tampered.context.y1 = other.context.y1;
+    tampered.context.y2 = other.context.y2;
+    assert!(!verifier.verify_noninteractive(&tampered)?);
+
+    Ok(())
+}
+
+/// Asserts that the prover and the verifier derive the same challenge for a context and binding,
+/// and that it depends on both.
+fn assert_noninteractive_challenge_is_derived_from_context(
+    prover: &dyn Prover,
+    verifier: &dyn Verifier,
+) -> Result<()> {
+    let proof =
+        prover.compute_noninteractive_proof(&prover.generate_registration_x(), binding(1000))?;
+    let c = prover.derive_noninteractive_challenge_c(&proof.context, &proof.binding);
+    assert_eq!(
+        verifier.derive_noninteractive_challenge_c(&proof.context, &proof.binding),
+        c
+    );
+
+    // The user is length-prefixed, so moving bytes between it and the timestamp changes c.
+    let other = ProofBinding {
+        user: "pegg".to_string(),
+        timestamp: proof.binding.timestamp,
+    };
+    assert_ne!(
+        verifier.derive_noninteractive_challenge_c(&proof.context, &other),
+        c
+    );
+    assert_ne!(
+        verifier.derive_noninteractive_challenge_c(&proof.context, &binding(1001)),
+        c
+    );
+
+    let mut context = proof.context;
+    context.r1 = context.r2.clone();
+    assert_ne!(
+        verifier.derive_noninteractive_challenge_c(&context, &proof.binding),
+        c
+    );
+    Ok(())
+}
+
+#[cfg(feature = "discrete_logarithm")]
+mod discrete_logarithm {
+    use super::*;
+    use crate::discrete_logarithm::test::test_prover_and_verifier;
+
+    #[test]
+    fn noninteractive_proof_verifies() -> Result<()> {
+        let (prover, verifier) = test_prover_and_verifier();
+        assert_noninteractive_proof_verifies(&prover, &verifier)
+    }
+
+    #[test]
+    fn noninteractive_challenge_is_derived_from_context() -> Result<()> {
+        let (prover, verifier) = test_prover_and_verifier();
+        assert_noninteractive_challenge_is_derived_from_context(&prover, &verifier)
+    }
+}
+
+#[cfg(feature = "elliptic_curve")]
+mod elliptic_curve {
+    use super::*;
+    use crate::elliptic_curve::curve::{Ristretto, Secp256k1};
+    use crate::elliptic_curve::test::test_prover_and_verifier_on;
+
+    #[test]
+    fn ristretto_noninteractive_proof_verifies() -> Result<()> {
+        let (prover, verifier) = test_prover_and_verifier_on::<Ristretto>();
+        assert_noninteractive_proof_verifies(&prover, &verifier)
+    }
+
+    #[test]
+    fn ristretto_noninteractive_challenge_is_derived_from_context() -> Result<()> {
+        let (prover, verifier) = test_prover_and_verifier_on::<Ristretto>();
+        assert_noninteractive_challenge_is_derived_from_context(&prover, &verifier)
+    }
+
+    #[test]
+    fn secp256k1_noninteractive_proof_verifies() -> Result<()> {
+        let (prover, verifier) = test_prover_and_verifier_on::<Secp256k1>();
+        assert_noninteractive_proof_verifies(&prover, &verifier)
+    }
+
+    #[test]
+    fn secp256k1_noninteractive_challenge_is_derived_from_context() -> Result<()> {
+        let (prover, verifier) = test_prover_and_verifier_on::<Secp256k1>();
+        assert_noninteractive_challenge_is_derived_from_context(&prover, &verifier)
+    }
+
+    #[test]
+    fn secp256k1_proof_does_not_verify_on_ristretto() -> Result<()> {
+        let (prover, _) = test_prover_and_verifier_on::<Secp256k1>();
+        let (_, verifier) = test_prover_and_verifier_on::<Ristretto>();
+        let proof = prover
+            .compute_noninteractive_proof(&prover.generate_registration_x(), binding(1000))?;
+        assert!(verifier.verify_noninteractive(&proof).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+//! A verifier that accepts any proof, for load testing the layers around verification without its
+//! cryptographic cost. It must never be used to authenticate real users.
+
+use anyhow::Result;
+
+use crate::{ChallengeContext, Element, ProofBinding, Scalar, Verifier};
+
+/// A verifier that accepts any proof. Challenges are generated by the wrapped verifier, so clients
+/// of its flavor can run the protocol unchanged.
+pub struct NullVerifier {
+    inner: Box<dyn Verifier>,
+}
+
+/// Implementation of the null verifier.
+impl NullVerifier {
+    /// Creates a null verifier generating challenges with the given verifier.
+    pub fn new(inner: Box<dyn Verifier>) -> Self {
+        NullVerifier { inner }
+    }
+}
+
+/// Implementation of the verifier trait for the null verifier.
+impl Verifier for NullVerifier {
+    /// Generates a challenge c value with the wrapped verifier.
+    fn generate_challenge_c(&self) -> Scalar {
+        self.inner.generate_challenge_c()
+    }
+
+    /// Generates a challenge c value for the context with the wrapped verifier.
+    fn generate_challenge_c_for(&self, context: &ChallengeContext) -> Scalar {
+        self.inner.generate_challenge_c_for(context)
+    }
+
+    /// Derives a non-interactive challenge c value with the wrapped verifier.
+    fn derive_noninteractive_challenge_c(
+        &self,
+        context: &ChallengeContext,
+        binding: &ProofBinding,
+    ) -> Scalar {
+        self.inner
+            .derive_noninteractive_challenge_c(context, binding)
+    }
+
+    /// Computes verification r1' and r2' values with the wrapped verifier. They are not used to
+    /// verify proofs, which are always accepted.
+    fn compute_verification_r1r2(
+        &self,
+        y1: Element,
+        y2: Element,
+        c: Scalar,
+        s: Scalar,
+    ) -> Result<(Element, Element)> {
+        self.inner.compute_verification_r1r2(y1, y2, c, s)
+    }
+
+    /// Accepts any proof without computing anything.
+    fn verify(
+        &self,
+        _y1: Element,
+        _y2: Element,
+        _r1: &Element,
+        _r2: &Element,
+        _c: Scalar,
+        _s: Scalar,
+    ) -> Result<bool> {
+        Ok(true)
+    }
+
+    /// Returns zero, since any proof is accepted.
+    fn security_level(&self) -> u32 {
+        0
+    }
+}
+
+#[cfg(all(test, feature = "discrete_logarithm"))]
+mod tests {
+    use super::*;
+    use crate::discrete_logarithm::{
+        configuration::DiscreteLogarithmConfiguration, verifier::DiscreteLogarithmVerifier,
+    };
+    use num_traits::{One, Zero};
+
+    #[test]
+    fn accepts_any_proof() -> Result<()> {
+        let verifier = NullVerifier::new(Box::new(DiscreteLogarithmVerifier::new(
+            DiscreteLogarithmConfiguration::generate(16),
+        )));
+        assert!(verifier.verify(
+            Element::one(),
+            Element::one(),
+            &Element::zero(),
+            &Element::one(),
+            verifier.generate_challenge_c(),
+            Scalar::one(),
+        )?);
+        assert_eq!(verifier.security_level(), 0);
+        Ok(())
+    }
+}
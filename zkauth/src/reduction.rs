@@ -0,0 +1,152 @@
+//! Tests of where the protocol reduces its arithmetic.
+//!
+//! `Scalar` and `Element` arithmetic never reduces on its own. Each protocol reduces explicitly
+//! where the math requires it:
+//!
+//! * Discrete logarithm: `y = g^x mod p` and `r = g^k mod p` reduce mod p, the response
+//!   `s = k - c * x` reduces into [0, q), and the verifier reduces s mod q and
+//!   `r' = g^s * y^c` mod p.
+//! * Elliptic curve: the response `s = k + c * x` reduces mod the group order, since it is
+//!   computed with dalek scalars, and every point is canonical by construction.
+//!
+//! The tests use known values so that a missing reduction shows up as a wrong value rather than
+//! only as a failed round trip.
+
+use num_bigint::BigInt;
+
+use crate::Scalar;
+
+#[test]
+fn scalar_arithmetic_does_not_reduce() {
+    let q = BigInt::from(11);
+    let sum = Scalar::from(BigInt::from(7)) + Scalar::from(BigInt::from(8));
+    let product = Scalar::from(BigInt::from(7)) * Scalar::from(BigInt::from(8));
+    assert_eq!(sum, Scalar::from(BigInt::from(15)));
+    assert_eq!(product, Scalar::from(BigInt::from(56)));
+    assert!(sum.value() > &q);
+}
+
+#[cfg(feature = "discrete_logarithm")]
+mod discrete_logarithm {
+    use super::*;
+    use crate::discrete_logarithm::{
+        configuration::DiscreteLogarithmConfiguration, prover::DiscreteLogarithmProver,
+        verifier::DiscreteLogarithmVerifier,
+    };
+    use crate::{Element, Prover, Verifier};
+
+    /// The subgroup of order 11 of the integers mod 23, generated by 4 and 9.
+    fn small_config() -> DiscreteLogarithmConfiguration {
+        DiscreteLogarithmConfiguration {
+            p: 23.into(),
+            q: 11.into(),
+            g: 4.into(),
+            h: 9.into(),
+        }
+    }
+
+    fn scalar(value: i64) -> Scalar {
+        BigInt::from(value).into()
+    }
+
+    fn element(value: i64) -> Element {
+        BigInt::from(value).into()
+    }
+
+    #[test]
+    fn registration_and_commitment_reduce_mod_p() {
+        let prover = DiscreteLogarithmProver::new(small_config());
+
+        // 4^7 = 16384 = 8 mod 23 and 9^7 = 4782969 = 4 mod 23.
+        let (y1, y2) = prover.compute_registration_y1y2(&scalar(7)).unwrap();
+        assert_eq!((y1, y2), (element(8), element(4)));
+
+        // 4^3 = 64 = 18 mod 23 and 9^3 = 729 = 16 mod 23.
+        let (r1, r2) = prover
+            .compute_challenge_commitment_r1r2(&scalar(3))
+            .unwrap();
+        assert_eq!((r1, r2), (element(18), element(16)));
+    }
+
+    #[test]
+    fn response_is_k_minus_cx_reduced_into_q() {
+        let prover = DiscreteLogarithmProver::new(small_config());
+
+        // 3 - 5 * 7 = -32 = 1 mod 11, where the remainder operator alone would give -10.
+        let s = prover
+            .compute_challenge_response_s(&scalar(7), &scalar(3), scalar(5))
+            .unwrap();
+        assert_eq!(s, scalar(1));
+
+        // A response that is already in range is unchanged.
+        let s = prover
+            .compute_challenge_response_s(&scalar(1), &scalar(9), scalar(2))
+            .unwrap();
+        assert_eq!(s, scalar(7));
+    }
+
+    #[test]
+    fn verification_reduces_mod_p() {
+        let verifier = DiscreteLogarithmVerifier::new(small_config());
+
+        // 4^1 * 8^5 = 18 mod 23 and 9^1 * 4^5 = 16 mod 23, which are r1 and r2 for k = 3.
+        let (r1, r2) = verifier
+            .compute_verification_r1r2(element(8), element(4), scalar(5), scalar(1))
+            .unwrap();
+        assert_eq!((r1, r2), (element(18), element(16)));
+    }
+
+    #[test]
+    fn verification_reduces_s_mod_q() {
+        let verifier = DiscreteLogarithmVerifier::new(small_config());
+        let expected = (element(18), element(16));
+
+        // s + q and s - q are congruent to s, so they verify the same, and a negative s does not
+        // reach the exponentiation.
+        for s in [1, 12, 1 + 11 * 100, -10] {
+            let r = verifier
+                .compute_verification_r1r2(element(8), element(4), scalar(5), scalar(s))
+                .unwrap();
+            assert_eq!(r, expected, "s = {}", s);
+        }
+    }
+}
+
+#[cfg(feature = "elliptic_curve")]
+mod elliptic_curve {
+    use super::*;
+    use crate::elliptic_curve::{
+        configuration::EllipticCurveConfiguration, prover::EllipticCurveProver,
+    };
+    use crate::Prover;
+    use curve25519_dalek::Scalar as DalekScalar;
+
+    /// The order of the ristretto255 group, 2^252 + 27742317777372353535851937790883648493.
+    fn group_order() -> BigInt {
+        (BigInt::from(1) << 252usize)
+            + "27742317777372353535851937790883648493"
+                .parse::<BigInt>()
+                .unwrap()
+    }
+
+    #[test]
+    fn response_is_k_plus_cx_reduced_mod_order() {
+        let prover = EllipticCurveProver::new(EllipticCurveConfiguration::generate());
+        let order = group_order();
+
+        // (order - 1) + 1 * 2 = order + 1 = 1 mod order.
+        let k: Scalar = (&order - 1u32).into();
+        let c: Scalar = BigInt::from(1).into();
+        let x: Scalar = BigInt::from(2).into();
+        let s = prover
+            .compute_challenge_response_s(&x, &k, c.clone())
+            .unwrap();
+        assert_eq!(s, BigInt::from(1).into());
+
+        // The same composition with plain scalar arithmetic is not reduced, and is not a
+        // canonical dalek scalar.
+        let unreduced = k + c * x;
+        assert_eq!(unreduced, (&order + 1u32).into());
+        assert!(DalekScalar::try_from(unreduced).is_err());
+    }
+}
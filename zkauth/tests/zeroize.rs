@@ -0,0 +1,171 @@
+//! Tests that wiping a scalar overwrites its digits, rather than only setting it to zero, and that
+//! the elliptic curve prover leaves no copies of secrets behind, by inspecting the buffers freed
+//! along the way. This needs a global allocator, so it lives in its own test binary.
+
+use num_bigint::{BigInt, Sign};
+use num_traits::Zero;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use zeroize::Zeroize;
+use zkauth::Scalar;
+
+thread_local! {
+    /// Whether buffers freed on this thread are inspected.
+    static INSPECTING: Cell<bool> = const { Cell::new(false) };
+
+    /// The number of inspected buffers, and how many of them still held non-zero bytes.
+    static FREED: Cell<(usize, usize)> = const { Cell::new((0, 0)) };
+
+    /// Bytes of a secret to look for in freed buffers, in either byte order.
+    static NEEDLE: Cell<Option<[u8; 8]>> = const { Cell::new(None) };
+
+    /// The number of freed buffers that held the needle.
+    static HOLDING: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The system allocator, inspecting the contents of buffers as they are freed.
+struct InspectingAllocator;
+
+unsafe impl GlobalAlloc for InspectingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if INSPECTING.try_with(Cell::get).unwrap_or(false) {
+            let bytes = std::slice::from_raw_parts(ptr, layout.size());
+            let dirty = bytes.iter().any(|byte| *byte != 0);
+            FREED.with(|freed| {
+                let (count, dirty_count) = freed.get();
+                freed.set((count + 1, dirty_count + dirty as usize));
+            });
+            if let Some(needle) = NEEDLE.with(Cell::get) {
+                let mut reversed = needle;
+                reversed.reverse();
+                if bytes
+                    .windows(needle.len())
+                    .any(|window| window == needle || window == reversed)
+                {
+                    HOLDING.with(|holding| holding.set(holding.get() + 1));
+                }
+            }
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: InspectingAllocator = InspectingAllocator;
+
+/// Runs the given function, and returns the number of buffers it freed and how many of them still
+/// held non-zero bytes.
+fn inspect_frees(f: impl FnOnce()) -> (usize, usize) {
+    FREED.with(|freed| freed.set((0, 0)));
+    INSPECTING.with(|inspecting| inspecting.set(true));
+    f();
+    INSPECTING.with(|inspecting| inspecting.set(false));
+    FREED.with(Cell::get)
+}
+
+/// Runs the given function, and returns the number of buffers it freed that still held bytes of
+/// the given secret, in either byte order.
+fn frees_holding(secret: &Scalar, f: impl FnOnce()) -> usize {
+    let (_, bytes) = secret.0.to_bytes_le();
+    NEEDLE.with(|needle| needle.set(Some(bytes[8..16].try_into().unwrap())));
+    HOLDING.with(|holding| holding.set(0));
+    inspect_frees(f);
+    NEEDLE.with(|needle| needle.set(None));
+    HOLDING.with(Cell::get)
+}
+
+/// A value with 512 bits set, so its digits are non-zero throughout.
+fn secret() -> BigInt {
+    BigInt::from_bytes_be(Sign::Plus, &[0xa5; 64])
+}
+
+#[test]
+fn zeroize_overwrites_scalar_digits() {
+    for value in [secret(), -secret()] {
+        let mut scalar = Scalar::from(value);
+        let (freed, dirty) = inspect_frees(|| {
+            scalar.zeroize();
+            assert!(scalar.is_zero());
+            drop(scalar);
+        });
+        assert!(freed > 0);
+        assert_eq!(dirty, 0);
+    }
+}
+
+#[test]
+fn set_zero_leaves_scalar_digits() {
+    // Setting a value to zero keeps its digits in the buffer, which the inspection catches.
+    let mut value = secret();
+    let (freed, dirty) = inspect_frees(|| {
+        value.set_zero();
+        drop(value);
+    });
+    assert_eq!((freed, dirty), (1, 1));
+}
+
+#[test]
+fn cloned_scalar_leaves_digits() {
+    // Dropping a clone of a secret frees a buffer holding it, which the inspection catches.
+    let value = Scalar::from(secret());
+    assert_eq!(frees_holding(&value, || drop(value.clone())), 1);
+}
+
+#[cfg(feature = "elliptic_curve")]
+#[test]
+fn elliptic_curve_prover_leaves_no_copies_of_secrets() {
+    use zkauth::elliptic_curve::configuration::EllipticCurveConfiguration;
+    use zkauth::elliptic_curve::curve::{Curve, Ristretto, Secp256k1};
+    use zkauth::elliptic_curve::prover::EllipticCurveProver;
+    use zkauth::Prover;
+
+    fn check<C: Curve>() {
+        let prover = EllipticCurveProver::new(EllipticCurveConfiguration::<C>::generate_on_curve());
+        let x = prover.generate_registration_x();
+        let k = prover.generate_challenge_k();
+        let c = prover.generate_challenge_k();
+
+        // Results are kept until after the inspection, so only the temporaries are freed.
+        let mut points = Vec::new();
+        let mut responses = Vec::new();
+        for secret in [&x, &k] {
+            let holding = frees_holding(secret, || {
+                assert!(prover.is_valid_registration_x(&x));
+                points.push(prover.compute_registration_y1y2(&x).unwrap());
+                points.push(prover.compute_challenge_commitment_r1r2(&k).unwrap());
+                responses.push(
+                    prover
+                        .compute_challenge_response_s(&x, &k, c.clone())
+                        .unwrap(),
+                );
+            });
+            assert_eq!(holding, 0, "{}", C::NAME);
+        }
+    }
+
+    check::<Ristretto>();
+    check::<Secp256k1>();
+}
+
+#[cfg(feature = "discrete_logarithm")]
+#[test]
+fn discrete_logarithm_password_derivation_leaves_no_copies_of_x() {
+    use zkauth::discrete_logarithm::configuration::{DiscreteLogarithmConfiguration, ModpGroup};
+    use zkauth::discrete_logarithm::prover::DiscreteLogarithmProver;
+    use zkauth::Prover;
+
+    let prover = DiscreteLogarithmProver::new(DiscreteLogarithmConfiguration::from_modp_group(
+        ModpGroup::Modp2048,
+    ));
+    let x = prover.compute_registration_x("password".to_string());
+    let mut derived = None;
+    let holding = frees_holding(&x, || {
+        derived = Some(prover.compute_registration_x("password".to_string()));
+    });
+    assert_eq!(derived, Some(x));
+    assert_eq!(holding, 0);
+}